@@ -4,28 +4,283 @@
 // Load the WebAssembly plugin
 #let energetics-plugin = plugin("energetium.wasm")
 
-// Load thermodynamic data
+// Load thermodynamic data (298.15 K; aqueous ions on the H+(aq) = 0 convention)
 #let thermo-data = json("data/Standard_E_formation.json")
 
+// Load standard reduction potentials (298.15 K)
+#let reduction-potential-data = json("data/Standard_reduction_potentials.json")
+
+// Load acid dissociation constants (298.15 K)
+#let acid-data = json("data/Acid_dissociation_constants.json")
+
+// Load solubility products (298.15 K)
+#let ksp-data = json("data/Solubility_products.json")
+
+// Load cryoscopic and ebullioscopic constants of solvents
+#let solvent-data = json("data/Colligative_constants.json")
+
+// Load complex formation constants (298.15 K)
+#let complex-data = json("data/Formation_constants.json")
+
+// Load van der Waals constants and critical properties of gases
+#let gas-data = json("data/Critical_constants.json")
+
+// Load biochemical standard free energies ΔG°′ (pH 7, 298.15 K)
+#let biochem-data = json("data/Biochemical_free_energies.json")
+
+// Load average bond enthalpies (kJ/mol, 298.15 K)
+#let bond-data = json("data/Bond_enthalpies.json")
+
+// Load heat capacities and phase-change data (1 atm)
+#let phase-data = json("data/Phase_change_data.json")
+
+// Load ionization energies and electron affinities (kJ/mol)
+#let ionization-data = json("data/Ionization_energies.json")
+
+// Load standard hydration enthalpies of gaseous ions (kJ/mol, 298.15 K)
+#let hydration-data = json("data/Hydration_enthalpies.json")
+
+// Load Antoine vapor-pressure coefficients of common solvents (mmHg, °C)
+#let antoine-data = json("data/Antoine_coefficients.json")
+
+// Load common names of substances ("water" → "H2O(l)")
+#let alias-data = json("data/Aliases.json")
+
+/// Decode a data table written as JSON, TOML or YAML
+///
+/// Every `data` parameter accepts either a dictionary (e.g. from `json()`, `toml()` or
+/// `yaml()`) or the table as text, which is decoded with this function. With `format: auto`
+/// text starting with `{` is read as JSON, text with `key:` mapping lines as YAML (even if a
+/// block scalar contains `key = value`), text with `[table]` headers or `key = value` lines
+/// as TOML, and anything else as YAML. Pass `format` for text that fits neither pattern.
+///
+/// Arguments:
+/// - source: Table text or bytes, e.g. `read("my-data.toml")`
+/// - format: "json", "toml", "yaml" or auto (default: auto)
+///
+/// Returns: The table as a dictionary
+///
+/// Example:
+/// ```typst
+/// #let my-data = load-data("[\"X(g)\"]\ndelta_Hf = 10\nS = 200\ndelta_Gf = 5\n")
+/// #get-substance-data("X(g)", data: my-data).S // 200
+/// ```
+/// -> dict
+#let load-data(source, format: auto) = {
+  let text = str(source)
+  let format = if format != auto {
+    format
+  } else if text.trim().starts-with("{") {
+    "json"
+  } else if text.contains(regex("(?m)^\\s*(\"[^\"]*\"|'[^']*'|(-\\S|[^\\s\\[\\]{}#\"'=:-])[^=:#]*?)\\s*:(\\s|$)")) {
+    "yaml"
+  } else if text.contains(regex("(?m)^\\s*(\\[[^\\]]+\\]|(\"[^\"]*\"|[\\w.-]+)\\s*=)")) {
+    "toml"
+  } else {
+    "yaml"
+  }
+  
+  if format == "json" {
+    json.decode(text)
+  } else if format == "toml" {
+    toml.decode(text)
+  } else if format == "yaml" {
+    yaml.decode(text)
+  } else {
+    panic("Unknown data format: " + format + " (expected json, toml or yaml)")
+  }
+}
+
+/// Merge data tables with explicit precedence
+///
+/// Entries are merged whole (not field by field). With `precedence: "last"` later tables
+/// override earlier ones, as with `+`; with `"first"` earlier tables win. Entries that the
+/// tables define differently are reported, so a textbook appendix combined with custom
+/// species shows exactly which values were replaced. Tables may also be given as text (see
+/// `load-data`), and every `data` parameter accepts an array of tables, merged with
+/// `precedence: "last"`.
+///
+/// Arguments:
+/// - tables: Tables in order, positional or named (names label the conflict report)
+/// - precedence: "last" or "first" (default: "last")
+///
+/// Returns: Dictionary with the merged `data`, `conflicts` (`key`, `sources`, `used`) and
+/// `counts` (entries taken from each table)
+///
+/// Example:
+/// ```typst
+/// #let merged = merge-data(textbook: thermo-data, custom: ("H2O(l)": (delta_Hf: -285.8, S: 70.0, delta_Gf: -237.1)))
+/// #merged.conflicts.first().used // "custom"
+/// #calc-reaction-enthalpy(reactants, products, data: merged.data)
+/// ```
+/// -> dict
+#let merge-data(..tables, precedence: "last") = {
+  let positional = tables.pos().enumerate().map(((i, table)) => ("table " + str(i + 1), table))
+  let all = positional + tables.named().pairs()
+  let input = (
+    tables: all.map(((name, table)) => if type(table) in (str, bytes) { load-data(table) } else { table }),
+    names: all.map(((name, table)) => name),
+    precedence: precedence,
+  )
+  
+  let result-bytes = energetics-plugin.merge_databases(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Data table as a dictionary: dictionaries as they are, text decoded by `load-data` and
+/// arrays of tables merged by `merge-data`; other arrays (measurements) are left alone
+#let _decode-table(data) = {
+  if type(data) in (str, bytes) {
+    load-data(data)
+  } else if type(data) == array and data.len() > 0 and data.all(table => type(table) in (dictionary, str, bytes)) {
+    merge-data(..data).data
+  } else {
+    data
+  }
+}
+
+/// Add common names of substances to a data table
+///
+/// Each alias becomes an extra key holding the entry of its target. Targets are formulas
+/// with a state ("H2O(l)"); tables keyed by bare formulas, such as `acid-data` or
+/// `phase-data`, are matched without the state. Aliases whose target is not in the table,
+/// or which are already keys, are ignored. The built-in `alias-data` is applied to every
+/// `data` parameter, so `"water"` or `"ethanol"` can be used wherever a formula is expected;
+/// this function adds further names. Reaction and substance lookups in thermodynamic
+/// tables match names case-insensitively ("Water").
+///
+/// Arguments:
+/// - data: Data table (dictionary, text or array of tables)
+/// - aliases: Dictionary of name → formula (default: alias-data)
+///
+/// Returns: The table with the alias keys added
+///
+/// Example:
+/// ```typst
+/// #let my-data = with-aliases(thermo-data + ("C3H8(g)": (delta_Hf: -104.7, S: 270.3, delta_Gf: -24.3)), aliases: (propane: "C3H8(g)"))
+/// #calc-reaction-enthalpy((("propane", 1), ("oxygen", 5)), (("carbon dioxide", 3), ("water", 4)), data: my-data)
+/// ```
+/// -> dict
+#let with-aliases(data, aliases: alias-data) = {
+  let table = _decode-table(data)
+  if type(table) != dictionary { return table }
+  for (name, target) in aliases {
+    let bare = target.replace(regex("\\s*\\((g|l|s|aq)\\)$"), "")
+    let key = if target in table { target } else if bare in table { bare } else { none }
+    if key != none and name not in table {
+      table.insert(name, table.at(key))
+    }
+  }
+  table
+}
+
+/// Data table passed to the plugin, decoded by `_decode-table` with the built-in aliases added
+#let _data-table(data) = with-aliases(data)
+
+/// Check a thermodynamic data table for internal consistency
+///
+/// ΔGf of each entry is compared with ΔHf - T·ΔSf, where ΔSf is the entropy of formation
+/// from the elements in their reference states (ions on the H⁺(aq) = 0 convention). Values
+/// that only agree after a factor of 1000 are reported as J/kJ mix-ups; missing fields,
+/// negative entropies of neutral species and non-zero formation values of elements in their
+/// reference state are reported too. Entries whose elements have no reference entropy
+/// (or whose keys are not formulas) are listed as skipped.
+///
+/// Arguments:
+/// - data: Thermodynamic data table (default: thermo-data)
+/// - temp: Temperature of the tabulated values (K, default: 298.15)
+/// - tolerance: Largest accepted ΔGf discrepancy (kJ/mol, default: 1.0)
+///
+/// Returns: Dictionary with `valid`, `checked`, `skipped` and `issues` (`key`, `severity`,
+/// `message` and, for discrepancies, `expected_delta_gf`)
+///
+/// Example:
+/// ```typst
+/// #let report = validate-data(data: read("my-data.toml"))
+/// #for issue in report.issues [- #issue.key: #issue.message]
+/// ```
+/// -> dict
+#let validate-data(data: thermo-data, temp: 298.15, tolerance: 1.0) = {
+  let result-bytes = energetics-plugin.validate_database(
+    bytes(json.encode(_decode-table(data))),
+    bytes(json.encode((temperature: temp, tolerance: tolerance)))
+  )
+  
+  json(result-bytes)
+}
+
+/// List the entries of a data table, optionally filtered
+///
+/// Entries come sorted by key with the formula, state, charge and molar mass read from the
+/// key and the entry itself as `properties`, so appendix tables can be generated from the
+/// same data used in the calculations. Keys that are not formulas (bonds, reaction names)
+/// are listed without composition and are left out by the `element` and `phase` filters.
+///
+/// Arguments:
+/// - data: Data table (default: thermo-data)
+/// - element: Element or array of elements that must all occur (default: none)
+/// - phase: State such as "g", "gas", "aq" or "solid" (default: none)
+/// - search: Case-insensitive text in the key or the entry's `name` (default: none)
+///
+/// Returns: Array of dictionaries with `key`, `formula`, `state`, `charge`, `molar_mass`
+/// and `properties`
+///
+/// Example:
+/// ```typst
+/// #table(
+///   columns: 4,
+///   [Species], [ΔHf° (kJ/mol)], [S° (J/(mol·K))], [ΔGf° (kJ/mol)],
+///   ..list-substances(phase: "gas").map(s => (
+///     s.key, str(s.properties.delta_Hf), str(s.properties.S), str(s.properties.delta_Gf)
+///   )).flatten()
+/// )
+/// ```
+/// -> array
+#let list-substances(data: thermo-data, element: none, phase: none, search: none) = {
+  let filter = (
+    elements: if element == none { () } else if type(element) == array { element } else { (element,) },
+    phase: phase,
+    search: search,
+  )
+  
+  let result-bytes = energetics-plugin.list_substances(
+    bytes(json.encode(_decode-table(data))),
+    bytes(json.encode(filter))
+  )
+  
+  json(result-bytes)
+}
+
 /// Format a number with optional scientific notation
 ///
+/// Results flagged with `finite: false` carry none in place of NaN or infinite numbers;
+/// these are formatted as "undefined". Decimal strings such as the `digits` of
+/// high-precision results are rounded from all their digits.
+///
 /// Arguments:
-/// - value: Number to format
+/// - value: Number or decimal string to format
 /// - precision: Number of decimal places (default: 2)
 /// - scientific: Use scientific notation (default: auto - uses scientific for very large/small numbers)
 ///
 /// -> str
 #let format-number(value, precision: 2, scientific: auto) = {
+  if value == none {
+    return "undefined"
+  }
+  
   let use-sci = if scientific == auto {
     // Auto: use scientific for very large or very small numbers
-    let abs-val = calc.abs(value)
+    let abs-val = calc.abs(float(value))
     abs-val >= 1000 or (abs-val < 0.001 and abs-val != 0)
   } else {
     scientific
   }
   
   let result = energetics-plugin.format_number(
-    bytes(repr(value)),
+    bytes(if type(value) == str { value } else { repr(value) }),
     bytes(str(precision)),
     bytes(if use-sci { "true" } else { "false" })
   )
@@ -36,7 +291,8 @@
 /// Format a result with value and unit
 /// -> str
 #let format-result(result, precision: 2, scientific: auto) = {
-  let formatted-value = format-number(result.value, precision: precision, scientific: scientific)
+  let value = result.at("digits", default: result.value)
+  let formatted-value = format-number(value, precision: precision, scientific: scientific)
   if result.unit != "" {
     formatted-value + " " + result.unit
   } else {
@@ -44,14 +300,111 @@
   }
 }
 
+/// Label of an equilibrium constant in a given `format-equilibrium-constant` style
+#let _k-label(style) = ("k": "K", "ln": "ln K", "log": "log K", "pk": "pK").at(style)
+
+/// Format an equilibrium constant as K, ln K, log10 K or pK
+///
+/// K is written from log10 K as mantissa × 10^exponent, so constants beyond the range of
+/// floating-point numbers (whose `value` is none) are still shown exactly.
+///
+/// Arguments:
+/// - k: Result of `calc-equilibrium-constant` (with `ln_k` and `log10_k`)
+/// - style: "k", "ln", "log" or "pk" (default: "k")
+/// - precision: Number of decimal places (default: 2)
+///
+/// Example:
+/// ```typst
+/// #format-equilibrium-constant(calc-equilibrium-constant(-2000)) // 2.43×10^350
+/// #format-equilibrium-constant(calc-equilibrium-constant(57.1), style: "pk") // 10.00
+/// ```
+/// -> str
+#let format-equilibrium-constant(k, style: "k", precision: 2) = {
+  assert(style in ("k", "ln", "log", "pk"), message: "Unknown style: " + str(style) + " (expected k, ln, log or pk)")
+  let precise = k.at("precise", default: none)
+  if style == "k" {
+    let (exponent, mantissa) = if precise != none {
+      (precise.exponent, calc.round(float(precise.mantissa), digits: precision))
+    } else {
+      let exponent = calc.floor(k.log10_k)
+      (exponent, calc.round(calc.pow(10.0, k.log10_k - exponent), digits: precision))
+    }
+    if mantissa >= 10 {
+      mantissa /= 10
+      exponent += 1
+    }
+    if k.value != none and calc.abs(exponent) < 3 {
+      format-number(k.value, precision: precision, scientific: false)
+    } else {
+      format-number(mantissa, precision: precision, scientific: false) + "×10^" + str(exponent)
+    }
+  } else {
+    let logs = if precise != none { precise } else { (ln_k: k.ln_k, log10_k: k.log10_k, pk: -k.log10_k) }
+    let value = (ln: logs.ln_k, log: logs.log10_k, pk: logs.pk).at(style)
+    format-number(value, precision: precision, scientific: false)
+  }
+}
+
+/// Standard-state conventions selectable with the `standard` argument: `"iupac"` (1 bar,
+/// 298.15 K, the convention of the built-in data), `"atm"` (1 atm, 298.15 K) and `"legacy"`
+/// (1 atm, 298 K, as in older textbooks). To use one throughout a document, rebind the
+/// functions, e.g. `#let analyze-reaction = analyze-reaction.with(standard: "legacy")`.
+#let standard-states = (
+  iupac: (pressure: "bar", temperature: 298.15),
+  atm: (pressure: "atm", temperature: 298.15),
+  legacy: (pressure: "atm", temperature: 298),
+)
+
+/// Resolve a `standard` argument, a convention name or a `(pressure: ..., temperature: ...)`
+/// dictionary, to a complete standard state
+#let _standard-state(standard) = {
+  let state = if type(standard) == str {
+    assert(
+      standard in standard-states,
+      message: "Unknown standard state: " + standard + " (expected " + standard-states.keys().join(", ") + ")",
+    )
+    standard-states.at(standard)
+  } else {
+    standard-states.iupac + standard
+  }
+  assert(state.pressure in ("bar", "atm"), message: "Standard-state pressure must be \"bar\" or \"atm\"")
+  state
+}
+
+/// Temperature argument, defaulting to the reference temperature of the standard state
+#let _standard-temp(temp, standard) = {
+  if temp == auto { _standard-state(standard).temperature } else { temp }
+}
+
+/// Argument text of a number, or of a result dictionary with all its `digits`
+#let _precise-arg(x) = if type(x) == dictionary { x.at("digits", default: repr(x.value)) } else { repr(x) }
+
 /// Calculate the enthalpy change of a reaction using Hess's Law
 ///
+/// Aqueous ions (e.g. "Na+(aq)", "SO4^2-(aq)") are tabulated on the convention that
+/// ΔHf°, S° and ΔGf° of H⁺(aq) are zero, so ionic entropies may be negative. Reactions
+/// involving ions must be charge balanced.
+///
+/// Formulas may use Unicode subscripts and spelled-out states ("H₂O (liquid)" finds
+/// "H2O(l)"); unknown formulas are reported with suggestions such as "Did you mean H2O(l),
+/// H2O(g)?".
+///
+/// Entries may carry a `source` (e.g. `source: "CODATA 1989"`); the result then lists the
+/// sources of the entries used as `sources`, so values can be cited.
+///
+/// With `high-precision: true` the sum is carried to about 27 significant digits from the
+/// decimal values of the table, so e.g. 0.1 + 0.2 - 0.3 gives exactly 0. The result then
+/// has `digits`, the value as a decimal string, which `format-result` rounds from and which
+/// `calc-gibbs-energy` accepts.
+///
 /// Arguments:
 /// - reactants: Array of tuples (formula, coefficient), e.g., (("CH4", 1), ("O2", 2))
 /// - products: Array of tuples (formula, coefficient), e.g., (("CO2", 1), ("H2O", 2))
 /// - data: Optional custom thermodynamic data dictionary (defaults to built-in data)
+/// - high-precision: Use double-double arithmetic (default: false)
 ///
-/// Returns: Dictionary with keys `value` (number) and `unit` (string)
+/// Returns: Dictionary with keys `value` (number) and `unit` (string), and `digits` in
+/// high-precision mode
 ///
 /// Example:
 /// ```typst
@@ -62,15 +415,16 @@
 /// #result.value // -890.3
 /// ```
 /// -> dict
-#let calc-reaction-enthalpy(reactants, products, data: thermo-data) = {
+#let calc-reaction-enthalpy(reactants, products, data: thermo-data, high-precision: false) = {
   let reactants-json = json.encode(reactants)
   let products-json = json.encode(products)
-  let data-json = json.encode(data)
+  let data-json = json.encode(_data-table(data))
   
   let result-bytes = energetics-plugin.calculate_reaction_enthalpy(
     bytes(reactants-json),
     bytes(products-json),
-    bytes(data-json)
+    bytes(data-json),
+    bytes(repr(high-precision))
   )
   
   json(result-bytes)
@@ -78,42 +432,69 @@
 
 /// Calculate the entropy change of a reaction
 ///
+/// Ionic reactions must be charge balanced (see `calc-reaction-enthalpy`). The tabulated
+/// entropies refer to 1 bar; with a 1 atm standard state each gas loses R·ln(1.01325), so
+/// ΔS° shifts by -Δn(gas)·R·ln(1.01325), with Δn(gas) from the "(g)" annotations.
+///
 /// Arguments:
 /// - reactants: Array of tuples (formula, coefficient)
 /// - products: Array of tuples (formula, coefficient)
 /// - data: Optional custom thermodynamic data dictionary
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac")
+/// - high-precision: Use double-double arithmetic, see `calc-reaction-enthalpy` (default: false)
 ///
-/// Returns: Dictionary with keys `value` (number) and `unit` (string)
+/// Returns: Dictionary with keys `value` (number), `unit` (string) and `standard_pressure`
 /// -> dict
-#let calc-reaction-entropy(reactants, products, data: thermo-data) = {
+#let calc-reaction-entropy(reactants, products, data: thermo-data, standard: "iupac", high-precision: false) = {
   let reactants-json = json.encode(reactants)
   let products-json = json.encode(products)
-  let data-json = json.encode(data)
+  let data-json = json.encode(_data-table(data))
+  let state = _standard-state(standard)
   
   let result-bytes = energetics-plugin.calculate_reaction_entropy(
     bytes(reactants-json),
     bytes(products-json),
-    bytes(data-json)
+    bytes(data-json),
+    bytes(repr(high-precision))
   )
   
-  json(result-bytes)
+  let result = json(result-bytes)
+  if state.pressure != "bar" {
+    // The shift is an f64 correction, so the digits no longer apply
+    if "digits" in result {
+      let _ = result.remove("digits")
+    }
+    let gas-change = json(energetics-plugin.reaction_gas_change(
+      bytes(reactants-json),
+      bytes(products-json),
+      bytes(repr(state.temperature)),
+      bytes(state.pressure)
+    ))
+    result.value += gas-change.entropy_shift.value
+  }
+  result + (standard_pressure: state.pressure)
 }
 
 /// Calculate Gibbs free energy change
 /// ΔG = ΔH - T·ΔS
 ///
 /// Arguments:
-/// - enthalpy: Enthalpy change in kJ/mol
-/// - entropy: Entropy change in J/(mol·K)
-/// - temp: Temperature in Kelvin (default: 298.15 K)
+/// - enthalpy: Enthalpy change in kJ/mol, or a result of `calc-reaction-enthalpy`
+/// - entropy: Entropy change in J/(mol·K), or a result of `calc-reaction-entropy`
+/// - temp: Temperature in Kelvin (default: reference temperature of `standard`, 298.15 K)
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac")
+/// - high-precision: Use double-double arithmetic, reading the `digits` of high-precision
+///   results (default: false)
 ///
-/// Returns: Dictionary with keys `value` (number) and `unit` (string)
+/// Returns: Dictionary with keys `value` (number) and `unit` (string), and `digits` in
+/// high-precision mode
 /// -> dict
-#let calc-gibbs-energy(enthalpy, entropy, temp: 298.15) = {
+#let calc-gibbs-energy(enthalpy, entropy, temp: auto, standard: "iupac", high-precision: false) = {
   let result-bytes = energetics-plugin.calculate_gibbs_energy(
-    bytes(repr(enthalpy)),
-    bytes(repr(entropy)),
-    bytes(repr(temp))
+    bytes(_precise-arg(enthalpy)),
+    bytes(_precise-arg(entropy)),
+    bytes(repr(_standard-temp(temp, standard))),
+    bytes(repr(high-precision))
   )
   
   json(result-bytes)
@@ -122,31 +503,49 @@
 /// Calculate equilibrium constant from Gibbs free energy
 /// K = exp(-ΔG / RT)
 ///
+/// Gas pressures in K are relative to the standard-state pressure of the convention that
+/// ΔG° refers to (`standard`), 1 bar or 1 atm.
+///
 /// Arguments:
-/// - gibbs-energy: Gibbs free energy change in kJ/mol
-/// - temp: Temperature in Kelvin (default: 298.15 K)
+/// - gibbs-energy: Gibbs free energy change in kJ/mol, or a result of `calc-gibbs-energy`
+/// - temp: Temperature in Kelvin (default: reference temperature of `standard`, 298.15 K)
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac")
+/// - high-precision: Use double-double arithmetic (default: false)
 ///
-/// Returns: Dictionary with keys `value` (number) and `unit` (string)
+/// Returns: Dictionary with `value` (K), `unit`, `ln_k`, `log10_k`, `pk` (-log10 K) and
+/// `standard_pressure`. When K overflows or underflows a floating-point number, `value` is
+/// none and `out_of_range` is "overflow" or "underflow"; use the logarithms or
+/// `format-equilibrium-constant` then. In high-precision mode `precise` holds `mantissa`
+/// and `exponent` of K and `ln_k`, `log10_k` and `pk` as decimal strings.
+///
+/// Example:
+/// ```typst
+/// #calc-equilibrium-constant(-2000).log10_k // ≈ 350.4, K itself is out of range
+/// ```
 /// -> dict
-#let calc-equilibrium-constant(gibbs-energy, temp: 298.15) = {
+#let calc-equilibrium-constant(gibbs-energy, temp: auto, standard: "iupac", high-precision: false) = {
   let result-bytes = energetics-plugin.calculate_equilibrium_constant(
-    bytes(repr(gibbs-energy)),
-    bytes(repr(temp))
+    bytes(_precise-arg(gibbs-energy)),
+    bytes(repr(_standard-temp(temp, standard))),
+    bytes(repr(high-precision))
   )
   
-  json(result-bytes)
+  json(result-bytes) + (standard_pressure: _standard-state(standard).pressure)
 }
 
 /// Get thermodynamic data for a specific substance
 ///
+/// Subscript digits and state spellings are normalised ("CO₂ (gas)" finds "CO2(g)"); for
+/// unknown formulas the error suggests the closest keys in the table.
+///
 /// Arguments:
-/// - formula: Chemical formula as string, e.g., "H2O"
+/// - formula: Chemical formula as string, e.g., "H2O(l)"
 /// - data: Optional custom thermodynamic data dictionary
 ///
 /// Returns: Dictionary with keys `delta_Hf`, `S`, and `delta_Gf`
 /// -> dict
 #let get-substance-data(formula, data: thermo-data) = {
-  let data-json = json.encode(data)
+  let data-json = json.encode(_data-table(data))
   
   let result-bytes = energetics-plugin.get_substance_data(
     bytes(formula),
@@ -177,32 +576,114 @@
   format-side(reactants) + " → " + format-side(products)
 }
 
+/// Check an input against the domain the calculations require
+///
+/// Runs the same check a calculation applies to its inputs, but returns the problem instead
+/// of stopping compilation. Calculations reject out-of-domain input with the same error,
+/// serialized as JSON.
+///
+/// Arguments:
+/// - value: Number to check
+/// - constraint: "positive", "non-negative", "finite", "fraction" (between 0 and 1) or
+///   "temperature" (above 0 K)
+/// - field: Name of the input, used in the error (default: "value")
+///
+/// Returns: `none` when the value is valid, otherwise a dictionary with the `field`, the
+/// `constraint` it violates, the `value` and a `message`
+///
+/// Example:
+/// ```typst
+/// #check-input(-5, "temperature", field: "temperature").message
+/// ```
+/// -> none | dict
+#let check-input(value, constraint, field: "value") = {
+  let result-bytes = energetics-plugin.check_input(
+    bytes(repr(value)),
+    bytes(constraint),
+    bytes(field)
+  )
+  
+  json(result-bytes)
+}
+
+/// Check species against the temperature ranges of their data
+///
+/// Entries may declare `T_range: (min, max)` (K), the temperatures for which their values
+/// may be used. Outside that range results are extrapolated; `analyze-reaction`,
+/// `detailed-analysis` and `calc-fuel-cell-efficiency` attach the warnings returned here to
+/// their temperature-dependent results (ΔG, K) as `warnings`.
+///
+/// Arguments:
+/// - species: Array of formulas
+/// - temp: Temperature of the calculation (K)
+/// - data: Data table (default: thermo-data)
+///
+/// Returns: Array of warnings with `species`, `temperature`, `range` and `message` (empty
+/// when all species are within range or have no range)
+///
+/// Example:
+/// ```typst
+/// #let my-data = thermo-data + ("H2O(l)": thermo-data.at("H2O(l)") + (T_range: (273.15, 373.15)))
+/// #check-temperature-range(("H2O(l)",), 500, data: my-data).first().message
+/// ```
+/// -> array
+#let check-temperature-range(species, temp, data: thermo-data) = {
+  let result-bytes = energetics-plugin.check_temperature_range(
+    bytes(json.encode(species)),
+    bytes(json.encode(_data-table(data))),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Attach temperature-range warnings to a result dictionary, if there are any
+#let _with-warnings(result, warnings) = {
+  if warnings.len() > 0 { result + (warnings: warnings) } else { result }
+}
+
+/// Sorted union of the `sources` of several results
+#let _sources(..results) = {
+  results.pos().map(r => r.at("sources", default: ())).flatten().dedup().sorted()
+}
+
 /// Complete reaction analysis
 ///
-/// Calculates ΔH, ΔS, and ΔG for a reaction and formats the results
+/// Calculates ΔH, ΔS, and ΔG for a reaction and formats the results. If the temperature lies
+/// outside the `T_range` of any entry, ΔG and K carry `warnings` (see
+/// `check-temperature-range`), which are also returned as `warnings`. Entries may name
+/// their `source`; the sources used are returned as `sources` for citing.
 ///
 /// Arguments:
 /// - reactants: Array of tuples (formula, coefficient)
 /// - products: Array of tuples (formula, coefficient)
-/// - temp: Temperature in Kelvin (default: 298.15 K)
+/// - temp: Temperature in Kelvin (default: reference temperature of `standard`, 298.15 K)
 /// - data: Optional custom thermodynamic data dictionary
 /// - precision: Number of decimal places (default: 2)
 /// - scientific: Format mode (default: auto)
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac");
+///   with 1 atm, ΔS°, ΔG° and K are converted from the 1 bar data
 ///
 /// Returns: Dictionary with all calculated values
 /// -> dict
-#let analyze-reaction(reactants, products, temp: 298.15, data: thermo-data, precision: 2, scientific: auto) = {
+#let analyze-reaction(reactants, products, temp: auto, data: thermo-data, precision: 2, scientific: auto, standard: "iupac") = {
+  let temp = _standard-temp(temp, standard)
   let delta-h = calc-reaction-enthalpy(reactants, products, data: data)
-  let delta-s = calc-reaction-entropy(reactants, products, data: data)
-  let delta-g = calc-gibbs-energy(delta-h.value, delta-s.value, temp: temp)
-  let k-eq = calc-equilibrium-constant(delta-g.value, temp: temp)
+  let delta-s = calc-reaction-entropy(reactants, products, data: data, standard: standard)
+  let warnings = check-temperature-range((reactants + products).map(((f, c)) => f), temp, data: data)
+  let delta-g = _with-warnings(calc-gibbs-energy(delta-h.value, delta-s.value, temp: temp), warnings)
+  let k-eq = _with-warnings(calc-equilibrium-constant(delta-g.value, temp: temp, standard: standard), warnings)
+  let sources = _sources(delta-h, delta-s)
   
   (
     enthalpy: delta-h,
     entropy: delta-s,
     gibbs: delta-g,
     equilibrium-constant: k-eq,
+    warnings: warnings,
+    sources: sources,
     temperature: temp,
+    standard-state: _standard-state(standard),
     equation: format-reaction(reactants, products),
     precision: precision,
     scientific: scientific
@@ -214,12 +695,15 @@
 /// Arguments:
 /// - reactants: Array of tuples (formula, coefficient)
 /// - products: Array of tuples (formula, coefficient)
-/// - temp: Temperature in Kelvin (default: 298.15 K)
+/// - temp: Temperature in Kelvin (default: reference temperature of `standard`, 298.15 K)
 /// - data: Optional custom thermodynamic data dictionary
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac")
 ///
-/// Returns: Dictionary with detailed analysis including individual substance data
+/// Returns: Dictionary with detailed analysis including individual substance data, the
+/// temperature-range `warnings` (see `check-temperature-range`) and the `sources` of the data
 /// -> dict
-#let detailed-analysis(reactants, products, temp: 298.15, data: thermo-data) = {
+#let detailed-analysis(reactants, products, temp: auto, data: thermo-data, standard: "iupac") = {
+  let temp = _standard-temp(temp, standard)
   // Get individual substance data
   let reactant-details = reactants.map(((formula, coeff)) => {
     let substance-data = get-substance-data(formula, data: data)
@@ -241,13 +725,16 @@
   
   // Calculate reaction properties
   let delta-h = calc-reaction-enthalpy(reactants, products, data: data)
-  let delta-s = calc-reaction-entropy(reactants, products, data: data)
-  let delta-g = calc-gibbs-energy(delta-h.value, delta-s.value, temp: temp)
-  let k-eq = calc-equilibrium-constant(delta-g.value, temp: temp)
+  let delta-s = calc-reaction-entropy(reactants, products, data: data, standard: standard)
+  let warnings = check-temperature-range((reactants + products).map(((f, c)) => f), temp, data: data)
+  let delta-g = _with-warnings(calc-gibbs-energy(delta-h.value, delta-s.value, temp: temp), warnings)
+  let k-eq = _with-warnings(calc-equilibrium-constant(delta-g.value, temp: temp, standard: standard), warnings)
+  let sources = _sources(delta-h, delta-s)
   
   (
     equation: format-reaction(reactants, products),
     temperature: temp,
+    standard-state: _standard-state(standard),
     reactants: reactant-details,
     products: product-details,
     reaction: (
@@ -255,7 +742,9 @@
       entropy: delta-s,
       gibbs: delta-g,
       equilibrium-constant: k-eq
-    )
+    ),
+    warnings: warnings,
+    sources: sources
   )
 }
 
@@ -265,10 +754,11 @@
 /// - analysis: Result from detailed-analysis()
 /// - precision: Number of decimal places (default: 2)
 /// - scientific: Use scientific notation (default: auto)
+/// - k-style: Show the equilibrium constant as "k", "ln", "log" or "pk" (default: "k")
 ///
 /// Returns: Content with complete analysis
 /// -> content
-#let display-detailed-analysis(analysis, precision: 2, scientific: auto) = {
+#let display-detailed-analysis(analysis, precision: 2, scientific: auto, k-style: "k") = {
   [
     = Reaction Equation
     
@@ -321,8 +811,16 @@
       [ΔH°#sub[rxn]], [#format-result(analysis.reaction.enthalpy, precision: precision, scientific: scientific)],
       [ΔS°#sub[rxn]], [#format-result(analysis.reaction.entropy, precision: precision, scientific: scientific)],
       [ΔG°#sub[rxn]], [#format-result(analysis.reaction.gibbs, precision: precision, scientific: scientific)],
-      [K], [#format-number(analysis.reaction.equilibrium-constant.value, precision: precision, scientific: true)],
+      [#_k-label(k-style)], [#format-equilibrium-constant(analysis.reaction.equilibrium-constant, style: k-style, precision: precision)],
     )
+    
+    #for warning in analysis.at("warnings", default: ()) [
+      ⚠ #warning.message \
+    ]
+    
+    #if analysis.at("sources", default: ()).len() > 0 [
+      _Data sources:_ #analysis.sources.join("; ")
+    ]
   ]
 }
 
@@ -332,10 +830,11 @@
 /// - analysis: Result from analyze-reaction()
 /// - precision: Number of decimal places (default: 2)
 /// - scientific: Use scientific notation (default: auto)
+/// - k-style: Show the equilibrium constant as "k", "ln", "log" or "pk" (default: "k")
 ///
 /// Returns: Content representing a formatted table
 /// -> content
-#let display-analysis(analysis, precision: auto, scientific: auto) = {
+#let display-analysis(analysis, precision: auto, scientific: auto, k-style: "k") = {
   let prec = if precision == auto { analysis.precision } else { precision }
   let sci = if scientific == auto { analysis.scientific } else { scientific }
   
@@ -348,7 +847,8 @@
     [ΔH°], [#format-result(analysis.enthalpy, precision: prec, scientific: sci)],
     [ΔS°], [#format-result(analysis.entropy, precision: prec, scientific: sci)],
     [ΔG°], [#format-result(analysis.gibbs, precision: prec, scientific: sci)],
-    [K], [#format-number(analysis.equilibrium-constant.value, precision: prec, scientific: true)],
+    [#_k-label(k-style)], [#format-equilibrium-constant(analysis.equilibrium-constant, style: k-style, precision: prec)],
+    ..analysis.at("warnings", default: ()).map(w => ([⚠ Data range], [#w.message])).flatten(),
   )
 }
 
@@ -357,20 +857,22 @@
 /// Arguments:
 /// - reactants: Array of tuples (formula, coefficient)
 /// - products: Array of tuples (formula, coefficient)
-/// - temp: Temperature in Kelvin (default: 298.15 K)
+/// - temp: Temperature in Kelvin (default: reference temperature of `standard`, 298.15 K)
 /// - show-details: Show detailed substance data (default: true)
 /// - precision: Number of decimal places (default: 2)
 /// - scientific: Use scientific notation (default: auto)
+/// - standard: Standard state, a name in `standard-states` or a dictionary (default: "iupac")
+/// - k-style: Show the equilibrium constant as "k", "ln", "log" or "pk" (default: "k")
 ///
 /// Returns: Formatted content with all data
 /// -> content
-#let calculate-reaction(reactants, products, temp: 298.15, show-details: true, precision: 2, scientific: auto) = {
+#let calculate-reaction(reactants, products, temp: auto, show-details: true, precision: 2, scientific: auto, standard: "iupac", k-style: "k") = {
   if show-details {
-    let analysis = detailed-analysis(reactants, products, temp: temp)
-    display-detailed-analysis(analysis, precision: precision, scientific: scientific)
+    let analysis = detailed-analysis(reactants, products, temp: temp, standard: standard)
+    display-detailed-analysis(analysis, precision: precision, scientific: scientific, k-style: k-style)
   } else {
-    let analysis = analyze-reaction(reactants, products, temp: temp, precision: precision, scientific: scientific)
-    display-analysis(analysis)
+    let analysis = analyze-reaction(reactants, products, temp: temp, precision: precision, scientific: scientific, standard: standard)
+    display-analysis(analysis, k-style: k-style)
   }
 }
 
@@ -409,18 +911,53 @@
 /// - delta-h-activation: Enthalpy of activation (kJ/mol)
 /// - delta-s-activation: Entropy of activation (J/(mol·K))
 /// - temp: Temperature (K, default: 298.15)
+/// - time-unit: Time unit of the result: "s", "min", "h", "d" or "yr" (default: "s")
 ///
-/// Returns: Dictionary with rate constant value and unit (s⁻¹)
+/// Returns: Dictionary with rate constant value and unit (s⁻¹ or the requested time unit⁻¹)
 ///
 /// Example:
 /// ```typst
-/// #let k = calc-rate-constant-eyring(60, -50, temp: 298.15)
+/// #let k = calc-rate-constant-eyring(60, -50, temp: 298.15) // 0.468 s⁻¹
 /// ```
-#let calc-rate-constant-eyring(delta-h-activation, delta-s-activation, temp: 298.15) = {
+#let calc-rate-constant-eyring(delta-h-activation, delta-s-activation, temp: 298.15, time-unit: "s") = {
   let result-bytes = energetics-plugin.calculate_rate_constant_eyring(
     bytes(repr(delta-h-activation)),
     bytes(repr(delta-s-activation)),
-    bytes(repr(temp))
+    bytes(repr(temp)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate a bimolecular rate constant from simple collision theory
+/// k = p·σ·√(8·kB·T/(π·μ))·NA·exp(-Ea/(R·T))
+///
+/// Arguments:
+/// - molar-masses: Molar masses of the two reactants (g/mol), or a single value for A + A
+/// - collision-diameter: Collision diameter d = (d_A + d_B)/2 (nm)
+/// - ea: Activation energy (kJ/mol)
+/// - temp: Temperature (K, default: 298.15)
+/// - steric-factor: Steric factor p (default: 1.0)
+///
+/// Returns: Dictionary with rate constant `value` and `unit` (L/(mol·s)), plus the
+/// collision-theory `pre_exponential` factor, `cross_section` (m²),
+/// `mean_relative_speed` (m/s) and `reduced_mass` (g/mol)
+///
+/// Example:
+/// ```typst
+/// #let k = calc-rate-constant-collision((2.016, 32.0), 0.3, 50, temp: 298.15)
+/// #k.pre_exponential // ≈ 3.1e11 L/(mol·s), compare with the experimental A factor
+/// ```
+#let calc-rate-constant-collision(molar-masses, collision-diameter, ea, temp: 298.15, steric-factor: 1.0) = {
+  let masses = if type(molar-masses) == array { molar-masses } else { (molar-masses,) }
+  
+  let result-bytes = energetics-plugin.calculate_rate_constant_collision(
+    bytes(json.encode(masses)),
+    bytes(repr(collision-diameter)),
+    bytes(repr(ea)),
+    bytes(repr(temp)),
+    bytes(repr(steric-factor))
   )
   
   json(result-bytes)
@@ -452,6 +989,98 @@
   json(result-bytes)
 }
 
+/// Calculate the Q10 temperature coefficient from rate constants at two temperatures
+/// Q10 = (k2/k1)^(10/(T2 - T1))
+///
+/// Arguments:
+/// - k1: Rate constant at temperature T1
+/// - t1: Temperature 1 (K)
+/// - k2: Rate constant at temperature T2
+/// - t2: Temperature 2 (K)
+///
+/// Returns: Dictionary with the dimensionless Q10 value
+///
+/// Example:
+/// ```typst
+/// #let q = calc-q10(0.010, 298.15, 0.025, 308.15)
+/// #q.value // 2.5
+/// ```
+#let calc-q10(k1, t1, k2, t2) = {
+  let result-bytes = energetics-plugin.calculate_q10(
+    bytes(repr(k1)),
+    bytes(repr(t1)),
+    bytes(repr(k2)),
+    bytes(repr(t2))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate Q10 from the activation energy
+/// Q10 = exp(10·Ea / (R·T·(T + 10)))
+///
+/// Arguments:
+/// - ea: Activation energy (kJ/mol)
+/// - temp: Lower temperature of the 10 K interval (K, default: 298.15)
+///
+/// Returns: Dictionary with the dimensionless Q10 value
+///
+/// Example:
+/// ```typst
+/// #let q = calc-q10-from-activation-energy(50, temp: 298.15) // ≈ 1.9
+/// ```
+#let calc-q10-from-activation-energy(ea, temp: 298.15) = {
+  let result-bytes = energetics-plugin.calculate_q10_from_activation_energy(
+    bytes(repr(ea)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the activation energy implied by a Q10 value
+/// Ea = R·T·(T + 10)·ln(Q10) / 10
+///
+/// Arguments:
+/// - q10: Q10 temperature coefficient
+/// - temp: Lower temperature of the 10 K interval (K, default: 298.15)
+///
+/// Returns: Dictionary with activation energy in kJ/mol
+#let calc-activation-energy-from-q10(q10, temp: 298.15) = {
+  let result-bytes = energetics-plugin.calculate_activation_energy_from_q10(
+    bytes(repr(q10)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Scale a rate constant to another temperature using Q10
+/// k(T_new) = k(T)·Q10^((T_new - T)/10)
+///
+/// Arguments:
+/// - k: Rate constant at temperature `temp`
+/// - q10: Q10 temperature coefficient
+/// - temp: Temperature of the known rate constant (K)
+/// - new-temp: Target temperature (K)
+///
+/// Returns: Dictionary with the rate constant at the new temperature
+///
+/// Example:
+/// ```typst
+/// #let k = calc-rate-constant-q10(0.010, 2.0, 298.15, 308.15) // 0.020
+/// ```
+#let calc-rate-constant-q10(k, q10, temp, new-temp) = {
+  let result-bytes = energetics-plugin.calculate_rate_constant_q10(
+    bytes(repr(k)),
+    bytes(repr(q10)),
+    bytes(repr(temp)),
+    bytes(repr(new-temp))
+  )
+  
+  json(result-bytes)
+}
+
 /// Calculate half-life for a reaction
 /// - Zero order: t_1/2 = [A]0 / (2k)
 /// - First order: t_1/2 = ln(2) / k
@@ -461,18 +1090,20 @@
 /// - k: Rate constant
 /// - order: Reaction order (0, 1, or 2, default: 1)
 /// - initial-conc: Initial concentration (default: 1.0, required for 0th and 2nd order)
+/// - time-unit: Time unit of k and the half-life: "s", "min", "h", "d" or "yr" (default: "s")
 ///
-/// Returns: Dictionary with half-life in seconds
+/// Returns: Dictionary with the half-life in the requested time unit
 ///
 /// Example:
 /// ```typst
 /// #let t-half = calc-half-life(0.693, order: 1)
 /// ```
-#let calc-half-life(k, order: 1, initial-conc: 1.0) = {
+#let calc-half-life(k, order: 1, initial-conc: 1.0, time-unit: "s") = {
   let result-bytes = energetics-plugin.calculate_half_life(
     bytes(repr(k)),
     bytes(str(order)),
-    bytes(repr(initial-conc))
+    bytes(repr(initial-conc)),
+    bytes(time-unit)
   )
   
   json(result-bytes)
@@ -486,13 +1117,14 @@
 /// - temp: Temperature (K)
 /// - order: Reaction order
 /// - initial-conc: Initial concentration (optional)
+/// - time-unit: Time unit of A and the half-life
 /// - precision: Number of decimal places
 /// - scientific: Scientific notation mode
 ///
 /// Returns: Dictionary with all kinetic parameters
-#let analyze-kinetics(a, ea, temp: 298.15, order: 1, initial-conc: 1.0, precision: 2, scientific: auto) = {
+#let analyze-kinetics(a, ea, temp: 298.15, order: 1, initial-conc: 1.0, time-unit: "s", precision: 2, scientific: auto) = {
   let k = calc-rate-constant-arrhenius(a, ea, temp: temp)
-  let t-half = calc-half-life(k.value, order: order, initial-conc: initial-conc)
+  let t-half = calc-half-life(k.value, order: order, initial-conc: initial-conc, time-unit: time-unit)
   
   (
     rate-constant: k,
@@ -527,3 +1159,5008 @@
     *Half-life (t₁/₂):* #format-result(analysis.half-life, precision: prec, scientific: sci)
   ]
 }
+
+// ============================================================================
+// REACTION ENERGY PROFILES
+// ============================================================================
+
+/// Generate reaction-coordinate diagram data for a single- or multi-step mechanism
+///
+/// Each activation energy is measured from the well preceding its transition state,
+/// so step i peaks at E(minimum i) + Ea_i. The curve is smooth, with flat tangents at
+/// every well and peak, and spans x = 0 (reactants) to x = number of steps (products).
+///
+/// Arguments:
+/// - reactant-energy: Energy of the reactants (kJ/mol)
+/// - product-energy: Energy of the products (kJ/mol)
+/// - activation-energies: Array of activation energies, one per elementary step (kJ/mol)
+/// - intermediates: Energies of the intermediates (default: auto - evenly spaced between reactants and products)
+/// - labels: Labels for reactants, intermediates and products (default: auto - "Reactants", "I1", ..., "Products")
+/// - points-per-step: Number of curve points per step (default: 50)
+///
+/// Returns: Dictionary with `points` (array of (x, E) pairs), `stationary_points`
+/// (array of dictionaries with `x`, `energy`, `kind` and `label`) and `unit`
+///
+/// Example:
+/// ```typst
+/// #let profile = energy-profile(0, -20, (60, 45), intermediates: (10,))
+/// #profile.stationary_points.at(1).energy // 60
+/// ```
+/// -> dict
+#let energy-profile(
+  reactant-energy,
+  product-energy,
+  activation-energies,
+  intermediates: auto,
+  labels: auto,
+  points-per-step: 50,
+) = {
+  let result-bytes = energetics-plugin.energy_profile(
+    bytes(repr(reactant-energy)),
+    bytes(repr(product-energy)),
+    bytes(json.encode(activation-energies)),
+    bytes(json.encode(if intermediates == auto { () } else { intermediates })),
+    bytes(json.encode(if labels == auto { () } else { labels })),
+    bytes(str(points-per-step))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// KINETIC THEORY OF GASES
+// ============================================================================
+
+/// Generate Maxwell–Boltzmann distribution curves for one or more temperatures
+///
+/// In `"speed"` mode the x axis is the molecular speed (m/s); in `"energy"` mode it is
+/// the molar kinetic energy (kJ/mol). When a threshold energy is given, each curve also
+/// carries the fraction of molecules above it and the `shaded` points beyond it,
+/// ready to fill the classic "molecules with E ≥ Ea" region.
+///
+/// Arguments:
+/// - molar-mass: Molar mass of the gas (g/mol)
+/// - temps: Temperature or array of temperatures (K)
+/// - mode: `"speed"` or `"energy"` (default: "speed")
+/// - threshold: Threshold energy, e.g. an activation energy (kJ/mol, default: none)
+/// - points: Number of points per curve (default: 100)
+/// - max: Upper end of the x axis (default: auto)
+///
+/// Returns: Dictionary with `mode`, `x_unit`, `y_unit`, `threshold` and `curves`, an array of
+/// dictionaries with `temperature`, `points` and (with a threshold) `fraction_above_threshold` and `shaded`
+///
+/// Example:
+/// ```typst
+/// #let mb = maxwell-boltzmann-distribution(28.0, (300, 1000), threshold: 10)
+/// #mb.curves.at(0).fraction_above_threshold // ≈ 0.046
+/// ```
+/// -> dict
+#let maxwell-boltzmann-distribution(molar-mass, temps, mode: "speed", threshold: none, points: 100, max: auto) = {
+  let temps = if type(temps) == array { temps } else { (temps,) }
+  
+  let result-bytes = energetics-plugin.maxwell_boltzmann_distribution(
+    bytes(repr(molar-mass)),
+    bytes(json.encode(temps)),
+    bytes(mode),
+    bytes(repr(if threshold == none { 0 } else { threshold })),
+    bytes(str(points)),
+    bytes(repr(if max == auto { 0 } else { max }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the characteristic molecular speeds of a gas
+/// v_rms = √(3RT/M), v_mean = √(8RT/(πM)), v_mp = √(2RT/M)
+///
+/// Arguments:
+/// - molar-mass: Molar mass of the gas (g/mol)
+/// - temp: Temperature (K, default: 298.15)
+/// - unit: `"m/s"`, `"km/s"` or `"km/h"` (default: "m/s")
+///
+/// Returns: Dictionary with `rms`, `mean` and `most_probable` (each with `value` and `unit`),
+/// plus the `molar_mass` and `temperature`
+///
+/// Example:
+/// ```typst
+/// #let n2 = calc-molecular-speeds(28.0, temp: 300)
+/// #n2.rms.value // ≈ 517 m/s
+/// ```
+/// -> dict
+#let calc-molecular-speeds(molar-mass, temp: 298.15, unit: "m/s") = {
+  let result-bytes = energetics-plugin.molecular_speeds(
+    bytes(repr(molar-mass)),
+    bytes(repr(temp)),
+    bytes(unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate mean free paths, collision frequencies and collision densities
+///
+/// Pure gas: λ = kT/(√2·σ·p), z = √2·σ·⟨v⟩·n and Z_AA = σ·⟨v⟩·n²/√2. For a binary mixture
+/// the collision density is Z_AB = σ_AB·⟨v_rel⟩·nA·nB with σ_AB = π·((dA + dB)/2)², and each
+/// species' frequency counts collisions with both species. `pre_exponential` is the
+/// matching collision-theory A factor used by `calc-rate-constant-collision`.
+///
+/// Arguments:
+/// - molar-masses: Molar mass (g/mol) or array of two for a mixture
+/// - diameters: Collision diameter (nm) or array of two
+/// - pressures: Pressure or array of two partial pressures (pressure unit)
+/// - temp: Temperature (K, default: 298.15)
+/// - pressure-unit: Pressure unit (default: "bar")
+///
+/// Returns: Dictionary with `species` (array of dictionaries with `number_density` (m⁻³),
+/// `mean_speed` (m/s), `collision_frequency` and `mean_free_path`), `collision_density`,
+/// `cross_section` (m²), `mean_relative_speed` (m/s) and `pre_exponential` (L/(mol·s))
+///
+/// Example:
+/// ```typst
+/// #let n2 = calc-collision-properties(28.0, 0.37, 1, pressure-unit: "atm")
+/// #n2.species.at(0).mean_free_path.value // ≈ 6.7e-8 m
+/// ```
+/// -> dict
+#let calc-collision-properties(molar-masses, diameters, pressures, temp: 298.15, pressure-unit: "bar") = {
+  let as-array(x) = if type(x) == array { x } else { (x,) }
+  
+  let result-bytes = energetics-plugin.collision_properties(
+    bytes(json.encode(as-array(molar-masses))),
+    bytes(json.encode(as-array(diameters))),
+    bytes(json.encode(as-array(pressures))),
+    bytes(repr(temp)),
+    bytes(pressure-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Compare effusion or diffusion of two gases with Graham's law
+/// r1/r2 = √(M2/M1), t1/t2 = √(M1/M2)
+///
+/// With `gas: none` and a measured `rate-ratio`, the molar mass of the unknown gas is
+/// found instead. With an `initial-fraction` of the lighter gas, the ideal single-stage
+/// enrichment follows from α = √(M_heavy/M_light), and a `target-fraction` gives the
+/// number of stages n = ln[(x/(1 - x))/(x0/(1 - x0))]/ln α.
+///
+/// Arguments:
+/// - gas: Molar mass (g/mol) or formula of gas 1, or none
+/// - reference: Molar mass (g/mol) or formula of gas 2
+/// - rate-ratio: Measured r1/r2 for an unknown gas 1 (default: none)
+/// - initial-fraction: Mole fraction of the lighter gas in the feed (default: none)
+/// - target-fraction: Wanted mole fraction of the lighter gas (default: none)
+///
+/// Returns: Dictionary with `molar_masses` (M1, M2), `rate_ratio`, `time_ratio`,
+/// `separation_factor` and, for enrichment, `enrichment` with `single_stage_fraction` and
+/// `stages`
+///
+/// Example:
+/// ```typst
+/// #calc-effusion("H2", "O2").rate_ratio // ≈ 3.98
+/// #calc-effusion(349.03, 352.04, initial-fraction: 0.0072, target-fraction: 0.03).enrichment.stages // ≈ 338
+/// ```
+/// -> dict
+#let calc-effusion(gas, reference, rate-ratio: none, initial-fraction: none, target-fraction: none) = {
+  let input = (
+    gas: gas,
+    reference: reference,
+    rate_ratio: rate-ratio,
+    initial_fraction: initial-fraction,
+    target_fraction: target-fraction,
+  )
+  let result-bytes = energetics-plugin.graham_effusion(bytes(json.encode(input)))
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// KINETICS SIMULATION
+// ============================================================================
+
+/// Generate concentration-vs-time data for A → B with rate = k[A]^n
+///
+/// All points are computed in a single plugin call from the integrated rate law,
+/// so curves with hundreds of points stay cheap to produce.
+///
+/// Arguments:
+/// - k: Rate constant
+/// - order: Reaction order (any non-negative number, default: 1)
+/// - initial-conc: Initial concentration [A]₀ (mol/L, default: 1.0)
+/// - t-end: Final time (time unit, default: auto - five half-lives)
+/// - points: Number of sample points (default: 100)
+/// - time-unit: Time unit of all times and rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `times`, `curves` (species → array of (t, c) pairs),
+/// `rows` (arrays of (t, [A], [B])), `species`, `time_unit` and `concentration_unit`
+///
+/// Example:
+/// ```typst
+/// #let course = concentration-time-curve(0.1, order: 1, t-end: 30)
+/// #course.curves.A.last() // (30.0, 0.0498)
+/// ```
+/// -> dict
+#let concentration-time-curve(k, order: 1, initial-conc: 1.0, t-end: auto, points: 100, time-unit: "s") = {
+  let result-bytes = energetics-plugin.concentration_time_curve(
+    bytes(repr(k)),
+    bytes(repr(order)),
+    bytes(repr(initial-conc)),
+    bytes(repr(if t-end == auto { 0 } else { t-end })),
+    bytes(str(points)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Simulate an arbitrary mechanism of elementary steps
+///
+/// Each step follows mass-action kinetics (rate = k·Π[reactant]^coefficient). The system
+/// is integrated with an adaptive, stiff-capable Rosenbrock method, so mechanisms mixing
+/// very fast and very slow steps are handled without tiny fixed time steps.
+/// Reversible steps are written as two separate steps.
+///
+/// Arguments:
+/// - steps: Array of dictionaries with `reactants` and `products` (arrays of (species, coefficient)) and `k`
+/// - initial: Dictionary of initial concentrations (mol/L); species not listed start at 0
+/// - t-end: Final time (time unit)
+/// - points: Number of output points (default: 100)
+/// - tolerance: Relative tolerance of the integrator (default: 1e-6)
+/// - time-unit: Time unit of all times and rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `times`, `curves` (species → array of (t, c) pairs),
+/// `rows`, `species`, `time_unit` and `concentration_unit`
+///
+/// Example:
+/// ```typst
+/// #let course = simulate-mechanism(
+///   (
+///     (reactants: (("A", 1),), products: (("B", 1),), k: 1.0),
+///     (reactants: (("B", 1),), products: (("C", 1),), k: 0.5),
+///   ),
+///   (A: 1.0),
+///   10,
+/// )
+/// ```
+/// -> dict
+#let simulate-mechanism(steps, initial, t-end, points: 100, tolerance: 1e-6, time-unit: "s") = {
+  let result-bytes = energetics-plugin.simulate_mechanism(
+    bytes(json.encode(steps)),
+    bytes(json.encode(initial)),
+    bytes(repr(t-end)),
+    bytes(str(points)),
+    bytes(repr(tolerance)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Closed-form kinetics of consecutive reactions A → B → C (optionally A ⇌ B → C)
+///
+/// Starting from pure A, the analytic solution gives [A], [B] and [C] at every time
+/// together with the time and height of the intermediate maximum,
+/// t_max = ln(k₁/k₂) / (k₁ - k₂) for the irreversible case.
+///
+/// Arguments:
+/// - k1: Rate constant of A → B (time unit⁻¹)
+/// - k2: Rate constant of B → C (time unit⁻¹)
+/// - k-reverse: Rate constant of B → A (time unit⁻¹, default: 0)
+/// - initial-conc: Initial concentration of A (mol/L, default: 1.0)
+/// - t-end: Final time (time unit, default: auto)
+/// - points: Number of sample points (default: 100)
+/// - time-unit: Time unit of all times and rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with the time-course fields of `concentration-time-curve`, plus
+/// `eigenvalues` (λ₁, λ₂) and `intermediate_max` (dictionary with `time` and `concentration`)
+///
+/// Example:
+/// ```typst
+/// #let cons = consecutive-reaction-kinetics(1.0, 0.5)
+/// #cons.intermediate_max.time // ln(2) / 0.5 ≈ 1.386 s
+/// ```
+/// -> dict
+#let consecutive-reaction-kinetics(k1, k2, k-reverse: 0, initial-conc: 1.0, t-end: auto, points: 100, time-unit: "s") = {
+  let result-bytes = energetics-plugin.consecutive_reaction_kinetics(
+    bytes(repr(k1)),
+    bytes(repr(k2)),
+    bytes(repr(k-reverse)),
+    bytes(repr(initial-conc)),
+    bytes(repr(if t-end == auto { 0 } else { t-end })),
+    bytes(str(points)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Kinetics of parallel (competing) first-order reactions A → B and A → C
+///
+/// A decays with the effective rate constant k = k₁ + k₂, while the products form in the
+/// constant ratio [B]/[C] = k₁/k₂ at all times.
+///
+/// Arguments:
+/// - k1: Rate constant of A → B (time unit⁻¹)
+/// - k2: Rate constant of A → C (time unit⁻¹)
+/// - initial-conc: Initial concentration of A (mol/L, default: 1.0)
+/// - t-end: Final time (time unit, default: auto - five half-lives)
+/// - points: Number of sample points (default: 100)
+/// - time-unit: Time unit of all times and rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with the time-course fields of `concentration-time-curve`, plus
+/// `k_overall`, `branching_ratios` (fractions to B and C), `selectivity` (k₁/k₂) and `half_life`
+///
+/// Example:
+/// ```typst
+/// #let par = parallel-reaction-kinetics(0.3, 0.1)
+/// #par.branching_ratios // (0.75, 0.25)
+/// ```
+/// -> dict
+#let parallel-reaction-kinetics(k1, k2, initial-conc: 1.0, t-end: auto, points: 100, time-unit: "s") = {
+  let result-bytes = energetics-plugin.parallel_reaction_kinetics(
+    bytes(repr(k1)),
+    bytes(repr(k2)),
+    bytes(repr(initial-conc)),
+    bytes(repr(if t-end == auto { 0 } else { t-end })),
+    bytes(str(points)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Relaxation kinetics of the reversible first-order reaction A ⇌ B
+///
+/// Both concentrations approach equilibrium exponentially with the relaxation time
+/// τ = 1/(k_f + k_r), and the equilibrium ratio [B]/[A] equals K = k_f/k_r — the link
+/// between the kinetic and thermodynamic descriptions of the same reaction.
+///
+/// Arguments:
+/// - kf: Forward rate constant (time unit⁻¹)
+/// - kr: Reverse rate constant (time unit⁻¹)
+/// - initial-a: Initial concentration of A (mol/L, default: 1.0)
+/// - initial-b: Initial concentration of B (mol/L, default: 0.0)
+/// - t-end: Final time (time unit, default: auto - five relaxation times)
+/// - points: Number of sample points (default: 100)
+/// - time-unit: Time unit of all times and rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with the time-course fields of `concentration-time-curve`, plus
+/// `relaxation_time`, `equilibrium_constant`, `equilibrium_a` and `equilibrium_b`
+///
+/// Example:
+/// ```typst
+/// #let rel = reversible-reaction-kinetics(0.3, 0.1)
+/// #rel.relaxation_time // 2.5 s
+/// ```
+/// -> dict
+#let reversible-reaction-kinetics(kf, kr, initial-a: 1.0, initial-b: 0.0, t-end: auto, points: 100, time-unit: "s") = {
+  let result-bytes = energetics-plugin.reversible_reaction_kinetics(
+    bytes(repr(kf)),
+    bytes(repr(kr)),
+    bytes(repr(initial-a)),
+    bytes(repr(initial-b)),
+    bytes(repr(if t-end == auto { 0 } else { t-end })),
+    bytes(str(points)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ENZYME KINETICS
+// ============================================================================
+
+/// Calculate the Michaelis–Menten initial rate
+/// v = Vmax·[S] / (Km + [S])
+///
+/// Arguments:
+/// - vmax: Maximum rate Vmax
+/// - km: Michaelis constant Km (same unit as the substrate concentration)
+/// - substrate: Substrate concentration [S]
+///
+/// Returns: Dictionary with `value` (rate, in the unit of Vmax) and `unit`
+///
+/// Example:
+/// ```typst
+/// #let v = michaelis-menten-rate(10, 2, 2)
+/// #v.value // 5.0 (half of Vmax at [S] = Km)
+/// ```
+/// -> dict
+#let michaelis-menten-rate(vmax, km, substrate) = {
+  let result-bytes = energetics-plugin.michaelis_menten_rate(
+    bytes(repr(vmax)),
+    bytes(repr(km)),
+    bytes(repr(substrate))
+  )
+  
+  json(result-bytes)
+}
+
+/// Fit Vmax and Km to initial-rate data
+///
+/// The fit is a nonlinear least-squares fit to the untransformed data (seeded from the
+/// Lineweaver–Burk line), which avoids the error distortion of double-reciprocal fits.
+/// The Lineweaver–Burk and Eadie–Hofstee transformed data and their straight-line fits
+/// are returned as well, for plotting.
+///
+/// Arguments:
+/// - data: Array of ([S], v) pairs
+/// - points: Number of points in the fitted curve (default: 100)
+///
+/// Returns: Dictionary with `vmax`, `km`, `vmax_error`, `km_error`, `r_squared`,
+/// `lineweaver_burk` and `eadie_hofstee` (point arrays), `lineweaver_burk_fit` and
+/// `eadie_hofstee_fit` (dictionaries with `slope`, `intercept`, `r_squared`) and `curve`
+///
+/// Example:
+/// ```typst
+/// #let fit = fit-michaelis-menten(((0.5, 2.05), (1, 3.3), (2, 5.1), (4, 6.6), (8, 8.05)))
+/// #fit.km // ≈ 2
+/// ```
+/// -> dict
+#let fit-michaelis-menten(data, points: 100) = {
+  let result-bytes = energetics-plugin.fit_michaelis_menten(
+    bytes(json.encode(data)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// MECHANISM APPROXIMATIONS
+// ============================================================================
+
+/// Effective rate constant of a linear mechanism R ⇌ I₁ ⇌ ... ⇌ Iₙ → P
+///
+/// Compares the steady-state approximation, 1/k_obs = Σⱼ (k₋₁···k₋₍ⱼ₋₁₎)/(k₁···kⱼ), with the
+/// pre-equilibrium approximation, k_obs = K₁···K₍ₙ₋₁₎·kₙ, and reports the limiting k_obs when each
+/// step is rate-determining. Bimolecular steps take pseudo-first-order constants (k·[B]).
+///
+/// Arguments:
+/// - steps: Array of dictionaries with `kf` and (except for the last step) `kr`
+///
+/// Returns: Dictionary with `steady_state`, `pre_equilibrium`, `pre_equilibrium_error`
+/// (relative deviation), `rate_determining_step` (1-based) and `limits` (array of dictionaries
+/// with `step`, `k_limit` and `contribution`)
+///
+/// Example:
+/// ```typst
+/// #let k = effective-rate-constant(((kf: 1e3, kr: 1e5), (kf: 10,)))
+/// #k.steady_state // 1e3·10 / (1e5 + 10) ≈ 0.09999
+/// ```
+/// -> dict
+#let effective-rate-constant(steps) = {
+  let result-bytes = energetics-plugin.effective_rate_constant(
+    bytes(json.encode(steps))
+  )
+  
+  json(result-bytes)
+}
+
+/// Effective rate constant of the two-step template A ⇌ I → P
+///
+/// Steady state: k_obs = k₁·k₂ / (k₋₁ + k₂); pre-equilibrium (k₋₁ ≫ k₂): k_obs = K₁·k₂;
+/// first step rate-determining (k₂ ≫ k₋₁): k_obs = k₁.
+///
+/// Arguments:
+/// - k1: Rate constant of A → I
+/// - k-reverse: Rate constant of I → A
+/// - k2: Rate constant of I → P
+///
+/// Returns: Same dictionary as `effective-rate-constant`
+/// -> dict
+#let two-step-rate-constant(k1, k-reverse, k2) = {
+  effective-rate-constant(((kf: k1, kr: k-reverse), (kf: k2,)))
+}
+
+/// Effective rate constant of the three-step template A ⇌ I₁ ⇌ I₂ → P
+///
+/// Steady state: k_obs = k₁k₂k₃ / (k₋₁k₋₂ + k₋₁k₃ + k₂k₃); pre-equilibrium: k_obs = K₁K₂k₃.
+///
+/// Arguments:
+/// - k1, k1-reverse: Rate constants of A ⇌ I₁
+/// - k2, k2-reverse: Rate constants of I₁ ⇌ I₂
+/// - k3: Rate constant of I₂ → P
+///
+/// Returns: Same dictionary as `effective-rate-constant`
+/// -> dict
+#let three-step-rate-constant(k1, k1-reverse, k2, k2-reverse, k3) = {
+  effective-rate-constant(((kf: k1, kr: k1-reverse), (kf: k2, kr: k2-reverse), (kf: k3,)))
+}
+
+/// Unimolecular rate constant from the Lindemann–Hinshelwood mechanism
+/// k_uni = k₁·k₂·[M] / (k₋₁·[M] + k₂)
+///
+/// At high pressure k_uni approaches the first-order limit k∞ = k₁k₂/k₋₁; at low pressure it
+/// falls to the second-order limit k₀ = k₁[M]. The fall-off curve is returned in log–log form.
+///
+/// Arguments:
+/// - k1: Activation rate constant (L/(mol·s))
+/// - k-reverse: Deactivation rate constant k₋₁ (L/(mol·s))
+/// - k2: Rate constant of the energised molecule A\* → P (s⁻¹)
+/// - conc: Third-body concentration [M] (mol/L, default: none)
+/// - pressure: Total pressure (bar, default: none); converted with [M] = P/(RT)
+/// - temp: Temperature (K, default: 298.15)
+/// - points: Number of fall-off curve points (default: 100)
+///
+/// Returns: Dictionary with `k_uni`, `k_infinity`, `k_zero`, `concentration`,
+/// `half_concentration`, `half_pressure` (bar) and `fall_off` (array of (log₁₀[M], log₁₀k_uni))
+///
+/// Example:
+/// ```typst
+/// #let lh = lindemann-rate-constant(1e9, 1e10, 1e6, pressure: 1.0)
+/// #lh.k_infinity // 1e5 s⁻¹
+/// ```
+/// -> dict
+#let lindemann-rate-constant(k1, k-reverse, k2, conc: none, pressure: none, temp: 298.15, points: 100) = {
+  assert(conc != none or pressure != none, message: "Either conc or pressure must be given")
+  
+  let result-bytes = energetics-plugin.lindemann_rate_constant(
+    bytes(repr(k1)),
+    bytes(repr(k-reverse)),
+    bytes(repr(k2)),
+    bytes(repr(if conc == none { 0 } else { conc })),
+    bytes(repr(if pressure == none { 0 } else { pressure })),
+    bytes(repr(temp)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// RATE LAW DETERMINATION
+// ============================================================================
+
+/// Determine a rate law from a table of initial-rate experiments
+///
+/// The orders are obtained from a least-squares fit of ln(rate) against ln[X] for all
+/// species at once (no need for experiments that vary one concentration at a time), then
+/// rounded to the nearest half-integer. The rate constant is averaged over all experiments.
+///
+/// Arguments:
+/// - experiments: Array of dictionaries with `concentrations` (dictionary species → mol/L)
+///   and `rate` (mol/(L·s))
+///
+/// Returns: Dictionary with `orders` (array of dictionaries with `species`, `order` and
+/// `raw_order`), `overall_order`, `rate_constant` (dictionary with `value` and `unit`),
+/// `rate_law` (string) and `r_squared`
+///
+/// Example:
+/// ```typst
+/// #let law = determine-rate-law((
+///   (concentrations: (NO: 0.01, O2: 0.01), rate: 7.1e-3),
+///   (concentrations: (NO: 0.02, O2: 0.01), rate: 2.84e-2),
+///   (concentrations: (NO: 0.01, O2: 0.02), rate: 1.42e-2),
+/// ))
+/// #law.rate_law // "rate = k[NO]²[O2]"
+/// ```
+/// -> dict
+#let determine-rate-law(experiments) = {
+  let result-bytes = energetics-plugin.determine_rate_law(
+    bytes(json.encode(experiments))
+  )
+  
+  json(result-bytes)
+}
+
+/// Determine the reaction order from concentration–time data
+///
+/// Tests the zeroth-, first- and second-order integrated rate laws by linear regression of
+/// [A], ln[A] and 1/[A] against t, and reports the order with the best linear fit.
+///
+/// Arguments:
+/// - data: Array of (t, [A]) pairs (s, mol/L)
+///
+/// Returns: Dictionary with `best_order` and `candidates`, an array of dictionaries with
+/// `order`, `rate_constant` (dictionary with `value` and `unit`), `r_squared`,
+/// `linearized` (points for plotting) and `fit` (dictionary with `slope`, `intercept`, `r_squared`)
+///
+/// Example:
+/// ```typst
+/// #let fit = fit-reaction-order(((0, 1.0), (10, 0.5), (20, 0.333), (30, 0.25)))
+/// #fit.best_order // 2
+/// ```
+/// -> dict
+#let fit-reaction-order(data) = {
+  let result-bytes = energetics-plugin.fit_reaction_order(
+    bytes(json.encode(data))
+  )
+  
+  json(result-bytes)
+}
+
+/// Extract a first-order rate constant when the infinity reading is unknown
+///
+/// Applies both the Guggenheim method, ln|y(t+Δ) - y(t)| = const - k·t, and the
+/// Kezdy–Swinbourne method, y(t+Δ) = y∞(1 - e^(-kΔ)) + e^(-kΔ)·y(t), to a time series of any
+/// property proportional to concentration (absorbance, conductance, pressure, ...).
+/// The Kezdy–Swinbourne fit also estimates the infinity value y∞.
+///
+/// Arguments:
+/// - data: Array of (t, y) pairs (time unit, any unit)
+/// - delta: Fixed time shift Δ, ideally 2–3 half-lives (time unit, default: auto - half the time span)
+/// - time-unit: Time unit of t, Δ and the rate constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `delta`, `guggenheim` and `kezdy_swinbourne`, each a dictionary with
+/// `rate_constant`, `r_squared`, `points`, `fit` and (Kezdy–Swinbourne only) `infinity_value`
+///
+/// Example:
+/// ```typst
+/// #let res = first-order-without-infinity(absorbance-data, delta: 40)
+/// #res.guggenheim.rate_constant.value
+/// ```
+/// -> dict
+#let first-order-without-infinity(data, delta: auto, time-unit: "s") = {
+  let result-bytes = energetics-plugin.first_order_without_infinity(
+    bytes(json.encode(data)),
+    bytes(repr(if delta == auto { 0 } else { delta })),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Extract initial and instantaneous rates from concentration–time data
+///
+/// With `"polynomial"` (default) a low-degree polynomial is fitted to the data points nearest
+/// each requested time and differentiated, which smooths measurement noise. With
+/// `"finite-difference"` central differences between neighbouring points are used.
+/// The initial rate is the derivative at the first data point, as needed for
+/// `determine-rate-law`.
+///
+/// Arguments:
+/// - data: Array of (t, c) pairs
+/// - times: Times at which instantaneous rates are wanted (default: ())
+/// - method: `"polynomial"` or `"finite-difference"` (default: "polynomial")
+/// - window: Number of points per local fit (default: 5)
+/// - degree: Degree of the local polynomial (default: 2)
+///
+/// Returns: Dictionary with `method`, `initial_rate` (|dc/dt| at the first point),
+/// `initial_derivative` (dc/dt) and `rates`, an array of dictionaries with `time`,
+/// `concentration`, `derivative` and `rate`
+///
+/// Example:
+/// ```typst
+/// #let r = extract-rates(((0, 1.0), (2, 0.819), (4, 0.670), (6, 0.549)), times: (3,))
+/// #r.initial_rate
+/// ```
+/// -> dict
+#let extract-rates(data, times: (), method: "polynomial", window: 5, degree: 2) = {
+  let result-bytes = energetics-plugin.extract_rates(
+    bytes(json.encode(data)),
+    bytes(json.encode(times)),
+    bytes(method),
+    bytes(str(window)),
+    bytes(str(degree))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// CATALYSIS
+// ============================================================================
+
+/// Calculate the turnover number (TON) and turnover frequency (TOF) of a catalyst
+/// TON = n(product) / n(catalyst), TOF = TON / t
+///
+/// Arguments:
+/// - product-moles: Amount of product formed (mol)
+/// - catalyst-moles: Amount of catalyst or active sites (mol)
+/// - time: Reaction time (time unit, default: none - TOF omitted)
+/// - time-unit: Time unit of the reaction time and the TOF: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `turnover_number` and (with a time) `turnover_frequency`
+/// (dictionary with `value` and `unit`)
+///
+/// Example:
+/// ```typst
+/// #let t = calc-turnover(0.50, 1e-4, time: 3600)
+/// #t.turnover_number // 5000
+/// ```
+/// -> dict
+#let calc-turnover(product-moles, catalyst-moles, time: none, time-unit: "s") = {
+  let result-bytes = energetics-plugin.calculate_turnover(
+    bytes(repr(product-moles)),
+    bytes(repr(catalyst-moles)),
+    bytes(repr(if time == none { 0 } else { time })),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Compare the rates of catalysed and uncatalysed pathways
+/// k_cat / k_uncat = (A_cat / A_uncat)·exp((Ea_uncat - Ea_cat) / (R·T))
+///
+/// Arguments:
+/// - ea-uncatalyzed: Activation energy without catalyst (kJ/mol)
+/// - ea-catalyzed: Activation energy with catalyst (kJ/mol)
+/// - temp: Temperature (K, default: 298.15)
+/// - a-ratio: Ratio of pre-exponential factors A_cat / A_uncat (default: 1.0)
+///
+/// Returns: Dictionary with `rate_enhancement`, `activation_energy_lowering` (kJ/mol) and `temperature`
+///
+/// Example:
+/// ```typst
+/// #let c = compare-catalyzed-rate(75, 50, temp: 298.15)
+/// #c.rate_enhancement // ≈ 2.4e4
+/// ```
+/// -> dict
+#let compare-catalyzed-rate(ea-uncatalyzed, ea-catalyzed, temp: 298.15, a-ratio: 1.0) = {
+  let result-bytes = energetics-plugin.compare_catalyzed_rate(
+    bytes(repr(ea-uncatalyzed)),
+    bytes(repr(ea-catalyzed)),
+    bytes(repr(temp)),
+    bytes(repr(a-ratio))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// KINETIC ISOTOPE EFFECTS
+// ============================================================================
+
+/// Estimate the primary kinetic isotope effect kH/kD from a stretching frequency
+///
+/// Zero-point-energy model: the X–H and X–D stretches are assumed to be lost in the
+/// transition state, so kH/kD = exp(ΔZPE / (kB·T)) with ΔZPE = ½·h·c·(ν_H - ν_D) and
+/// ν_D = ν_H·√(μ_H/μ_D). Typical C–H values give kH/kD ≈ 6–7 at room temperature.
+///
+/// Arguments:
+/// - frequency: X–H stretching wavenumber (cm⁻¹)
+/// - temp: Temperature (K, default: 298.15)
+/// - heavy-atom-mass: Mass of the atom bonded to H/D (u, default: 12.0 for C–H)
+///
+/// Returns: Dictionary with `value` (kH/kD), `unit`, `hydrogen_frequency`,
+/// `deuterium_frequency` (cm⁻¹) and `zero_point_energy_difference` (kJ/mol)
+///
+/// Example:
+/// ```typst
+/// #let kie = calc-kinetic-isotope-effect(2900, temp: 298.15)
+/// #kie.value // ≈ 6.4
+/// ```
+/// -> dict
+#let calc-kinetic-isotope-effect(frequency, temp: 298.15, heavy-atom-mass: 12.0) = {
+  let result-bytes = energetics-plugin.calculate_kinetic_isotope_effect(
+    bytes(repr(frequency)),
+    bytes(repr(temp)),
+    bytes(repr(heavy-atom-mass))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// RADIOACTIVE DECAY
+// ============================================================================
+
+/// Calculate radioactive decay and activity
+/// N(t) = N₀·e^(-λt), λ = ln(2)/t₁/₂, A = λ·N
+///
+/// Arguments:
+/// - n0: Initial number of nuclei
+/// - half-life: Half-life (time unit)
+/// - time: Elapsed time (time unit)
+/// - time-unit: Time unit of the half-life, time and decay constant: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `remaining`, `fraction_remaining`, `decay_constant`,
+/// `mean_lifetime`, `initial_activity` and `activity` (dictionaries with `value` and `unit`,
+/// activities in Bq) and `activity_ci` (Ci)
+///
+/// Example:
+/// ```typst
+/// #let decay = calc-radioactive-decay(1e20, 8.02, 1, time-unit: "d") // ¹³¹I after one day
+/// #decay.fraction_remaining // ≈ 0.917
+/// ```
+/// -> dict
+#let calc-radioactive-decay(n0, half-life, time, time-unit: "s") = {
+  let result-bytes = energetics-plugin.calculate_radioactive_decay(
+    bytes(repr(n0)),
+    bytes(repr(half-life)),
+    bytes(repr(time)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the age of a sample from its remaining fraction (e.g. radiocarbon dating)
+/// t = t₁/₂·ln(1/f) / ln(2)
+///
+/// Arguments:
+/// - fraction: Remaining fraction N/N₀ or activity ratio A/A₀ (0 < f ≤ 1)
+/// - half-life: Half-life (time unit)
+/// - time-unit: Time unit of the half-life and the age: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with the age in the requested time unit
+///
+/// Example:
+/// ```typst
+/// #let age = calc-radioactive-age(0.25, 5730, time-unit: "yr") // two ¹⁴C half-lives: 11460 yr
+/// ```
+/// -> dict
+#let calc-radioactive-age(fraction, half-life, time-unit: "s") = {
+  let result-bytes = energetics-plugin.calculate_radioactive_age(
+    bytes(repr(fraction)),
+    bytes(repr(half-life)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Solve a sequential radioactive decay chain (parent → daughter → granddaughter → ...)
+/// with the Bateman equations, starting from a pure parent
+///
+/// Each consecutive parent/daughter pair is classified as secular equilibrium
+/// (parent half-life ≥ 100 × daughter half-life), transient equilibrium (parent
+/// longer-lived) or no equilibrium.
+///
+/// Arguments:
+/// - half-lives: Array of half-lives (time unit); the last member may be `none` (stable)
+/// - names: Array of member names (default: auto - parent, daughter, granddaughter, ...)
+/// - n0: Initial number of parent nuclei (default: 1e20)
+/// - t-end: Final time (time unit, default: auto - ten half-lives of the longest-lived daughter)
+/// - points: Number of sample points (default: 100)
+/// - time-unit: Time unit of half-lives, times and decay constants: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with the time-course fields of `concentration-time-curve` holding
+/// activities in Bq, plus `nuclei` (the same time course for numbers of nuclei),
+/// `decay_constants` (time unit⁻¹) and `equilibria` (array of dictionaries with `parent`,
+/// `daughter`, `equilibrium` and `activity_ratio`)
+///
+/// Example:
+/// ```typst
+/// #let chain = decay-chain((66.0, 6.01, none), names: ("⁹⁹Mo", "⁹⁹ᵐTc", "⁹⁹Tc"), time-unit: "h")
+/// #chain.equilibria.first().equilibrium // "transient"
+/// ```
+/// -> dict
+#let decay-chain(half-lives, names: auto, n0: 1e20, t-end: auto, points: 100, time-unit: "s") = {
+  let result-bytes = energetics-plugin.decay_chain(
+    bytes(json.encode(half-lives)),
+    bytes(json.encode(if names == auto { () } else { names })),
+    bytes(repr(n0)),
+    bytes(repr(if t-end == auto { 0 } else { t-end })),
+    bytes(str(points)),
+    bytes(time-unit)
+  )
+  
+  json(result-bytes)
+}
+
+
+/// Calculate the mass defect and binding energy of a nucleus from its atomic mass
+///
+/// Δm = Z·m(¹H) + N·mₙ - M(atom), BE = Δm·c² with 931.494 MeV/u
+///
+/// Arguments:
+/// - nuclide: Nuclide name from the built-in mass table ("U-235", "235U", "Fe56", "D") or a
+///   dictionary `(z: ..., a: ..., mass: ...)` with the atomic mass in u
+///
+/// Returns: Dictionary with `nuclide`, `z`, `a`, `neutrons`, `atomic_mass` and
+/// `mass_defect` (u), `value` and `unit` (MeV), `per_nucleon` (MeV) and `molar` (kJ/mol)
+///
+/// Example:
+/// ```typst
+/// #calc-binding-energy("Fe-56").per_nucleon.value // ≈ 8.790 MeV
+/// ```
+/// -> dict
+#let calc-binding-energy(nuclide) = {
+  let result-bytes = energetics-plugin.nuclear_binding_energy(
+    bytes(json.encode(nuclide))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the Q-value of a nuclear reaction
+///
+/// Q = (Σm_reactants - Σm_products)·c² from nuclear masses. Charge and mass number must
+/// balance; write electrons and positrons explicitly ("e-", "e+"). Particles: "n", "p",
+/// "d", "t", "alpha", "e-", "e+", "gamma", "nu".
+///
+/// Arguments:
+/// - reactants: Array of nuclides, or (nuclide, count) pairs
+/// - products: Array of nuclides, or (nuclide, count) pairs
+///
+/// Returns: Dictionary with `value` and `unit` (MeV), `mass_change` (u), `molar` (kJ/mol),
+/// `kind` ("exoergic" or "endoergic") and, for endoergic two-body reactions,
+/// `threshold_energy` of the first reactant (MeV)
+///
+/// Example:
+/// ```typst
+/// #calc-nuclear-q-value(("U-235", "n"), ("Ba-141", "Kr-92", ("n", 3))).value // ≈ 173.3 MeV
+/// ```
+/// -> dict
+#let calc-nuclear-q-value(reactants, products) = {
+  let result-bytes = energetics-plugin.nuclear_q_value(
+    bytes(json.encode(reactants)),
+    bytes(json.encode(products))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// CHEMICAL FORMULAS
+// ============================================================================
+
+/// Parse a chemical formula into its element composition and molar mass
+///
+/// Supports nested brackets ("Ca3(PO4)2", "[Cu(NH3)4]SO4"), hydrates and adducts
+/// ("CuSO4·5H2O", "CuSO4*5H2O" or "CuSO4.5H2O"), charges ("Fe3+", "SO4^2-") and state symbols
+/// ("NaCl(aq)"). Multiply charged polyatomic ions need a caret ("SO4^2-").
+///
+/// Arguments:
+/// - formula: Chemical formula
+///
+/// Returns: Dictionary with `formula`, `elements` (each with `element`, `count`, `mass`
+/// (g/mol) and `mass_percent`), `charge`, `state` (if given), `atoms` and `molar_mass`
+///
+/// Example:
+/// ```typst
+/// #let blue-vitriol = parse-formula("CuSO4·5H2O")
+/// #blue-vitriol.molar_mass.value // ≈ 249.68 g/mol
+/// ```
+/// -> dict
+#let parse-formula(formula) = {
+  let result-bytes = energetics-plugin.formula_composition(
+    bytes(formula)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the molar mass of a chemical formula from standard atomic weights
+///
+/// Arguments:
+/// - formula: Chemical formula
+///
+/// Returns: Dictionary with `value` and `unit` (g/mol)
+///
+/// Example:
+/// ```typst
+/// #calc-molar-mass("C6H12O6").value // 180.156 g/mol
+/// ```
+/// -> dict
+#let calc-molar-mass(formula) = {
+  let result-bytes = energetics-plugin.molar_mass(
+    bytes(formula)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the average, monoisotopic and nominal masses of a formula
+///
+/// Formulas may carry isotope labels: a caret and mass number or a superscript mass number
+/// before the symbol ("^13CO2", "¹⁵NH3"), or D and T for ²H and ³H. Labelled positions are
+/// taken as fully enriched.
+///
+/// Arguments:
+/// - formula: Chemical formula
+///
+/// Returns: Dictionary with `average_mass` (g/mol, standard atomic weights with labelled
+/// positions at their exact masses), `monoisotopic_mass` (u), `nominal_mass`,
+/// `monoisotopic_abundance`, `labels` (`isotope`, `count`, `mass`) and `label_shift` (u)
+///
+/// Example:
+/// ```typst
+/// #calc-isotopic-masses("^13CO2").monoisotopic_mass.value // ≈ 44.9932 u
+/// ```
+/// -> dict
+#let calc-isotopic-masses(formula) = {
+  let result-bytes = energetics-plugin.isotopic_masses(
+    bytes(formula)
+  )
+  
+  json(result-bytes)
+}
+
+/// Look up the isotopes of an element with their exact masses and natural abundances
+///
+/// Arguments:
+/// - element: Element symbol
+///
+/// Returns: Dictionary with `element`, `atomic_weight`, `isotopes` (`mass_number`, `mass`
+/// (u), `abundance` (%)), `radioisotopes` and `isotopic_average`
+///
+/// Example:
+/// ```typst
+/// #get-isotopes("Cl").isotopes.map(i => i.abundance) // (75.76, 24.24)
+/// ```
+/// -> dict
+#let get-isotopes(element) = {
+  let result-bytes = energetics-plugin.isotope_data(
+    bytes(element)
+  )
+  
+  json(result-bytes)
+}
+
+/// Normalise the state annotation of a species name
+///
+/// "(g)", "(gas)", "(G)", "[g]" and " (vapour)" become "g"; liquids "l", solids and
+/// crystals "s", and aqueous species "aq".
+///
+/// Arguments:
+/// - species: Species name with an optional state annotation
+///
+/// Returns: Dictionary with `species`, `formula`, `state` (if annotated) and `normalized`
+///
+/// Example:
+/// ```typst
+/// #normalize-species("H2O (gas)").normalized // "H2O(g)"
+/// ```
+/// -> dict
+#let normalize-species(species) = {
+  let result-bytes = energetics-plugin.normalize_species(
+    bytes(species)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the change in moles of gas of a reaction from its state annotations
+///
+/// Δn(gas) = Σν(g, products) - Σν(g, reactants), shared by ΔH = ΔU + Δn·RT and
+/// Kp = Kc·(RT/P°)^Δn
+///
+/// Arguments:
+/// - reactants: Array of (species, coefficient) tuples
+/// - products: Array of (species, coefficient) tuples
+/// - temp: Temperature for the conversions (K, default: reference temperature of `standard`)
+/// - standard: Standard state fixing P°, a name in `standard-states` or a dictionary
+///   (default: "iupac", 1 bar)
+///
+/// Returns: Dictionary with `value` (Δn), `gas_reactants`, `gas_products`, normalised
+/// `reactants` and `products`, `unspecified` (species without a state, not counted),
+/// `rt_delta_n` (kJ/mol), `kp_over_kc` ((RT/P°)^Δn with c° = 1 mol/L), `standard_pressure`
+/// and `entropy_shift` (ΔS° at P° minus ΔS° at 1 bar, J/(mol·K))
+///
+/// Example:
+/// ```typst
+/// #calc-delta-n-gas((("N2(g)", 1), ("H2(g)", 3)), (("NH3(g)", 2),)).value // -2
+/// ```
+/// -> dict
+#let calc-delta-n-gas(reactants, products, temp: auto, standard: "iupac") = {
+  let result-bytes = energetics-plugin.reaction_gas_change(
+    bytes(json.encode(reactants)),
+    bytes(json.encode(products)),
+    bytes(repr(_standard-temp(temp, standard))),
+    bytes(_standard-state(standard).pressure)
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// STOICHIOMETRY
+// ============================================================================
+
+/// Solve the stoichiometry of a balanced reaction: mole and mass conversions, the limiting
+/// reagent, theoretical and percent yield, and excess reagent left over
+///
+/// The reaction must conserve every element and the charge. With reactant amounts, the
+/// limiting reagent has the smallest n/ν and sets the extent ξ; a product amount alone
+/// gives the reactants needed to make it.
+///
+/// Arguments:
+/// - reactants: Array of (formula, coefficient) tuples
+/// - products: Array of (formula, coefficient) tuples
+/// - amounts: Known amounts as (formula, mass in g) or (formula, value, unit) with unit "g",
+///   "mg", "kg", "t", "mol", "mmol" or "kmol"
+/// - product: Product the yield refers to (default: auto - the first product)
+/// - actual-yield: Isolated mass of that product (g, default: none)
+///
+/// Returns: Dictionary with `extent` (mol), `limiting_reagent`, `reactants` and `products`
+/// (each with `species`, `coefficient`, `molar_mass`, `moles` and `mass` consumed or
+/// formed, and `initial_moles`, `initial_mass`, `remaining_moles`, `remaining_mass` and
+/// `limiting` for given reactants), `product`, `theoretical_yield` (g) and `percent_yield`
+///
+/// Example:
+/// ```typst
+/// #let water = calc-stoichiometry((("H2", 2), ("O2", 1)), (("H2O", 2),), (("H2", 4), ("O2", 1, "mol")))
+/// #water.limiting_reagent // "H2"
+/// #water.theoretical_yield.value // ≈ 35.74 g
+/// ```
+/// -> dict
+#let calc-stoichiometry(reactants, products, amounts, product: auto, actual-yield: none) = {
+  let input = (
+    reactants: reactants,
+    products: products,
+    amounts: amounts,
+    product: if product == auto { none } else { product },
+    actual_yield: actual-yield,
+  )
+  
+  let result-bytes = energetics-plugin.reaction_stoichiometry(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate green-chemistry metrics of a balanced reaction
+///
+/// Atom economy = ν·M(product) / Σν·M(reactants) follows from the equation alone. With the
+/// masses actually used, reaction mass efficiency = m(product) / Σm(reactants),
+/// E-factor = (Σm(inputs) - m(product)) / m(product) and process mass intensity
+/// = Σm(inputs) / m(product), where the inputs include auxiliary materials.
+///
+/// Arguments:
+/// - reactants: Array of (formula, coefficient) tuples
+/// - products: Array of (formula, coefficient) tuples
+/// - product: Desired product (default: auto - the first product)
+/// - reactant-masses: (formula, mass in g) of the reactants used (default: ())
+/// - product-mass: Isolated mass of the desired product (g, default: none)
+/// - auxiliary-mass: Solvents, catalysts and other materials used (g, default: 0)
+///
+/// Returns: Dictionary with `product`, `atom_economy` (%), `by_product_mass` (g per mole of
+/// reaction) and, with masses, `reaction_mass_efficiency` (%), `e_factor` and
+/// `process_mass_intensity`
+///
+/// Example:
+/// ```typst
+/// // Aspirin from salicylic acid and acetic anhydride
+/// #calc-green-metrics((("C7H6O3", 1), ("C4H6O3", 1)), (("C9H8O4", 1), ("C2H4O2", 1))).atom_economy // ≈ 75.0 %
+/// ```
+/// -> dict
+#let calc-green-metrics(
+  reactants,
+  products,
+  product: auto,
+  reactant-masses: (),
+  product-mass: none,
+  auxiliary-mass: 0,
+) = {
+  let input = (
+    reactants: reactants,
+    products: products,
+    product: if product == auto { none } else { product },
+    reactant_masses: reactant-masses,
+    product_mass: product-mass,
+    auxiliary_mass: auxiliary-mass,
+  )
+  
+  let result-bytes = energetics-plugin.green_metrics(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Determine an empirical formula from percent composition or combustion analysis
+///
+/// Masses are converted to moles, divided by the smallest amount and multiplied by 1-10 until
+/// every subscript is within the tolerance of a whole number. With a molar mass the molecular
+/// formula is (empirical formula)ₙ with n = M / M(empirical).
+///
+/// Arguments:
+/// - composition: Array of (element, mass) tuples, as mass percentages or grams (default: ())
+/// - combustion: Dictionary with `sample`, `co2` and `h2o` masses in g; C and H come from the
+///   CO₂ and H₂O, the rest of the sample is the remainder element (default: none)
+/// - remainder: Element making up the rest of a combustion sample (default: "O")
+/// - molar-mass: Molar mass of the compound (g/mol, default: none)
+/// - tolerance: Largest accepted deviation of a subscript from a whole number (between 0 and 0.5, default: 0.1)
+///
+/// Returns: Dictionary with `empirical_formula`, `empirical_mass`, per-element `elements`
+/// (mass, moles, ratio, subscript), `multiplier` and, with a molar mass, `molecular_formula`,
+/// `molecular_mass` and `formula_units`
+///
+/// Example:
+/// ```typst
+/// #calc-empirical-formula(composition: (("C", 40.0), ("H", 6.71), ("O", 53.29)), molar-mass: 180.16).molecular_formula // "C6H12O6"
+/// #calc-empirical-formula(combustion: (sample: 0.255, co2: 0.561, h2o: 0.306)).empirical_formula // "C3H8O"
+/// ```
+/// -> dict
+#let calc-empirical-formula(
+  composition: (),
+  combustion: none,
+  remainder: "O",
+  molar-mass: none,
+  tolerance: 0.1,
+) = {
+  let input = (
+    composition: composition,
+    combustion: if combustion == none { none } else {
+      (
+        sample_mass: combustion.sample,
+        co2_mass: combustion.co2,
+        h2o_mass: combustion.h2o,
+      )
+    },
+    remainder: remainder,
+    molar_mass: molar-mass,
+    tolerance: tolerance,
+  )
+  
+  let result-bytes = energetics-plugin.empirical_formula(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ELECTROCHEMISTRY
+// ============================================================================
+
+/// Calculate a cell or electrode potential under non-standard conditions (Nernst equation)
+/// E = E° - (RT/nF)·ln Q
+///
+/// The reaction quotient can be given directly or as activities (concentrations in mol/L,
+/// partial pressures in bar) with stoichiometric coefficients. The `"shortcut"` method uses
+/// the familiar 0.0592 V/n per decade, valid at 298.15 K only.
+///
+/// Arguments:
+/// - e-standard: Standard potential E° (V)
+/// - n: Number of electrons transferred
+/// - q: Reaction quotient, or dictionary with `reactants` and `products` arrays of (activity, coefficient)
+/// - temp: Temperature (K, default: 298.15)
+/// - method: `"exact"` or `"shortcut"` (default: "exact")
+///
+/// Returns: Dictionary with `value` and `unit` (V), `standard_potential`, `reaction_quotient`,
+/// `slope` (V per decade of Q) and `method`
+///
+/// Example:
+/// ```typst
+/// // Zn | Zn²⁺ (0.010 M) || Cu²⁺ (1.0 M) | Cu
+/// #let e = calc-nernst-potential(1.10, 2, (reactants: ((1.0, 1),), products: ((0.010, 1),)))
+/// #e.value // ≈ 1.159 V
+/// ```
+/// -> dict
+#let calc-nernst-potential(e-standard, n, q, temp: 298.15, method: "exact") = {
+  let result-bytes = energetics-plugin.calculate_nernst_potential(
+    bytes(repr(e-standard)),
+    bytes(repr(n)),
+    bytes(json.encode(q)),
+    bytes(repr(temp)),
+    bytes(method)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the standard cell potential of a galvanic cell from two reduction half-reactions
+/// E°cell = E°cathode - E°anode
+///
+/// The couple with the higher reduction potential is taken as the cathode. Each half-reaction
+/// may be an identifier looked up in `data`, an explicit dictionary
+/// `(E0: ..., n: ..., oxidized: ((species, coeff), ...), reduced: ((species, coeff), ...))`
+/// describing oxidized + n e⁻ → reduced, or a bare E° value. When both half-reactions carry
+/// species and electron counts, the overall equation is balanced and spectator species
+/// (H⁺, H₂O, ...) appearing on both sides are cancelled.
+///
+/// Arguments:
+/// - first: First half-reaction (identifier, dictionary or E° in V)
+/// - second: Second half-reaction (identifier, dictionary or E° in V)
+/// - data: Reduction potential table used to resolve identifiers (default: built-in table)
+///
+/// Returns: Dictionary with `value` and `unit` (V), `cathode` and `anode` (dictionaries with
+/// `label`, `e_standard`, `n` and `half_reaction`), `electrons_transferred`, `equation`,
+/// `reactants` and `products`
+///
+/// Example:
+/// ```typst
+/// #let cell = calc-cell-potential(
+///   (E0: 0.337, n: 2, oxidized: (("Cu2+(aq)", 1),), reduced: (("Cu(s)", 1),)),
+///   (E0: 0.7996, n: 1, oxidized: (("Ag+(aq)", 1),), reduced: (("Ag(s)", 1),)),
+/// )
+/// #cell.equation // "2 Ag+(aq) + Cu(s) → 2 Ag(s) + Cu2+(aq)"
+/// ```
+/// -> dict
+#let calc-cell-potential(first, second, data: reduction-potential-data) = {
+  let result-bytes = energetics-plugin.calculate_cell_potential(
+    bytes(json.encode(first)),
+    bytes(json.encode(second)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Look up a standard reduction potential
+///
+/// The built-in table covers common couples at 298.15 K, keyed as `"oxidized/reduced"`
+/// (e.g. `"Cu2+/Cu"`, `"MnO4-/Mn2+"`, `"O2/H2O"`). Custom or corrected couples can be
+/// supplied by extending the table: `data: reduction-potential-data + ("X/Y": (...))`.
+///
+/// Arguments:
+/// - couple: Couple identifier, e.g. "Zn2+/Zn"
+/// - data: Reduction potential table (default: built-in table)
+///
+/// Returns: Dictionary with `E0` (V), `n`, `oxidized`, `reduced` and `half_reaction`
+///
+/// Example:
+/// ```typst
+/// #let zn = get-reduction-potential("Zn2+/Zn")
+/// #zn.E0 // -0.7618
+/// ```
+/// -> dict
+#let get-reduction-potential(couple, data: reduction-potential-data) = {
+  let result-bytes = energetics-plugin.get_reduction_potential(
+    bytes(couple),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Interconvert E°, ΔG° and K for a redox reaction (internal helper)
+///
+/// Arguments:
+/// - quantity: Given quantity, `"E0"`, `"delta_G"` or `"K"`
+/// - value: Value of the given quantity
+/// - n: Number of electrons transferred
+/// - temp: Temperature (K)
+///
+/// Returns: Dictionary with `e_standard` (V), `delta_g` (kJ/mol), `equilibrium_constant`,
+/// `ln_k`, `log10_k`, `n` and `temperature`
+/// -> dict
+#let convert-standard-redox(quantity, value, n, temp) = {
+  let result-bytes = energetics-plugin.convert_standard_redox_quantities(
+    bytes(quantity),
+    bytes(repr(value)),
+    bytes(repr(n)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate ΔG° and K from a standard cell potential
+/// ΔG° = -nFE°, ln K = nFE°/(RT)
+///
+/// Arguments:
+/// - e-standard: Standard cell potential E° (V)
+/// - n: Number of electrons transferred
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `e_standard` and `delta_g` (dictionaries with `value` and `unit`),
+/// `equilibrium_constant` (omitted on overflow), `ln_k`, `log10_k`, `n` and `temperature`
+///
+/// Example:
+/// ```typst
+/// #let daniell = calc-gibbs-from-potential(1.10, 2)
+/// #daniell.delta_g.value // ≈ -212.3 kJ/mol
+/// ```
+/// -> dict
+#let calc-gibbs-from-potential(e-standard, n, temp: 298.15) = {
+  convert-standard-redox("E0", e-standard, n, temp)
+}
+
+/// Calculate E° and K from a standard Gibbs energy change
+/// E° = -ΔG°/(nF), K = exp(-ΔG°/(RT))
+///
+/// Arguments:
+/// - delta-g: Standard Gibbs energy change ΔG° (kJ/mol)
+/// - n: Number of electrons transferred
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with the same fields as `calc-gibbs-from-potential`
+///
+/// Example:
+/// ```typst
+/// #let res = calc-potential-from-gibbs(-237.13, 2) // H₂ + ½O₂ → H₂O(l)
+/// #res.e_standard.value // ≈ 1.229 V
+/// ```
+/// -> dict
+#let calc-potential-from-gibbs(delta-g, n, temp: 298.15) = {
+  convert-standard-redox("delta_G", delta-g, n, temp)
+}
+
+/// Calculate E° and ΔG° from an equilibrium constant
+/// E° = (RT/nF)·ln K
+///
+/// Arguments:
+/// - k: Equilibrium constant
+/// - n: Number of electrons transferred
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with the same fields as `calc-gibbs-from-potential`
+///
+/// Example:
+/// ```typst
+/// #let res = calc-potential-from-equilibrium-constant(1.8e-10, 1) // AgCl solubility
+/// #res.e_standard.value // ≈ -0.577 V
+/// ```
+/// -> dict
+#let calc-potential-from-equilibrium-constant(k, n, temp: 298.15) = {
+  convert-standard-redox("K", k, n, temp)
+}
+
+/// Electrolysis calculations with Faraday's laws
+/// n = η·I·t / (z·F)
+///
+/// Leave exactly one of `current`, `time` and the amount (`moles`, `mass` or `volume`)
+/// unspecified and it is calculated from the other two: the amount deposited or evolved,
+/// the time required, or the current required.
+///
+/// Arguments:
+/// - electrons: Electrons transferred per ion or molecule (z)
+/// - current: Current (A, default: none)
+/// - time: Electrolysis time (time unit, default: none)
+/// - moles: Amount of product (mol, default: none)
+/// - mass: Mass of product (g, default: none, requires `molar-mass`)
+/// - volume: Volume of gaseous product (L, default: none)
+/// - molar-mass: Molar mass of the product (g/mol, default: none)
+/// - gas: Report the amount as an ideal-gas volume (default: false)
+/// - efficiency: Current efficiency η (default: 1.0)
+/// - temp: Gas temperature (K, default: 298.15)
+/// - pressure: Gas pressure (bar, default: 1.0)
+/// - time-unit: Time unit: "s", "min", "h", "d" or "yr" (default: "s")
+///
+/// Returns: Dictionary with `solved_for`, `current`, `time` and `charge` (dictionaries with
+/// `value` and `unit`), `moles_electrons` (total charge passed), `moles`, and `mass` and
+/// `gas_volume` when applicable
+///
+/// Example:
+/// ```typst
+/// // Copper deposited by 2.0 A for 1 h
+/// #let cu = calc-electrolysis(2, current: 2.0, time: 1, molar-mass: 63.546, time-unit: "h")
+/// #cu.mass.value // ≈ 2.37 g
+/// ```
+/// -> dict
+#let calc-electrolysis(
+  electrons,
+  current: none,
+  time: none,
+  moles: none,
+  mass: none,
+  volume: none,
+  molar-mass: none,
+  gas: false,
+  efficiency: 1.0,
+  temp: 298.15,
+  pressure: 1.0,
+  time-unit: "s",
+) = {
+  let input = (
+    electrons: electrons,
+    current: current,
+    time: time,
+    moles: moles,
+    mass: mass,
+    volume: volume,
+    molar_mass: molar-mass,
+    gas: gas,
+    efficiency: efficiency,
+    temperature: temp,
+    pressure: pressure,
+    time_unit: time-unit,
+  )
+  let result-bytes = energetics-plugin.faraday_electrolysis(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the electrical energy and cost of producing a product by electrolysis
+/// E = z·F·(m/M)·(V + η) / ε
+///
+/// The charge is increased by the current efficiency ε and the applied voltage by the
+/// overpotential η; the theoretical energy uses the cell voltage at 100 % efficiency.
+///
+/// Arguments:
+/// - electrons: Electrons transferred per ion or molecule (z)
+/// - mass: Mass of product (g)
+/// - molar-mass: Molar mass of the product (g/mol)
+/// - voltage: Cell voltage (V)
+/// - overpotential: Additional overpotential (V, default: 0)
+/// - efficiency: Current efficiency ε (default: 1.0)
+/// - price: Electricity price per kWh (default: none)
+/// - currency: Currency label for the cost (default: "")
+///
+/// Returns: Dictionary with `moles`, `charge`, `applied_voltage`, `energy` (kWh),
+/// `specific_energy` (kWh/kg), `theoretical_energy` (kWh), `energy_efficiency`, and `cost` and
+/// `cost_per_kg` when a price is given
+///
+/// Example:
+/// ```typst
+/// // One tonne of aluminium (Hall–Héroult cell at 4.2 V + 0.3 V, 95 % efficiency)
+/// #let al = calc-electrolysis-energy(3, 1e6, 26.98, 4.2, overpotential: 0.3,
+///   efficiency: 0.95, price: 0.08, currency: "USD")
+/// #al.specific_energy.value // ≈ 14.1 kWh/kg
+/// #al.cost.value // ≈ 1129 USD
+/// ```
+/// -> dict
+#let calc-electrolysis-energy(
+  electrons,
+  mass,
+  molar-mass,
+  voltage,
+  overpotential: 0,
+  efficiency: 1.0,
+  price: none,
+  currency: "",
+) = {
+  let input = (
+    electrons: electrons,
+    mass: mass,
+    molar_mass: molar-mass,
+    voltage: voltage,
+    overpotential: overpotential,
+    efficiency: efficiency,
+    price: price,
+    currency: currency,
+  )
+  let result-bytes = energetics-plugin.electrolysis_energy(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the thermodynamic efficiency of a fuel cell
+/// ε = ΔG/ΔH, E°rev = -ΔG/nF, E_tn = -ΔH/nF
+///
+/// ΔH and ΔG (= ΔH - TΔS) are calculated from the thermodynamic data for the reaction.
+/// Given an operating voltage, the voltage efficiency V/E°rev and the overall efficiency
+/// V/E_tn (fraction of the heat of reaction delivered as electricity) are added.
+///
+/// Arguments:
+/// - reactants: Array of tuples (formula, coefficient)
+/// - products: Array of tuples (formula, coefficient)
+/// - n: Number of electrons transferred
+/// - data: Thermodynamic data (default: built-in data)
+/// - temp: Temperature (K, default: 298.15)
+/// - voltage: Operating cell voltage (V, default: none)
+///
+/// Returns: Dictionary with `value` (ΔG/ΔH), `delta_h` and `delta_g` (kJ/mol),
+/// `reversible_voltage`, `thermoneutral_voltage`, and `voltage_efficiency` and
+/// `overall_efficiency` when a voltage is given, and `warnings` when `temp` is outside the
+/// `T_range` of an entry
+///
+/// Example:
+/// ```typst
+/// #let pem = calc-fuel-cell-efficiency(
+///   (("H2(g)", 1), ("O2(g)", 0.5)), (("H2O(l)", 1),), 2, voltage: 0.7)
+/// #pem.value // ≈ 0.83
+/// #pem.overall_efficiency // ≈ 0.47
+/// ```
+/// -> dict
+#let calc-fuel-cell-efficiency(reactants, products, n, data: thermo-data, temp: 298.15, voltage: none) = {
+  let delta-h = calc-reaction-enthalpy(reactants, products, data: data).value
+  let delta-s = calc-reaction-entropy(reactants, products, data: data).value
+  let delta-g = calc-gibbs-energy(delta-h, delta-s, temp: temp).value
+  let result-bytes = energetics-plugin.fuel_cell_efficiency(
+    bytes(repr(delta-h)),
+    bytes(repr(delta-g)),
+    bytes(repr(n)),
+    bytes(repr(if voltage == none { 0 } else { voltage }))
+  )
+  let warnings = check-temperature-range((reactants + products).map(((f, c)) => f), temp, data: data)
+  
+  _with-warnings(json(result-bytes), warnings)
+}
+
+/// Compare the theoretical specific energy of battery couples
+/// Q = nF / (3.6·Σν·M) (mAh/g), w = Q·E°cell (Wh/kg)
+///
+/// Each pair of half-reactions is combined as in `calc-cell-potential`; the reactant masses
+/// come from the balanced cell reaction, so identifiers must refer to table entries that list
+/// their species (or be explicit half-reactions).
+///
+/// Arguments:
+/// - pairs: Array of (couple, couple) pairs, each an identifier or explicit half-reaction
+/// - data: Reduction potential table (default: built-in table)
+/// - exclude: Reactants left out of the mass, e.g. ("O2", "H+") for metal–air cells
+///   (default: ())
+///
+/// Returns: Array of dictionaries with `cathode`, `anode`, `cell_potential` (V), `n`,
+/// `equation`, `reactant_mass` (g/mol), `specific_capacity` (mAh/g) and `specific_energy`
+/// (Wh/kg)
+///
+/// Example:
+/// ```typst
+/// #let rows = compare-battery-couples((("PbO2/PbSO4", "PbSO4/Pb"), ("Cu2+/Cu", "Zn2+/Zn")))
+/// #table(columns: 3, ..rows.map(r => (r.cathode + " | " + r.anode,
+///   str(calc.round(r.cell_potential, digits: 2)),
+///   str(calc.round(r.specific_energy.value)))).flatten())
+/// ```
+/// -> array
+#let compare-battery-couples(pairs, data: reduction-potential-data, exclude: ()) = {
+  let result-bytes = energetics-plugin.battery_specific_energy(
+    bytes(json.encode(pairs)),
+    bytes(json.encode(_data-table(data))),
+    bytes(json.encode(exclude))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the theoretical specific energy of a battery couple
+///
+/// Single-couple form of `compare-battery-couples`.
+///
+/// Arguments:
+/// - first: First half-reaction (identifier or explicit half-reaction)
+/// - second: Second half-reaction
+/// - data: Reduction potential table (default: built-in table)
+/// - exclude: Reactants left out of the mass (default: ())
+///
+/// Returns: Dictionary with `cell_potential` (V), `n`, `equation`, `reactant_mass`,
+/// `specific_capacity` (mAh/g) and `specific_energy` (Wh/kg)
+///
+/// Example:
+/// ```typst
+/// #let lead-acid = calc-battery-specific-energy("PbO2/PbSO4", "PbSO4/Pb")
+/// #lead-acid.specific_energy.value // ≈ 171 Wh/kg
+/// ```
+/// -> dict
+#let calc-battery-specific-energy(first, second, data: reduction-potential-data, exclude: ()) = {
+  compare-battery-couples(((first, second),), data: data, exclude: exclude).first()
+}
+
+/// Calculate the potential of a concentration cell
+/// E = (RT/nF)·ln(c_cathode/c_anode)
+///
+/// Both half-cells contain the same couple, so E° = 0 and the potential comes entirely from
+/// the concentration difference. For a metal/metal-ion cell the more concentrated compartment
+/// is the cathode; a negative result means the labels are reversed.
+///
+/// Arguments:
+/// - c-cathode: Concentration in the cathode compartment (mol/L)
+/// - c-anode: Concentration in the anode compartment (mol/L)
+/// - n: Number of electrons transferred
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `value` and `unit` (V), `concentration_ratio`,
+/// `temperature_coefficient` (dE/dT in V/K) and `spontaneous`
+///
+/// Example:
+/// ```typst
+/// // Cu | Cu²⁺ (0.001 M) || Cu²⁺ (1.0 M) | Cu
+/// #let cell = calc-concentration-cell(1.0, 0.001, 2)
+/// #cell.value // ≈ 0.0887 V
+/// ```
+/// -> dict
+#let calc-concentration-cell(c-cathode, c-anode, n, temp: 298.15) = {
+  let result-bytes = energetics-plugin.calculate_concentration_cell(
+    bytes(repr(c-cathode)),
+    bytes(repr(c-anode)),
+    bytes(repr(n)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Analyse a Latimer diagram and generate Frost-diagram coordinates
+///
+/// Skip potentials between non-adjacent species are E° = Σ nₖE°ₖ / Σ nₖ. An interior species
+/// is unstable towards disproportionation when the potential to its right exceeds the potential
+/// to its left. Frost volt-equivalents nE° are referenced to the element (oxidation state 0),
+/// or to the lowest oxidation state listed.
+///
+/// Arguments:
+/// - species: Array of (species, oxidation state), from highest to lowest oxidation state
+/// - potentials: Array of stepwise E° values (V) between consecutive species
+///
+/// Returns: Dictionary with `skip_potentials` (array of dictionaries with `oxidized`, `reduced`,
+/// `n` and `e_standard`), `disproportionation` (array of dictionaries with `species`, `e_left`,
+/// `e_right`, `driving_force` and `disproportionates`), `frost` (array of dictionaries with
+/// `species`, `oxidation_state` and `volt_equivalent`) and `frost_reference`
+///
+/// Example:
+/// ```typst
+/// #let cu = latimer-diagram((("Cu2+", 2), ("Cu+", 1), ("Cu", 0)), (0.153, 0.521))
+/// #cu.disproportionation.first().disproportionates // true: Cu⁺ is unstable
+/// ```
+/// -> dict
+#let latimer-diagram(species, potentials) = {
+  let result-bytes = energetics-plugin.latimer_diagram(
+    bytes(json.encode(species)),
+    bytes(json.encode(potentials))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate E–pH boundary lines for a simplified Pourbaix diagram
+///
+/// Each species is formed from the element with H₂O, H⁺ and e⁻, and the predominant species
+/// at each (pH, E) is the one with the lowest transformed Gibbs energy. Boundaries between
+/// predominance regions are returned as straight segments clipped to the plot window, together
+/// with label positions and the water stability lines. Dissolved species have activity
+/// `concentration`; solids and liquids have unit activity.
+///
+/// Species are keys into `data` (formula with state symbol) and need `delta_Gf`; the pure
+/// element may be omitted from the data. Aqueous ions are not part of the built-in table, so
+/// they are usually added by extending it.
+///
+/// Arguments:
+/// - element: Element symbol, e.g. "Fe"
+/// - species: Array of species keys, e.g. ("Fe(s)", "Fe2+(aq)", "Fe2O3(s)")
+/// - data: Thermodynamic data with `delta_Gf` in kJ/mol (default: built-in data)
+/// - concentration: Activity of dissolved species (default: 1e-6)
+/// - ph-range: Plot window in pH (default: (0, 14))
+/// - e-range: Plot window in E (V, default: (-2, 2))
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `boundaries` (array of dictionaries with `species`, `kind`,
+/// `points` and `slope`), `regions` (array of dictionaries with `species` and `label_point`),
+/// `water_lines` (`oxygen` and `hydrogen` end points), `ph_range`, `e_range`,
+/// `concentration` and `temperature`
+///
+/// Example:
+/// ```typst
+/// #let fe-data = thermo-data + (
+///   "Fe2+(aq)": (delta_Gf: -78.90),
+///   "Fe3+(aq)": (delta_Gf: -4.7),
+/// )
+/// #let diagram = pourbaix-diagram("Fe", ("Fe(s)", "Fe2+(aq)", "Fe3+(aq)", "Fe2O3(s)", "Fe3O4(s)"), data: fe-data)
+/// #for b in diagram.boundaries [#b.species: #b.points \ ]
+/// ```
+/// -> dict
+#let pourbaix-diagram(
+  element,
+  species,
+  data: thermo-data,
+  concentration: 1e-6,
+  ph-range: (0, 14),
+  e-range: (-2, 2),
+  temp: 298.15,
+) = {
+  let result-bytes = energetics-plugin.pourbaix_diagram(
+    bytes(element),
+    bytes(json.encode(species)),
+    bytes(json.encode(_data-table(data))),
+    bytes(repr(concentration)),
+    bytes(json.encode(ph-range)),
+    bytes(json.encode(e-range)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Evaluate a galvanic cell written in conventional cell notation
+///
+/// The left half-cell is the anode and the right half-cell the cathode; phases are separated
+/// by `|`, species in the same phase by `,` and the salt bridge by `||`. Each half-cell is
+/// matched to a couple in the reduction potential table (H⁺, H₂O and OH⁻ may be omitted).
+/// Species may carry activities, e.g. `"Zn2+(aq, 0.010 M)"` or `"H2(g, 0.5 bar)"`, in which case
+/// the Nernst potential under those conditions is also returned.
+///
+/// Arguments:
+/// - notation: Cell notation, e.g. "Zn(s) | Zn2+(aq) || Cu2+(aq) | Cu(s)"
+/// - data: Reduction potential table (default: built-in table)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with the fields of `calc-cell-potential` (`value` is E°cell), plus
+/// `potential` (Nernst E), `reaction_quotient` and `spontaneous`
+///
+/// Example:
+/// ```typst
+/// #let cell = parse-cell-notation("Zn(s) | Zn2+(aq, 0.010 M) || Cu2+(aq, 1.0 M) | Cu(s)")
+/// #cell.value // 1.1037 V
+/// #cell.potential.value // ≈ 1.163 V
+/// ```
+/// -> dict
+#let parse-cell-notation(notation, data: reduction-potential-data, temp: 298.15) = {
+  let result-bytes = energetics-plugin.parse_cell_notation(
+    bytes(notation),
+    bytes(json.encode(_data-table(data))),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Balance a redox half-reaction or full redox equation in acidic or basic solution
+///
+/// H₂O, H⁺ (acidic) or OH⁻ (basic) and electrons are added as needed. A full equation is split
+/// into its reduction and oxidation half-reactions, which are balanced separately and combined
+/// so the electrons cancel; `n` is the number of electrons transferred, ready for E°/ΔG
+/// calculations. Equations that cannot be split, such as "H2O2 -> H2O + O2", are balanced as a
+/// whole, keeping any H₂O, H⁺ or OH⁻ written in them. Species are separated by ` + ` (with
+/// spaces) and the sides by `→`, `->` or `=`.
+///
+/// Arguments:
+/// - equation: Unbalanced equation, e.g. "MnO4- + Fe2+ -> Mn2+ + Fe3+" or "MnO4- -> MnO2"
+/// - medium: "acidic" or "basic" (default: "acidic")
+///
+/// Returns: Dictionary with `equation`, `reactants`, `products` (lists of (species, coefficient)),
+/// `n` (absent for disproportionations) and `half_reactions` (each with `process`, `equation`,
+/// `n`, `oxidized` and `reduced`, usable as explicit half-reactions in `calc-cell-potential`
+/// once `E0` is added)
+///
+/// Example:
+/// ```typst
+/// #let r = balance-redox("MnO4- + I- -> MnO2 + I2", medium: "basic")
+/// #r.equation // 2 MnO4- + 6 I- + 4 H2O → 2 MnO2 + 3 I2 + 8 OH-
+/// #r.n // 6
+/// ```
+/// -> dict
+#let balance-redox(equation, medium: "acidic") = {
+  let result-bytes = energetics-plugin.balance_redox_equation(
+    bytes(equation),
+    bytes(medium)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the current density at an overpotential with the Butler–Volmer equation
+/// j = j0·[exp((1-α)nFη/RT) - exp(-αnFη/RT)]
+///
+/// Anodic current is positive. At large |η| one branch dominates and the Tafel slopes
+/// b = 2.303RT/((1-α)nF) (anodic) and -2.303RT/(αnF) (cathodic) apply; near η = 0 the
+/// electrode behaves as a resistance R_ct = RT/(nF·j0).
+///
+/// Arguments:
+/// - overpotential: Overpotential η (V)
+/// - j0: Exchange current density (A/cm²)
+/// - alpha: Cathodic transfer coefficient α (default: 0.5)
+/// - n: Number of electrons in the rate-determining step (default: 1)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `value` and `unit` (A/cm²), `anodic` and `cathodic` branch
+/// currents, `anodic_tafel_slope` and `cathodic_tafel_slope` (V/decade) and
+/// `charge_transfer_resistance` (Ω·cm²)
+///
+/// Example:
+/// ```typst
+/// #let j = calc-butler-volmer(0.1, 1e-3)
+/// #j.value // ≈ 6.86e-3 A/cm²
+/// ```
+/// -> dict
+#let calc-butler-volmer(overpotential, j0, alpha: 0.5, n: 1, temp: 298.15) = {
+  let result-bytes = energetics-plugin.calculate_butler_volmer(
+    bytes(repr(overpotential)),
+    bytes(repr(j0)),
+    bytes(repr(alpha)),
+    bytes(repr(n)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate a Butler–Volmer polarisation curve
+///
+/// Arguments:
+/// - j0: Exchange current density (A/cm²)
+/// - alpha: Cathodic transfer coefficient α (default: 0.5)
+/// - n: Number of electrons in the rate-determining step (default: 1)
+/// - eta-range: Overpotential range (V, default: (-0.3, 0.3))
+/// - points: Number of points (default: 100)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `net`, `anodic` and `cathodic` ((η, j) point arrays) and `tafel`
+/// ((η, log10|j|) points)
+///
+/// Example:
+/// ```typst
+/// #let curve = butler-volmer-curve(1e-3, alpha: 0.4)
+/// // Plot curve.tafel for a Tafel plot
+/// ```
+/// -> dict
+#let butler-volmer-curve(j0, alpha: 0.5, n: 1, eta-range: (-0.3, 0.3), points: 100, temp: 298.15) = {
+  let result-bytes = energetics-plugin.butler_volmer_curve(
+    bytes(repr(j0)),
+    bytes(repr(alpha)),
+    bytes(repr(n)),
+    bytes(json.encode(eta-range)),
+    bytes(str(points)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Extract Tafel slopes and the exchange current density from Tafel data
+///
+/// Each branch (η > 0 anodic, η < 0 cathodic) is fitted as log10|j| = log10 j0 + η/b.
+/// Points closer to equilibrium than `min-overpotential` are left out, since the Tafel
+/// approximation only holds once the opposite branch is negligible.
+///
+/// Arguments:
+/// - data: Array of (η, log10|j|) pairs (V, j in A/cm²)
+/// - n: Number of electrons in the rate-determining step (default: 1)
+/// - temp: Temperature (K, default: 298.15)
+/// - min-overpotential: Smallest |η| included (V, default: 0.05)
+///
+/// Returns: Dictionary with `anodic` and `cathodic` branches (each with `tafel_slope`,
+/// `transfer_coefficient`, `exchange_current_density`, `r_squared`, `points`, present when
+/// the branch has at least 2 points), the combined `exchange_current_density` and `alpha`
+///
+/// Example:
+/// ```typst
+/// #let tafel = fit-tafel(((0.10, -2.694), (0.15, -2.179), (0.20, -1.671),
+///   (-0.10, -3.032), (-0.15, -2.686), (-0.20, -2.347)))
+/// #tafel.anodic.tafel_slope.value // ≈ 0.098 V/decade
+/// ```
+/// -> dict
+#let fit-tafel(data, n: 1, temp: 298.15, min-overpotential: 0.05) = {
+  let result-bytes = energetics-plugin.fit_tafel(
+    bytes(json.encode(data)),
+    bytes(repr(n)),
+    bytes(repr(temp)),
+    bytes(repr(min-overpotential))
+  )
+  
+  json(result-bytes)
+}
+
+/// Fit the exchange current density and transfer coefficient to a polarisation curve
+///
+/// The slope of the linear region near equilibrium (dj/dη = nF·j0/RT) gives a first estimate
+/// of j0, which is refined together with α by a nonlinear least-squares fit of the full
+/// Butler–Volmer equation.
+///
+/// Arguments:
+/// - data: Array of (η, j) pairs (V, A/cm²), anodic current positive
+/// - n: Number of electrons in the rate-determining step (default: 1)
+/// - temp: Temperature (K, default: 298.15)
+/// - linear-limit: Largest |η| of the linear region (V, default: 0.01)
+/// - points: Number of points in the fitted curve (default: 100)
+///
+/// Returns: Dictionary with `exchange_current_density`, `alpha`, `j0_error`, `alpha_error`,
+/// `r_squared`, `charge_transfer_resistance`, `linear_estimate` (when the linear region has
+/// at least 2 points) and `curve`
+///
+/// Example:
+/// ```typst
+/// #let fit = fit-exchange-current(((-0.2, -4.5e-3), (-0.1, -1.0e-3), (-0.005, -3.9e-5),
+///   (0.005, 3.9e-5), (0.1, 2.0e-3), (0.2, 2.1e-2)))
+/// #fit.exchange_current_density.value // ≈ 2e-4 A/cm²
+/// ```
+/// -> dict
+#let fit-exchange-current(data, n: 1, temp: 298.15, linear-limit: 0.01, points: 100) = {
+  let result-bytes = energetics-plugin.fit_exchange_current(
+    bytes(json.encode(data)),
+    bytes(repr(n)),
+    bytes(repr(temp)),
+    bytes(repr(linear-limit)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// SOLUTION CONCENTRATIONS
+// ============================================================================
+
+/// Convert a solution concentration into every common scale
+///
+/// The composition is referred to 1 kg of solvent: b = n(solute)/kg, w = m(solute)/m(solution),
+/// x = n(solute)/n(total) and c = n(solute)/V(solution) with V = m(solution)/ρ. Molarity needs
+/// the solution density, both to convert from it and to report it.
+///
+/// Arguments:
+/// - value: Concentration in the `from` scale
+/// - solute: Solute formula or molar mass (g/mol)
+/// - from: "molarity" (mol/L), "molality" (mol/kg), "mass-percent" (%) or "mole-fraction"
+///   (default: "molarity")
+/// - solvent: Solvent formula or molar mass (g/mol, default: "H2O")
+/// - density: Solution density (g/mL, default: none)
+///
+/// Returns: Dictionary with `molality`, `mass_percent`, `mole_fraction`, the molar masses used
+/// and, with a density, `molarity` and `mass_concentration` (g/L)
+///
+/// Example:
+/// ```typst
+/// // Concentrated hydrochloric acid, 37 % at 1.18 g/mL
+/// #convert-concentration(37, "HCl", from: "mass-percent", density: 1.18).molarity.value // ≈ 12.0 mol/L
+/// ```
+/// -> dict
+#let convert-concentration(
+  value,
+  solute,
+  from: "molarity",
+  solvent: "H2O",
+  density: none,
+) = {
+  let input = (
+    value: value,
+    from: from,
+    solute: solute,
+    solvent: solvent,
+    density: density,
+  )
+  
+  let result-bytes = energetics-plugin.convert_concentration(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Solve the dilution equation for the missing quantity
+/// c₁·V₁ = c₂·V₂
+///
+/// Leave exactly one of the four quantities unspecified. Concentrations may be in any unit
+/// and come back in the same unit.
+///
+/// Arguments:
+/// - initial-concentration: Stock concentration c₁ (default: none)
+/// - initial-volume: Volume of stock V₁ (volume unit, default: none)
+/// - final-concentration: Diluted concentration c₂ (default: none)
+/// - final-volume: Final volume V₂ (volume unit, default: none)
+/// - volume-unit: "L", "dm3", "mL", "cm3" or "m3" (default: "L")
+///
+/// Returns: Dictionary with `solved_for`, all four quantities, `dilution_factor` and
+/// `solvent_added` (V₂ - V₁)
+///
+/// Example:
+/// ```typst
+/// // Stock needed for 250 mL of 0.5 M HCl from 12 M
+/// #calc-dilution(initial-concentration: 12, final-concentration: 0.5, final-volume: 250, volume-unit: "mL").initial_volume.value // ≈ 10.4 mL
+/// ```
+/// -> dict
+#let calc-dilution(
+  initial-concentration: none,
+  initial-volume: none,
+  final-concentration: none,
+  final-volume: none,
+  volume-unit: "L",
+) = {
+  let input = (
+    initial_concentration: initial-concentration,
+    initial_volume: initial-volume,
+    final_concentration: final-concentration,
+    final_volume: final-volume,
+    volume_unit: volume-unit,
+  )
+  
+  let result-bytes = energetics-plugin.dilution(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the concentration after mixing solutions of the same solute
+/// c = Σcᵢ·Vᵢ / V
+///
+/// Volumes are taken as additive unless a final volume (making up with solvent) is given.
+///
+/// Arguments:
+/// - solutions: Array of (concentration in mol/L, volume) tuples
+/// - final-volume: Volume after making up with solvent (volume unit, default: none)
+/// - volume-unit: "L", "dm3", "mL", "cm3" or "m3" (default: "L")
+///
+/// Returns: Dictionary with `concentration` (mol/L), `amount` (mol), `volume` and
+/// `solvent_added`
+///
+/// Example:
+/// ```typst
+/// #calc-mixing(((0.5, 100), (0.2, 300)), volume-unit: "mL").concentration.value // 0.275 mol/L
+/// ```
+/// -> dict
+#let calc-mixing(
+  solutions,
+  final-volume: none,
+  volume-unit: "L",
+) = {
+  let input = (
+    solutions: solutions,
+    final_volume: final-volume,
+    volume_unit: volume-unit,
+  )
+  
+  let result-bytes = energetics-plugin.mix_solutions(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ACID–BASE EQUILIBRIA
+// ============================================================================
+
+/// Look up tabulated acid–base constants
+///
+/// The query may be the table formula, any protonation state of the system or its name,
+/// so "H2PO4-", "phosphoric acid" and "H3PO4" all find the phosphate system. Constants
+/// of the queried species itself are given as `pka`/`ka` (acting as an acid) and
+/// `pkb`/`kb` (acting as a base).
+///
+/// Arguments:
+/// - query: Formula, species or name, e.g. "CH3COOH", "HCO3-", "ammonia"
+/// - data: Acid dissociation constant table (default: built-in table)
+/// - kw: Ion product of water used for the Kb values (default: 1e-14)
+///
+/// Returns: Dictionary with `formula`, `name`, `species`, `kind` ("acid", "base" or
+/// "amphiprotic"), `form` (protons lost from the fully protonated form), the stepwise
+/// `pKa`, `Ka`, `pKb` and `Kb` arrays, `forms` (all protonation states) and, where they
+/// apply, `pka`, `ka`, `pkb` and `kb`
+///
+/// Example:
+/// ```typst
+/// #get-acid-data("CH3COOH").pka // 4.756
+/// #get-acid-data("NH3").kb // ≈ 1.78e-5
+/// #get-acid-data("H2PO4-").kind // "amphiprotic"
+/// ```
+/// -> dict
+#let get-acid-data(query, data: acid-data, kw: 1e-14) = {
+  let result-bytes = energetics-plugin.get_acid_constant(
+    bytes(query),
+    bytes(json.encode(_data-table(data))),
+    bytes(repr(kw))
+  )
+  
+  json(result-bytes)
+}
+
+/// pKa of the acid/base pair a buffer is made of, looked up when given as a species
+#let buffer-pka(pka, data, kw) = {
+  if type(pka) != str { return pka }
+  let entry = get-acid-data(pka, data: data, kw: kw)
+  entry.at("pka", default: entry.pKa.at(entry.form - 1))
+}
+
+/// Calculate the pH of a strong or weak monoprotic acid or base
+///
+/// The pH is the exact solution of the charge balance including water autoionisation
+/// (the cubic for weak acids and bases), so it stays correct for dilute or relatively
+/// strong weak acids where the textbook approximations fail. The approximations are
+/// reported alongside with their errors.
+///
+/// Arguments:
+/// - concentration: Analytical concentration (mol/L)
+/// - kind: "acid" or "base" (default: "acid")
+/// - k: Ka or Kb; `none` for a strong acid or base, or a species name looked up in `data`,
+///   which also sets `kind` (default: none)
+/// - kw: Ion product of water (default: 1e-14)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (pH), `poh`, `h_concentration`, `oh_concentration`,
+/// `degree_of_dissociation`, `without_water` and (weak only) `square_root` approximations
+/// (each with `ph`, `error` in pH units and `relative_error` in %), and `five_percent_rule`
+///
+/// Example:
+/// ```typst
+/// #let acetic = calc-ph(0.1, k: 1.8e-5)
+/// #acetic.value // ≈ 2.875
+/// #acetic.square_root.error // ≈ -0.003
+/// #calc-ph(0.1, k: "NH3").value // ≈ 11.12
+/// ```
+/// -> dict
+#let calc-ph(concentration, kind: "acid", k: none, kw: 1e-14, data: acid-data) = {
+  let (kind, k) = if type(k) == str {
+    let entry = get-acid-data(k, data: data, kw: kw)
+    assert(
+      entry.kind != "amphiprotic",
+      message: entry.species + " is amphiprotic; use polyprotic-speciation",
+    )
+    if entry.kind == "acid" { ("acid", entry.ka) } else { ("base", entry.kb) }
+  } else {
+    (kind, k)
+  }
+  let result-bytes = energetics-plugin.calculate_ph(
+    bytes(repr(concentration)),
+    bytes(kind),
+    bytes(repr(if k == none { 0 } else { k })),
+    bytes(repr(kw))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the pH and speciation of a polyprotic acid solution
+///
+/// Solves the exact charge balance for the acid dissolved in protonation state `form`
+/// (0 for H₃PO₄, 1 for NaH₂PO₄, 2 for Na₂HPO₄, ...) and returns the fraction α of every
+/// protonation state, plus α-vs-pH curves for a speciation diagram.
+///
+/// Arguments:
+/// - kas: Stepwise dissociation constants (Ka1, Ka2, ...), or a species looked up in `data`
+/// - concentration: Analytical concentration (mol/L)
+/// - form: Number of protons already removed in the dissolved substance (default: auto,
+///   the looked-up species or 0)
+/// - names: Species names from the fully protonated acid to the fully deprotonated base
+///   (default: auto, the tabulated species or H3A, H2A-, HA^2-, A^3-)
+/// - kw: Ion product of water (default: 1e-14)
+/// - ph-range: pH range of the curves (default: (0, 14))
+/// - points: Number of points per curve (default: 100)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (pH), `h_concentration`, `pka`, `fractions` (each with
+/// `species`, `alpha`, `concentration`), `dominant` and `curves` ((pH, α) point arrays in
+/// the order of `fractions`)
+///
+/// Example:
+/// ```typst
+/// #let phosphate = polyprotic-speciation((7.1e-3, 6.3e-8, 4.5e-13), 0.1, form: 1)
+/// #phosphate.value // ≈ 4.69
+/// #phosphate.dominant // "H2A-"
+/// #polyprotic-speciation("HCO3-", 0.05).value // ≈ 8.34
+/// ```
+/// -> dict
+#let polyprotic-speciation(
+  kas,
+  concentration,
+  form: auto,
+  names: auto,
+  kw: 1e-14,
+  ph-range: (0, 14),
+  points: 100,
+  data: acid-data,
+) = {
+  let (kas, form, names) = if type(kas) == str {
+    let entry = get-acid-data(kas, data: data, kw: kw)
+    (
+      entry.Ka,
+      if form == auto { entry.form } else { form },
+      if names == auto { entry.forms } else { names },
+    )
+  } else {
+    (kas, if form == auto { 0 } else { form }, names)
+  }
+  let result-bytes = energetics-plugin.polyprotic_speciation(
+    bytes(json.encode(kas)),
+    bytes(repr(concentration)),
+    bytes(str(form)),
+    bytes(json.encode(if names == auto { () } else { names })),
+    bytes(repr(kw)),
+    bytes(json.encode(ph-range)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the pH of a buffer with the Henderson–Hasselbalch equation
+/// pH = pKa + log10([A⁻]/[HA])
+///
+/// The exact pH from the full charge balance is returned alongside; the two differ for
+/// dilute buffers or extreme ratios.
+///
+/// Arguments:
+/// - pka: pKa of the weak acid, or a species of the pair looked up in `data`
+/// - acid: Concentration of the weak acid HA (mol/L)
+/// - base: Concentration of the conjugate base A⁻ (mol/L)
+/// - kw: Ion product of water (default: 1e-14)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (Henderson–Hasselbalch pH), `exact`, `ratio` and
+/// `capacity` (β in mol/(L·pH))
+///
+/// Example:
+/// ```typst
+/// #let acetate = calc-buffer-ph(4.76, 0.10, 0.15)
+/// #acetate.value // ≈ 4.94
+/// #calc-buffer-ph("NH4+", 0.1, 0.1).value // 9.25
+/// ```
+/// -> dict
+#let calc-buffer-ph(pka, acid, base, kw: 1e-14, data: acid-data) = {
+  let result-bytes = energetics-plugin.calculate_buffer_ph(
+    bytes(repr(buffer-pka(pka, data, kw))),
+    bytes(repr(acid)),
+    bytes(repr(base)),
+    bytes(repr(kw))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the base/acid ratio needed for a target buffer pH
+/// [A⁻]/[HA] = 10^(pH - pKa)
+///
+/// Arguments:
+/// - pka: pKa of the weak acid, or a species of the pair looked up in `data`
+/// - target-ph: Desired pH
+/// - total: Total buffer concentration [HA] + [A⁻] (mol/L, default: none)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `ratio`, `base_fraction`, `effective` (target within pKa ± 1)
+/// and, when `total` is given, `acid_concentration` and `base_concentration`
+///
+/// Example:
+/// ```typst
+/// // Phosphate buffer (pKa2 = 7.21) at pH 7.40
+/// #calc-buffer-ratio(7.21, 7.40, total: 0.1).base_concentration // ≈ 0.0608 M
+/// ```
+/// -> dict
+#let calc-buffer-ratio(pka, target-ph, total: none, data: acid-data) = {
+  let result-bytes = energetics-plugin.calculate_buffer_ratio(
+    bytes(repr(buffer-pka(pka, data, 1e-14))),
+    bytes(repr(target-ph)),
+    bytes(repr(if total == none { 0 } else { total }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the pH change of a buffer on adding strong acid or base
+///
+/// Both pH values come from the exact charge balance, so an addition beyond the buffer
+/// capacity gives the pH of the excess strong acid or base.
+///
+/// Arguments:
+/// - pka: pKa of the weak acid, or a species of the pair looked up in `data`
+/// - acid: Amount of weak acid HA (mol)
+/// - base: Amount of conjugate base A⁻ (mol)
+/// - volume: Buffer volume (L)
+/// - strong-acid: Strong acid added (mol, default: 0)
+/// - strong-base: Strong base added (mol, default: 0)
+/// - added-volume: Volume of the added solution (L, default: 0)
+/// - kw: Ion product of water (default: 1e-14)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `ph_before`, `ph_after`, `delta_ph`, `acid_after` and
+/// `base_after` (mol), `henderson_hasselbalch` (while both components remain) and
+/// `capacity_exceeded`
+///
+/// Example:
+/// ```typst
+/// // 1 mmol HCl into 100 mL of 0.1 M acetic acid / 0.1 M acetate
+/// #calc-buffer-addition(4.76, 0.01, 0.01, 0.1, strong-acid: 0.001).delta_ph // ≈ -0.087
+/// ```
+/// -> dict
+#let calc-buffer-addition(
+  pka,
+  acid,
+  base,
+  volume,
+  strong-acid: 0,
+  strong-base: 0,
+  added-volume: 0,
+  kw: 1e-14,
+  data: acid-data,
+) = {
+  let input = (
+    pka: buffer-pka(pka, data, kw),
+    acid: acid,
+    base: base,
+    volume: volume,
+    strong_acid: strong-acid,
+    strong_base: strong-base,
+    added_volume: added-volume,
+    kw: kw,
+  )
+  let result-bytes = energetics-plugin.buffer_addition(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the buffer capacity β
+/// β = 2.303·(Kw/[H⁺] + [H⁺] + C·Ka·[H⁺]/(Ka + [H⁺])²)
+///
+/// Arguments:
+/// - pka: pKa of the weak acid, or a species of the pair looked up in `data`
+/// - total: Total buffer concentration [HA] + [A⁻] (mol/L)
+/// - ph: pH at which β is evaluated (default: auto, the pKa)
+/// - kw: Ion product of water (default: 1e-14)
+/// - points: Number of points in the β curve (default: 100)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `value` and `unit` (mol/(L·pH)), `maximum` (β at pH = pKa) and
+/// `curve` ((pH, β) points over pKa ± 3)
+///
+/// Example:
+/// ```typst
+/// #calc-buffer-capacity(4.76, 0.2).value // ≈ 0.115 = 2.303·C/4
+/// ```
+/// -> dict
+#let calc-buffer-capacity(pka, total, ph: auto, kw: 1e-14, points: 100, data: acid-data) = {
+  let pka = buffer-pka(pka, data, kw)
+  let result-bytes = energetics-plugin.calculate_buffer_capacity(
+    bytes(repr(pka)),
+    bytes(repr(total)),
+    bytes(repr(if ph == auto { pka } else { ph })),
+    bytes(repr(kw)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate a pH titration curve
+///
+/// An acid analyte is titrated with strong base, a base analyte with strong acid. Every
+/// point solves the exact charge balance of the diluted mixture, and the curve is sampled
+/// more densely around each equivalence point so the jump plots cleanly. For polyprotic
+/// systems give all stepwise constants; a weak base is described by its Kb values.
+///
+/// Arguments:
+/// - concentration: Analyte concentration (mol/L)
+/// - volume: Analyte volume (mL)
+/// - titrant-concentration: Titrant concentration (mol/L)
+/// - analyte: "acid" or "base" (default: "acid")
+/// - k: Ka (acid) or Kb (base), or an array of stepwise constants; `none` for strong, or a
+///   species looked up in `data`, which also sets `analyte` (default: none)
+/// - max-volume: Largest titrant volume (mL, default: auto, 1.5 × the last equivalence volume)
+/// - points: Number of uniformly spaced points (default: 200)
+/// - kw: Ion product of water (default: 1e-14)
+/// - data: Acid dissociation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `points` ((volume, pH) pairs), `initial_ph`, and
+/// `equivalence_points` and `half_equivalence_points` (each with `volume`, `ph`, `label`)
+///
+/// Example:
+/// ```typst
+/// // 25 mL of 0.1 M acetic acid with 0.1 M NaOH
+/// #let curve = titration-curve(0.1, 25, 0.1, k: 1.8e-5)
+/// #curve.equivalence_points.first().ph // ≈ 8.72
+/// #titration-curve(0.1, 25, 0.1, k: "H3PO4").equivalence_points.len() // 3
+/// ```
+/// -> dict
+#let titration-curve(
+  concentration,
+  volume,
+  titrant-concentration,
+  analyte: "acid",
+  k: none,
+  max-volume: auto,
+  points: 200,
+  kw: 1e-14,
+  data: acid-data,
+) = {
+  let (analyte, k) = if type(k) == str {
+    let entry = get-acid-data(k, data: data, kw: kw)
+    assert(
+      entry.kind != "amphiprotic",
+      message: entry.species + " is amphiprotic; titrate the fully protonated acid or the fully deprotonated base",
+    )
+    if entry.kind == "acid" { ("acid", entry.Ka) } else { ("base", entry.Kb) }
+  } else {
+    (analyte, k)
+  }
+  let input = (
+    analyte: analyte,
+    concentration: concentration,
+    volume: volume,
+    titrant_concentration: titrant-concentration,
+    k: if k == none { () } else if type(k) == array { k } else { (k,) },
+    max_volume: if max-volume == auto { 0 } else { max-volume },
+    points: points,
+    kw: kw,
+  )
+  let result-bytes = energetics-plugin.titration_curve(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// SOLUBILITY EQUILIBRIA
+// ============================================================================
+
+/// Calculate the molar solubility of a sparingly soluble salt from its Ksp
+/// Ksp = Π(νᵢ·s + cᵢ)^νᵢ
+///
+/// Works for any stoichiometry (AgCl, PbI₂, Ca₃(PO₄)₂, ...). Common ions already in
+/// solution suppress the solubility. Activities are taken equal to concentrations and
+/// hydrolysis of the ions is ignored.
+///
+/// Arguments:
+/// - salt: Formula in the Ksp table, or a dictionary `(Ksp: ..., ions: (("Ag+", 1), ("Cl-", 1)))`
+/// - common-ions: Concentrations of ions already present, e.g. `("Cl-": 0.1)` (mol/L, default: none)
+/// - data: Solubility product table (default: built-in table)
+///
+/// Returns: Dictionary with `value` and `unit` (mol/L), `salt`, `ksp`, `mass_solubility`
+/// (g/L, for table formulas), `ion_concentrations` (each with `species`, `concentration`),
+/// `pure_water` (solubility without common ions) and, with common ions, `suppression`
+///
+/// Example:
+/// ```typst
+/// #calc-molar-solubility("AgCl").value // ≈ 1.33e-5 mol/L
+/// #calc-molar-solubility("AgCl", common-ions: ("Cl-": 0.1)).value // ≈ 1.77e-9 mol/L
+/// ```
+/// -> dict
+#let calc-molar-solubility(salt, common-ions: (:), data: ksp-data) = {
+  let result-bytes = energetics-plugin.molar_solubility(
+    bytes(json.encode(salt)),
+    bytes(json.encode(common-ions)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate Ksp from the measured solubility of a salt
+/// Ksp = Π(νᵢ·s)^νᵢ
+///
+/// Arguments:
+/// - solubility: Molar solubility (mol/L), or mass solubility (g/L) when `molar-mass` is given
+/// - salt: Formula in the Ksp table, or an array of (ion, ν) pairs
+/// - molar-mass: Molar mass of the salt to convert a mass solubility (g/mol, default: none)
+/// - data: Solubility product table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (Ksp), `pksp`, `ion_concentrations` and, for table
+/// formulas, the `tabulated` Ksp
+///
+/// Example:
+/// ```typst
+/// #calc-ksp(2.1e-4, "CaF2").value // ≈ 3.7e-11
+/// #calc-ksp(1e-3, (("Pb2+", 1), ("I-", 2))).value // 4e-9
+/// ```
+/// -> dict
+#let calc-ksp(solubility, salt, molar-mass: none, data: ksp-data) = {
+  let s = if molar-mass == none { solubility } else { solubility / molar-mass }
+  let result-bytes = energetics-plugin.ksp_from_solubility(
+    bytes(repr(s)),
+    bytes(json.encode(if type(salt) == array { (ions: salt) } else { salt })),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Predict precipitation by comparing the ion product Qsp with Ksp
+///
+/// A solution precipitates when Qsp > Ksp; the amount precipitated and the ion
+/// concentrations left at equilibrium are returned.
+///
+/// Arguments:
+/// - salt: Formula in the Ksp table, or a dictionary `(Ksp: ..., ions: ...)`
+/// - concentrations: Ion concentrations after mixing, e.g. `("Pb2+": 0.01, "I-": 0.03)` (mol/L)
+/// - data: Solubility product table (default: built-in table)
+///
+/// Returns: Dictionary with `ion_product`, `ksp`, `saturation_index` (log10(Qsp/Ksp)),
+/// `state` ("unsaturated", "saturated" or "supersaturated"), `precipitates`,
+/// `precipitated` (mol/L) and `remaining` (each with `species`, `concentration`)
+///
+/// Example:
+/// ```typst
+/// #let mix = predict-precipitation("PbI2", ("Pb2+": 0.01, "I-": 0.03))
+/// #mix.precipitates // true
+/// #mix.precipitated // ≈ 0.0099 mol/L
+/// ```
+/// -> dict
+#let predict-precipitation(salt, concentrations, data: ksp-data) = {
+  let result-bytes = energetics-plugin.predict_precipitation(
+    bytes(json.encode(salt)),
+    bytes(json.encode(concentrations)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ACTIVITY COEFFICIENTS
+// ============================================================================
+
+/// Calculate the ionic strength of a solution
+/// I = ½·Σcᵢzᵢ²
+///
+/// Arguments:
+/// - composition: Ion concentrations as a dictionary, e.g. `("Na+": 0.1, "SO4^2-": 0.05)`,
+///   or an array of (formula, concentration) pairs (mol/L); charges are read from the formulas
+///
+/// Returns: Dictionary with `value` and `unit` (mol/L), `ions` (each with `species`,
+/// `concentration`, `charge`, `contribution`) and `charge_balance` (Σcᵢzᵢ)
+///
+/// Example:
+/// ```typst
+/// #calc-ionic-strength(("Mg2+": 0.1, "Cl-": 0.2)).value // 0.3
+/// ```
+/// -> dict
+#let calc-ionic-strength(composition) = {
+  let pairs = if type(composition) == dictionary { composition.pairs() } else { composition }
+  let result-bytes = energetics-plugin.ionic_strength(
+    bytes(json.encode(pairs))
+  )
+  
+  json(result-bytes)
+}
+
+/// Ionic strength given as a number or as a composition
+#let ionic-strength-value(ionic-strength) = {
+  if type(ionic-strength) in (dictionary, array) {
+    calc-ionic-strength(ionic-strength).value
+  } else {
+    ionic-strength
+  }
+}
+
+/// Calculate the activity coefficient of an ion
+/// Limiting law: log γ = -A·z²·√I
+/// Extended law: log γ = -A·z²·√I/(1 + B·a·√I)
+/// Davies: log γ = -A·z²·(√I/(1 + √I) - 0.3·I)
+///
+/// A and B are computed from the permittivity of water at `temp`. The limiting law holds
+/// up to I ≈ 0.005 M, the extended law to ≈ 0.1 M and the Davies equation to ≈ 0.5 M.
+///
+/// Arguments:
+/// - ion: Charge number or ion formula, e.g. `2` or `"SO4^2-"`
+/// - ionic-strength: Ionic strength (mol/L), or a composition as for `calc-ionic-strength`
+/// - model: "limiting", "extended" or "davies" (default: "davies")
+/// - size: Effective ion size a for the extended law (Å, default: 3)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `value` (γ), `log_gamma`, `charge`, `ionic_strength`, `a`, `b`
+/// and `within_range`
+///
+/// Example:
+/// ```typst
+/// #calc-activity-coefficient("SO4^2-", 0.01, model: "extended", size: 4).value // ≈ 0.66
+/// ```
+/// -> dict
+#let calc-activity-coefficient(ion, ionic-strength, model: "davies", size: 3, temp: 298.15) = {
+  let result-bytes = energetics-plugin.activity_coefficient(
+    bytes(json.encode(ion)),
+    bytes(repr(ionic-strength-value(ionic-strength))),
+    bytes(model),
+    bytes(repr(size)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Convert a thermodynamic equilibrium constant to the concentration constant
+/// Kc = K°/Πγᵢ^νᵢ
+///
+/// Tabulated constants refer to activities; at finite ionic strength the concentrations
+/// at equilibrium follow Kc instead. Neutral species have γ = 1.
+///
+/// Arguments:
+/// - k: Thermodynamic equilibrium constant K°
+/// - reactants: Array of (formula, coefficient) pairs
+/// - products: Array of (formula, coefficient) pairs
+/// - ionic-strength: Ionic strength (mol/L), or a composition as for `calc-ionic-strength`
+/// - model: "limiting", "extended" or "davies" (default: "davies")
+/// - sizes: Ion sizes for the extended law, e.g. `("H+": 9)` (Å, default: 3 Å for every ion)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `value` (Kc), `thermodynamic` (K°), `activity_quotient`
+/// (Πγᵢ^νᵢ), `log_shift` (log10(Kc/K°)), `species` (each with `species`, `coefficient`,
+/// `charge`, `gamma`) and `within_range`
+///
+/// Example:
+/// ```typst
+/// // Acetic acid in 0.1 M NaCl
+/// #calc-activity-corrected-k(1.8e-5, (("CH3COOH", 1),), (("H+", 1), ("CH3COO-", 1)), 0.1).value
+/// // ≈ 2.95e-5
+/// ```
+/// -> dict
+#let calc-activity-corrected-k(
+  k,
+  reactants,
+  products,
+  ionic-strength,
+  model: "davies",
+  sizes: (:),
+  temp: 298.15,
+) = {
+  let result-bytes = energetics-plugin.activity_corrected_constant(
+    bytes(repr(k)),
+    bytes(json.encode(reactants)),
+    bytes(json.encode(products)),
+    bytes(repr(ionic-strength-value(ionic-strength))),
+    bytes(model),
+    bytes(json.encode(sizes)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// COLLIGATIVE PROPERTIES
+// ============================================================================
+
+/// Calculate the freezing-point depression of a solution
+/// ΔTf = i·Kf·b
+///
+/// Arguments:
+/// - molality: Solute molality b (mol/kg)
+/// - i: van't Hoff factor (default: 1)
+/// - solvent: Solvent name in the table, or a dictionary `(Kf: ..., Tf: ...)` (default: "water")
+/// - data: Solvent table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (ΔTf) and `unit` (K), `solvent`, `constant` (Kf),
+/// `vant_hoff_factor` and `temperature` (freezing point of the solution, K)
+///
+/// Example:
+/// ```typst
+/// // 0.5 mol/kg NaCl in water
+/// #calc-freezing-point-depression(0.5, i: 2).value // 1.86 K
+/// ```
+/// -> dict
+#let calc-freezing-point-depression(molality, i: 1, solvent: "water", data: solvent-data) = {
+  let result-bytes = energetics-plugin.colligative_temperature_shift(
+    bytes("freezing"),
+    bytes(repr(molality)),
+    bytes(repr(i)),
+    bytes(json.encode(solvent)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the boiling-point elevation of a solution
+/// ΔTb = i·Kb·b
+///
+/// Arguments:
+/// - molality: Solute molality b (mol/kg)
+/// - i: van't Hoff factor (default: 1)
+/// - solvent: Solvent name in the table, or a dictionary `(Kb: ..., Tb: ...)` (default: "water")
+/// - data: Solvent table (default: built-in table)
+///
+/// Returns: Dictionary with `value` (ΔTb) and `unit` (K), `solvent`, `constant` (Kb),
+/// `vant_hoff_factor` and `temperature` (boiling point of the solution, K)
+///
+/// Example:
+/// ```typst
+/// #calc-boiling-point-elevation(1, solvent: "benzene").temperature // 355.78 K
+/// ```
+/// -> dict
+#let calc-boiling-point-elevation(molality, i: 1, solvent: "water", data: solvent-data) = {
+  let result-bytes = energetics-plugin.colligative_temperature_shift(
+    bytes("boiling"),
+    bytes(repr(molality)),
+    bytes(repr(i)),
+    bytes(json.encode(solvent)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the osmotic pressure of a dilute solution
+/// Π = i·c·R·T
+///
+/// Arguments:
+/// - concentration: Solute concentration (mol/L)
+/// - i: van't Hoff factor (default: 1)
+/// - temp: Temperature (K, default: 298.15)
+///
+/// Returns: Dictionary with `value` and `unit` (kPa), `atm`, `bar` and `osmolarity`
+///
+/// Example:
+/// ```typst
+/// #calc-osmotic-pressure(0.1).atm // ≈ 2.45 atm
+/// ```
+/// -> dict
+#let calc-osmotic-pressure(concentration, i: 1, temp: 298.15) = {
+  let result-bytes = energetics-plugin.osmotic_pressure(
+    bytes(repr(concentration)),
+    bytes(repr(i)),
+    bytes(repr(temp))
+  )
+  
+  json(result-bytes)
+}
+
+/// Determine the molar mass of a solute from a colligative measurement
+/// Cryoscopy/ebullioscopy: M = i·K·m_solute/(ΔT·m_solvent)
+/// Osmometry: M = i·m_solute·R·T/(Π·V)
+///
+/// Arguments:
+/// - method: "freezing", "boiling" or "osmotic"
+/// - change: Measured ΔT (K) or osmotic pressure Π (kPa)
+/// - solute-mass: Mass of solute (g)
+/// - amount: Mass of solvent (g) for ΔT, or solution volume (L) for Π
+/// - i: van't Hoff factor (default: 1)
+/// - solvent: Solvent name in the table, or a dictionary `(Kf: ..., Kb: ...)` (default: "water")
+/// - temp: Temperature for osmometry (K, default: 298.15)
+/// - data: Solvent table (default: built-in table)
+///
+/// Returns: Dictionary with `value` and `unit` (g/mol), `moles` and `concentration`
+/// (molality or molarity implied by the measurement)
+///
+/// Example:
+/// ```typst
+/// // 5.00 g of solute in 100 g benzene lowers the freezing point by 0.52 K
+/// #calc-colligative-molar-mass("freezing", 0.52, 5, 100, solvent: "benzene").value // ≈ 492 g/mol
+/// ```
+/// -> dict
+#let calc-colligative-molar-mass(
+  method,
+  change,
+  solute-mass,
+  amount,
+  i: 1,
+  solvent: "water",
+  temp: 298.15,
+  data: solvent-data,
+) = {
+  let input = (
+    method: method,
+    change: change,
+    solute_mass: solute-mass,
+    amount: amount,
+    i: i,
+    solvent: solvent,
+    temperature: temp,
+  )
+  let result-bytes = energetics-plugin.colligative_molar_mass(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Convert between the van't Hoff factor and the degree of dissociation
+/// i = 1 + α·(ν - 1)
+///
+/// Arguments:
+/// - ions: Number of ions ν per formula unit (e.g. 2 for NaCl, 3 for CaCl₂)
+/// - dissociation: Degree of dissociation α (default: none)
+/// - i: Measured van't Hoff factor, to derive α (default: none)
+///
+/// Returns: Dictionary with `value` (i), `ions` and `dissociation` (α)
+///
+/// Example:
+/// ```typst
+/// #calc-vant-hoff-factor(3, dissociation: 0.8).value // 2.6
+/// #calc-vant-hoff-factor(2, i: 1.9).dissociation // 0.9
+/// ```
+/// -> dict
+#let calc-vant-hoff-factor(ions, dissociation: none, i: none) = {
+  assert(
+    (dissociation == none) != (i == none),
+    message: "Give exactly one of dissociation and i",
+  )
+  let (known, value) = if i == none { ("dissociation", dissociation) } else { ("i", i) }
+  let result-bytes = energetics-plugin.vant_hoff_factor(
+    bytes(repr(ions)),
+    bytes(known),
+    bytes(repr(value))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// VAPOR–LIQUID EQUILIBRIA
+// ============================================================================
+
+/// Calculate the vapor above an ideal liquid mixture (Raoult's law)
+/// pᵢ = xᵢ·pᵢ*, P = Σpᵢ, yᵢ = pᵢ/P
+///
+/// Arguments:
+/// - components: Dictionary of components with their pure vapor pressure and liquid mole
+///   fraction, e.g. `(benzene: (pressure: 12.7, fraction: 0.4), ...)`; fractions must sum to 1
+///
+/// Returns: Dictionary with `value` (total vapor pressure, in the unit of the given
+/// pressures) and `components` (each with `name`, `liquid_fraction`, `partial_pressure`,
+/// `vapor_fraction`)
+///
+/// Example:
+/// ```typst
+/// #let mix = calc-raoult-mixture((
+///   benzene: (pressure: 12.7, fraction: 0.4),
+///   toluene: (pressure: 3.79, fraction: 0.6),
+/// ))
+/// #mix.value // 7.354 kPa
+/// #mix.components.first().vapor_fraction // ≈ 0.691
+/// ```
+/// -> dict
+#let calc-raoult-mixture(components) = {
+  let pairs = if type(components) == dictionary { components.pairs() } else { components }
+  let result-bytes = energetics-plugin.raoult_mixture(
+    bytes(json.encode(pairs))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate P–x–y data for an ideal binary mixture at constant temperature
+/// Bubble line: P = x₁·p₁* + (1 - x₁)·p₂*; dew line: 1/P = y₁/p₁* + (1 - y₁)/p₂*
+///
+/// Arguments:
+/// - p1: Vapor pressure of pure component 1
+/// - p2: Vapor pressure of pure component 2
+/// - points: Number of points per line (default: 100)
+/// - composition: Mole fraction of component 1 at which to report the bubble and dew
+///   pressures (default: none)
+///
+/// Returns: Dictionary with `bubble` ((x₁, P) points), `dew` ((y₁, P) points) and, with a
+/// composition, `bubble_pressure` and `dew_pressure`
+///
+/// Example:
+/// ```typst
+/// #let vle = pxy-diagram(12.7, 3.79, composition: 0.4)
+/// #vle.bubble_pressure // 7.354
+/// #vle.dew_pressure // ≈ 5.27
+/// ```
+/// -> dict
+#let pxy-diagram(p1, p2, points: 100, composition: none) = {
+  let result-bytes = energetics-plugin.pxy_diagram(
+    bytes(repr(p1)),
+    bytes(repr(p2)),
+    bytes(str(points)),
+    bytes(repr(if composition == none { -1 } else { composition }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the solubility of a gas from Henry's law
+/// c = kH·p, kH(T) = kH°·exp(C·(1/T - 1/298.15))
+///
+/// Arguments:
+/// - kh: Henry solubility constant at 298.15 K (mol/(L·bar))
+/// - pressure: Partial pressure of the gas (bar)
+/// - temp: Temperature (K, default: 298.15)
+/// - temp-coefficient: C = -ΔsolH/R (K, default: 0, no temperature dependence)
+/// - molar-mass: Molar mass of the gas for the mass solubility (g/mol, default: none)
+///
+/// Returns: Dictionary with `value` and `unit` (mol/L), `henry_constant` at `temp` and,
+/// with a molar mass, `mass_solubility` (g/L)
+///
+/// Example:
+/// ```typst
+/// // O₂ from air (0.21 bar) in water at 37 °C
+/// #calc-henry-solubility(1.3e-3, 0.21, temp: 310.15, temp-coefficient: 1700).value // ≈ 2.19e-4 M
+/// ```
+/// -> dict
+#let calc-henry-solubility(kh, pressure, temp: 298.15, temp-coefficient: 0, molar-mass: none) = {
+  let result-bytes = energetics-plugin.henry_solubility(
+    bytes(repr(kh)),
+    bytes(repr(pressure)),
+    bytes(repr(temp-coefficient)),
+    bytes(repr(temp)),
+    bytes(repr(if molar-mass == none { 0 } else { molar-mass }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Predict a solubility at another temperature from the enthalpy of solution, or extract
+/// ΔHsoln from solubilities measured at two temperatures (van 't Hoff equation)
+/// ν·ln(s2/s1) = -(ΔHsoln/R)·(1/T2 - 1/T1)
+///
+/// For a salt releasing ν ions Ksp ∝ s^ν, so ΔHsoln is the enthalpy of the dissolution
+/// equilibrium; molecular solutes use ν = 1. ΔHsoln is taken as constant over the range.
+///
+/// Arguments:
+/// - s1: Solubility at t1 (any unit; s2 uses the same unit)
+/// - t1: Temperature of s1 (K)
+/// - t2: Second temperature (K)
+/// - s2: Solubility at t2, to extract ΔHsoln (default: none)
+/// - delta-h: Enthalpy of solution ΔHsoln, to predict s2 (kJ/mol, default: none)
+/// - ions: Ions ν released per formula unit (default: 1)
+///
+/// Returns: Dictionary with `s1`, `t1`, `s2`, `t2`, `ratio` (s2/s1), `delta_h`, `unit`
+/// (kJ/mol) and `solved` ("solubility" or "delta_h")
+///
+/// Example:
+/// ```typst
+/// #calc-solubility-temperature(1.0, 298.15, 323.15, delta-h: 34.9).s2 // ≈ 2.97
+/// #calc-solubility-temperature(1.0, 298.15, 323.15, s2: 2.97).delta_h // ≈ 34.9 kJ/mol
+/// ```
+/// -> dict
+#let calc-solubility-temperature(s1, t1, t2, s2: none, delta-h: none, ions: 1) = {
+  let input = (
+    s1: s1,
+    t1: t1,
+    t2: t2,
+    s2: s2,
+    delta_h: delta-h,
+    ions: ions,
+  )
+  
+  let result-bytes = energetics-plugin.solubility_temperature(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// COMPLEX-ION EQUILIBRIA
+// ============================================================================
+
+/// Convert between stepwise and overall formation constants
+/// βₙ = K₁·K₂·…·Kₙ
+///
+/// Arguments:
+/// - values: Constants K₁, K₂, ... or β₁, β₂, ...
+/// - from: "stepwise" or "overall" (default: "stepwise")
+/// - log: Whether the values are log10 constants (default: true)
+///
+/// Returns: Dictionary with `log_k`, `log_beta`, `k` and `beta` arrays
+///
+/// Example:
+/// ```typst
+/// // Cu²⁺/NH₃ stepwise log K values
+/// #convert-formation-constants((4.04, 3.43, 2.80, 1.48)).log_beta.last() // 11.75
+/// ```
+/// -> dict
+#let convert-formation-constants(values, from: "stepwise", log: true) = {
+  let result-bytes = energetics-plugin.convert_formation_constants(
+    bytes(json.encode(values)),
+    bytes(from),
+    bytes(repr(log))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate metal speciation in a metal–ligand system
+/// αₙ = βₙ·[L]ⁿ/(1 + Σβᵢ·[L]ⁱ)
+///
+/// Arguments:
+/// - system: System key in the formation constant table (e.g. "Cu2+/NH3"), or a dictionary
+///   `(metal: ..., ligand: ..., log_beta: (...), complexes: (...))`
+/// - free-ligand: Free ligand concentration [L] (mol/L)
+/// - total-metal: Total metal concentration for species concentrations (mol/L, default: 0)
+/// - log-range: log10[L] range of the curves (default: (-8, 0))
+/// - points: Number of points per curve (default: 100)
+/// - data: Formation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `metal`, `ligand`, `free_ligand`, `fractions` (each with
+/// `species`, `alpha`, `concentration`), `dominant`, `mean_ligand_number` and `curves`
+/// ((log10[L], α) point arrays in the order of `fractions`)
+///
+/// Example:
+/// ```typst
+/// #let copper = complex-speciation("Cu2+/NH3", 0.1)
+/// #copper.dominant // "[Cu(NH3)4]2+"
+/// #copper.mean_ligand_number // ≈ 3.74
+/// ```
+/// -> dict
+#let complex-speciation(
+  system,
+  free-ligand,
+  total-metal: 0,
+  log-range: (-8, 0),
+  points: 100,
+  data: complex-data,
+) = {
+  let result-bytes = energetics-plugin.complex_speciation(
+    bytes(json.encode(system)),
+    bytes(repr(free-ligand)),
+    bytes(repr(total-metal)),
+    bytes(json.encode(_data-table(data))),
+    bytes(json.encode(log-range)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the solubility of a salt enhanced by complexation of its metal ion
+///
+/// Solves the solubility product together with the ligand mass balance, so ligand bound
+/// in the complexes is accounted for. Protonation of the ligand is ignored.
+///
+/// Arguments:
+/// - salt: Formula in the Ksp table, or a dictionary `(Ksp: ..., ions: ...)` with two ions
+/// - system: System key in the formation constant table, or an explicit system
+/// - ligand-total: Total ligand concentration (mol/L)
+/// - ksp-data: Solubility product table (default: built-in table)
+/// - data: Formation constant table (default: built-in table)
+///
+/// Returns: Dictionary with `value` and `unit` (mol/L), `pure_water`, `enhancement`,
+/// `free_ligand`, `free_metal` and `fractions` of the dissolved metal
+///
+/// Example:
+/// ```typst
+/// // AgCl in 1 M ammonia
+/// #calc-complexation-solubility("AgCl", "Ag+/NH3", 1).value // ≈ 0.049 mol/L
+/// ```
+/// -> dict
+#let calc-complexation-solubility(
+  salt,
+  system,
+  ligand-total,
+  ksp-data: ksp-data,
+  data: complex-data,
+) = {
+  let result-bytes = energetics-plugin.complexation_solubility(
+    bytes(json.encode(salt)),
+    bytes(json.encode(system)),
+    bytes(repr(ligand-total)),
+    bytes(json.encode(_data-table(ksp-data))),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// GAS LAWS
+// ============================================================================
+
+/// Solve the ideal gas law PV = nRT for the missing quantity
+///
+/// Leave exactly one of `pressure`, `volume`, the amount (`moles`, or `mass` with
+/// `molar-mass` or `gas`) and `temp` unspecified. A `mass` given together with P, V and T
+/// yields the molar mass instead, and the density follows whenever the molar mass is known.
+///
+/// Arguments:
+/// - pressure: Pressure (pressure unit, default: none)
+/// - volume: Volume (volume unit, default: none)
+/// - moles: Amount of gas (mol, default: none)
+/// - temp: Temperature (temperature unit, default: none)
+/// - mass: Mass of gas (g, default: none)
+/// - molar-mass: Molar mass (g/mol, default: none)
+/// - gas: Gas formula for the molar mass, e.g. "CO2" (default: none)
+/// - pressure-unit: "Pa", "kPa", "MPa", "bar", "atm", "Torr", "mmHg" or "psi" (default: "bar")
+/// - volume-unit: "L", "dm3", "mL", "cm3" or "m3" (default: "L")
+/// - temp-unit: "K", "°C" or "°F" (default: "K")
+///
+/// Returns: Dictionary with `solved_for`, `pressure`, `volume`, `moles`, `temperature` and
+/// `molar_volume` (dictionaries with `value` and `unit`, in the chosen units) and, when the
+/// molar mass is known or derived, `molar_mass`, `mass` and `density` (g/L)
+///
+/// Example:
+/// ```typst
+/// #ideal-gas-solve(moles: 1, pressure: 1, temp: 0, pressure-unit: "atm", temp-unit: "°C").volume.value
+/// // ≈ 22.41 L
+/// #ideal-gas-solve(mass: 1.25, pressure: 101.325, volume: 1, temp: 273.15, pressure-unit: "kPa").molar_mass.value
+/// // ≈ 28.0 g/mol
+/// ```
+/// -> dict
+#let ideal-gas-solve(
+  pressure: none,
+  volume: none,
+  moles: none,
+  temp: none,
+  mass: none,
+  molar-mass: none,
+  gas: none,
+  pressure-unit: "bar",
+  volume-unit: "L",
+  temp-unit: "K",
+) = {
+  let input = (
+    pressure: pressure,
+    volume: volume,
+    moles: moles,
+    temperature: temp,
+    mass: mass,
+    molar_mass: molar-mass,
+    gas: gas,
+    pressure_unit: pressure-unit,
+    volume_unit: volume-unit,
+    temperature_unit: temp-unit,
+  )
+  let result-bytes = energetics-plugin.ideal_gas_solve(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Solve a real-gas equation of state for the pressure or the molar volume
+///
+/// Supports van der Waals ("vdw"), Redlich–Kwong ("rk"), Soave–Redlich–Kwong ("srk") and
+/// Peng–Robinson ("pr"). Give the molar volume to get the pressure, or the pressure to
+/// get the molar volume; below Tc the cubic can have three roots, and `phase` picks the
+/// vapor-like (largest) or liquid-like (smallest) one; "auto" takes the stable root with
+/// the lower fugacity.
+///
+/// Arguments:
+/// - gas: Formula in the gas table, or a dictionary `(a: ..., b: ..., Tc: ..., Pc: ..., omega: ...)`
+/// - temp: Temperature (K)
+/// - pressure: Pressure (pressure unit, default: none)
+/// - molar-volume: Molar volume (L/mol, default: none)
+/// - model: "vdw", "rk", "srk" or "pr" (default: "pr")
+/// - phase: "auto", "vapor" or "liquid" (default: "auto")
+/// - pressure-unit: Unit of `pressure` and the result, as for `ideal-gas-solve` (default: "bar")
+/// - data: Gas table (default: built-in table)
+///
+/// Returns: Dictionary with `gas`, `model`, `solved_for`, `pressure` and `molar_volume`
+/// (dictionaries with `value` and `unit`), `z`, `roots` (all physical Z roots), `ideal`
+/// (the ideal-gas value of the solved quantity), `deviation` (%), the EOS constants `a`
+/// and `b`, and `reduced_temperature` and `reduced_pressure`
+///
+/// Example:
+/// ```typst
+/// #let co2 = real-gas-solve("CO2", 300, pressure: 50)
+/// #co2.z // ≈ 0.671
+/// #real-gas-solve("CO2", 300, molar-volume: 0.5, model: "vdw").pressure.value // ≈ 40.0 bar
+/// ```
+/// -> dict
+#let real-gas-solve(
+  gas,
+  temp,
+  pressure: none,
+  molar-volume: none,
+  model: "pr",
+  phase: "auto",
+  pressure-unit: "bar",
+  data: gas-data,
+) = {
+  let input = (
+    gas: gas,
+    model: model,
+    temperature: temp,
+    pressure: pressure,
+    molar_volume: molar-volume,
+    phase: phase,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.real_gas_solve(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate compressibility factor curves Z(P) from a real-gas equation of state
+///
+/// Arguments:
+/// - gas: Formula in the gas table, or an explicit dictionary of constants
+/// - temp: Temperature (K)
+/// - model: "vdw", "rk", "srk" or "pr" (default: "pr")
+/// - pressure-range: Pressure range (bar, default: (0, 300))
+/// - points: Number of points (default: 100)
+/// - data: Gas table (default: built-in table)
+///
+/// Returns: Dictionary with `gas`, `model`, `temperature` and `points` ((P, Z) pairs,
+/// vapor-like root)
+///
+/// Example:
+/// ```typst
+/// #let methane = compressibility-curve("CH4", 200)
+/// #methane.points.last() // (300, ≈ 0.78)
+/// ```
+/// -> dict
+#let compressibility-curve(
+  gas,
+  temp,
+  model: "pr",
+  pressure-range: (0, 300),
+  points: 100,
+  data: gas-data,
+) = {
+  let result-bytes = energetics-plugin.compressibility_curve(
+    bytes(json.encode(gas)),
+    bytes(json.encode(_data-table(data))),
+    bytes(model),
+    bytes(repr(temp)),
+    bytes(json.encode(pressure-range)),
+    bytes(str(points))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the fugacity and fugacity coefficient of a pure gas
+///
+/// From a cubic equation of state ("vdw", "rk", "srk", "pr") or the generalized virial
+/// correlation ("virial"): ln φ = (Pr/Tr)·(B⁰ + ω·B¹), with B⁰ = 0.083 - 0.422/Tr^1.6 and
+/// B¹ = 0.139 - 0.172/Tr^4.2, which suits low to moderate reduced pressures.
+///
+/// Arguments:
+/// - gas: Formula in the gas table, or an explicit dictionary of constants
+/// - temp: Temperature (K)
+/// - pressure: Pressure (pressure unit)
+/// - method: "vdw", "rk", "srk", "pr" or "virial" (default: "pr")
+/// - phase: "auto", "vapor" or "liquid" root for the cubic models (default: "auto")
+/// - pressure-unit: Unit of `pressure` and the fugacity (default: "bar")
+/// - data: Gas table (default: built-in table)
+///
+/// Returns: Dictionary with `gas`, `method`, `fugacity` (dictionary with `value` and `unit`),
+/// `phi`, `ln_phi`, `z`, `reduced_temperature` and `reduced_pressure`
+///
+/// Example:
+/// ```typst
+/// #calc-fugacity("CO2", 300, 50).phi // ≈ 0.746
+/// #calc-fugacity("CO2", 300, 50, method: "virial").phi // ≈ 0.782
+/// ```
+/// -> dict
+#let calc-fugacity(gas, temp, pressure, method: "pr", phase: "auto", pressure-unit: "bar", data: gas-data) = {
+  let input = (
+    gas: gas,
+    method: method,
+    temperature: temp,
+    pressure: pressure,
+    phase: phase,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.fugacity_coefficient(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate ΔG and the equilibrium constants of a gas reaction from partial pressures
+///
+/// ΔG = ΔG° + RT·ln Q, where Q uses fugacities f = φ·p (standard state 1 bar). With
+/// `method: "ideal"` φ = 1 and Q is the usual pressure quotient; otherwise each φ is the
+/// pure-gas value at the total pressure (Lewis–Randall rule) and Kp = K/Kφ.
+///
+/// Arguments:
+/// - delta-g: Standard reaction Gibbs energy (kJ/mol)
+/// - reactants: Array of tuples (formula, coefficient, partial pressure)
+/// - products: Array of tuples (formula, coefficient, partial pressure)
+/// - temp: Temperature (K, default: 298.15)
+/// - method: "ideal", "vdw", "rk", "srk", "pr" or "virial" (default: "ideal")
+/// - pressure-unit: Unit of the partial pressures (default: "bar")
+/// - data: Gas table (default: built-in table)
+///
+/// Returns: Dictionary with `delta_g` (dictionary with `value` and `unit`),
+/// `standard_delta_g`, `reaction_quotient`, `k`, `k_phi`, `kp`, `fugacity_coefficients`
+/// and `method`
+///
+/// Example:
+/// ```typst
+/// #let nh3 = calc-gas-reaction-gibbs(
+///   48.6,
+///   (("N2", 1, 75), ("H2", 3, 225)),
+///   (("NH3", 2, 1),),
+///   temp: 723.15,
+///   method: "pr",
+/// )
+/// #nh3.k_phi // ≈ 0.64
+/// ```
+/// -> dict
+#let calc-gas-reaction-gibbs(
+  delta-g,
+  reactants,
+  products,
+  temp: 298.15,
+  method: "ideal",
+  pressure-unit: "bar",
+  data: gas-data,
+) = {
+  let input = (
+    delta_g: delta-g,
+    temperature: temp,
+    reactants: reactants,
+    products: products,
+    method: method,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.gas_reaction_gibbs(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the equilibrium partial pressures of a gas-phase reaction
+///
+/// Solves Kp = Π pᵢ^νᵢ for the extent of reaction ξ. Initial amounts are partial
+/// pressures, or moles when `total-pressure` is given. At constant volume the total
+/// pressure changes with Δn(gas); at constant pressure an inert gas dilutes the mixture
+/// and shifts the equilibrium towards the side with more moles of gas.
+///
+/// Arguments:
+/// - kp: Equilibrium constant with partial pressures in `pressure-unit`
+/// - reactants: Array of tuples (formula, coefficient, initial amount)
+/// - products: Array of tuples (formula, coefficient, initial amount)
+/// - total-pressure: Initial total pressure; the amounts are then moles (default: none)
+/// - inert: Inert gas, in the same terms as the other amounts (default: 0)
+/// - constant: "volume" or "pressure", held constant as the reaction proceeds (default: "volume")
+/// - pressure-unit: Unit of the pressures (default: "bar")
+///
+/// Returns: Dictionary with `reactants` and `products` (each an array of dictionaries with
+/// `species`, `coefficient`, `initial_pressure`, `pressure`, `mole_fraction` and, for
+/// moles, `moles`), `extent`, `conversion`, `initial_total_pressure`, `total_pressure`,
+/// `inert_pressure`, `delta_n`, `kp`, `constant` and `pressure_unit`
+///
+/// Example:
+/// ```typst
+/// #let eq = calc-gas-equilibrium(0.15, (("N2O4", 1, 1),), (("NO2", 2, 0),))
+/// #eq.products.at(0).pressure // ≈ 0.352 bar
+/// ```
+/// -> dict
+#let calc-gas-equilibrium(
+  kp,
+  reactants,
+  products,
+  total-pressure: none,
+  inert: 0,
+  constant: "volume",
+  pressure-unit: "bar",
+) = {
+  let input = (
+    kp: kp,
+    reactants: reactants,
+    products: products,
+    total_pressure: total-pressure,
+    inert: inert,
+    constant: constant,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.gas_equilibrium(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate the Gibbs energy of a reaction mixture along the extent of reaction, G(ξ)
+///
+/// G(ξ) = Σnᵢ(μᵢ° + RT·ln aᵢ) with aᵢ = xᵢ·P/P° (ideal gas) or xᵢ (ideal solution). The
+/// unmixed species alone give a straight line of slope ΔG°; the negative mixing term pulls
+/// the minimum inside the range, where ΔG = ΔG° + RT·ln Q = 0 although ΔG° ≠ 0. μ° of the
+/// reactants is taken as zero.
+///
+/// Arguments:
+/// - delta-g: Standard reaction Gibbs energy ΔG° (kJ/mol)
+/// - reactants: Array of tuples (formula, coefficient) or (formula, coefficient, initial moles);
+///   reactants default to their coefficients
+/// - products: Array of tuples as for reactants; products default to zero
+/// - temp: Temperature (K, default: 298.15)
+/// - mixture: "ideal-gas" or "ideal-solution" (default: "ideal-gas")
+/// - pressure: Total pressure of a gas mixture (bar, default: 1)
+/// - inert: Inert gas or solvent (mol, default: 0)
+/// - points: Number of sample points (default: 101)
+///
+/// Returns: Dictionary with `points`, `unmixed` and `mixing` (arrays of (ξ, G) pairs, kJ),
+/// `minimum` (dictionary with `extent`, `gibbs` and `mole_fractions`), `extent_range`,
+/// `delta_g`, `k`, `mixture` and `unit`
+///
+/// Example:
+/// ```typst
+/// #let curve = gibbs-extent-curve(4.73, (("N2O4(g)", 1),), (("NO2(g)", 2),))
+/// #curve.minimum.extent // ≈ 0.189
+/// ```
+/// -> dict
+#let gibbs-extent-curve(
+  delta-g,
+  reactants,
+  products,
+  temp: 298.15,
+  mixture: "ideal-gas",
+  pressure: 1,
+  inert: 0,
+  points: 101,
+) = {
+  let input = (
+    delta_g: delta-g,
+    temperature: temp,
+    reactants: reactants,
+    products: products,
+    mixture: mixture,
+    pressure: pressure,
+    inert: inert,
+    points: points,
+  )
+  let result-bytes = energetics-plugin.gibbs_extent_curve(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate work, heat, ΔU, ΔH and ΔS of an ideal-gas process
+///
+/// Work is done on the gas (negative for an expansion) and ΔU = q + w.
+/// - "isothermal-reversible": w = -nRT·ln(V2/V1)
+/// - "isothermal-irreversible": w = -P_ext·(V2 - V1) against a constant external pressure
+/// - "adiabatic-reversible": q = 0, T·V^(R/Cv) constant
+/// - "isobaric": q = n·Cp·ΔT, w = -P·ΔV
+/// - "isochoric": q = n·Cv·ΔT, w = 0
+///
+/// Arguments:
+/// - process: Process name as listed above
+/// - temp: Initial temperature (K)
+/// - moles: Amount of gas (mol, default: 1)
+/// - initial-pressure: Initial pressure (pressure unit, default: none)
+/// - initial-volume: Initial volume (volume unit, default: none); give one of the two
+/// - final-pressure: Final pressure (default: none)
+/// - final-volume: Final volume (default: none)
+/// - final-temp: Final temperature (K, default: none); give the one final value the process allows
+/// - external-pressure: Opposing pressure of an irreversible step (default: final pressure)
+/// - cv: Cv,m in J/(mol·K), or "monatomic", "diatomic"/"linear", "nonlinear" (default: "monatomic")
+/// - pressure-unit: Pressure unit (default: "bar")
+/// - volume-unit: Volume unit (default: "L")
+///
+/// Returns: Dictionary with `process`, `work`, `heat`, `internal_energy`, `enthalpy` (J) and
+/// `entropy` (J/K), each a dictionary with `value` and `unit`, the `initial` and `final`
+/// states (`temperature`, `pressure`, `volume`), and the `cv` and `cp` used
+///
+/// Example:
+/// ```typst
+/// #let expansion = ideal-gas-process("isothermal-reversible", 298.15, initial-volume: 10, final-volume: 20)
+/// #expansion.work.value // ≈ -1718 J
+/// ```
+/// -> dict
+#let ideal-gas-process(
+  process,
+  temp,
+  moles: 1,
+  initial-pressure: none,
+  initial-volume: none,
+  final-pressure: none,
+  final-volume: none,
+  final-temp: none,
+  external-pressure: none,
+  cv: "monatomic",
+  pressure-unit: "bar",
+  volume-unit: "L",
+) = {
+  let input = (
+    process: process,
+    moles: moles,
+    temperature: temp,
+    initial_pressure: initial-pressure,
+    initial_volume: initial-volume,
+    final_pressure: final-pressure,
+    final_volume: final-volume,
+    final_temperature: final-temp,
+    external_pressure: external-pressure,
+    cv: cv,
+    pressure_unit: pressure-unit,
+    volume_unit: volume-unit,
+  )
+  let result-bytes = energetics-plugin.ideal_gas_process(bytes(json.encode(input)))
+  
+  json(result-bytes)
+}
+
+/// Solve a reversible adiabatic expansion or compression of an ideal gas
+/// T2/T1 = (V1/V2)^(γ-1) = (P2/P1)^((γ-1)/γ)
+///
+/// γ comes from one of `gamma`, `cp` (Cv = Cp - R), `degrees-of-freedom` (Cv = fR/2) or
+/// `gas-type` ("monatomic" γ = 5/3, "diatomic"/"linear" 7/5, "nonlinear" 4/3); with none of
+/// them the gas is taken as monatomic. Give exactly one of `final-pressure` (needs
+/// `initial-pressure`), `volume-ratio` (V2/V1) and `final-temp`.
+///
+/// Arguments:
+/// - temp: Initial temperature (K)
+/// - initial-pressure: Initial pressure (pressure unit, default: none)
+/// - final-pressure: Final pressure (pressure unit, default: none)
+/// - volume-ratio: V2/V1 (default: none)
+/// - final-temp: Final temperature (K, default: none)
+/// - gamma: Heat capacity ratio Cp/Cv (default: none)
+/// - cp: Molar heat capacity Cp,m (J/(mol·K), default: none)
+/// - degrees-of-freedom: Active degrees of freedom (default: none)
+/// - gas-type: "monatomic", "diatomic", "linear" or "nonlinear" (default: none)
+/// - pressure-unit: Pressure unit (default: "bar")
+///
+/// Returns: Dictionary with `gamma`, `cv`, `cp`, `final_temperature`, `final_pressure` (when
+/// the initial pressure is given), `temperature_ratio`, `pressure_ratio`, `volume_ratio` and
+/// the molar `work` done on the gas
+///
+/// Example:
+/// ```typst
+/// #adiabatic-state(300, initial-pressure: 1, final-pressure: 10, gas-type: "diatomic").final_temperature.value // ≈ 579 K
+/// ```
+/// -> dict
+#let adiabatic-state(
+  temp,
+  initial-pressure: none,
+  final-pressure: none,
+  volume-ratio: none,
+  final-temp: none,
+  gamma: none,
+  cp: none,
+  degrees-of-freedom: none,
+  gas-type: none,
+  pressure-unit: "bar",
+) = {
+  let input = (
+    temperature: temp,
+    initial_pressure: initial-pressure,
+    final_pressure: final-pressure,
+    volume_ratio: volume-ratio,
+    final_temperature: final-temp,
+    gamma: gamma,
+    cp: cp,
+    degrees_of_freedom: degrees-of-freedom,
+    gas_type: gas-type,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.adiabatic_state(bytes(json.encode(input)))
+  
+  json(result-bytes)
+}
+
+/// Estimate the Joule–Thomson coefficient of a van der Waals gas
+/// μ_JT ≈ (2a/(RT) - b)/Cp,m, T_inv = 2a/(Rb)
+///
+/// A positive μ means the gas cools on expansion (below the inversion temperature). The
+/// throttling temperature change integrates dT/dP = μ_JT(T) over `pressure-change`.
+///
+/// Arguments:
+/// - gas: Formula in the gas table, or a dictionary with `a` and `b` (or `Tc` and `Pc`)
+/// - temp: Initial temperature (K)
+/// - cp: Molar heat capacity Cp,m (J/(mol·K), default: none)
+/// - gas-type: "monatomic", "diatomic", "linear" or "nonlinear" when Cp is not given (default: none)
+/// - pressure-change: P_final - P_initial of a throttling step (pressure unit, default: none)
+/// - pressure-unit: Pressure unit of μ and the pressure change (default: "bar")
+/// - data: Gas table (default: built-in table)
+///
+/// Returns: Dictionary with `gas`, `value` (μ_JT) and `unit`, `inversion_temperature`,
+/// `temperature_change` and `final_temperature` (with a pressure change), and the `a`, `b`
+/// and `cp` used
+///
+/// Example:
+/// ```typst
+/// #let n2 = calc-joule-thomson("N2", 298.15, cp: 29.12, pressure-change: -100)
+/// #n2.value // ≈ 0.247 K/bar
+/// #n2.temperature_change.value // ≈ -26.4 K
+/// ```
+/// -> dict
+#let calc-joule-thomson(
+  gas,
+  temp,
+  cp: none,
+  gas-type: none,
+  pressure-change: none,
+  pressure-unit: "bar",
+  data: gas-data,
+) = {
+  let input = (
+    gas: gas,
+    temperature: temp,
+    cp: cp,
+    gas_type: gas-type,
+    pressure_change: pressure-change,
+    pressure_unit: pressure-unit,
+  )
+  let result-bytes = energetics-plugin.joule_thomson(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// HEAT ENGINES
+// ============================================================================
+
+/// Calculate the Carnot efficiency of a heat engine
+/// η = 1 - Tc/Th
+///
+/// Arguments:
+/// - t-hot: Hot reservoir temperature (K)
+/// - t-cold: Cold reservoir temperature (K)
+/// - heat: Heat absorbed from the hot reservoir (J, default: none)
+/// - actual: Actual efficiency of the engine (default: none)
+///
+/// Returns: Dictionary with `value` (η) and `unit`, plus the maximum `work` and
+/// `heat_rejected` when `heat` is given and the `second_law_efficiency` when `actual` is given
+///
+/// Example:
+/// ```typst
+/// #calc-carnot-efficiency(500, 300, heat: 1000).work // 400 J
+/// ```
+/// -> dict
+#let calc-carnot-efficiency(t-hot, t-cold, heat: none, actual: none) = {
+  let result-bytes = energetics-plugin.carnot_efficiency(
+    bytes(repr(t-hot)),
+    bytes(repr(t-cold)),
+    bytes(repr(if heat == none { 0 } else { heat })),
+    bytes(repr(if actual == none { 0 } else { actual }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the reversible coefficient of performance of a refrigerator or heat pump
+/// Refrigerator: COP = Tc/(Th - Tc); heat pump: COP = Th/(Th - Tc)
+///
+/// Arguments:
+/// - t-hot: Hot reservoir temperature (K)
+/// - t-cold: Cold reservoir temperature (K)
+/// - mode: "refrigerator" or "heat-pump" (default: "refrigerator")
+/// - work: Work input (J, default: none)
+/// - actual: Actual COP of the device (default: none)
+///
+/// Returns: Dictionary with `value` (Carnot COP), `unit` and `mode`, plus the `heat` moved
+/// for the work input and the `second_law_efficiency` when given
+///
+/// Example:
+/// ```typst
+/// #calc-cop(298, 268).value // ≈ 8.93
+/// #calc-cop(293, 273, mode: "heat-pump").value // 14.65
+/// ```
+/// -> dict
+#let calc-cop(t-hot, t-cold, mode: "refrigerator", work: none, actual: none) = {
+  let result-bytes = energetics-plugin.coefficient_of_performance(
+    bytes(repr(t-hot)),
+    bytes(repr(t-cold)),
+    bytes(mode),
+    bytes(repr(if work == none { 0 } else { work })),
+    bytes(repr(if actual == none { 0 } else { actual }))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the entropy generated when heat flows irreversibly between two reservoirs
+/// S_gen = q·(1/Tc - 1/Th)
+///
+/// Arguments:
+/// - heat: Heat transferred from the hot to the cold reservoir (J)
+/// - t-hot: Hot reservoir temperature (K)
+/// - t-cold: Cold reservoir temperature (K)
+/// - t-surroundings: Dead-state temperature for the lost work (K, default: t-cold)
+///
+/// Returns: Dictionary with `value` (S_gen) and `unit`, the reservoir entropy changes
+/// `hot_reservoir` and `cold_reservoir`, and the `lost_work` T0·S_gen (J)
+///
+/// Example:
+/// ```typst
+/// #calc-entropy-generation(1000, 500, 300).value // ≈ 1.333 J/K
+/// ```
+/// -> dict
+#let calc-entropy-generation(heat, t-hot, t-cold, t-surroundings: none) = {
+  let result-bytes = energetics-plugin.heat_transfer_entropy(
+    bytes(repr(heat)),
+    bytes(repr(t-hot)),
+    bytes(repr(t-cold)),
+    bytes(repr(if t-surroundings == none { 0 } else { t-surroundings }))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// STATISTICAL THERMODYNAMICS
+// ============================================================================
+
+/// Calculate the molar entropy of an ideal gas from molecular constants, by mode of motion
+///
+/// Translational (Sackur–Tetrode): S = R·[ln(kT/(P·Λ³)) + 5/2], Λ = h/√(2πmkT);
+/// rotational: R·(ln q_rot + 1) (linear) or R·(ln q_rot + 3/2) (nonlinear);
+/// vibrational: R·Σ[x/(eˣ - 1) - ln(1 - e⁻ˣ)] with x = hcṽ/(kT);
+/// electronic: R·(ln q_el + ⟨ε⟩/(kT)).
+///
+/// Arguments:
+/// - molecule: Dictionary with `mass` (g/mol or formula), `rotational` (array of rotational
+///   constants in cm⁻¹: empty for an atom, (B,) for a linear molecule, (A, B, C) for a
+///   nonlinear one), `symmetry` (σ, default 1), `vibrations` (wavenumbers in cm⁻¹) and
+///   `electronic` (array of (energy in cm⁻¹, degeneracy), default ((0, 1),))
+/// - temp: Temperature (K, default: 298.15)
+/// - pressure: Pressure (pressure unit, default: 1)
+/// - pressure-unit: Pressure unit (default: "bar")
+///
+/// Returns: Dictionary with the total `value` and `unit` (J/(mol·K)), the `translational`,
+/// `rotational`, `vibrational` and `electronic` contributions, `temperature` and `pressure` (bar)
+///
+/// Example:
+/// ```typst
+/// #let n2 = (mass: "N2", rotational: (1.998,), symmetry: 2, vibrations: (2358.6,))
+/// #calc-statistical-entropy(n2).value // ≈ 191.6 J/(mol·K)
+/// ```
+/// -> dict
+#let calc-statistical-entropy(molecule, temp: 298.15, pressure: 1, pressure-unit: "bar") = {
+  let result-bytes = energetics-plugin.statistical_entropy(
+    bytes(json.encode(molecule)),
+    bytes(repr(temp)),
+    bytes(repr(pressure)),
+    bytes(pressure-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the translational, rotational, vibrational and electronic partition functions
+///
+/// q_trans = kT/(P·Λ³); q_rot = kT/(σhcB) (linear) or (√π/σ)·(kT/hc)^(3/2)/√(ABC)
+/// (nonlinear), high-temperature limit; q_vib = Π 1/(1 - e^(-hcṽ/kT)) from the vibrational
+/// ground state; q_el = Σ g·e^(-hcε/kT). These are the molecular partition functions behind
+/// transition-state (Eyring) theory.
+///
+/// Arguments:
+/// - molecule: Dictionary of molecular constants, as for `calc-statistical-entropy`
+/// - temp: Temperature (K, default: 298.15)
+/// - pressure: Pressure fixing the translational volume kT/P (pressure unit, default: 1)
+/// - pressure-unit: Pressure unit (default: "bar")
+///
+/// Returns: Dictionary with `translational`, `translational_per_volume` (m⁻³), `rotational`,
+/// `vibrational`, `electronic` and `total`, the characteristic `rotational_temperatures` and
+/// `vibrational_temperatures` (K), the `zero_point_energy` (kJ/mol) and `temperature`
+///
+/// Example:
+/// ```typst
+/// #let n2 = (mass: "N2", rotational: (1.998,), symmetry: 2, vibrations: (2358.6,))
+/// #calc-partition-functions(n2).rotational // ≈ 51.9
+/// ```
+/// -> dict
+#let calc-partition-functions(molecule, temp: 298.15, pressure: 1, pressure-unit: "bar") = {
+  let result-bytes = energetics-plugin.partition_functions(
+    bytes(json.encode(molecule)),
+    bytes(repr(temp)),
+    bytes(repr(pressure)),
+    bytes(pressure-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the equilibrium constant of a gas-phase reaction from partition functions
+/// K = Π (q°_m/NA)^ν · exp(-ΔE0/RT)
+///
+/// Standard molar partition functions are taken at the standard pressure with vibrations
+/// counted from their ground states, so ΔE0 is the difference of the ground-state energies
+/// of products and reactants (zero-point energies included, e.g. D0 for a dissociation).
+/// The returned `delta_g` can be compared directly with thermochemical ΔG° values.
+///
+/// Arguments:
+/// - reactants: Array of tuples (molecule, coefficient); molecules as for `calc-partition-functions`, optionally with a `name`
+/// - products: Array of tuples (molecule, coefficient)
+/// - delta-e0: ΔE0 (kJ/mol)
+/// - temp: Temperature (K, default: 298.15)
+/// - standard-pressure: Standard pressure (bar, default: 1)
+///
+/// Returns: Dictionary with `value` (K), `unit`, `ln_k`, `delta_g` (kJ/mol), `partition_factor`,
+/// `energy_factor` and `species` (array of dictionaries with `name`, `coefficient` and
+/// `molar_partition_function` q°_m/NA)
+///
+/// Example:
+/// ```typst
+/// #let i2 = (name: "I2", mass: "I2", rotational: (0.0374,), symmetry: 2, vibrations: (214.5,))
+/// #let i = (name: "I", mass: "I", electronic: ((0, 4),))
+/// #calc-k-from-partition-functions(((i2, 1),), ((i, 2),), 148.16, temp: 1000).value // ≈ 3.5e-3
+/// ```
+/// -> dict
+#let calc-k-from-partition-functions(reactants, products, delta-e0, temp: 298.15, standard-pressure: 1) = {
+  let result-bytes = energetics-plugin.equilibrium_constant_statistical(
+    bytes(json.encode(reactants)),
+    bytes(json.encode(products)),
+    bytes(repr(delta-e0)),
+    bytes(repr(temp)),
+    bytes(repr(standard-pressure))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate fractional Boltzmann populations of a set of energy levels
+/// p_i = g_i·e^(-(E_i - E_0)/kT)/q, q = Σ g_i·e^(-(E_i - E_0)/kT)
+///
+/// Arguments:
+/// - levels: Array of energies, or of (energy, degeneracy) pairs
+/// - temp: Temperature (K, default: 298.15)
+/// - unit: Energy unit: "cm-1", "eV", "kJ/mol", "J" (per molecule) or "K" (default: "cm-1")
+///
+/// Returns: Dictionary with `partition_function`, `mean_energy`, `energy_unit`, `temperature`
+/// and `levels` (array of dictionaries with `energy`, `degeneracy`, `fraction` and
+/// `relative`, the population relative to the lowest level)
+///
+/// Example:
+/// ```typst
+/// // HCl rotational levels J = 0–3 (B = 10.59 cm⁻¹)
+/// #let hcl = calc-boltzmann-populations(((0, 1), (21.18, 3), (63.54, 5), (127.08, 7)))
+/// #hcl.levels.at(1).fraction // ≈ 0.242
+/// ```
+/// -> dict
+#let calc-boltzmann-populations(levels, temp: 298.15, unit: "cm-1") = {
+  let result-bytes = energetics-plugin.boltzmann_populations(
+    bytes(json.encode(levels)),
+    bytes(repr(temp)),
+    bytes(unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the Boltzmann population ratio of two levels
+/// N_upper/N_lower = (g_upper/g_lower)·e^(-ΔE/kT)
+///
+/// Arguments:
+/// - delta-e: Energy gap E_upper - E_lower (energy unit)
+/// - temp: Temperature (K, default: 298.15)
+/// - g-upper: Degeneracy of the upper level (default: 1)
+/// - g-lower: Degeneracy of the lower level (default: 1)
+/// - unit: Energy unit, as for `calc-boltzmann-populations` (default: "cm-1")
+///
+/// Returns: Dictionary with `value` (the ratio), `unit`, `upper_fraction` of a two-level
+/// system and the `characteristic_temperature` ΔE/k (K)
+///
+/// Example:
+/// ```typst
+/// #calc-boltzmann-ratio(2886).value // ≈ 8.9e-7 (HCl, v = 1 vs v = 0)
+/// ```
+/// -> dict
+#let calc-boltzmann-ratio(delta-e, temp: 298.15, g-upper: 1, g-lower: 1, unit: "cm-1") = {
+  let result-bytes = energetics-plugin.boltzmann_ratio(
+    bytes(repr(delta-e)),
+    bytes(repr(temp)),
+    bytes(repr(g-upper)),
+    bytes(repr(g-lower)),
+    bytes(unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the heat capacity of a solid from the Einstein or Debye model
+///
+/// Einstein: Cv = 3nR·x²·eˣ/(eˣ - 1)² with x = θE/T
+/// Debye: Cv = 9nR·(T/θD)³·∫₀^(θD/T) x⁴·eˣ/(eˣ - 1)² dx
+///
+/// Arguments:
+/// - theta: Einstein or Debye temperature (K)
+/// - temp: Temperature (K, default: 298.15)
+/// - model: "einstein" or "debye" (default: "debye")
+/// - atoms: Atoms per formula unit n (default: 1)
+///
+/// Returns: Dictionary with `value` and `unit` (J/(mol·K)), `model`, the Dulong–Petit limit
+/// `dulong_petit` (3nR) and `fraction_of_classical`
+///
+/// Example:
+/// ```typst
+/// #calc-solid-heat-capacity(343).value // ≈ 23.4 J/(mol·K) for copper
+/// ```
+/// -> dict
+#let calc-solid-heat-capacity(theta, temp: 298.15, model: "debye", atoms: 1) = {
+  let result-bytes = energetics-plugin.solid_heat_capacity(
+    bytes(model),
+    bytes(repr(theta)),
+    bytes(repr(temp)),
+    bytes(repr(atoms))
+  )
+  
+  json(result-bytes)
+}
+
+/// Generate Cv(T) curve data of a solid for plotting against the Dulong–Petit limit
+///
+/// Arguments:
+/// - theta: Einstein or Debye temperature (K)
+/// - model: "einstein" or "debye" (default: "debye")
+/// - temp-range: Temperature range (K, default: auto - 0 to 2θ)
+/// - points: Number of points (default: 100)
+/// - atoms: Atoms per formula unit n (default: 1)
+///
+/// Returns: Dictionary with `model`, `theta`, `points` ((T, Cv) pairs) and `dulong_petit`
+///
+/// Example:
+/// ```typst
+/// #let debye = solid-heat-capacity-curve(343)
+/// #let einstein = solid-heat-capacity-curve(240, model: "einstein")
+/// ```
+/// -> dict
+#let solid-heat-capacity-curve(theta, model: "debye", temp-range: auto, points: 100, atoms: 1) = {
+  let temp-range = if temp-range == auto { (0, 2 * theta) } else { temp-range }
+  
+  let result-bytes = energetics-plugin.solid_heat_capacity_curve(
+    bytes(model),
+    bytes(repr(theta)),
+    bytes(json.encode(temp-range)),
+    bytes(str(points)),
+    bytes(repr(atoms))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the thermal de Broglie wavelength of a gas
+///
+/// Λ = h/√(2πmkT); the gas behaves classically while n·Λ³ ≪ 1
+///
+/// Arguments:
+/// - mass: Molar mass (g/mol) or formula string
+/// - temp: Temperature (K, default: 298.15)
+/// - pressure: Pressure for the degeneracy parameter n·Λ³ (default: none)
+/// - pressure-unit: Pressure unit (default: "bar")
+///
+/// Returns: Dictionary with `value` and `unit` (pm), and `degeneracy_parameter` when a
+/// pressure is given
+///
+/// Example:
+/// ```typst
+/// #calc-thermal-wavelength("Ar").value // ≈ 16.0 pm
+/// ```
+/// -> dict
+#let calc-thermal-wavelength(mass, temp: 298.15, pressure: none, pressure-unit: "bar") = {
+  let result-bytes = energetics-plugin.thermal_wavelength(
+    bytes(json.encode(mass)),
+    bytes(repr(temp)),
+    bytes(repr(if pressure == none { 0 } else { pressure })),
+    bytes(pressure-unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the classical equipartition energy and heat capacities of an ideal gas
+///
+/// U = (f/2)·RT and Cv = (f/2)·R for f quadratic terms: 3 translational, 2 (linear) or
+/// 3 (nonlinear) rotational, and 2 per vibrational mode when vibrations are counted
+///
+/// Arguments:
+/// - gas-type: "monatomic", "linear", "diatomic" or "nonlinear" (default: "monatomic")
+/// - temp: Temperature (K, default: 298.15)
+/// - atoms: Number of atoms, needed for vibrations (default: none)
+/// - vibrations: Count fully excited vibrational modes, 3N - 5 or 3N - 6 (default: false)
+///
+/// Returns: Dictionary with `quadratic_terms`, `translational`, `rotational`,
+/// `vibrational_modes`, `energy_per_term` (kJ/mol), `internal_energy`, `cv`, `cp` and `gamma`
+///
+/// Example:
+/// ```typst
+/// #calc-equipartition(gas-type: "diatomic").cv // 20.79 J/(mol·K) = 5R/2
+/// ```
+/// -> dict
+#let calc-equipartition(gas-type: "monatomic", temp: 298.15, atoms: none, vibrations: false) = {
+  let result-bytes = energetics-plugin.equipartition_energy(
+    bytes(gas-type),
+    bytes(repr(temp)),
+    bytes(repr(if atoms == none { 0 } else { atoms })),
+    bytes(repr(vibrations))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// PHOTOCHEMISTRY
+// ============================================================================
+
+/// Convert between photon wavelength, frequency, wavenumber and energy
+///
+/// E = hν = hc/λ = hc·ν̃, per photon or per mole of photons
+///
+/// Arguments:
+/// - value: Photon quantity
+/// - unit: "nm", "pm", "um", "cm", "m", "Hz", "THz", "cm-1", "J" (per photon), "eV" or
+///   "kJ/mol" (default: "nm")
+///
+/// Returns: Dictionary with `wavelength` (nm), `frequency` (Hz), `wavenumber` (cm⁻¹),
+/// `energy` (J per photon), `energy_ev`, `molar_energy` (kJ/mol) and the spectral `region`
+///
+/// Example:
+/// ```typst
+/// #calc-photon-energy(500).molar_energy.value // ≈ 239.3 kJ/mol
+/// #calc-photon-energy(2, unit: "eV").wavelength.value // ≈ 619.9 nm
+/// ```
+/// -> dict
+#let calc-photon-energy(value, unit: "nm") = {
+  let result-bytes = energetics-plugin.photon_energy(
+    bytes(repr(value)),
+    bytes(unit)
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the longest wavelength able to break a bond
+///
+/// λmax = hc·NA/D
+///
+/// Arguments:
+/// - energy: Bond dissociation energy
+/// - unit: "kJ/mol", "eV", "J" (per bond) or "cm-1" (default: "kJ/mol")
+/// - wavelength: Photon wavelength to test (nm, default: none)
+///
+/// Returns: Dictionary with `value` and `unit` (nm), the `threshold` photon (all quantities,
+/// as in `calc-photon-energy`), and `can_dissociate` and `excess_energy` (kJ/mol) when a
+/// wavelength is given
+///
+/// Example:
+/// ```typst
+/// #calc-bond-photolysis(498).value // ≈ 240 nm for O=O
+/// ```
+/// -> dict
+#let calc-bond-photolysis(energy, unit: "kJ/mol", wavelength: none) = {
+  let result-bytes = energetics-plugin.bond_photolysis_wavelength(
+    bytes(repr(energy)),
+    bytes(unit),
+    bytes(repr(if wavelength == none { 0 } else { wavelength }))
+  )
+  
+  json(result-bytes)
+}
+
+// Nuclear mass sent to the plugin: the proton for hydrogen by default, 0 for an infinitely
+// heavy nucleus
+#let _nuclear-mass(z, nuclear-mass) = {
+  if nuclear-mass == auto {
+    if z == 1 { 1.007276 } else { 0 }
+  } else if nuclear-mass == none {
+    0
+  } else {
+    nuclear-mass
+  }
+}
+
+/// Calculate the energy levels of hydrogen or a hydrogen-like ion
+///
+/// E_n = -hc·R·Z²/n², with the reduced-mass Rydberg constant R = R∞/(1 + mₑ/M)
+///
+/// Arguments:
+/// - z: Nuclear charge (default: 1)
+/// - n-max: Highest principal quantum number (default: 6)
+/// - nuclear-mass: Nuclear mass (g/mol, default: auto - the proton for Z = 1, otherwise
+///   none for an infinitely heavy nucleus)
+///
+/// Returns: Dictionary with `rydberg` (cm⁻¹), `ionization_energy` (eV) and `levels`, each
+/// with `n`, `energy` (eV), `term` (cm⁻¹) and `degeneracy` n²
+///
+/// Example:
+/// ```typst
+/// #hydrogen-energy-levels().levels.at(1).energy // ≈ -3.40 eV
+/// ```
+/// -> dict
+#let hydrogen-energy-levels(z: 1, n-max: 6, nuclear-mass: auto) = {
+  let result-bytes = energetics-plugin.hydrogen_energy_levels(
+    bytes(repr(z)),
+    bytes(str(n-max)),
+    bytes(repr(_nuclear-mass(z, nuclear-mass)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate a transition of hydrogen or a hydrogen-like ion with the Rydberg formula
+///
+/// ν̃ = R·Z²·(1/n_lower² - 1/n_upper²)
+///
+/// Arguments:
+/// - n-initial: Initial principal quantum number
+/// - n-final: Final principal quantum number
+/// - z: Nuclear charge (default: 1)
+/// - nuclear-mass: Nuclear mass (g/mol, default: auto)
+///
+/// Returns: Dictionary with `n_upper`, `n_lower`, `series` (Lyman, Balmer, Paschen, ...),
+/// `process` ("emission" or "absorption"), the photon quantities of `calc-photon-energy` and
+/// the `series_limit` (nm)
+///
+/// Example:
+/// ```typst
+/// #calc-hydrogen-transition(3, 2).wavelength.value // ≈ 656.5 nm (Hα)
+/// ```
+/// -> dict
+#let calc-hydrogen-transition(n-initial, n-final, z: 1, nuclear-mass: auto) = {
+  let result-bytes = energetics-plugin.hydrogen_transition(
+    bytes(str(n-initial)),
+    bytes(str(n-final)),
+    bytes(repr(z)),
+    bytes(repr(_nuclear-mass(z, nuclear-mass)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Calculate the ionization energy of hydrogen or a hydrogen-like ion from level n
+///
+/// E = hc·R·Z²/n²
+///
+/// Arguments:
+/// - n: Principal quantum number (default: 1)
+/// - z: Nuclear charge (default: 1)
+/// - nuclear-mass: Nuclear mass (g/mol, default: auto)
+///
+/// Returns: Dictionary with `value` and `unit` (eV), `n` and the `threshold` photon
+///
+/// Example:
+/// ```typst
+/// #calc-hydrogen-ionization().value // ≈ 13.60 eV
+/// ```
+/// -> dict
+#let calc-hydrogen-ionization(n: 1, z: 1, nuclear-mass: auto) = {
+  let result-bytes = energetics-plugin.hydrogen_ionization_energy(
+    bytes(str(n)),
+    bytes(repr(z)),
+    bytes(repr(_nuclear-mass(z, nuclear-mass)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Identify the hydrogen-like transition closest to an observed wavelength
+///
+/// Arguments:
+/// - wavelength: Observed wavelength (nm)
+/// - z: Nuclear charge (default: 1)
+/// - nuclear-mass: Nuclear mass (g/mol, default: auto)
+///
+/// Returns: The closest transition (as in `calc-hydrogen-transition`) with its `deviation`
+/// from the observed wavelength (%)
+///
+/// Example:
+/// ```typst
+/// #let line = identify-hydrogen-line(486.1)
+/// #line.series, #line.n_upper → #line.n_lower // Balmer, 4 → 2
+/// ```
+/// -> dict
+#let identify-hydrogen-line(wavelength, z: 1, nuclear-mass: auto) = {
+  let result-bytes = energetics-plugin.identify_hydrogen_line(
+    bytes(repr(wavelength)),
+    bytes(repr(z)),
+    bytes(repr(_nuclear-mass(z, nuclear-mass)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// BIOCHEMICAL THERMODYNAMICS
+// ============================================================================
+
+/// Convert between the chemical standard ΔG° and the biochemical standard ΔG°′
+///
+/// ΔG°′ = ΔG° - ν(H⁺)·RT·ln 10·pH + RT·Σν·ln γ, with H⁺ held at pH and the other charged
+/// species corrected by log γ = -A·z²·√I/(1 + 1.6·√I)
+///
+/// Arguments:
+/// - delta-g: ΔG° or ΔG°′ (kJ/mol)
+/// - from: Standard state of `delta-g`: "chemical" or "biochemical" (default: "chemical")
+/// - protons: Net H⁺ produced by the reaction, negative if consumed (default: 0)
+/// - ph: pH of the biochemical standard state (default: 7)
+/// - temp: Temperature (K, default: 298.15)
+/// - ionic-strength: Ionic strength of the biochemical standard state (mol/L, default: 0)
+/// - charges: (charge, coefficient) pairs of the other charged species, coefficients
+///   negative for reactants (default: ())
+///
+/// Returns: Dictionary with the converted `value` and `unit` (kJ/mol), `chemical`,
+/// `biochemical`, `proton_term`, `ionic_strength_term`, `k` and `k_prime`
+///
+/// Example:
+/// ```typst
+/// // A reaction releasing one H⁺ with ΔG° = +10 kJ/mol
+/// #calc-biochemical-standard(10, protons: 1).value // ≈ -29.96 kJ/mol
+/// ```
+/// -> dict
+#let calc-biochemical-standard(
+  delta-g,
+  from: "chemical",
+  protons: 0,
+  ph: 7,
+  temp: 298.15,
+  ionic-strength: 0,
+  charges: (),
+) = {
+  let input = (
+    delta_g: delta-g,
+    from: from,
+    protons: protons,
+    ph: ph,
+    temperature: temp,
+    ionic_strength: ionic-strength,
+    charges: charges,
+  )
+  
+  let result-bytes = energetics-plugin.biochemical_standard_state(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+/// Add the standard free energies of coupled reactions
+///
+/// ΔG°′ = Σ cᵢ·ΔG°′ᵢ and K′ = e^(-ΔG°′/RT), e.g. ATP hydrolysis driving an unfavourable step
+///
+/// Arguments:
+/// - steps: Array of ΔG°′ values (kJ/mol) or names from `biochem-data`, or (step, coefficient)
+///   pairs; a negative coefficient reverses a step
+/// - temp: Temperature (K, default: 298.15)
+/// - data: Biochemical free energy data (default: biochem-data)
+///
+/// Returns: Dictionary with the overall `value` and `unit` (kJ/mol), `k_prime`,
+/// `spontaneous` and `steps` (`reaction`, `delta_g`, `coefficient`, `contribution`)
+///
+/// Example:
+/// ```typst
+/// #calc-coupled-reactions(("glucose phosphorylation", "ATP hydrolysis")).value // -16.7 kJ/mol
+/// ```
+/// -> dict
+#let calc-coupled-reactions(steps, temp: 298.15, data: biochem-data) = {
+  let result-bytes = energetics-plugin.coupled_reactions(
+    bytes(json.encode(steps)),
+    bytes(repr(temp)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// BOND ENTHALPIES
+// ============================================================================
+
+/// Look up an average bond enthalpy
+///
+/// The built-in table holds mean single, double and triple bond enthalpies of the common
+/// main-group elements, keyed as e.g. `"C-H"`, `"C=O"` and `"C≡N"`. The atoms may be given in
+/// either order and triple bonds may be written with `#`. Custom or corrected values can be
+/// supplied by extending the table: `data: bond-data + ("C=O": (enthalpy: 799))`.
+///
+/// Arguments:
+/// - bond: Bond, e.g. "O-H", "C=C" or "N#N"
+/// - data: Bond enthalpy table (default: built-in table)
+///
+/// Returns: Dictionary with `bond` (table key), `atoms`, `order`, `value` and `unit` (kJ/mol),
+/// `per_bond` (eV) and any `note`
+///
+/// Example:
+/// ```typst
+/// #get-bond-enthalpy("C-H").value // 413 kJ/mol
+/// #get-bond-enthalpy("N#N").per_bond // ≈ 9.75 eV
+/// ```
+/// -> dict
+#let get-bond-enthalpy(bond, data: bond-data) = {
+  let result-bytes = energetics-plugin.get_bond_enthalpy(
+    bytes(bond),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// HEAT CAPACITIES AND PHASE CHANGES
+// ============================================================================
+
+/// Look up the heat capacities and phase-change data of a substance
+///
+/// The built-in table holds molar heat capacities of the solid, liquid and gas, normal
+/// melting and boiling points and enthalpies of fusion and vaporization for water, common
+/// solvents, gases and metals. Specific heats follow from the molar mass of the formula.
+/// Entries can be added or corrected with `data: phase-data + ("X": (...))`.
+///
+/// Arguments:
+/// - substance: Table formula or name, e.g. "H2O" or "ethanol"
+/// - data: Heat capacity and phase-change table (default: built-in table)
+///
+/// Returns: Dictionary with `formula`, `name`, `molar_mass`, `heat_capacities` (`phase`,
+/// `molar` in J/(mol·K), `specific` in J/(g·K)) and, where known, `melting_point`,
+/// `boiling_point`, `enthalpy_fusion`, `enthalpy_vaporization`, `entropy_fusion` and
+/// `entropy_vaporization`
+///
+/// Example:
+/// ```typst
+/// #get-phase-data("H2O").heat_capacities.at(1).specific // ≈ 4.18 J/(g·K)
+/// #get-phase-data("ethanol").enthalpy_vaporization.value // 38.56 kJ/mol
+/// ```
+/// -> dict
+#let get-phase-data(substance, data: phase-data) = {
+  let result-bytes = energetics-plugin.get_phase_data(
+    bytes(substance),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// IONIZATION ENERGIES AND ELECTRON AFFINITIES
+// ============================================================================
+
+/// Look up the ionization energies and electron affinity of an element
+///
+/// The built-in table holds the first and second ionization energies of H–Sr and of common
+/// heavier elements, with electron affinities (energy released on forming the gas-phase
+/// anion, omitted where the anion is unbound). `electron_gain_enthalpy` = -EA is the value
+/// entering Born–Haber cycles. Extend or correct entries with `data: ionization-data + (...)`.
+///
+/// Arguments:
+/// - element: Element symbol or name, e.g. "Na" or "chlorine"
+/// - data: Ionization energy and electron affinity table (default: built-in table)
+///
+/// Returns: Dictionary with `element`, `name`, `z`, `ionization_energies` (kJ/mol),
+/// `ionization_energies_ev`, `cumulative` (energy to form the n+ ion), `stable_anion` and,
+/// for bound anions, `electron_affinity`, `electron_affinity_ev` and `electron_gain_enthalpy`
+///
+/// Example:
+/// ```typst
+/// #get-ionization-data("Na").ionization_energies.first() // 495.8 kJ/mol
+/// #get-ionization-data("Mg").cumulative.at(1) // 2188.4 kJ/mol to form Mg²⁺
+/// #get-ionization-data("chlorine").electron_affinity.value // 348.6 kJ/mol
+/// ```
+/// -> dict
+#let get-ionization-data(element, data: ionization-data) = {
+  let result-bytes = energetics-plugin.get_ionization_data(
+    bytes(element),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Tabulate an ionization energy or the electron affinity against atomic number
+///
+/// Useful for periodic-trend plots; elements without a value are left out.
+///
+/// Arguments:
+/// - property: "IE1", "IE2", ... or "EA" (default: "IE1")
+/// - data: Ionization energy and electron affinity table (default: built-in table)
+///
+/// Returns: Dictionary with `property`, `unit` (kJ/mol) and `points` as (Z, symbol, value)
+/// in order of atomic number
+///
+/// Example:
+/// ```typst
+/// #let trend = ionization-trend()
+/// #trend.points.map(((z, symbol, ie)) => (z, ie)) // data for a line plot
+/// ```
+/// -> dict
+#let ionization-trend(property: "IE1", data: ionization-data) = {
+  let result-bytes = energetics-plugin.ionization_trend(
+    bytes(property),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// DATA IMPORT
+// ============================================================================
+
+/// Read a thermodynamic data table from CSV text
+///
+/// The header row names the columns: the formula (also "species" or "substance"), an
+/// optional state, ΔHf (kJ/mol), S (J/(mol·K)) and ΔGf (kJ/mol), and an optional source
+/// (also "reference") kept as each entry's `source`. Headers are matched
+/// loosely, so "delta_Hf", "ΔHf (kJ/mol)" and "dHf" all work; other layouts can be mapped
+/// with `columns`. Entries are keyed like `thermo-data` ("H2O(l)"), so imported tables can
+/// be used directly or merged into it.
+///
+/// Arguments:
+/// - source: CSV text, e.g. `read("my-data.csv")`
+/// - delimiter: Field delimiter (default: auto - "," or ";" with decimal commas)
+/// - decimal-comma: Numbers use a decimal comma, e.g. "-285,83" (default: false)
+/// - columns: Dictionary mapping `formula`, `state`, `delta_Hf`, `S`, `delta_Gf` and `source`
+///   to header names (default: (:) - detect from the header)
+///
+/// Returns: Dictionary of entries with `formula`, `state`, `delta_Hf`, `S`, `delta_Gf` and
+/// `source`
+///
+/// Example:
+/// ```typst
+/// #let my-data = load-thermo-csv(read("my-data.csv"), decimal-comma: true)
+/// #calc-reaction-enthalpy((("A(g)", 1),), (("B(g)", 1),), data: thermo-data + my-data)
+/// ```
+/// -> dict
+#let load-thermo-csv(source, delimiter: auto, decimal-comma: false, columns: (:)) = {
+  let options = (
+    delimiter: if delimiter == auto { none } else { delimiter },
+    decimal_comma: decimal-comma,
+    columns: columns,
+  )
+  
+  let result-bytes = energetics-plugin.parse_thermo_csv(
+    bytes(source),
+    bytes(json.encode(options))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// REACTION ALGEBRA
+// ============================================================================
+
+/// Reaction with ΔH, ΔS, ΔG and K from thermodynamic data, ready for `reverse-reaction`,
+/// `scale-reaction` and `add-reactions`
+///
+/// Arguments:
+/// - reactants: Array of tuples (formula, coefficient)
+/// - products: Array of tuples (formula, coefficient)
+/// - temp: Temperature of ΔG and K (K, default: 298.15)
+/// - data: Thermodynamic data (default: thermo-data)
+///
+/// Returns: Dictionary with `reactants`, `products`, `delta_h` (kJ/mol), `delta_s`
+/// (J/(mol·K)), `delta_g` (kJ/mol), `k` and `temperature`
+/// -> dict
+#let reaction-from-data(reactants, products, temp: 298.15, data: thermo-data) = {
+  let analysis = analyze-reaction(reactants, products, temp: temp, data: data)
+  (
+    reactants: reactants,
+    products: products,
+    delta_h: analysis.enthalpy.value,
+    delta_s: analysis.entropy.value,
+    delta_g: analysis.gibbs.value,
+    k: analysis.equilibrium-constant.value,
+    temperature: temp,
+  )
+}
+
+/// Reverse a reaction
+/// ΔH, ΔS, ΔG → -ΔH, -ΔS, -ΔG and K → 1/K
+///
+/// Reactions are dictionaries with `reactants` and `products` ((formula, coefficient)
+/// tuples) and any of `delta_h` (kJ/mol), `delta_s` (J/(mol·K)), `delta_g` (kJ/mol), `k` and
+/// `temperature`. The results have the same form, so manipulations can be chained.
+///
+/// Arguments:
+/// - reaction: Reaction dictionary
+///
+/// Returns: Reaction dictionary with the transformed quantities, the `equation` and the
+/// `rules` applied
+///
+/// Example:
+/// ```typst
+/// #let haber = (reactants: (("N2(g)", 1), ("H2(g)", 3)), products: (("NH3(g)", 2),), delta_h: -91.8, k: 5.6e5)
+/// #reverse-reaction(haber).k // 1.79e-6
+/// ```
+/// -> dict
+#let reverse-reaction(reaction) = {
+  let result-bytes = energetics-plugin.reverse_reaction(
+    bytes(json.encode(reaction))
+  )
+  
+  json(result-bytes)
+}
+
+/// Multiply the coefficients of a reaction by a factor
+/// ΔH, ΔS, ΔG → n·ΔH, n·ΔS, n·ΔG and K → Kⁿ
+///
+/// Arguments:
+/// - reaction: Reaction dictionary (see `reverse-reaction`)
+/// - factor: Multiplier n; a negative factor also reverses the reaction
+///
+/// Returns: Reaction dictionary with the transformed quantities, the `equation` and the
+/// `rules` applied
+///
+/// Example:
+/// ```typst
+/// #scale-reaction(haber, 0.5).k // √(5.6e5) ≈ 748
+/// ```
+/// -> dict
+#let scale-reaction(reaction, factor) = {
+  let result-bytes = energetics-plugin.scale_reaction(
+    bytes(json.encode(reaction)),
+    bytes(repr(factor))
+  )
+  
+  json(result-bytes)
+}
+
+/// Add reactions (Hess's law)
+/// ΔH = ΣΔHᵢ, ΔS = ΣΔSᵢ, ΔG = ΣΔGᵢ and K = ΠKᵢ
+///
+/// Species appearing on both sides cancel. A quantity is only returned if every reaction
+/// has it; reactions with different temperatures cannot be added.
+///
+/// Arguments:
+/// - reactions: Reaction dictionaries (see `reverse-reaction`)
+///
+/// Returns: Reaction dictionary with the summed quantities, the `equation` and the `rules`
+/// applied
+///
+/// Example:
+/// ```typst
+/// #let combustion = (reactants: (("C(s)", 1), ("O2(g)", 1)), products: (("CO2(g)", 1),), delta_h: -393.5)
+/// #let co-burning = (reactants: (("CO(g)", 1), ("O2(g)", 0.5)), products: (("CO2(g)", 1),), delta_h: -283.0)
+/// #add-reactions(combustion, reverse-reaction(co-burning)).delta_h // -110.5 kJ/mol for C + ½O₂ → CO
+/// ```
+/// -> dict
+#let add-reactions(..reactions) = {
+  let result-bytes = energetics-plugin.add_reactions(
+    bytes(json.encode(reactions.pos()))
+  )
+  
+  json(result-bytes)
+}
+
+/// Predict how an equilibrium shifts after each perturbation (Le Chatelier's principle)
+///
+/// Δn(gas) is taken from the "(g)" annotations and the temperature response from the sign
+/// of `delta_h`. Pure solids and liquids ("(s)", "(l)") cause no shift when added or removed.
+///
+/// Arguments:
+/// - reaction: Reaction dictionary (see `reverse-reaction`), with `delta_h` for temperature changes
+/// - perturbations: Change names ("increase-pressure", "decrease-pressure",
+///   "increase-volume", "decrease-volume", "increase-temperature", "decrease-temperature",
+///   "add-inert", "catalyst") or dictionaries with `change` ("add", "remove", "add-inert"),
+///   `species` and, for an inert gas, `constant` ("volume" or "pressure")
+///
+/// Returns: Dictionary with `equation`, `delta_n`, `delta_h`, `thermicity`, `warnings` and
+/// `shifts`, an array of dictionaries with `change`, `species`, `shift` ("forward",
+/// "reverse", "none" or "unknown"), `k_change`, `reason` (e.g. "fewer-gas-moles") and
+/// `explanation`
+///
+/// Example:
+/// ```typst
+/// #let shifts = le-chatelier(haber, (change: "add", species: "N2(g)"), "increase-temperature")
+/// #shifts.shifts.map(s => s.shift) // ("forward", "reverse")
+/// ```
+/// -> dict
+#let le-chatelier(reaction, ..perturbations) = {
+  let input = (
+    reaction: reaction,
+    perturbations: perturbations.pos(),
+  )
+  let result-bytes = energetics-plugin.le_chatelier(
+    bytes(json.encode(input))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ENTHALPY INCREMENT TABLES
+// ============================================================================
+
+/// Interpolate H(T) - H(298.15 K) and S°(T) of a species from JANAF-style tables
+///
+/// Increment tables give, for each species, the temperatures `T` (K, ascending), the
+/// enthalpy increments `H_minus_H298` (kJ/mol) and the entropies `S` (J/(mol·K)) as arrays,
+/// as printed in JANAF or NIST compilations. Values are interpolated linearly in T;
+/// temperatures outside the table are rejected.
+///
+/// Arguments:
+/// - species: Formula, e.g. "H2O(g)"
+/// - temp: Temperature (K)
+/// - increments: Increment tables (dictionary, or JSON/TOML/YAML text)
+///
+/// Returns: Dictionary with `species`, `temperature`, `h_increment` (kJ/mol) and `s`
+/// (J/(mol·K))
+///
+/// Example:
+/// ```typst
+/// #let janaf = ("H2O(g)": (T: (298.15, 500, 1000), H_minus_H298: (0, 6.925, 26.0), S: (188.834, 206.534, 232.738)))
+/// #interpolate-increments("H2O(g)", 750, janaf).h_increment // 16.46 kJ/mol
+/// ```
+/// -> dict
+#let interpolate-increments(species, temp, increments) = {
+  let result-bytes = energetics-plugin.interpolate_increments(
+    bytes(species),
+    bytes(repr(temp)),
+    bytes(json.encode(_data-table(increments)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Reaction enthalpy, entropy, Gibbs energy and K at any temperature from increment tables
+/// ΔH°(T) = ΔH°(298.15 K) + Σν·[H(T) - H(298.15 K)], ΔS°(T) = Σν·S°(T), ΔG°(T) = ΔH°(T) - T·ΔS°(T)
+///
+/// ΔH°(298.15 K) comes from the formation data; the increments and entropies from the
+/// tables (see `interpolate-increments`), which must cover every species and the temperature.
+///
+/// Arguments:
+/// - reactants: Array of tuples (formula, coefficient)
+/// - products: Array of tuples (formula, coefficient)
+/// - temp: Temperature (K)
+/// - increments: Increment tables of all species
+/// - data: Formation data for ΔH°(298.15 K) (default: thermo-data)
+///
+/// Returns: Dictionary with `enthalpy`, `entropy`, `gibbs` and `equilibrium_constant`
+/// (value/unit), `enthalpy_298`, `enthalpy_correction` (kJ/mol) and the interpolated
+/// `species` values
+///
+/// Example:
+/// ```typst
+/// #let r = calc-reaction-at-temperature((("H2(g)", 1), ("O2(g)", 0.5)), (("H2O(g)", 1),), 1000, janaf)
+/// #r.gibbs.value // -192.6 kJ/mol
+/// ```
+/// -> dict
+#let calc-reaction-at-temperature(reactants, products, temp, increments, data: thermo-data) = {
+  let input = (
+    reactants: reactants,
+    products: products,
+    temperature: temp,
+  )
+  
+  let result-bytes = energetics-plugin.reaction_from_increments(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data))),
+    bytes(json.encode(_data-table(increments)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ELLINGHAM DIAGRAMS
+// ============================================================================
+
+/// Generate the lines of an Ellingham diagram
+/// ΔG°(T) = ΔH° - T·ΔS° per mol O₂, e.g. 4/3 Fe + O₂ → 2/3 Fe₂O₃
+///
+/// ΔH° and ΔS° of each oxidation are calculated from the formation data and taken as constant
+/// (phase changes are not modelled). Elements missing from the data are taken in their
+/// reference states with ΔHf° = 0 and tabulated standard entropies. Every line reports the
+/// temperature where it crosses the C/CO line (2C + O₂ → 2CO), above which carbon can reduce
+/// the oxide.
+///
+/// Arguments:
+/// - oxides: Array of oxide formulas such as "Fe2O3(s)" or "H2O(g)", or dictionaries
+///   `(label, delta_h, delta_s)` per mol O₂ (kJ, J/K) for oxides not in the data
+/// - temp-range: Temperature range (K, default: (300, 2500))
+/// - points: Number of points per line (default: 50)
+/// - data: Thermodynamic data (default: thermo-data)
+///
+/// Returns: Dictionary with `lines` (each with `label`, `equation`, `delta_h`, `delta_s`,
+/// `points` as (T, ΔG°) pairs in K and kJ/mol O₂, and `carbon_reduction_temperature`),
+/// the `carbon_line` and the `range`
+///
+/// Example:
+/// ```typst
+/// #let diagram = ellingham-diagram(("Fe2O3(s)", "MgO(s)", (label: "ZnO", delta_h: -701, delta_s: -201)))
+/// #for line in diagram.lines [#line.label: reduced by carbon above #calc.round(line.carbon_reduction_temperature) K \ ]
+/// ```
+/// -> dict
+#let ellingham-diagram(oxides, temp-range: (300, 2500), points: 50, data: thermo-data) = {
+  let input = (
+    oxides: oxides,
+    range: temp-range,
+    points: points,
+  )
+  
+  let result-bytes = energetics-plugin.ellingham_diagram(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// PHASE DIAGRAMS
+// ============================================================================
+
+/// Generate the phase boundaries of a single-component P–T phase diagram
+/// Solid–liquid: P = Pm + ΔHfus/ΔVfus·ln(T/Tm) (Clapeyron)
+/// Liquid–gas and solid–gas: ln(P₂/P₁) = -ΔH/R·(1/T₂ - 1/T₁) (Clausius–Clapeyron)
+///
+/// The liquid–gas line is anchored at the boiling point and the solid–gas line (with
+/// ΔHsub = ΔHfus + ΔHvap unless given) at the triple point. Without a given triple point it
+/// is estimated where the melting and vaporization lines cross; without molar volumes the
+/// melting line is taken as vertical and the triple point lies at Tm. Transition enthalpies
+/// are taken as constant, so the curves are approximate far from the anchors.
+///
+/// Arguments:
+/// - substance: Formula or name in the phase data, filling in Tm, Tb, ΔHfus and ΔHvap (default: none)
+/// - melting-point: Melting point at the reference pressure (K, default: from data)
+/// - boiling-point: Boiling point at the reference pressure (K, default: from data)
+/// - reference-pressure: Pressure of the melting and boiling points (default: 1 atm)
+/// - triple-point: Known (T, P) of the triple point (K, pressure unit, default: estimated)
+/// - critical-point: (T, P) ending the liquid–gas line (K, pressure unit, default: none)
+/// - delta-h-fus: Enthalpy of fusion (kJ/mol, default: from data)
+/// - delta-h-vap: Enthalpy of vaporization (kJ/mol, default: from data)
+/// - delta-h-sub: Enthalpy of sublimation (kJ/mol, default: ΔHfus + ΔHvap)
+/// - molar-volumes: (solid, liquid) molar volumes (cm³/mol, default: none)
+/// - temp-range: Temperature range (K, default: 0.8·T_triple to the critical point or 1.25·Tb)
+/// - max-pressure: Upper end of the solid–liquid line (default: top of the liquid–gas line)
+/// - points: Number of points per line (default: 50)
+/// - pressure-unit: "Pa", "kPa", "bar", "atm", "Torr", ... (default: "atm")
+/// - data: Phase-change data (default: phase-data)
+///
+/// Returns: Dictionary with `triple_point` (`temperature`, `pressure`, `estimated`), the
+/// `solid_liquid`, `liquid_gas` and `solid_gas` lines as (T, P) pairs, `melting_slope`
+/// (dP/dT, pressure unit per K) when molar volumes are given, and `pressure_unit`
+///
+/// Example:
+/// ```typst
+/// #let water = phase-diagram(substance: "water", molar-volumes: (19.65, 18.02))
+/// Triple point: #calc.round(water.triple_point.temperature, digits: 2) K,
+/// #format-number(water.triple_point.pressure, precision: 2) atm
+/// ```
+/// -> dict
+#let phase-diagram(
+  substance: none,
+  melting-point: none,
+  boiling-point: none,
+  reference-pressure: none,
+  triple-point: none,
+  critical-point: none,
+  delta-h-fus: none,
+  delta-h-vap: none,
+  delta-h-sub: none,
+  molar-volumes: none,
+  temp-range: none,
+  max-pressure: none,
+  points: 50,
+  pressure-unit: "atm",
+  data: phase-data,
+) = {
+  let input = (
+    substance: substance,
+    melting_point: melting-point,
+    boiling_point: boiling-point,
+    reference_pressure: reference-pressure,
+    triple_point: triple-point,
+    critical_point: critical-point,
+    delta_h_fus: delta-h-fus,
+    delta_h_vap: delta-h-vap,
+    delta_h_sub: delta-h-sub,
+    molar_volume_solid: if molar-volumes != none { molar-volumes.at(0) },
+    molar_volume_liquid: if molar-volumes != none { molar-volumes.at(1) },
+    range: temp-range,
+    max_pressure: max-pressure,
+    points: points,
+    pressure_unit: pressure-unit,
+  )
+  
+  let result-bytes = energetics-plugin.phase_diagram(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// HEATING CURVES
+// ============================================================================
+
+/// Calculate the heat needed to take a sample from one temperature to another across
+/// phase changes, with the points of its heating (or cooling) curve
+/// q = Σ n·Cp,m·ΔT + Σ n·ΔHtrs
+///
+/// Transition temperatures, enthalpies and molar heat capacities come from the phase data
+/// unless given. A transition is included when the path passes through it: heating ice from
+/// 273.15 K includes melting, heating it up to 273.15 K does not. Cooling paths give
+/// negative heats with freezing and condensation steps.
+///
+/// Arguments:
+/// - substance: Formula or name in the phase data, or none with all values given
+/// - mass: Sample mass (g)
+/// - start-temp: Initial temperature (K)
+/// - end-temp: Final temperature (K)
+/// - molar-mass: Molar mass (g/mol, default: from the formula)
+/// - melting-point: Melting point (K, default: from data)
+/// - boiling-point: Boiling point (K, default: from data)
+/// - delta-h-fus: Enthalpy of fusion (kJ/mol, default: from data)
+/// - delta-h-vap: Enthalpy of vaporization (kJ/mol, default: from data)
+/// - heat-capacities: Dictionary of molar heat capacities by phase, e.g. `(solid: 37.7)`
+///   (J/(mol·K), default: from data)
+/// - data: Phase-change data (default: phase-data)
+///
+/// Returns: Dictionary with `total_heat` (kJ), `amount` (mol), `steps` (each with `process`,
+/// `from` and `to` in K and `heat` in kJ) and `points` as (q, T) pairs in kJ and K
+///
+/// Example:
+/// ```typst
+/// #let curve = heating-curve("H2O", 18.015, 263.15, 393.15)
+/// Total: #calc.round(curve.total_heat.value, digits: 1) kJ // 55.2 kJ
+/// #for step in curve.steps [#step.process: #calc.round(step.heat, digits: 2) kJ \ ]
+/// ```
+/// -> dict
+#let heating-curve(
+  substance,
+  mass,
+  start-temp,
+  end-temp,
+  molar-mass: none,
+  melting-point: none,
+  boiling-point: none,
+  delta-h-fus: none,
+  delta-h-vap: none,
+  heat-capacities: (:),
+  data: phase-data,
+) = {
+  let input = (
+    substance: substance,
+    mass: mass,
+    start: start-temp,
+    end: end-temp,
+    molar_mass: molar-mass,
+    melting_point: melting-point,
+    boiling_point: boiling-point,
+    delta_h_fus: delta-h-fus,
+    delta_h_vap: delta-h-vap,
+    cp_solid: heat-capacities.at("solid", default: none),
+    cp_liquid: heat-capacities.at("liquid", default: none),
+    cp_gas: heat-capacities.at("gas", default: none),
+  )
+  
+  let result-bytes = energetics-plugin.heating_curve(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ENTHALPY OF SOLUTION
+// ============================================================================
+
+/// Look up the standard hydration enthalpy of a gaseous ion, M^z±(g) → M^z±(aq)
+///
+/// The built-in table holds data-book values of common cations and anions. Extend or
+/// correct it with `data: hydration-data + ("ClO4-": (delta_Hhyd: -229))`.
+///
+/// Arguments:
+/// - ion: Ion, e.g. "Na+", "Mg2+" or "SO42-"
+/// - data: Hydration enthalpy table (default: built-in table)
+///
+/// Returns: Dictionary with `ion`, `name`, `charge`, `value` and `unit` (kJ/mol)
+///
+/// Example:
+/// ```typst
+/// #get-hydration-enthalpy("Na+").value // -406 kJ/mol
+/// ```
+/// -> dict
+#let get-hydration-enthalpy(ion, data: hydration-data) = {
+  let result-bytes = energetics-plugin.get_hydration_enthalpy(
+    bytes(ion),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+/// Solve the dissolution cycle of an ionic solid for its missing term
+/// ΔHsoln = ΔHlatt + ΣΔHhyd
+///
+/// ΔHlatt is the lattice dissociation enthalpy (positive, MX(s) → M⁺(g) + X⁻(g)). Give two
+/// of the three terms; ΣΔHhyd is summed from the table over `ions` unless given. If ΔHsoln
+/// and ΔHlatt are given and one ion is missing from the table, its hydration enthalpy is
+/// solved for.
+///
+/// Arguments:
+/// - ions: Array of (ion, count) per formula unit, e.g. `(("Mg2+", 1), ("Cl-", 2))` (default: ())
+/// - solution: Enthalpy of solution ΔHsoln (kJ/mol, default: none)
+/// - lattice: Lattice dissociation enthalpy ΔHlatt (kJ/mol, default: none)
+/// - hydration: Total hydration enthalpy ΣΔHhyd (kJ/mol, default: from the ions)
+/// - data: Hydration enthalpy table (default: hydration-data)
+///
+/// Returns: Dictionary with `solution`, `lattice`, `hydration`, `unit` (kJ/mol), `solved`
+/// (the calculated term) and, with ions, their `ions` contributions
+///
+/// Example:
+/// ```typst
+/// #calc-enthalpy-of-solution(ions: (("Na+", 1), ("Cl-", 1)), lattice: 787).solution // +17 kJ/mol
+/// #calc-enthalpy-of-solution(ions: (("Na+", 1), ("Cl-", 1)), solution: 3.9).lattice // 773.9 kJ/mol
+/// ```
+/// -> dict
+#let calc-enthalpy-of-solution(
+  ions: (),
+  solution: none,
+  lattice: none,
+  hydration: none,
+  data: hydration-data,
+) = {
+  let input = (
+    ions: ions,
+    solution: solution,
+    lattice: lattice,
+    hydration: hydration,
+  )
+  
+  let result-bytes = energetics-plugin.enthalpy_of_solution(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
+
+// ============================================================================
+// ANTOINE VAPOR PRESSURES
+// ============================================================================
+
+/// Calculate the vapor pressure of a liquid at a temperature, or its boiling temperature at
+/// a pressure, with the Antoine equation
+/// log10(P) = A - B/(C + T)
+///
+/// The built-in table holds coefficients of water and common solvents (mmHg, °C) with the
+/// temperature range they were fitted over; results outside it carry `warnings`. Other
+/// coefficients are given as `(A: ..., B: ..., C: ..., P_unit: "bar", T_unit: "K")`, the
+/// units defaulting to the NIST WebBook convention (bar, K), or added to the table with
+/// `data: antoine-data + ("X": (...))`.
+///
+/// Arguments:
+/// - substance: Table formula or name, e.g. "H2O" or "ethanol", or a coefficient dictionary
+/// - temp: Temperature (K), to calculate the vapor pressure (default: none)
+/// - pressure: Pressure, to calculate the boiling temperature (default: none)
+/// - pressure-unit: "Pa", "kPa", "bar", "atm", "Torr", ... (default: "atm")
+/// - data: Antoine coefficient table (default: built-in table)
+///
+/// Returns: Dictionary with `substance`, `name`, `temperature` (K), `pressure`,
+/// `pressure_unit`, `solved` ("pressure" or "temperature"), the `coefficients` used and any
+/// `warnings` and `sources`
+///
+/// Example:
+/// ```typst
+/// #calc-vapor-pressure("H2O", temp: 298.15, pressure-unit: "kPa").pressure // ≈ 3.16 kPa
+/// #calc-vapor-pressure("ethanol", pressure: 1).temperature // ≈ 351.5 K
+/// ```
+/// -> dict
+#let calc-vapor-pressure(
+  substance,
+  temp: none,
+  pressure: none,
+  pressure-unit: "atm",
+  data: antoine-data,
+) = {
+  let input = (
+    substance: substance,
+    temperature: temp,
+    pressure: pressure,
+    pressure_unit: pressure-unit,
+  )
+  
+  let result-bytes = energetics-plugin.antoine_vapor_pressure(
+    bytes(json.encode(input)),
+    bytes(json.encode(_data-table(data)))
+  )
+  
+  json(result-bytes)
+}
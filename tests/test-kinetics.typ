@@ -90,7 +90,7 @@ Given:
 
 #let k_eyring = calc-rate-constant-eyring(50.0, -20.0, temp: 310)
 
-*Rate constant (Eyring):* #format-result(k_eyring, precision: 3, scientific: true)
+*Rate constant (Eyring):* #format-result(k_eyring, precision: 3, scientific: true) (expected 2.19×10³ s⁻¹)
 
 *Note:* Negative ΔS‡ indicates a more ordered transition state (common in enzyme reactions)
 
@@ -151,11 +151,11 @@ For the same activation parameters:
 
 #let k_eyring_compare = calc-rate-constant-eyring(47.5, 0, temp: 298.15)
 
-*Arrhenius k:* #format-result(k_arrhenius, precision: 3, scientific: true)
+*Arrhenius k:* #format-result(k_arrhenius, precision: 3, scientific: true) (expected 1.08×10⁴)
 
-*Eyring k:* #format-result(k_eyring_compare, precision: 3, scientific: true)
+*Eyring k:* #format-result(k_eyring_compare, precision: 3, scientific: true) (expected 2.96×10⁴ s⁻¹, kB·T/h = 6.21×10¹² s⁻¹ times exp(−ΔH‡/RT))
 
-*Ratio:* #format-number(k_arrhenius.value / k_eyring_compare.value, precision: 2, scientific: false)
+*Ratio:* #format-number(k_arrhenius.value / k_eyring_compare.value, precision: 2, scientific: false) (expected 0.36)
 
 *Note:* Both approaches give similar results when parameters are properly related.
 
@@ -186,6 +186,541 @@ For the same activation parameters:
 
 ---
 
+== Test 9: Reaction Energy Profile
+
+*Mechanism:* Two-step reaction via an intermediate
+- Reactants: 0 kJ/mol, Intermediate: 10 kJ/mol, Products: -20 kJ/mol
+- Ea(step 1) = 60 kJ/mol, Ea(step 2) = 45 kJ/mol
+
+#let profile = energy-profile(0, -20, (60, 45), intermediates: (10,), points-per-step: 40)
+
+#table(
+  columns: (auto, auto, auto, auto),
+  [*Point*], [*Kind*], [*x*], [*E (kJ/mol)*],
+  ..profile.stationary_points.map(p => (
+    p.label,
+    p.kind,
+    format-number(p.x, precision: 1, scientific: false),
+    format-number(p.energy, precision: 1, scientific: false),
+  )).flatten()
+)
+
+*Curve points:* #profile.points.len() (expected 81)
+
+#if profile.stationary_points.at(3).energy == 55 [
+  ✓ Second transition state lies Ea(step 2) above the intermediate
+]
+
+---
+
+== Test 10: Maxwell–Boltzmann Distribution
+
+*Gas:* N₂ (M = 28.0 g/mol) at 300 K and 1000 K, threshold Ea = 10 kJ/mol
+
+#let mb = maxwell-boltzmann-distribution(28.0, (300, 1000), threshold: 10, points: 80)
+
+#table(
+  columns: (auto, auto, auto),
+  [*T (K)*], [*Curve points*], [*Fraction with E ≥ Ea*],
+  ..mb.curves.map(c => (
+    format-number(c.temperature, precision: 0, scientific: false),
+    str(c.points.len()),
+    format-number(c.fraction_above_threshold, precision: 4, scientific: false),
+  )).flatten()
+)
+
+*Expected:* fraction ≈ 0.0456 at 300 K and ≈ 0.493 at 1000 K
+
+#let mb-energy = maxwell-boltzmann-distribution(28.0, 300, mode: "energy", max: 10, points: 5)
+
+*Energy mode:* x in #mb-energy.x_unit, density in #mb-energy.y_unit, #mb-energy.curves.at(0).points.len() points
+
+---
+
+== Test 11: Concentration–Time Curves
+
+*Reaction:* A → B, k = 0.1, [A]₀ = 1.0 M, 30 s
+
+#let orders = (0, 1, 2)
+
+#table(
+  columns: (auto, auto, auto, auto),
+  [*Order*], [*Points*], [*[A] at 30 s*], [*[B] at 30 s*],
+  ..orders.map(n => {
+    let course = concentration-time-curve(0.1, order: n, t-end: 30, points: 61)
+    let last = course.rows.last()
+    (
+      str(n),
+      str(course.rows.len()),
+      format-number(last.at(1), precision: 4, scientific: false),
+      format-number(last.at(2), precision: 4, scientific: false),
+    )
+  }).flatten()
+)
+
+*Expected [A] at 30 s:* 0 M (zero order, consumed at 10 s), e⁻³ = 0.0498 M (first order), 1/(1 + 3) = 0.25 M (second order)
+
+---
+
+== Test 12: Mechanism Simulation (Stiff ODE Integrator)
+
+*Mechanism:* Robertson's stiff test problem
+- A → B (k₁ = 0.04)
+- 2B → B + C (k₂ = 3 × 10⁷)
+- B + C → A + C (k₃ = 1 × 10⁴)
+
+#let robertson = simulate-mechanism(
+  (
+    (reactants: (("A", 1),), products: (("B", 1),), k: 0.04),
+    (reactants: (("B", 2),), products: (("B", 1), ("C", 1)), k: 3e7),
+    (reactants: (("B", 1), ("C", 1)), products: (("A", 1), ("C", 1)), k: 1e4),
+  ),
+  (A: 1.0),
+  40,
+  points: 41,
+  tolerance: 1e-4,
+)
+
+#let final = robertson.rows.last()
+
+*At t = 40 s:*
+- [A] = #format-number(final.at(1), precision: 4, scientific: false) (reference: 0.7158)
+- [B] = #format-number(final.at(2), precision: 3, scientific: true) (reference: 9.185×10⁻⁶)
+- [C] = #format-number(final.at(3), precision: 4, scientific: false) (reference: 0.2842)
+
+*Mass balance:* [A] + [B] + [C] = #format-number(final.at(1) + final.at(2) + final.at(3), precision: 6, scientific: false)
+
+---
+
+== Test 13: Consecutive Reactions A → B → C
+
+*Rate constants:* k₁ = 1.0 s⁻¹, k₂ = 0.5 s⁻¹, [A]₀ = 1.0 M
+
+#let cons = consecutive-reaction-kinetics(1.0, 0.5, t-end: 4, points: 41)
+
+*Intermediate maximum:*
+- t_max = #format-number(cons.intermediate_max.time, precision: 3, scientific: false) s (expected ln(k₁/k₂)/(k₁ - k₂) = 1.386 s)
+- [B]_max = #format-number(cons.intermediate_max.concentration, precision: 3, scientific: false) M (expected (k₁/k₂)^(k₂/(k₂ - k₁)) = 0.500 M)
+
+*At t = 4 s:* [B] = #format-number(cons.rows.last().at(2), precision: 4, scientific: false) M (expected 0.2340 M)
+
+*With reverse step (k₋₁ = 0.3 s⁻¹):*
+
+#let cons-rev = consecutive-reaction-kinetics(1.0, 0.5, k-reverse: 0.3, t-end: 4, points: 41)
+
+- λ₁, λ₂ = #format-number(cons-rev.eigenvalues.at(0), precision: 4, scientific: false), #format-number(cons-rev.eigenvalues.at(1), precision: 4, scientific: false) s⁻¹
+- [C] at 4 s = #format-number(cons-rev.rows.last().at(3), precision: 4, scientific: false) M (numerical integration: 0.6694 M)
+
+---
+
+== Test 14: Parallel (Competing) Reactions
+
+*Reactions:* A → B (k₁ = 0.3 s⁻¹), A → C (k₂ = 0.1 s⁻¹), [A]₀ = 1.0 M
+
+#let par = parallel-reaction-kinetics(0.3, 0.1, t-end: 10, points: 11)
+
+- Effective k = #format-number(par.k_overall, precision: 2, scientific: false) s⁻¹ (expected 0.40 s⁻¹)
+- Branching ratios: B #format-number(par.branching_ratios.at(0), precision: 2, scientific: false), C #format-number(par.branching_ratios.at(1), precision: 2, scientific: false) (expected 0.75, 0.25)
+- Selectivity [B]/[C] = #format-number(par.selectivity, precision: 2, scientific: false) (expected 3.00)
+- Half-life of A = #format-number(par.half_life, precision: 3, scientific: false) s (expected 1.733 s)
+
+*At t = 10 s:* [A] = #format-number(par.rows.last().at(1), precision: 4, scientific: false), [B] = #format-number(par.rows.last().at(2), precision: 4, scientific: false), [C] = #format-number(par.rows.last().at(3), precision: 4, scientific: false) M
+
+---
+
+== Test 15: Reversible Reaction Relaxation A ⇌ B
+
+*Rate constants:* k_f = 0.3 s⁻¹, k_r = 0.1 s⁻¹, [A]₀ = 1.0 M, [B]₀ = 0
+
+#let rel = reversible-reaction-kinetics(0.3, 0.1, t-end: 25, points: 26)
+
+- τ = #format-number(rel.relaxation_time, precision: 2, scientific: false) s (expected 1/(0.3 + 0.1) = 2.50 s)
+- K = #format-number(rel.equilibrium_constant, precision: 2, scientific: false) (expected 3.00)
+- [A]eq = #format-number(rel.equilibrium_a, precision: 3, scientific: false) M, [B]eq = #format-number(rel.equilibrium_b, precision: 3, scientific: false) M (expected 0.250, 0.750)
+- [A] after 10τ = #format-number(rel.rows.last().at(1), precision: 4, scientific: false) M
+
+*Consistency with thermodynamics:* ΔG° = -RT ln K = #format-number(-8.314462618 * 298.15 * calc.ln(rel.equilibrium_constant) / 1000, precision: 2, scientific: false) kJ/mol at 298.15 K
+
+---
+
+== Test 16: Michaelis–Menten Enzyme Kinetics
+
+*Rate at [S] = Km:*
+
+#let v-half = michaelis-menten-rate(10, 2, 2)
+
+- v = #format-number(v-half.value, precision: 2, scientific: false) (expected Vmax/2 = 5.00)
+
+*Fit of noisy data (generated with Vmax = 10, Km = 2):*
+
+#let mm-data = ((0.5, 2.05), (1, 3.30), (2, 5.1), (4, 6.6), (8, 8.05), (16, 8.85))
+#let mm-fit = fit-michaelis-menten(mm-data, points: 50)
+
+#table(
+  columns: (auto, auto, auto),
+  [*Method*], [*Vmax*], [*Km*],
+  [Nonlinear fit],
+  [#format-number(mm-fit.vmax, precision: 2, scientific: false) ± #format-number(mm-fit.vmax_error, precision: 2, scientific: false)],
+  [#format-number(mm-fit.km, precision: 2, scientific: false) ± #format-number(mm-fit.km_error, precision: 2, scientific: false)],
+  [Lineweaver–Burk],
+  [#format-number(1 / mm-fit.lineweaver_burk_fit.intercept, precision: 2, scientific: false)],
+  [#format-number(mm-fit.lineweaver_burk_fit.slope / mm-fit.lineweaver_burk_fit.intercept, precision: 2, scientific: false)],
+  [Eadie–Hofstee],
+  [#format-number(mm-fit.eadie_hofstee_fit.intercept, precision: 2, scientific: false)],
+  [#format-number(-mm-fit.eadie_hofstee_fit.slope, precision: 2, scientific: false)],
+)
+
+*R² (nonlinear):* #format-number(mm-fit.r_squared, precision: 4, scientific: false)
+
+---
+
+== Test 17: Steady-State and Pre-Equilibrium Approximations
+
+*Mechanism:* A ⇌ I → P with k₁ = 10³, k₋₁ = 10⁵, k₂ = 10 s⁻¹
+
+#let k-fast-eq = two-step-rate-constant(1e3, 1e5, 10)
+
+- Steady state: k_obs = #format-number(k-fast-eq.steady_state, precision: 5, scientific: false) s⁻¹ (expected k₁k₂/(k₋₁ + k₂) = 0.09999)
+- Pre-equilibrium: k_obs = #format-number(k-fast-eq.pre_equilibrium, precision: 5, scientific: false) s⁻¹ (expected K₁k₂ = 0.1)
+- Deviation: #format-number(k-fast-eq.pre_equilibrium_error * 100, precision: 3, scientific: false) %
+- Rate-determining step: #k-fast-eq.rate_determining_step (expected 2)
+
+*Three-step mechanism:* k₁ = 1, k₋₁ = 2, k₂ = 3, k₋₂ = 4, k₃ = 5
+
+#let k-three = three-step-rate-constant(1, 2, 3, 4, 5)
+
+- Steady state: k_obs = #format-number(k-three.steady_state, precision: 4, scientific: false) (expected 15/33 = 0.4545)
+
+#table(
+  columns: (auto, auto, auto),
+  [*Step*], [*Limiting k*], [*Contribution*],
+  ..k-three.limits.map(l => (
+    str(l.step),
+    format-number(l.k_limit, precision: 3, scientific: false),
+    format-number(l.contribution, precision: 3, scientific: false),
+  )).flatten()
+)
+
+---
+
+== Test 18: Lindemann–Hinshelwood Fall-Off
+
+*Parameters:* k₁ = 10⁹ L/(mol·s), k₋₁ = 10¹⁰ L/(mol·s), k₂ = 10⁶ s⁻¹
+
+#let lh-high = lindemann-rate-constant(1e9, 1e10, 1e6, pressure: 10.0, temp: 500)
+#let lh-low = lindemann-rate-constant(1e9, 1e10, 1e6, conc: 1e-7)
+
+- k∞ = #format-number(lh-high.k_infinity, precision: 3, scientific: true) s⁻¹ (expected 1.000×10^5)
+- [M]½ = #format-number(lh-high.half_concentration, precision: 3, scientific: true) mol/L (expected k₂/k₋₁ = 1.000×10^-4)
+- At 10 bar, 500 K: k_uni = #format-number(lh-high.k_uni, precision: 3, scientific: true) s⁻¹ (close to k∞)
+- At [M] = 10⁻⁷ M: k_uni = #format-number(lh-low.k_uni, precision: 3, scientific: true) s⁻¹, k₀ = #format-number(lh-low.k_zero, precision: 3, scientific: true) s⁻¹ (low-pressure limit)
+- Fall-off curve: #lh-high.fall_off.len() points
+
+---
+
+== Test 19: Collision Theory Rate Constant
+
+*Reaction:* H₂ + O₂ collisions, d = 0.30 nm, Ea = 50 kJ/mol, T = 298.15 K
+
+#let k-coll = calc-rate-constant-collision((2.016, 32.0), 0.30, 50, temp: 298.15)
+#let k-coll-p = calc-rate-constant-collision((2.016, 32.0), 0.30, 50, temp: 298.15, steric-factor: 0.01)
+
+- Reduced mass μ = #format-number(k-coll.reduced_mass, precision: 3, scientific: false) g/mol (expected 1.897)
+- Mean relative speed = #format-number(k-coll.mean_relative_speed, precision: 0, scientific: false) m/s (expected ≈ 1824)
+- Collision-theory A = #format-number(k-coll.pre_exponential, precision: 3, scientific: true) L/(mol·s)
+- k (p = 1) = #format-result(k-coll, precision: 3, scientific: true)
+- k (p = 0.01) = #format-result(k-coll-p, precision: 3, scientific: true)
+
+*Comparison with Arrhenius:*
+
+#let k-arr = calc-rate-constant-arrhenius(k-coll.pre_exponential, 50, temp: 298.15)
+
+- Arrhenius k with the same A = #format-number(k-arr.value, precision: 3, scientific: true) ✓
+
+---
+
+== Test 20: Q10 Temperature Coefficient
+
+*Enzyme assay:* k = 0.010 s⁻¹ at 25 °C, k = 0.025 s⁻¹ at 35 °C
+
+#let q10 = calc-q10(0.010, 298.15, 0.025, 308.15)
+
+- Q10 = #format-number(q10.value, precision: 2, scientific: false) (expected 2.50)
+
+#let ea-q10 = calc-activation-energy-from-q10(q10.value, temp: 298.15)
+
+- Implied Ea = #format-result(ea-q10, precision: 1, scientific: false) (expected ≈ 70.0 kJ/mol)
+
+#let q10-back = calc-q10-from-activation-energy(ea-q10.value, temp: 298.15)
+
+- Q10 recovered from Ea = #format-number(q10-back.value, precision: 2, scientific: false) ✓
+
+#let k-37 = calc-rate-constant-q10(0.010, q10.value, 298.15, 310.15)
+
+- k at 37 °C = #format-number(k-37.value, precision: 4, scientific: false) s⁻¹ (expected 0.010 × 2.5^1.2 = 0.0300)
+
+---
+
+== Test 21: Rate Law from Initial Rates
+
+*Reaction:* 2NO + O₂ → 2NO₂
+
+#let experiments = (
+  (concentrations: (NO: 0.01, O2: 0.01), rate: 7.1e-3),
+  (concentrations: (NO: 0.02, O2: 0.01), rate: 2.84e-2),
+  (concentrations: (NO: 0.01, O2: 0.02), rate: 1.42e-2),
+  (concentrations: (NO: 0.03, O2: 0.02), rate: 1.278e-1),
+)
+
+#table(
+  columns: (auto, auto, auto),
+  [*[NO] (M)*], [*[O₂] (M)*], [*Rate (M/s)*],
+  ..experiments.map(e => (
+    str(e.concentrations.NO),
+    str(e.concentrations.O2),
+    format-number(e.rate, precision: 2, scientific: true),
+  )).flatten()
+)
+
+#let law = determine-rate-law(experiments)
+
+- Rate law: #law.rate_law (expected rate = k[NO]²[O2])
+- Orders: #law.orders.map(o => o.species + ": " + format-number(o.raw_order, precision: 3, scientific: false)).join(", ")
+- Overall order: #law.overall_order
+- k = #format-result(law.rate_constant, precision: 0, scientific: false) (expected 7100 M⁻²·s⁻¹)
+
+---
+
+== Test 22: Reaction Order from Concentration–Time Data
+
+*Data:* decomposition following second-order kinetics (k = 0.1 M⁻¹s⁻¹, [A]₀ = 1.0 M)
+
+#let order-data = ((0, 1.0), (10, 0.5), (20, 0.3333), (30, 0.25), (40, 0.2))
+#let order-fit = fit-reaction-order(order-data)
+
+#table(
+  columns: (auto, auto, auto),
+  [*Order*], [*k*], [*R²*],
+  ..order-fit.candidates.map(c => (
+    str(c.order),
+    format-result(c.rate_constant, precision: 4, scientific: false),
+    format-number(c.r_squared, precision: 5, scientific: false),
+  )).flatten()
+)
+
+*Best order:* #order-fit.best_order (expected 2, k ≈ 0.1000 M⁻¹·s⁻¹)
+
+---
+
+== Test 23: Guggenheim and Kezdy–Swinbourne Methods
+
+*Data:* absorbance A(t) = 0.8 - 0.6·e^(-0.05t), infinity reading not recorded
+
+#let abs-data = range(13).map(i => {
+  let t = i * 10
+  (t, 0.8 - 0.6 * calc.exp(-0.05 * t))
+})
+
+#let no-inf = first-order-without-infinity(abs-data, delta: 40)
+
+- Guggenheim: k = #format-result(no-inf.guggenheim.rate_constant, precision: 4, scientific: false), R² = #format-number(no-inf.guggenheim.r_squared, precision: 5, scientific: false)
+- Kezdy–Swinbourne: k = #format-result(no-inf.kezdy_swinbourne.rate_constant, precision: 4, scientific: false), A∞ = #format-number(no-inf.kezdy_swinbourne.infinity_value, precision: 4, scientific: false)
+
+*Expected:* k = 0.0500 s⁻¹, A∞ = 0.8000
+
+---
+
+== Test 24: Numerical Rate Extraction
+
+*Data:* [A] = e^(-0.1t) M sampled every 2 s (exact dc/dt: -0.1 at t = 0, -0.0368 at t = 10 s)
+
+#let decay-data = range(11).map(i => (i * 2, calc.exp(-0.1 * i * 2)))
+
+#let poly-rates = extract-rates(decay-data, times: (10,), method: "polynomial", window: 5, degree: 3)
+#let fd-rates = extract-rates(decay-data, times: (10,), method: "finite-difference")
+
+#table(
+  columns: (auto, auto, auto),
+  [*Method*], [*Initial rate (M/s)*], [*Rate at 10 s (M/s)*],
+  [Polynomial (cubic, 5 points)],
+  format-number(poly-rates.initial_rate, precision: 4, scientific: false),
+  format-number(poly-rates.rates.at(0).rate, precision: 4, scientific: false),
+  [Finite difference],
+  format-number(fd-rates.initial_rate, precision: 4, scientific: false),
+  format-number(fd-rates.rates.at(0).rate, precision: 4, scientific: false),
+)
+
+---
+
+== Test 25: Catalysis Metrics
+
+*Hydrogenation:* 0.50 mol product with 0.10 mmol catalyst in 1 h
+
+#let turnover = calc-turnover(0.50, 1e-4, time: 3600)
+
+- TON = #format-number(turnover.turnover_number, precision: 0, scientific: false) (expected 5000)
+- TOF = #format-result(turnover.turnover_frequency, precision: 3, scientific: false) (expected 1.389 s⁻¹)
+
+*Catalysed H₂O₂ decomposition:* Ea = 75 kJ/mol uncatalysed, 50 kJ/mol with catalyst, 298.15 K
+
+#let cat-cmp = compare-catalyzed-rate(75, 50, temp: 298.15)
+
+- Ea lowered by #format-number(cat-cmp.activation_energy_lowering, precision: 1, scientific: false) kJ/mol
+- Rate enhancement = #format-number(cat-cmp.rate_enhancement, precision: 3, scientific: true) (expected exp(25000/(R × 298.15)) ≈ 2.40×10^4)
+
+---
+
+== Test 26: Kinetic Isotope Effect
+
+*C–H bond:* ν(C–H) = 2900 cm⁻¹
+
+#let kie-298 = calc-kinetic-isotope-effect(2900, temp: 298.15)
+#let kie-400 = calc-kinetic-isotope-effect(2900, temp: 400)
+
+- ν(C–D) = #format-number(kie-298.deuterium_frequency, precision: 0, scientific: false) cm⁻¹ (expected ≈ 2129 cm⁻¹)
+- ΔZPE = #format-number(kie-298.zero_point_energy_difference, precision: 2, scientific: false) kJ/mol
+- kH/kD at 298.15 K = #format-number(kie-298.value, precision: 2, scientific: false) (expected ≈ 6.4)
+- kH/kD at 400 K = #format-number(kie-400.value, precision: 2, scientific: false) (smaller at higher temperature)
+
+---
+
+== Test 27: Radioactive Decay and Dating
+
+*Iodine-131:* t₁/₂ = 8.02 days, N₀ = 1.0 × 10²⁰ nuclei, after 30 days
+
+#let i131 = calc-radioactive-decay(1e20, 8.02, 30, time-unit: "d")
+
+- Remaining fraction = #format-number(i131.fraction_remaining, precision: 4, scientific: false) (expected 2^(-30/8.02) = 0.0747)
+- Decay constant = #format-result(i131.decay_constant, precision: 3, scientific: true)
+- Initial activity = #format-result(i131.initial_activity, precision: 3, scientific: true) (expected 1.000×10^14 Bq)
+- Activity after 30 days = #format-result(i131.activity, precision: 3, scientific: true) = #format-number(i131.activity_ci, precision: 1, scientific: false) Ci
+
+*Radiocarbon dating:* sample retains 25 % of the original ¹⁴C (t₁/₂ = 5730 years)
+
+#let c14-age = calc-radioactive-age(0.25, 5730, time-unit: "yr")
+
+- Age = #format-result(c14-age, precision: 0, scientific: false) (expected 11460 years)
+
+---
+
+== Test 28: Bateman Decay Chain
+
+*⁹⁹Mo → ⁹⁹ᵐTc → ⁹⁹Tc generator:* t₁/₂ = 66.0 h and 6.01 h, 10²⁰ ⁹⁹Mo nuclei, 48 h
+
+#let chain = decay-chain((66.0, 6.01, none), names: ("Mo-99", "Tc-99m", "Tc-99"), t-end: 48, points: 5, time-unit: "h")
+#let final = chain.rows.last()
+
+- Equilibrium: #chain.equilibria.first().equilibrium (expected transient)
+- Limiting activity ratio A(Tc)/A(Mo) = #format-number(chain.equilibria.first().activity_ratio, precision: 3, scientific: false) (expected 66.0/(66.0 − 6.01) = 1.100)
+- Activity ratio after 48 h = #format-number(final.at(2) / final.at(1), precision: 3, scientific: false) (approaching the limit)
+
+*²²⁶Ra → ²²²Rn:* t₁/₂ = 1600 years and 3.82 days
+
+#let radium = decay-chain((1600 * 365.25, 3.82), names: ("Ra-226", "Rn-222"), points: 3, time-unit: "d")
+
+- Equilibrium: #radium.equilibria.first().equilibrium (expected secular)
+- Activity ratio after 10 radon half-lives = #format-number(radium.rows.last().at(2) / radium.rows.last().at(1), precision: 3, scientific: false) (expected ≈ 1.000)
+
+---
+
+== Test 29: Time Units
+
+*Drug elimination:* k = 0.1155 h⁻¹
+
+#let t-half-h = calc-half-life(0.1155, order: 1, time-unit: "h")
+#let k-eyring-s = calc-rate-constant-eyring(60, -50, temp: 298.15)
+#let k-eyring-h = calc-rate-constant-eyring(60, -50, temp: 298.15, time-unit: "h")
+#let course-min = concentration-time-curve(0.05, t-end: 60, points: 4, time-unit: "min")
+
+- Half-life = #format-result(t-half-h, precision: 2, scientific: false) (expected 6.00 h)
+- Eyring k = #format-result(k-eyring-s, precision: 3, scientific: true) = #format-result(k-eyring-h, precision: 3, scientific: true) (expected 0.468 s⁻¹ = 1.68×10³ h⁻¹, ratio #format-number(k-eyring-h.value / k-eyring-s.value, precision: 0, scientific: false), expected 3600)
+- Concentration curve time unit: #course-min.time_unit (expected min)
+
+---
+
+== Test 30: Molecular Speeds
+
+#let n2-speeds = calc-molecular-speeds(28.0, temp: 300)
+#let h2-speeds = calc-molecular-speeds(2.016, unit: "km/h")
+
+#table(
+  columns: (auto, auto, auto, auto),
+  [*Gas*], [*v#sub[rms]*], [*v#sub[mean]*], [*v#sub[mp]*],
+  ..((("N₂, 300 K", n2-speeds), ("H₂, 298.15 K", h2-speeds)).map(((gas, v)) => (
+    gas,
+    [#calc.round(v.rms.value, digits: 1) #v.rms.unit],
+    [#calc.round(v.mean.value, digits: 1) #v.mean.unit],
+    [#calc.round(v.most_probable.value, digits: 1) #v.most_probable.unit],
+  )).flatten())
+)
+
+*Expected:* N₂ 517.0, 476.3, 422.1 m/s; H₂ 6914, 6370, 5646 km/h (ratio v#sub[rms] : v#sub[mean] : v#sub[mp] = 1.225 : 1.128 : 1)
+
+---
+
+== Test 31: Mean Free Path and Collision Frequency
+
+#let n2-coll = calc-collision-properties(28.0, 0.37, 1, pressure-unit: "atm")
+#let h2-o2 = calc-collision-properties((2.016, 32.0), (0.27, 0.36), (0.5, 0.5), pressure-unit: "atm")
+#let h2-o2-k = calc-rate-constant-collision((2.016, 32.0), 0.315, 0)
+
+*N₂ at 1 atm, 298.15 K (d = 0.37 nm):* λ = #format-number(n2-coll.species.at(0).mean_free_path.value, precision: 3) m (expected ≈ 6.68e-8), z = #format-number(n2-coll.species.at(0).collision_frequency.value, precision: 3) s⁻¹ (expected ≈ 7.11e9), Z#sub[AA] = #format-number(n2-coll.collision_density.value, precision: 3) m⁻³·s⁻¹ (expected ≈ 8.75e34)
+
+*H₂/O₂ 1:1 at 1 atm:* Z#sub[AB] = #format-number(h2-o2.collision_density.value, precision: 3) m⁻³·s⁻¹, λ(H₂) = #format-number(h2-o2.species.at(0).mean_free_path.value, precision: 3) m, λ(O₂) = #format-number(h2-o2.species.at(1).mean_free_path.value, precision: 3) m
+
+*Collision-theory A factor:* #format-number(h2-o2.pre_exponential, precision: 4) L/(mol·s), same as `calc-rate-constant-collision` with d = 0.315 nm: #format-number(h2-o2-k.pre_exponential, precision: 4)
+
+---
+
+== Test 32: Graham's Law of Effusion
+
+#let h2-o2-rate = calc-effusion("H2", "O2")
+#let unknown-gas = calc-effusion(none, "O2", rate-ratio: 0.5)
+#let uf6 = calc-effusion(349.03, 352.04, initial-fraction: 0.0072, target-fraction: 0.03)
+
+*H₂ vs O₂:* r#sub[H₂]/r#sub[O₂] = #calc.round(h2-o2-rate.rate_ratio, digits: 3) (expected 3.984), t#sub[H₂]/t#sub[O₂] = #calc.round(h2-o2-rate.time_ratio, digits: 4) (expected 0.2510)
+
+*Unknown gas effusing at half the rate of O₂:* M = #calc.round(unknown-gas.molar_masses.at(0), digits: 2) g/mol (expected 127.99)
+
+*²³⁵UF₆/²³⁸UF₆ enrichment:* α = #calc.round(uf6.separation_factor, digits: 5) (expected 1.00430), one stage: #calc.round(uf6.enrichment.single_stage_fraction * 100, digits: 4) % ²³⁵U, 0.72 % → 3 %: #calc.round(uf6.enrichment.stages, digits: 0) stages (expected 338)
+
+---
+
+== Test 33: Nuclear Binding Energy and Q-Values
+
+#let nuclides = ("H-2", "He-4", "C-12", "Fe-56", "Ni-62", "U-238").map(n => calc-binding-energy(n))
+#let u236 = calc-binding-energy((z: 92, a: 236, mass: 236.0455682))
+
+#table(
+  columns: 4,
+  [*Nuclide*], [*Δm (u)*], [*BE (MeV)*], [*BE/A (MeV)*],
+  ..nuclides.map(r => (
+    r.nuclide,
+    str(calc.round(r.mass_defect, digits: 6)),
+    str(calc.round(r.value, digits: 3)),
+    str(calc.round(r.per_nucleon.value, digits: 3)),
+  )).flatten()
+)
+
+Expected BE/A = 1.112, 7.074, 7.680, 8.790, 8.795, 7.570 MeV; ²³⁶U (custom mass): #calc.round(u236.per_nucleon.value, digits: 3) MeV (expected 7.586)
+
+#let fission = calc-nuclear-q-value(("U-235", "n"), ("Ba-141", "Kr-92", ("n", 3)))
+#let fusion = calc-nuclear-q-value(("d", "t"), ("alpha", "n"))
+#let rutherford = calc-nuclear-q-value(("alpha", "N-14"), ("p", "O-17"))
+#let alpha-decay = calc-nuclear-q-value(("U-238",), ("Th-234", "alpha"))
+#let beta-minus = calc-nuclear-q-value(("C-14",), ("N-14", "e-", "nu"))
+#let beta-plus = calc-nuclear-q-value(("C-11",), ("B-11", "e+", "nu"))
+
+*²³⁵U fission (→ ¹⁴¹Ba + ⁹²Kr + 3n):* Q = #calc.round(fission.value, digits: 1) MeV (expected 173.3), #format-number(fission.molar.value, precision: 3) kJ/mol
+
+*D–T fusion:* Q = #calc.round(fusion.value, digits: 2) MeV (expected 17.59)
+
+*¹⁴N(α, p)¹⁷O:* Q = #calc.round(rutherford.value, digits: 3) MeV (expected -1.192, #rutherford.kind), threshold #calc.round(rutherford.threshold_energy, digits: 3) MeV (expected 1.533)
+
+*Decays:* ²³⁸U α #calc.round(alpha-decay.value, digits: 3) MeV (expected 4.270), ¹⁴C β⁻ #calc.round(beta-minus.value, digits: 4) MeV (expected 0.1565), ¹¹C β⁺ #calc.round(beta-plus.value, digits: 3) MeV (expected 0.960)
+
+---
+
 == Summary
 
 All reaction kinetics functions have been tested:
@@ -204,4 +739,54 @@ All reaction kinetics functions have been tested:
 
 ✅ *Practical applications* - Drug degradation example demonstrates utility
 
+✅ *Energy profiles* - Multi-step reaction-coordinate diagram data generated
+
+✅ *Maxwell–Boltzmann curves* - Fraction above the activation energy matches the analytic result
+
+✅ *Concentration–time curves* - Integrated rate laws sampled in a single call
+
+✅ *Mechanism simulation* - Stiff Robertson problem reproduces reference values
+
+✅ *Consecutive reactions* - Intermediate maximum matches the closed-form result
+
+✅ *Parallel reactions* - Branching ratios and effective rate constant verified
+
+✅ *Reversible relaxation* - Relaxation time and equilibrium composition verified
+
+✅ *Michaelis–Menten* - Nonlinear fit and linearised plots recover Vmax and Km
+
+✅ *Mechanism approximations* - Steady-state and pre-equilibrium rate constants verified
+
+✅ *Lindemann–Hinshelwood* - High- and low-pressure limits and fall-off curve verified
+
+✅ *Collision theory* - Pre-exponential factor consistent with the Arrhenius form
+
+✅ *Q10 coefficient* - Q10 ↔ Ea round trip and rate scaling verified
+
+✅ *Rate-law determination* - Orders and rate constant recovered from initial rates
+
+✅ *Order fitting* - Integrated rate laws compared by R²
+
+✅ *Guggenheim / Kezdy–Swinbourne* - Rate constant and infinity value recovered without an infinity reading
+
+✅ *Rate extraction* - Initial and instantaneous rates from numerical differentiation
+
+✅ *Catalysis metrics* - TON, TOF and rate enhancement verified
+
+✅ *Kinetic isotope effect* - Zero-point-energy estimate of kH/kD
+
+✅ *Radioactive decay* - Activity and radiocarbon age verified
+
+✅ *Bateman decay chain* - Transient and secular equilibria verified
+
+✅ *Time units* - Half-life, Eyring and time courses in h/min verified
+
+✅ *Molecular speeds* - rms, mean and most probable speeds with unit options verified
+
+✅ *Collision frequencies* - Mean free path, z, Z#sub[AA] and Z#sub[AB] verified
+
+✅ *Graham's law* - Effusion rate and time ratios, unknown molar mass and isotope enrichment verified
+
+✅ *Nuclear energetics* - Binding energies per nucleon and fission, fusion and decay Q-values verified
+
 *The reaction kinetics module is fully functional and validated!*
@@ -29,9 +29,9 @@ Reaction: H₂(g) + ½O₂(g) → H₂O(l)
 
 *Manual verification:*
 - ΔG = ΔH - T·ΔS = #h2o-test.enthalpy.value - #h2o-test.temperature × (#h2o-test.entropy.value / 1000.0) = #(h2o-test.enthalpy.value - h2o-test.temperature * (h2o-test.entropy.value / 1000.0)) kJ/mol ✓
-- K = exp(-ΔG/(RT)) where R = 8.314 J/(mol·K)
-- K = exp(-#h2o-test.gibbs.value × 1000 / (8.314 × #h2o-test.temperature))
-- K = #calc.exp(-h2o-test.gibbs.value * 1000.0 / (8.314 * h2o-test.temperature)) ✓
+- K = exp(-ΔG/(RT)) where R = 8.314462618 J/(mol·K)
+- K = exp(-#h2o-test.gibbs.value × 1000 / (8.314462618 × #h2o-test.temperature))
+- K = #calc.exp(-h2o-test.gibbs.value * 1000.0 / (8.314462618 * h2o-test.temperature)) ✓
 
 ---
 
@@ -52,8 +52,8 @@ Reaction: CH₄(g) + 2O₂(g) → CO₂(g) + 2H₂O(l)
 
 *Manual verification:*
 - ΔG = ΔH - T·ΔS = #ch4-test.enthalpy.value - #ch4-test.temperature × (#ch4-test.entropy.value / 1000.0) = #(ch4-test.enthalpy.value - ch4-test.temperature * (ch4-test.entropy.value / 1000.0)) kJ/mol ✓
-- K = exp(-#ch4-test.gibbs.value × 1000 / (8.314 × #ch4-test.temperature))
-- K = #calc.exp(-ch4-test.gibbs.value * 1000.0 / (8.314 * ch4-test.temperature)) ✓
+- K = exp(-#ch4-test.gibbs.value × 1000 / (8.314462618 × #ch4-test.temperature))
+- K = #calc.exp(-ch4-test.gibbs.value * 1000.0 / (8.314462618 * ch4-test.temperature)) ✓
 
 ---
 
@@ -74,8 +74,8 @@ Reaction: N₂(g) + 3H₂(g) → 2NH₃(g)
 
 *Manual verification:*
 - ΔG = ΔH - T·ΔS = #nh3-high-test.enthalpy.value - #nh3-high-test.temperature × (#nh3-high-test.entropy.value / 1000.0) = #(nh3-high-test.enthalpy.value - nh3-high-test.temperature * (nh3-high-test.entropy.value / 1000.0)) kJ/mol ✓
-- K = exp(-#nh3-high-test.gibbs.value × 1000 / (8.314 × #nh3-high-test.temperature))
-- K = #calc.exp(-nh3-high-test.gibbs.value * 1000.0 / (8.314 * nh3-high-test.temperature)) ✓
+- K = exp(-#nh3-high-test.gibbs.value × 1000 / (8.314462618 × #nh3-high-test.temperature))
+- K = #calc.exp(-nh3-high-test.gibbs.value * 1000.0 / (8.314462618 * nh3-high-test.temperature)) ✓
 
 Note: At higher temperatures, ΔG becomes less negative (or more positive) because the entropy term (-T·ΔS) becomes larger.
 
@@ -148,9 +148,145 @@ Let's test the individual functions directly:
 - Difference: #(delta-g.value - (delta-h.value - 298.15 * (delta-s.value / 1000.0))) (should be ~0)
 
 - K = exp(-ΔG/(RT))
-- #k-eq.value = exp(-#delta-g.value × 1000 / (8.314 × 298.15))
-- #k-eq.value = #calc.exp(-delta-g.value * 1000.0 / (8.314 * 298.15))
-- Ratio: #(k-eq.value / calc.exp(-delta-g.value * 1000.0 / (8.314 * 298.15))) (should be ~1)
+- #k-eq.value = exp(-#delta-g.value × 1000 / (8.314462618 × 298.15))
+- #k-eq.value = #calc.exp(-delta-g.value * 1000.0 / (8.314462618 * 298.15))
+- Ratio: #(k-eq.value / calc.exp(-delta-g.value * 1000.0 / (8.314462618 * 298.15))) (should be ~1)
+
+---
+
+== Test 6: Aqueous Ions
+
+#let neutralization = analyze-reaction((("H+(aq)", 1), ("OH-(aq)", 1)), (("H2O(l)", 1),))
+#let dissolution = calc-reaction-enthalpy((("NaCl(s)", 1),), (("Na+(aq)", 1), ("Cl-(aq)", 1)))
+#let calcite = calc-reaction-entropy((("CaCO3(s)", 1),), (("Ca2+(aq)", 1), ("CO3^2-(aq)", 1)))
+
+*H⁺(aq) + OH⁻(aq) → H₂O(l):* ΔH = #calc.round(neutralization.enthalpy.value, digits: 2) kJ/mol (expected -55.84), ΔS = #calc.round(neutralization.entropy.value, digits: 2) J/(mol·K) (expected 80.66), K = #format-number(neutralization.equilibrium-constant.value, precision: 2) (expected ≈ 1/Kw = 1.0 × 10¹⁴)
+
+*NaCl(s) → Na⁺(aq) + Cl⁻(aq):* ΔH#sub[soln] = #calc.round(dissolution.value, digits: 2) kJ/mol (expected 3.84)
+
+*CaCO₃(s) → Ca²⁺(aq) + CO₃²⁻(aq):* ΔS = #calc.round(calcite.value, digits: 1) J/(mol·K) (expected -202.9; S°(Ca²⁺, aq) = #get-substance-data("Ca2+(aq)").S J/(mol·K) is negative)
+
+Unbalanced charges, e.g. `calc-reaction-enthalpy((("Cu2+(aq)", 1),), (("Cu+(aq)", 1),))`, are rejected with "Reaction is not charge balanced".
+
+---
+
+== Test 7: Formula Normalisation
+
+#let water = get-substance-data("H₂O (liquid)")
+#let methane = calc-reaction-enthalpy((("CH₄(g)", 1), ("O₂ (gas)", 2)), (("CO₂(g)", 1), ("H₂O(l)", 2)))
+
+*Unicode subscripts and spelled-out states:* ΔHf(H₂O (liquid)) = #water.delta_Hf kJ/mol (expected -285.83); CH₄ combustion ΔH = #calc.round(methane.value, digits: 2) kJ/mol (expected -890.29)
+
+Unknown formulas are rejected with suggestions from the table, e.g. `get-substance-data("H2O(aq)")` fails with "No data found for substance: H2O(aq). Did you mean H2O(g), H2O(l), H+(aq)?" and a reactant `"C02(g)"` (zero instead of O) with "Did you mean CO2(g), …".
+
+---
+
+== Test 8: Temperature Ranges
+
+#let ranged = thermo-data + (
+  "H2O(l)": thermo-data.at("H2O(l)") + (T_range: (273.15, 373.15)),
+  "H2(g)": thermo-data.at("H2(g)") + (T_range: (298.15, 3000)),
+)
+#let hot = analyze-reaction((("H2(g)", 1), ("O2(g)", 0.5)), (("H2O(l)", 1),), temp: 500, data: ranged)
+#let warm = analyze-reaction((("H2(g)", 1), ("O2(g)", 0.5)), (("H2O(l)", 1),), temp: 350, data: ranged)
+
+*Outside the range (500 K):* #hot.warnings.len() warning (expected 1, for H2O(l) only); ΔG carries it: #hot.gibbs.warnings.first().message
+
+*Inside the range (350 K):* #warm.warnings.len() warnings (expected 0); `gibbs` has no `warnings` key: #("warnings" not in warm.gibbs)
+
+*Declared range is returned with the entry:* #get-substance-data("H2O(l)", data: ranged).T_range
+
+#display-analysis(hot)
+
+---
+
+== Test 9: Standard-State Conventions
+Reaction: N₂(g) + 3H₂(g) → 2NH₃(g), Δn(gas) = -2
+
+#let haber-bar = analyze-reaction((("N2(g)", 1), ("H2(g)", 3)), (("NH3(g)", 2),))
+#let haber-legacy = analyze-reaction((("N2(g)", 1), ("H2(g)", 3)), (("NH3(g)", 2),), standard: "legacy")
+#let haber-gas = calc-delta-n-gas((("N2(g)", 1), ("H2(g)", 3)), (("NH3(g)", 2),), standard: "atm")
+
+*IUPAC (1 bar, 298.15 K):* ΔS° = #calc.round(haber-bar.entropy.value, digits: 2) J/(mol·K), ΔG° = #calc.round(haber-bar.gibbs.value, digits: 2) kJ/mol, K = #format-number(haber-bar.equilibrium-constant.value, precision: 3, scientific: true) (expected -198.11, -33.15, 6.430 × 10⁵)
+
+*Older textbooks (1 atm, 298 K):* T = #haber-legacy.temperature K, ΔS° = #calc.round(haber-legacy.entropy.value, digits: 2) J/(mol·K), ΔG° = #calc.round(haber-legacy.gibbs.value, digits: 2) kJ/mol, K = #format-number(haber-legacy.equilibrium-constant.value, precision: 3, scientific: true) (expected -197.89, -33.25, 6.727 × 10⁵, pressures in #haber-legacy.equilibrium-constant.standard_pressure)
+
+*Kp/Kc with P° = 1 atm:* #format-number(haber-gas.kp_over_kc, precision: 4, scientific: true) (expected 1.6707 × 10⁻³), ΔS° shift #calc.round(haber-gas.entropy_shift.value, digits: 4) J/(mol·K) (expected 0.2189)
+
+*Reference temperature only:* ΔG = ΔH − TΔS at 298 K: #calc.round(calc-gibbs-energy(-92.22, -198.11, standard: (temperature: 298)).value, digits: 2) kJ/mol (expected -33.18)
+
+---
+
+== Test 10: Extreme Equilibrium Constants
+
+#let huge = calc-equilibrium-constant(-2000)
+#let tiny = calc-equilibrium-constant(2000)
+#let weak = calc-equilibrium-constant(57.1)
+
+*ΔG° = −2000 kJ/mol:* value #repr(huge.value), #huge.out_of_range (expected none, overflow); log₁₀ K = #calc.round(huge.log10_k, digits: 2) (expected 350.38), K = #format-equilibrium-constant(huge) (expected 2.43×10^350)
+
+*ΔG° = +2000 kJ/mol:* #tiny.out_of_range, pK = #format-equilibrium-constant(tiny, style: "pk") (expected underflow, 350.38)
+
+*ΔG° = +57.1 kJ/mol:* K = #format-equilibrium-constant(weak), ln K = #format-equilibrium-constant(weak, style: "ln"), pK = #format-equilibrium-constant(weak, style: "pk") (expected 9.92×10^-11, -23.03, 10.00)
+
+#display-analysis(analyze-reaction((("H2(g)", 1), ("O2(g)", 0.5)), (("H2O(l)", 1),)), k-style: "log")
+
+---
+
+== Test 11: Non-Finite Results
+
+#let overflow = gibbs-extent-curve(-2000, (("A", 1),), (("B", 1),), mixture: "ideal-solution", points: 3)
+#let regular = gibbs-extent-curve(-5, (("A", 1),), (("B", 1),), mixture: "ideal-solution", points: 3)
+
+*K overflows for ΔG° = −2000 kJ/mol:* finite: #repr(overflow.at("finite", default: true)), non-finite: #overflow.non_finite.join(", "), K = #format-number(overflow.k) (expected false, k, undefined)
+
+*Curve still usable:* ξ#sub[eq] = #calc.round(overflow.minimum.extent, digits: 4) (expected 1)
+
+*Finite result is not flagged:* #repr(regular.at("finite", default: true)) (expected true)
+
+---
+
+== Test 12: High-Precision Mode
+
+#let tiny-data = (
+  "A": (delta_Hf: 0.1, S: 0, delta_Gf: 0),
+  "B": (delta_Hf: 0.2, S: 0, delta_Gf: 0),
+  "C": (delta_Hf: 0.3, S: 0, delta_Gf: 0),
+)
+#let cancel = calc-reaction-enthalpy((("A", 1), ("B", 1)), (("C", 1),), data: tiny-data, high-precision: true)
+#let g-precise = calc-gibbs-energy(-285.83, -163.34, high-precision: true)
+#let k-precise = calc-equilibrium-constant(-2000, high-precision: true)
+
+*0.1 + 0.2 − 0.3 cancels exactly:* #cancel.digits (expected 0)
+
+*ΔG° of H₂O(l):* #g-precise.digits kJ/mol (expected -237.130179)
+
+*K beyond floating point:* #format-equilibrium-constant(k-precise, precision: 6) (expected 2.425720×10^350)
+
+*log₁₀ K:* #k-precise.precise.log10_k.slice(0, 16) (expected 350.384840654466)
+
+*Digits passed on:* #calc-equilibrium-constant(g-precise, high-precision: true).precise.exponent (expected 41)
+
+---
+
+== Test 13: Input Validation
+
+#let below-zero = check-input(-5, "temperature", field: "T1")
+#let fraction = check-input(1.2, "fraction", field: "initial fraction")
+
+*Temperature below 0 K:* #below-zero.message (expected Invalid T1: -5 (must be above 0 K))
+
+*Error fields:* #below-zero.field, #below-zero.constraint, #below-zero.value (expected T1, above 0 K, -5.0)
+
+*Fraction above 1:* #fraction.constraint (expected between 0 and 1)
+
+*Zero concentration:* #check-input(0, "positive", field: "anode concentration").message (expected Invalid anode concentration: 0 (must be positive))
+
+*Negative amount:* #check-input(-0.1, "non-negative", field: "amount").message (expected Invalid amount: -0.1 (must be non-negative))
+
+*Valid inputs pass:* #repr(check-input(298.15, "temperature")), #repr(check-input(0, "non-negative")) (expected none, none)
+
+The calculations stop with the same error as JSON. `calc-q10(0.010, 0, 0.025, 10)` is rejected with `{"field":"T1","constraint":"above 0 K","value":0.0,"message":"Invalid T1: 0 (must be above 0 K)"}`, `calc-q10(0.010, 300, 0.025, 300)` with "Invalid T2: 300 (must be different from T1)", `calc-rate-constant-q10(0.010, 2.0, -5, 310)` with "Invalid temperature: -5 (must be above 0 K)", `calc-concentration-cell(1.0, 0, 2)` with "Invalid anode concentration: 0 (must be positive)", `calc-activity-coefficient("Na+", 0.1, size: 0)` with "Invalid ion size: 0 (must be positive)" and `calc-activity-corrected-k(1.8e-5, (("CH3COOH", 1),), (("H+", 1), ("CH3COO-", 1)), -0.1)` with "Invalid ionic strength: -0.1 (must be non-negative)".
 
 ---
 
@@ -172,7 +308,7 @@ K = exp(-ΔG / (R·T))
 ```
 where:
 - ΔG is in kJ/mol, so we multiply by 1000 to convert to J/mol
-- R = 8.314 J/(mol·K)
+- R = 8.314462618 J/(mol·K)
 - T is in K
 - K is dimensionless
 
@@ -5,6 +5,29 @@ use std::collections::HashMap;
 // Initialize the protocol
 initiate_protocol!();
 
+// ============================================================================
+// PHYSICAL CONSTANTS (CODATA 2018)
+// ============================================================================
+
+/// Avogadro constant (mol⁻¹)
+const AVOGADRO: f64 = 6.022_140_76e23;
+/// Boltzmann constant (J/K)
+const BOLTZMANN: f64 = 1.380_649e-23;
+/// Planck constant (J·s)
+const PLANCK: f64 = 6.626_070_15e-34;
+/// Speed of light in vacuum (m/s)
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+/// Elementary charge (C), the joules in one electronvolt
+const ELECTRON_VOLT: f64 = 1.602_176_634e-19;
+/// Molar gas constant R = NA·k (J/(mol·K))
+const R: f64 = AVOGADRO * BOLTZMANN;
+/// Gas constant in L·bar/(mol·K)
+const R_LITRE_BAR: f64 = R / 100.0;
+/// Faraday constant F = NA·e (C/mol)
+const F: f64 = AVOGADRO * ELECTRON_VOLT;
+/// Second radiation constant hc/k (cm·K), converting wavenumbers to temperatures
+const HC_OVER_K: f64 = PLANCK * SPEED_OF_LIGHT * 100.0 / BOLTZMANN;
+
 /// Format a float number in scientific notation
 fn format_scientific(value: f64, precision: usize) -> String {
     if value == 0.0 {
@@ -16,7 +39,7 @@ fn format_scientific(value: f64, precision: usize) -> String {
     let mantissa = value / 10_f64.powi(exponent);
     
     // For numbers close to 1, use regular notation
-    if exponent.abs() < 3 && abs_value >= 0.001 && abs_value < 1000.0 {
+    if exponent.abs() < 3 && (0.001..1000.0).contains(&abs_value) {
         format!("{:.prec$}", value, prec = precision)
     } else {
         format!("{:.prec$}×10^{}", mantissa, exponent, prec = precision)
@@ -32,6 +55,39 @@ struct ThermodynamicData {
     s: f64,         // Standard entropy (J/(mol·K))
     #[serde(rename = "delta_Gf")]
     delta_gf: f64,  // Standard Gibbs free energy of formation (kJ/mol)
+    #[serde(rename = "T_range", default, skip_serializing_if = "Option::is_none")]
+    t_range: Option<[f64; 2]>,  // Temperatures (K) for which the entry may be used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<String>,  // Reference the values were taken from
+}
+
+/// Warning attached to a result computed from data outside its stated temperature range
+#[derive(Serialize, Deserialize, Debug)]
+struct DataWarning {
+    species: String,
+    temperature: f64,
+    /// Valid range of the entry (K)
+    range: [f64; 2],
+    message: String,
+}
+
+impl DataWarning {
+    /// Warning if `temperature` lies outside `range`
+    fn temperature(species: &str, range: Option<[f64; 2]>, temperature: f64) -> Option<Self> {
+        let [min, max] = range?;
+        if (min..=max).contains(&temperature) {
+            return None;
+        }
+        Some(Self {
+            species: species.to_string(),
+            temperature,
+            range: [min, max],
+            message: format!(
+                "{} K is outside the range {}–{} K of the data for {}; values are extrapolated",
+                temperature, min, max, species
+            ),
+        })
+    }
 }
 
 /// Result structure for calculations
@@ -41,6 +97,12 @@ struct CalculationResult {
     unit: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     formatted: Option<String>,
+    /// The value to about 27 significant digits, in high-precision mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    digits: Option<String>,
+    /// Sources of the data entries used, in alphabetical order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<String>,
 }
 
 impl CalculationResult {
@@ -49,17 +111,912 @@ impl CalculationResult {
             value,
             unit: unit.to_string(),
             formatted: None,
+            digits: None,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Result of a high-precision calculation, with `value` rounded to f64
+    fn precise(value: DoubleDouble, unit: &str) -> Self {
+        let mut result = Self::new(value.to_f64(), unit);
+        result.digits = Some(value.to_decimal_string());
+        result
+    }
+
+    /// Record the sources of the data entries used, dropping duplicates
+    fn with_sources<'a>(mut self, sources: impl IntoIterator<Item = &'a Option<String>>) -> Self {
+        self.sources = sources.into_iter().flatten().cloned().collect();
+        self.sources.sort();
+        self.sources.dedup();
+        self
+    }
+}
+
+/// Parse a plain-text argument (number, integer or boolean) sent by Typst
+fn parse_arg<T>(bytes: &[u8], name: &str) -> Result<T, String>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    std::str::from_utf8(bytes)
+        .map_err(|e| format!("Invalid UTF-8 in {}: {}", name, e))?
+        .trim()
+        .parse()
+        .map_err(|e| format!("Failed to parse {}: {}", name, e))
+}
+
+/// Parse a JSON-encoded argument sent by Typst
+fn parse_json<T: serde::de::DeserializeOwned>(bytes: &[u8], name: &str) -> Result<T, String> {
+    serde_json::from_slice(bytes).map_err(|e| format!("Failed to parse {}: {}", name, e))
+}
+
+// Input domain checks. Out-of-domain input is reported as a `DomainError` before it turns
+// into NaN or inf in the result.
+
+/// Input outside the domain of a calculation
+///
+/// Returned to Typst as the error text, serialized as JSON with the offending `field`, the
+/// `constraint` it violates, its `value` (null for NaN and infinities) and a readable
+/// `message`, e.g. `{"field":"temperature","constraint":"above 0 K","value":-5.0,
+/// "message":"Invalid temperature: -5 (must be above 0 K)"}`.
+#[derive(Serialize)]
+struct DomainError {
+    field: String,
+    constraint: String,
+    value: f64,
+    message: String,
+}
+
+impl DomainError {
+    fn new(field: &str, value: f64, constraint: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            constraint: constraint.to_string(),
+            value,
+            message: format!("Invalid {}: {} (must be {})", field, value, constraint),
+        }
+    }
+}
+
+impl From<DomainError> for String {
+    fn from(error: DomainError) -> Self {
+        serde_json::to_string(&error).unwrap()
+    }
+}
+
+/// Reject a value outside the domain of a calculation; `constraint` completes "must be ..."
+fn check_domain(field: &str, value: f64, valid: bool, constraint: &str) -> Result<(), String> {
+    if valid && !value.is_nan() {
+        Ok(())
+    } else {
+        Err(DomainError::new(field, value, constraint).into())
+    }
+}
+
+/// Require a positive, finite value, e.g. a rate constant or a concentration
+fn check_positive(field: &str, value: f64) -> Result<(), String> {
+    check_domain(field, value, value > 0.0 && value.is_finite(), "positive")
+}
+
+/// Require a finite value that is not negative, e.g. an amount or a pre-exponential factor
+fn check_non_negative(field: &str, value: f64) -> Result<(), String> {
+    check_domain(field, value, value >= 0.0 && value.is_finite(), "non-negative")
+}
+
+/// Require a finite value, e.g. an energy
+fn check_finite(field: &str, value: f64) -> Result<(), String> {
+    check_domain(field, value, value.is_finite(), "finite")
+}
+
+/// Require a value in the open interval (0, 1), e.g. a mole fraction or transfer coefficient
+fn check_fraction(field: &str, value: f64) -> Result<(), String> {
+    check_domain(field, value, value > 0.0 && value < 1.0, "between 0 and 1")
+}
+
+/// Require an absolute temperature above 0 K
+fn check_temperature(temperature: f64) -> Result<(), String> {
+    check_temperature_of("temperature", temperature)
+}
+
+/// Require a named absolute temperature, e.g. T1 or the cold reservoir, above 0 K
+fn check_temperature_of(field: &str, temperature: f64) -> Result<(), String> {
+    check_domain(field, temperature, temperature > 0.0 && temperature.is_finite(), "above 0 K")
+}
+
+/// Require two values to differ where their difference is a denominator, e.g. T1 and T2
+fn check_distinct(first: &str, a: f64, second: &str, b: f64) -> Result<(), String> {
+    check_domain(second, b, a != b, &format!("different from {}", first))
+}
+
+/// Parse a plain-text number sent by Typst, rejecting NaN and infinities
+fn parse_number(bytes: &[u8], name: &str) -> Result<f64, String> {
+    let value = parse_arg(bytes, name)?;
+    check_finite(name, value)?;
+    Ok(value)
+}
+
+/// Serialize a result for Typst, guarding against NaN and infinite numbers
+///
+/// serde_json writes them as null, which breaks formatting in the Typst wrapper. A
+/// non-finite `value` (or bare number) is reported as an error; anywhere else the result is
+/// returned with `finite: false` and the paths of the affected numbers in `non_finite`,
+/// e.g. `["points[3][1]"]`.
+fn to_json<T: Serialize + ?Sized>(result: &T) -> Result<Vec<u8>, String> {
+    let mut found = Vec::new();
+    result
+        .serialize(FiniteCheck {
+            path: String::new(),
+            found: &mut found,
+        })
+        .map_err(|_| "Failed to check the result for non-finite numbers".to_string())?;
+    if found.is_empty() {
+        return Ok(serde_json::to_vec(result).unwrap());
+    }
+
+    let describe = |(path, value): &(String, f64)| {
+        format!("{} = {}", if path.is_empty() { "result" } else { path }, value)
+    };
+    if let Some(main) = found.iter().find(|(path, _)| path.is_empty() || path == "value") {
+        return Err(format!(
+            "Non-finite result: {} (the inputs lead to an undefined or overflowing value)",
+            describe(main)
+        ));
+    }
+    let mut json = serde_json::to_value(result).unwrap();
+    let Some(object) = json.as_object_mut() else {
+        let numbers: Vec<String> = found.iter().map(describe).collect();
+        return Err(format!("Non-finite result: {}", numbers.join(", ")));
+    };
+    object.insert("finite".to_string(), serde_json::Value::Bool(false));
+    object.insert(
+        "non_finite".to_string(),
+        found.into_iter().map(|(path, _)| serde_json::Value::String(path)).collect(),
+    );
+
+    Ok(serde_json::to_vec(&json).unwrap())
+}
+
+/// Serializer that writes nothing and records the paths of NaN and infinite numbers
+struct FiniteCheck<'a> {
+    path: String,
+    found: &'a mut Vec<(String, f64)>,
+}
+
+/// Sequence, map or struct being checked by `FiniteCheck`
+struct FiniteCheckCompound<'a> {
+    path: String,
+    found: &'a mut Vec<(String, f64)>,
+    index: usize,
+    key: String,
+}
+
+impl<'a> FiniteCheck<'a> {
+    fn compound(self) -> FiniteCheckCompound<'a> {
+        FiniteCheckCompound {
+            path: self.path,
+            found: self.found,
+            index: 0,
+            key: String::new(),
+        }
+    }
+}
+
+impl FiniteCheckCompound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), std::fmt::Error> {
+        let path = format!("{}[{}]", self.path, self.index);
+        self.index += 1;
+        value.serialize(FiniteCheck {
+            path,
+            found: self.found,
+        })
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), std::fmt::Error> {
+        let path = if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.path, key)
+        };
+        value.serialize(FiniteCheck {
+            path,
+            found: self.found,
+        })
+    }
+}
+
+impl<'a> serde::Serializer for FiniteCheck<'a> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+    type SerializeSeq = FiniteCheckCompound<'a>;
+    type SerializeTuple = FiniteCheckCompound<'a>;
+    type SerializeTupleStruct = FiniteCheckCompound<'a>;
+    type SerializeTupleVariant = FiniteCheckCompound<'a>;
+    type SerializeMap = FiniteCheckCompound<'a>;
+    type SerializeStruct = FiniteCheckCompound<'a>;
+    type SerializeStructVariant = FiniteCheckCompound<'a>;
+
+    fn serialize_bool(self, _: bool) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_i8(self, _: i8) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_i16(self, _: i16) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_i32(self, _: i32) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_i64(self, _: i64) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_u8(self, _: u8) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_u16(self, _: u16) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_u32(self, _: u32) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_u64(self, _: u64) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_f32(self, value: f32) -> Result<(), Self::Error> {
+        self.serialize_f64(value as f64)
+    }
+    fn serialize_f64(self, value: f64) -> Result<(), Self::Error> {
+        if !value.is_finite() {
+            self.found.push((self.path, value));
+        }
+        Ok(())
+    }
+    fn serialize_char(self, _: char) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_str(self, _: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.compound())
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(self.compound())
+    }
+}
+
+impl serde::ser::SerializeSeq for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeTuple for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeTupleVariant for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeMap for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = match serde_json::to_value(key) {
+            Ok(serde_json::Value::String(key)) => key,
+            Ok(key) => key.to_string(),
+            Err(_) => return Err(std::fmt::Error),
+        };
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = std::mem::take(&mut self.key);
+        self.field(&key, value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeStruct for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl serde::ser::SerializeStructVariant for FiniteCheckCompound<'_> {
+    type Ok = ();
+    type Error = std::fmt::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Parse a time unit, returning its length in seconds and its canonical symbol
+fn parse_time_unit(bytes: &[u8]) -> Result<(f64, &'static str), String> {
+    let unit: String = parse_arg(bytes, "time unit")?;
+    match unit.as_str() {
+        "" | "s" | "sec" | "second" | "seconds" => Ok((1.0, "s")),
+        "min" | "minute" | "minutes" => Ok((60.0, "min")),
+        "h" | "hr" | "hour" | "hours" => Ok((3600.0, "h")),
+        "d" | "day" | "days" => Ok((86400.0, "d")),
+        // Julian year
+        "y" | "yr" | "a" | "year" | "years" => Ok((31_557_600.0, "yr")),
+        _ => Err(format!(
+            "Unknown time unit: {} (expected s, min, h, d or yr)",
+            unit
+        )),
+    }
+}
+
+/// Parse a pressure unit, returning its size in pascals and its canonical symbol
+fn parse_pressure_unit(unit: &str) -> Result<(f64, &'static str), String> {
+    match unit {
+        "Pa" => Ok((1.0, "Pa")),
+        "hPa" | "mbar" => Ok((100.0, "hPa")),
+        "kPa" => Ok((1000.0, "kPa")),
+        "MPa" => Ok((1e6, "MPa")),
+        "" | "bar" => Ok((1e5, "bar")),
+        "atm" => Ok((101_325.0, "atm")),
+        "Torr" | "torr" | "mmHg" => Ok((101_325.0 / 760.0, "Torr")),
+        "psi" => Ok((6894.757, "psi")),
+        _ => Err(format!(
+            "Unknown pressure unit: {} (expected Pa, kPa, MPa, bar, atm, Torr, mmHg or psi)",
+            unit
+        )),
+    }
+}
+
+/// Parse a volume unit, returning its size in litres and its canonical symbol
+fn parse_volume_unit(unit: &str) -> Result<(f64, &'static str), String> {
+    match unit {
+        "" | "L" | "l" | "dm3" => Ok((1.0, "L")),
+        "mL" | "ml" | "cm3" => Ok((1e-3, "mL")),
+        "m3" => Ok((1000.0, "m³")),
+        _ => Err(format!(
+            "Unknown volume unit: {} (expected L, dm3, mL, cm3 or m3)",
+            unit
+        )),
+    }
+}
+
+/// Temperature scale, converting to and from kelvin
+#[derive(Clone, Copy)]
+enum TemperatureScale {
+    Kelvin,
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureScale {
+    fn parse(unit: &str) -> Result<Self, String> {
+        match unit {
+            "" | "K" => Ok(TemperatureScale::Kelvin),
+            "C" | "°C" | "degC" => Ok(TemperatureScale::Celsius),
+            "F" | "°F" | "degF" => Ok(TemperatureScale::Fahrenheit),
+            _ => Err(format!("Unknown temperature unit: {} (expected K, °C or °F)", unit)),
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            TemperatureScale::Kelvin => "K",
+            TemperatureScale::Celsius => "°C",
+            TemperatureScale::Fahrenheit => "°F",
+        }
+    }
+
+    fn to_kelvin(self, value: f64) -> f64 {
+        match self {
+            TemperatureScale::Kelvin => value,
+            TemperatureScale::Celsius => value + 273.15,
+            TemperatureScale::Fahrenheit => (value - 32.0) * 5.0 / 9.0 + 273.15,
+        }
+    }
+
+    fn kelvin_to_scale(self, kelvin: f64) -> f64 {
+        match self {
+            TemperatureScale::Kelvin => kelvin,
+            TemperatureScale::Celsius => kelvin - 273.15,
+            TemperatureScale::Fahrenheit => (kelvin - 273.15) * 9.0 / 5.0 + 32.0,
+        }
+    }
+}
+
+/// Double-double number hi + lo for the high-precision mode, reported to about 27
+/// significant digits
+///
+/// Sums and products use error-free transformations, so Hess sums of tabulated decimals and
+/// ln K from large ΔG keep the digits that f64 rounds away.
+#[derive(Clone, Copy)]
+struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// ln 2 and ln 10 to double-double precision
+const LN_2_DD: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::LN_2,
+    lo: 2.3190468138462996e-17,
+};
+const LN_10_DD: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::LN_10,
+    lo: -2.1707562233822494e-16,
+};
+
+/// a + b as a rounded sum and its exact error
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// a + b for |a| ≥ |b|
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+impl DoubleDouble {
+    fn new(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+
+    fn from_pair((hi, lo): (f64, f64)) -> Self {
+        Self { hi, lo }
+    }
+
+    /// The decimal a float was written as, e.g. 0.1 exactly rather than 0.1000000000000000055…
+    fn from_decimal(value: f64) -> Self {
+        if !value.is_finite() {
+            return Self::new(value);
+        }
+        Self::parse(&format!("{:e}", value)).unwrap_or(Self::new(value))
+    }
+
+    /// Parse a decimal number such as "-285.83", "1.5e-3" or a `digits` string
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (mantissa, mut scale) = match text.find(['e', 'E']) {
+            Some(i) => (&text[..i], text[i + 1..].parse::<i32>().ok()?),
+            None => (text, 0),
+        };
+        let mut value = Self::new(0.0);
+        let (mut point, mut digits, mut significant) = (false, 0, 0);
+        for c in mantissa.chars() {
+            match c {
+                '.' if !point => point = true,
+                '0'..='9' => {
+                    digits += 1;
+                    // Digits beyond the precision only shift the exponent
+                    if significant < 32 {
+                        value = value * Self::new(10.0) + Self::new(f64::from(c as u8 - b'0'));
+                        if value.hi != 0.0 {
+                            significant += 1;
+                        }
+                        if point {
+                            scale -= 1;
+                        }
+                    } else if !point {
+                        scale += 1;
+                    }
+                }
+                _ => return None,
+            }
+        }
+        if digits == 0 {
+            return None;
+        }
+        let value = value.scale10(scale);
+        Some(if negative { -value } else { value })
+    }
+
+    fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// self·10^exponent
+    fn scale10(self, exponent: i32) -> Self {
+        let mut power = Self::new(1.0);
+        let mut base = Self::new(10.0);
+        let mut n = exponent.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                power = power * base;
+            }
+            base = base * base;
+            n >>= 1;
+        }
+        if exponent < 0 {
+            self / power
+        } else {
+            self * power
+        }
+    }
+
+    fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            Self::from_pair(quick_two_sum(hi, self.lo.floor()))
+        } else {
+            Self::new(hi)
         }
     }
+
+    /// eˣ by reduction to x = k·ln 2 + r, a Taylor series of r/1024 and ten squarings
+    fn exp(self) -> Self {
+        if self.hi > 709.0 {
+            return Self::new(f64::INFINITY);
+        }
+        if self.hi < -745.0 {
+            return Self::new(0.0);
+        }
+        let k = (self.hi / LN_2_DD.hi).round();
+        let reduced = self - LN_2_DD * Self::new(k);
+        let r = Self {
+            hi: reduced.hi / 1024.0,
+            lo: reduced.lo / 1024.0,
+        };
+        let mut sum = Self::new(1.0);
+        let mut term = Self::new(1.0);
+        for i in 1..=20 {
+            term = term * r / Self::new(f64::from(i));
+            sum = sum + term;
+            if term.hi.abs() < 1e-33 {
+                break;
+            }
+        }
+        for _ in 0..10 {
+            sum = sum * sum;
+        }
+        let scale = 2_f64.powi(k as i32);
+        Self {
+            hi: sum.hi * scale,
+            lo: sum.lo * scale,
+        }
+    }
+
+    /// `count` correctly rounded significant digits and the decimal exponent e of
+    /// |self| = d₁.d₂d₃… × 10^e
+    fn decimal_digits(self, count: usize) -> (Vec<u8>, i32) {
+        let mut x = if self.hi < 0.0 { -self } else { self };
+        if x.hi == 0.0 {
+            return (vec![0; count], 0);
+        }
+        let mut exponent = x.hi.log10().floor() as i32;
+        x = x.scale10(-exponent);
+        while x.hi >= 10.0 {
+            x = x / Self::new(10.0);
+            exponent += 1;
+        }
+        while x.hi < 1.0 {
+            x = x * Self::new(10.0);
+            exponent -= 1;
+        }
+        let mut digits = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            let digit = x.floor().to_f64().clamp(0.0, 9.0);
+            digits.push(digit as u8);
+            x = (x - Self::new(digit)) * Self::new(10.0);
+        }
+        let last = digits.pop().unwrap_or(0);
+        if last >= 5 {
+            let mut i = digits.len();
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    digits.pop();
+                    exponent += 1;
+                    break;
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+        (digits, exponent)
+    }
+
+    /// Plain decimal notation with `decimals` places from digits d₁.d₂… × 10^exponent
+    fn plain(negative: bool, digits: &[u8], exponent: i32, decimals: usize) -> String {
+        let digit = |i: i32| -> char {
+            match usize::try_from(i).ok().and_then(|i| digits.get(i)) {
+                Some(d) => char::from(b'0' + d),
+                None => '0',
+            }
+        };
+        let mut text = String::new();
+        if exponent < 0 {
+            text.push('0');
+        } else {
+            text.extend((0..=exponent).map(digit));
+        }
+        if decimals > 0 {
+            text.push('.');
+            text.extend((1..=decimals as i32).map(|i| digit(exponent + i)));
+        }
+        if negative && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            text.insert(0, '-');
+        }
+        text
+    }
+
+    /// Rounded to a number of decimal places, like `format!("{:.decimals$}")`
+    fn to_fixed(self, decimals: usize) -> String {
+        let (_, exponent) = self.decimal_digits(1);
+        let count = exponent + 1 + decimals as i32;
+        if count <= 0 {
+            return format!("{:.prec$}", 0.0, prec = decimals);
+        }
+        let (digits, exponent) = self.decimal_digits(count as usize);
+        Self::plain(self.hi < 0.0, &digits, exponent, decimals)
+    }
+
+    /// All reliable digits in plain notation, trailing zeros removed; sent as `digits`
+    fn to_decimal_string(self) -> String {
+        let (mut digits, exponent) = self.decimal_digits(27);
+        while digits.len() > 1 && digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let decimals = (digits.len() as i32 - 1 - exponent).max(0) as usize;
+        Self::plain(self.hi < 0.0, &digits, exponent, decimals)
+    }
+
+    /// Like `format_scientific`, rounding from all digits
+    fn to_scientific(self, precision: usize) -> String {
+        if self.hi == 0.0 {
+            return "0".to_string();
+        }
+        let (digits, exponent) = self.decimal_digits(precision + 1);
+        let abs_value = self.hi.abs();
+        if exponent.abs() < 3 && (0.001..1000.0).contains(&abs_value) {
+            self.to_fixed(precision)
+        } else {
+            format!(
+                "{}×10^{}",
+                Self::plain(self.hi < 0.0, &digits, 0, precision),
+                exponent
+            )
+        }
+    }
+}
+
+impl std::ops::Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (s, e) = two_sum(self.hi, other.hi);
+        let (t, f) = two_sum(self.lo, other.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        Self::from_pair(quick_two_sum(s, e + f))
+    }
+}
+
+impl std::ops::Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl std::ops::Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl std::ops::Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let p = self.hi * other.hi;
+        let e = self.hi.mul_add(other.hi, -p) + (self.hi * other.lo + self.lo * other.hi);
+        Self::from_pair(quick_two_sum(p, e))
+    }
+}
+
+impl std::ops::Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let q1 = self.hi / other.hi;
+        let r = self - other * Self::new(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other * Self::new(q2);
+        let q3 = r.hi / other.hi;
+        Self::from_pair(quick_two_sum(q1, q2)) + Self::new(q3)
+    }
+}
+
+/// Σν·x of decimal coefficients and values, as in a Hess sum
+///
+/// Decimals such as 0.1 are not exact in double-double either, so terms that cancel leave a
+/// residue far below the precision of the largest term; it is set to zero.
+fn precise_sum(terms: &[(f64, f64)]) -> DoubleDouble {
+    let mut sum = DoubleDouble::new(0.0);
+    let mut largest: f64 = 0.0;
+    for &(coefficient, value) in terms {
+        let term = DoubleDouble::from_decimal(coefficient) * DoubleDouble::from_decimal(value);
+        largest = largest.max(term.hi.abs());
+        sum = sum + term;
+    }
+    if sum.hi.abs() < largest * 1e-28 {
+        DoubleDouble::new(0.0)
+    } else {
+        sum
+    }
+}
+
+/// Parse a decimal argument sent by Typst at double-double precision
+fn parse_precise(bytes: &[u8], name: &str) -> Result<DoubleDouble, String> {
+    let text = std::str::from_utf8(bytes).map_err(|e| format!("Invalid UTF-8 in {}: {}", name, e))?;
+    DoubleDouble::parse(text).ok_or_else(|| format!("Failed to parse {}: {}", name, text))
+}
+
+/// Number of significant digits written in a decimal number
+fn significant_digits(text: &str) -> usize {
+    let mantissa = text.split(['e', 'E']).next().unwrap_or("");
+    mantissa
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .skip_while(|&c| c == '0')
+        .count()
 }
 
 /// Calculate reaction enthalpy using Hess's Law
 /// ΔH_reaction = Σ(ΔH_f products) - Σ(ΔH_f reactants)
+///
+/// Reactions of ions must be charge balanced; aqueous ions use the H⁺(aq) = 0 convention.
+/// In high-precision mode the sum is carried in double-double arithmetic from the decimal
+/// values of the table, and the result includes `digits`.
 #[wasm_func]
 pub fn calculate_reaction_enthalpy(
     reactants_json: &[u8],
     products_json: &[u8],
     data_json: &[u8],
+    high_precision_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     // Parse input data
     let reactants: Vec<(String, f64)> = serde_json::from_slice(reactants_json)
@@ -70,36 +1027,53 @@ pub fn calculate_reaction_enthalpy(
     
     let data: HashMap<String, ThermodynamicData> = serde_json::from_slice(data_json)
         .map_err(|e| format!("Failed to parse thermodynamic data: {}", e))?;
+
+    let high_precision: bool = parse_arg(high_precision_bytes, "high-precision flag")?;
+    
+    check_charge_balance(&reactants, &products)?;
     
     // Calculate ΔH = Σ(products) - Σ(reactants)
     let mut delta_h = 0.0;
+    let mut terms = Vec::new();
+    let mut sources = Vec::new();
     
     // Add products contribution
     for (formula, coeff) in products {
-        let thermo_data = data.get(&formula)
-            .ok_or_else(|| format!("No data found for product: {}", formula))?;
+        let thermo_data = lookup_species(&data, &formula, "product")?;
         delta_h += coeff * thermo_data.delta_hf;
+        terms.push((coeff, thermo_data.delta_hf));
+        sources.push(&thermo_data.source);
     }
     
     // Subtract reactants contribution
     for (formula, coeff) in reactants {
-        let thermo_data = data.get(&formula)
-            .ok_or_else(|| format!("No data found for reactant: {}", formula))?;
+        let thermo_data = lookup_species(&data, &formula, "reactant")?;
         delta_h -= coeff * thermo_data.delta_hf;
+        terms.push((-coeff, thermo_data.delta_hf));
+        sources.push(&thermo_data.source);
     }
     
-    let result = CalculationResult::new(delta_h, "kJ/mol");
-    
-    Ok(serde_json::to_vec(&result).unwrap())
+    let result = if high_precision {
+        CalculationResult::precise(precise_sum(&terms), "kJ/mol")
+    } else {
+        CalculationResult::new(delta_h, "kJ/mol")
+    };
+    let result = result.with_sources(sources);
+    
+    to_json(&result)
 }
 
 /// Calculate reaction entropy change
 /// ΔS_reaction = Σ(S products) - Σ(S reactants)
+///
+/// Reactions of ions must be charge balanced; aqueous ions use the H⁺(aq) = 0 convention.
+/// The high-precision mode works as for `calculate_reaction_enthalpy`.
 #[wasm_func]
 pub fn calculate_reaction_entropy(
     reactants_json: &[u8],
     products_json: &[u8],
     data_json: &[u8],
+    high_precision_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     let reactants: Vec<(String, f64)> = serde_json::from_slice(reactants_json)
         .map_err(|e| format!("Failed to parse reactants: {}", e))?;
@@ -109,33 +1083,50 @@ pub fn calculate_reaction_entropy(
     
     let data: HashMap<String, ThermodynamicData> = serde_json::from_slice(data_json)
         .map_err(|e| format!("Failed to parse thermodynamic data: {}", e))?;
+
+    let high_precision: bool = parse_arg(high_precision_bytes, "high-precision flag")?;
+    
+    check_charge_balance(&reactants, &products)?;
     
     let mut delta_s = 0.0;
+    let mut terms = Vec::new();
+    let mut sources = Vec::new();
     
     for (formula, coeff) in products {
-        let thermo_data = data.get(&formula)
-            .ok_or_else(|| format!("No data found for product: {}", formula))?;
+        let thermo_data = lookup_species(&data, &formula, "product")?;
         delta_s += coeff * thermo_data.s;
+        terms.push((coeff, thermo_data.s));
+        sources.push(&thermo_data.source);
     }
     
     for (formula, coeff) in reactants {
-        let thermo_data = data.get(&formula)
-            .ok_or_else(|| format!("No data found for reactant: {}", formula))?;
+        let thermo_data = lookup_species(&data, &formula, "reactant")?;
         delta_s -= coeff * thermo_data.s;
+        terms.push((-coeff, thermo_data.s));
+        sources.push(&thermo_data.source);
     }
     
-    let result = CalculationResult::new(delta_s, "J/(mol·K)");
+    let result = if high_precision {
+        CalculationResult::precise(precise_sum(&terms), "J/(mol·K)")
+    } else {
+        CalculationResult::new(delta_s, "J/(mol·K)")
+    };
+    let result = result.with_sources(sources);
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
 }
 
 /// Calculate Gibbs free energy change
 /// ΔG = ΔH - T·ΔS
+///
+/// In high-precision mode the arguments are read as decimals (e.g. the `digits` of a
+/// high-precision ΔH) and the result includes `digits`.
 #[wasm_func]
 pub fn calculate_gibbs_energy(
     enthalpy_bytes: &[u8],
     entropy_bytes: &[u8],
     temperature_bytes: &[u8],
+    high_precision_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     let enthalpy: f64 = std::str::from_utf8(enthalpy_bytes)
         .map_err(|e| format!("Invalid UTF-8 in enthalpy: {}", e))?
@@ -151,21 +1142,70 @@ pub fn calculate_gibbs_energy(
         .map_err(|e| format!("Invalid UTF-8 in temperature: {}", e))?
         .parse()
         .map_err(|e| format!("Failed to parse temperature: {}", e))?;
+
+    let high_precision: bool = parse_arg(high_precision_bytes, "high-precision flag")?;
+
+    check_finite("enthalpy", enthalpy)?;
+    check_finite("entropy", entropy)?;
+    check_temperature(temperature)?;
     
     // ΔG = ΔH - T·ΔS (convert entropy from J/(mol·K) to kJ/(mol·K))
-    let delta_g = enthalpy - temperature * (entropy / 1000.0);
-    
-    let result = CalculationResult::new(delta_g, "kJ/mol");
+    let result = if high_precision {
+        let enthalpy = parse_precise(enthalpy_bytes, "enthalpy")?;
+        let entropy = parse_precise(entropy_bytes, "entropy")?;
+        let temperature = parse_precise(temperature_bytes, "temperature")?;
+        let delta_g = enthalpy - temperature * entropy / DoubleDouble::new(1000.0);
+        CalculationResult::precise(delta_g, "kJ/mol")
+    } else {
+        let delta_g = enthalpy - temperature * (entropy / 1000.0);
+        CalculationResult::new(delta_g, "kJ/mol")
+    };
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
+}
+
+/// Equilibrium constant with its logarithms
+#[derive(Serialize)]
+struct EquilibriumConstant {
+    /// K; null when it lies outside the range of f64 (see `out_of_range`)
+    value: Option<f64>,
+    unit: String,
+    ln_k: f64,
+    log10_k: f64,
+    /// pK = -log10 K
+    pk: f64,
+    /// "overflow" or "underflow" when K cannot be represented; ln K and log10 K remain exact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    out_of_range: Option<String>,
+    /// Digits of the high-precision mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    precise: Option<PreciseConstant>,
+}
+
+/// Equilibrium constant to about 27 significant digits, K = mantissa × 10^exponent
+#[derive(Serialize)]
+struct PreciseConstant {
+    mantissa: String,
+    exponent: i32,
+    ln_k: String,
+    log10_k: String,
+    pk: String,
 }
 
 /// Calculate equilibrium constant from Gibbs free energy
 /// K = exp(-ΔG / RT)
+///
+/// ln K and log10 K are always returned. K beyond about 10^±308 does not fit an f64;
+/// `value` is then null and `out_of_range` says which way it failed, rather than
+/// returning inf or 0.
+///
+/// In high-precision mode ln K is computed in double-double arithmetic from the decimal
+/// arguments, and `precise` gives K as mantissa and exponent, even beyond the f64 range.
 #[wasm_func]
 pub fn calculate_equilibrium_constant(
     gibbs_energy_bytes: &[u8],
     temperature_bytes: &[u8],
+    high_precision_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     let delta_g: f64 = std::str::from_utf8(gibbs_energy_bytes)
         .map_err(|e| format!("Invalid UTF-8 in Gibbs energy: {}", e))?
@@ -177,14 +1217,60 @@ pub fn calculate_equilibrium_constant(
         .parse()
         .map_err(|e| format!("Failed to parse temperature: {}", e))?;
     
-    const R: f64 = 8.314; // J/(mol·K)
+    let high_precision: bool = parse_arg(high_precision_bytes, "high-precision flag")?;
     
-    // K = exp(-ΔG / RT), convert ΔG from kJ/mol to J/mol
-    let k = (-delta_g * 1000.0 / (R * temperature)).exp();
+    check_finite("Gibbs energy", delta_g)?;
+    check_temperature(temperature)?;
     
-    let result = CalculationResult::new(k, "");
+    // ln K = -ΔG / RT, convert ΔG from kJ/mol to J/mol
+    let (ln_k, log10_k, precise) = if high_precision {
+        let delta_g = parse_precise(gibbs_energy_bytes, "Gibbs energy")?;
+        let temperature = parse_precise(temperature_bytes, "temperature")?;
+        // Exact CODATA R = NA·k, so the digits are not limited by a rounded gas constant
+        let gas_constant =
+            DoubleDouble::from_decimal(AVOGADRO) * DoubleDouble::from_decimal(BOLTZMANN);
+        let ln_k = -delta_g * DoubleDouble::new(1000.0) / (gas_constant * temperature);
+        let log10_k = ln_k / LN_10_DD;
+        let exponent = log10_k.floor();
+        let mantissa = ((log10_k - exponent) * LN_10_DD).exp();
+        let (mantissa, exponent) = if mantissa.hi >= 10.0 {
+            (mantissa / DoubleDouble::new(10.0), exponent.to_f64() + 1.0)
+        } else {
+            (mantissa, exponent.to_f64())
+        };
+        let precise = PreciseConstant {
+            mantissa: mantissa.to_decimal_string(),
+            exponent: exponent as i32,
+            ln_k: ln_k.to_decimal_string(),
+            log10_k: log10_k.to_decimal_string(),
+            pk: (-log10_k).to_decimal_string(),
+        };
+        (ln_k.to_f64(), log10_k.to_f64(), Some(precise))
+    } else {
+        let ln_k = -delta_g * 1000.0 / (R * temperature);
+        (ln_k, ln_k / std::f64::consts::LN_10, None)
+    };
+    let k = ln_k.exp();
+    // Below f64::MIN_POSITIVE, K is subnormal and loses its significant digits
+    let out_of_range = if k.is_infinite() {
+        Some("overflow")
+    } else if k < f64::MIN_POSITIVE {
+        Some("underflow")
+    } else {
+        None
+    };
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    let result = EquilibriumConstant {
+        value: out_of_range.is_none().then_some(k),
+        unit: String::new(),
+        ln_k,
+        log10_k,
+        pk: -log10_k,
+        out_of_range: out_of_range.map(String::from),
+        precise,
+    };
+    
+    to_json(&result)
 }
 
 /// Get formation data for a single substance
@@ -199,10 +1285,91 @@ pub fn get_substance_data(
     let data: HashMap<String, ThermodynamicData> = serde_json::from_slice(data_json)
         .map_err(|e| format!("Failed to parse thermodynamic data: {}", e))?;
     
-    let substance_data = data.get(formula)
-        .ok_or_else(|| format!("No data found for substance: {}", formula))?;
+    let substance_data = lookup_species(&data, formula, "substance")?;
     
-    Ok(serde_json::to_vec(substance_data).unwrap())
+    to_json(substance_data)
+}
+
+/// Check one input against a domain constraint without raising an error
+///
+/// Uses the same checks as the calculations, so the result is exactly the error a
+/// calculation would report for this input.
+///
+/// Arguments:
+/// - value_bytes: Value to check
+/// - constraint_bytes: "positive", "non-negative", "finite", "fraction" or "temperature"
+/// - field_bytes: Name of the input, used in the error
+///
+/// Returns null when the value is valid, otherwise the `DomainError`
+#[wasm_func]
+pub fn check_input(
+    value_bytes: &[u8],
+    constraint_bytes: &[u8],
+    field_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let value: f64 = parse_arg(value_bytes, "value")?;
+    let constraint: String = parse_arg(constraint_bytes, "constraint")?;
+    let field: String = parse_arg(field_bytes, "field")?;
+
+    let check = match constraint.as_str() {
+        "positive" => check_positive(&field, value),
+        "non-negative" => check_non_negative(&field, value),
+        "finite" => check_finite(&field, value),
+        "fraction" => check_fraction(&field, value),
+        "temperature" => check_temperature_of(&field, value),
+        _ => {
+            return Err(format!(
+                "Unknown constraint: {} (expected positive, non-negative, finite, fraction or temperature)",
+                constraint
+            ))
+        }
+    };
+
+    match check {
+        Ok(()) => Ok(b"null".to_vec()),
+        Err(error) => Ok(error.into_bytes()),
+    }
+}
+
+/// Check the species of a calculation against the temperature ranges of their data
+///
+/// Entries may declare `"T_range": [min, max]` (K). Species without a range, or not in the
+/// table, are not checked.
+///
+/// Arguments:
+/// - species_json: Array of species names
+/// - data_json: Data table
+/// - temperature_bytes: Temperature of the calculation (K)
+#[wasm_func]
+pub fn check_temperature_range(
+    species_json: &[u8],
+    data_json: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let species: Vec<String> = parse_json(species_json, "species")?;
+    let data: HashMap<String, serde_json::Value> = parse_json(data_json, "data table")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_temperature(temperature)?;
+    let mut warnings = Vec::new();
+    for name in &species {
+        let Ok(entry) = lookup_species(&data, name, "species") else {
+            continue;
+        };
+        let range = match entry.get("T_range") {
+            Some(range) => Some(
+                serde_json::from_value::<[f64; 2]>(range.clone())
+                    .map_err(|e| format!("Invalid T_range for {}: {}", name, e))?,
+            ),
+            None => None,
+        };
+        if warnings.iter().any(|w: &DataWarning| &w.species == name) {
+            continue;
+        }
+        warnings.extend(DataWarning::temperature(name, range, temperature));
+    }
+
+    to_json(&warnings)
 }
 
 /// Format a number with scientific notation
@@ -228,10 +1395,16 @@ pub fn format_number(
         .parse::<bool>()
         .unwrap_or(false);
     
-    let formatted = if use_scientific {
-        format_scientific(value, precision)
-    } else {
-        format!("{:.prec$}", value, prec = precision)
+    // High-precision `digits` are rounded from all their digits, not from the nearest f64
+    let text = std::str::from_utf8(value_bytes).unwrap_or("").trim();
+    let precise = (significant_digits(text) > 17)
+        .then(|| DoubleDouble::parse(text))
+        .flatten();
+    let formatted = match (precise, use_scientific) {
+        (Some(value), true) => value.to_scientific(precision),
+        (Some(value), false) => value.to_fixed(precision),
+        (None, true) => format_scientific(value, precision),
+        (None, false) => format!("{:.prec$}", value, prec = precision),
     };
     
     Ok(formatted.into_bytes())
@@ -267,14 +1440,16 @@ pub fn calculate_rate_constant_arrhenius(
         .parse()
         .map_err(|e| format!("Failed to parse temperature: {}", e))?;
     
-    const R: f64 = 8.314; // J/(mol·K)
+    check_non_negative("A", a)?;
+    check_finite("Ea", ea)?;
+    check_temperature(temperature)?;
     
     // k = A·exp(-Ea/(R·T)), convert Ea from kJ/mol to J/mol
     let k = a * (-ea * 1000.0 / (R * temperature)).exp();
     
     let result = CalculationResult::new(k, "");
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
 }
 
 /// Calculate rate constant using Eyring equation (transition state theory)
@@ -285,13 +1460,15 @@ pub fn calculate_rate_constant_arrhenius(
 /// - delta_h_activation: Enthalpy of activation (kJ/mol)
 /// - delta_s_activation: Entropy of activation (J/(mol·K))
 /// - T: Temperature (K)
+/// - time_unit: Time unit of the result (s, min, h, d or yr)
 /// 
-/// Returns: Rate constant k (s⁻¹)
+/// Returns: Rate constant k (s⁻¹ or the requested time unit⁻¹)
 #[wasm_func]
 pub fn calculate_rate_constant_eyring(
     delta_h_bytes: &[u8],
     delta_s_bytes: &[u8],
     temperature_bytes: &[u8],
+    time_unit_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     let delta_h: f64 = std::str::from_utf8(delta_h_bytes)
         .map_err(|e| format!("Invalid UTF-8 in ΔH‡: {}", e))?
@@ -308,22 +1485,96 @@ pub fn calculate_rate_constant_eyring(
         .parse()
         .map_err(|e| format!("Failed to parse temperature: {}", e))?;
     
-    const R: f64 = 8.314; // J/(mol·K)
-    const KB: f64 = 1.380649e-23; // Boltzmann constant (J/K)
-    const H: f64 = 6.62607015e-34; // Planck constant (J·s)
-    const NA: f64 = 6.02214076e23; // Avogadro's number (mol⁻¹)
+    let (seconds, time_unit) = parse_time_unit(time_unit_bytes)?;
+    
+    check_finite("ΔH‡", delta_h)?;
+    check_finite("ΔS‡", delta_s)?;
+    check_temperature(temperature)?;
     
     // Calculate ΔG‡ = ΔH‡ - T·ΔS‡ (in J/mol)
     let delta_g = delta_h * 1000.0 - temperature * delta_s;
     
     // k = (kB·T/h)·exp(-ΔG‡/(R·T))
     // Note: kB/h has units s⁻¹·K⁻¹, multiply by T gives s⁻¹
-    let kb_over_h = KB / H; // s⁻¹·K⁻¹
-    let k = (kb_over_h * temperature / NA) * (-delta_g / (R * temperature)).exp();
+    let kb_over_h = BOLTZMANN / PLANCK; // s⁻¹·K⁻¹
+    let k = kb_over_h * temperature * (-delta_g / (R * temperature)).exp();
     
-    let result = CalculationResult::new(k, "s⁻¹");
+    let result = CalculationResult::new(k * seconds, &format!("{}⁻¹", time_unit));
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
+}
+
+/// Collision-theory rate constant with intermediate quantities
+#[derive(Serialize)]
+struct CollisionRateConstant {
+    value: f64,
+    unit: String,
+    /// Collision-theory pre-exponential factor p·σ·⟨v_rel⟩·NA (L/(mol·s))
+    pre_exponential: f64,
+    /// Collision cross-section σ = π·d² (m²)
+    cross_section: f64,
+    /// Mean relative speed √(8·kB·T/(π·μ)) (m/s)
+    mean_relative_speed: f64,
+    /// Reduced mass (g/mol)
+    reduced_mass: f64,
+}
+
+/// Calculate a bimolecular rate constant from simple collision theory
+/// k = p·σ·√(8·kB·T/(π·μ))·NA·exp(-Ea/(R·T))
+///
+/// Arguments:
+/// - molar_masses_json: Array with the molar masses of A and B (g/mol); one value for A + A
+/// - collision_diameter: Collision diameter d = (dA + dB)/2 (nm)
+/// - Ea: Activation energy (kJ/mol)
+/// - T: Temperature (K)
+/// - steric_factor: Steric factor p
+///
+/// Returns: Rate constant k (L/(mol·s)) and the collision-theory A factor
+#[wasm_func]
+pub fn calculate_rate_constant_collision(
+    molar_masses_json: &[u8],
+    collision_diameter_bytes: &[u8],
+    ea_bytes: &[u8],
+    temperature_bytes: &[u8],
+    steric_factor_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molar_masses: Vec<f64> = parse_json(molar_masses_json, "molar masses")?;
+    let diameter = parse_number(collision_diameter_bytes, "collision diameter")?;
+    let ea = parse_number(ea_bytes, "Ea")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let steric_factor = parse_number(steric_factor_bytes, "steric factor")?;
+
+    let (m_a, m_b) = match molar_masses.as_slice() {
+        [m] => (*m, *m),
+        [m_a, m_b] => (*m_a, *m_b),
+        _ => return Err("Expected one or two molar masses".to_string()),
+    };
+    check_positive("molar mass of A", m_a)?;
+    check_positive("molar mass of B", m_b)?;
+    check_positive("collision diameter", diameter)?;
+    check_non_negative("Ea", ea)?;
+    check_temperature(temperature)?;
+    check_positive("steric factor", steric_factor)?;
+
+    let reduced_mass = m_a * m_b / (m_a + m_b); // g/mol
+    let mu = reduced_mass / 1000.0 / AVOGADRO; // kg per pair
+    let cross_section = std::f64::consts::PI * (diameter * 1e-9).powi(2);
+    let mean_relative_speed = (8.0 * BOLTZMANN * temperature / (std::f64::consts::PI * mu)).sqrt();
+
+    // m³/(mol·s) → L/(mol·s)
+    let pre_exponential = steric_factor * cross_section * mean_relative_speed * AVOGADRO * 1000.0;
+    let k = pre_exponential * (-ea * 1000.0 / (R * temperature)).exp();
+
+    let result = CollisionRateConstant {
+        value: k,
+        unit: "L/(mol·s)".to_string(),
+        pre_exponential,
+        cross_section,
+        mean_relative_speed,
+        reduced_mass,
+    };
+
+    to_json(&result)
 }
 
 /// Calculate activation energy from rate constants at two temperatures
@@ -356,23 +1607,123 @@ pub fn calculate_activation_energy(
         .parse()
         .map_err(|e| format!("Failed to parse T2: {}", e))?;
     
-    const R: f64 = 8.314; // J/(mol·K)
+    check_positive("k1", k1)?;
+    check_positive("k2", k2)?;
+    check_temperature_of("T1", t1)?;
+    check_temperature_of("T2", t2)?;
+    check_distinct("T1", t1, "T2", t2)?;
     
     // Ea = R·ln(k2/k1) / (1/T1 - 1/T2)
     let ea = R * (k2 / k1).ln() / (1.0/t1 - 1.0/t2) / 1000.0; // Convert to kJ/mol
     
     let result = CalculationResult::new(ea, "kJ/mol");
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
+}
+
+/// Calculate the Q10 temperature coefficient from rate constants at two temperatures
+/// Q10 = (k2/k1)^(10/(T2 - T1))
+#[wasm_func]
+pub fn calculate_q10(
+    k1_bytes: &[u8],
+    t1_bytes: &[u8],
+    k2_bytes: &[u8],
+    t2_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k1 = parse_number(k1_bytes, "k1")?;
+    let t1 = parse_number(t1_bytes, "T1")?;
+    let k2 = parse_number(k2_bytes, "k2")?;
+    let t2 = parse_number(t2_bytes, "T2")?;
+
+    check_positive("k1", k1)?;
+    check_positive("k2", k2)?;
+    check_temperature_of("T1", t1)?;
+    check_temperature_of("T2", t2)?;
+    check_distinct("T1", t1, "T2", t2)?;
+
+    let q10 = (k2 / k1).powf(10.0 / (t2 - t1));
+
+    let result = CalculationResult::new(q10, "");
+
+    to_json(&result)
+}
+
+/// Calculate Q10 from the activation energy
+/// Q10 = exp(10·Ea / (R·T·(T + 10)))
+#[wasm_func]
+pub fn calculate_q10_from_activation_energy(
+    ea_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let ea = parse_number(ea_bytes, "Ea")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_temperature(temperature)?;
+
+    // Convert Ea from kJ/mol to J/mol
+    let q10 = (10.0 * ea * 1000.0 / (R * temperature * (temperature + 10.0))).exp();
+
+    let result = CalculationResult::new(q10, "");
+
+    to_json(&result)
+}
+
+/// Calculate the activation energy implied by a Q10 value
+/// Ea = R·T·(T + 10)·ln(Q10) / 10
+#[wasm_func]
+pub fn calculate_activation_energy_from_q10(
+    q10_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let q10 = parse_number(q10_bytes, "Q10")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_positive("Q10", q10)?;
+    check_temperature(temperature)?;
+
+    let ea = R * temperature * (temperature + 10.0) * q10.ln() / 10.0 / 1000.0; // Convert to kJ/mol
+
+    let result = CalculationResult::new(ea, "kJ/mol");
+
+    to_json(&result)
+}
+
+/// Scale a rate constant to a new temperature with Q10
+/// k(T_new) = k(T)·Q10^((T_new - T)/10)
+#[wasm_func]
+pub fn calculate_rate_constant_q10(
+    k_bytes: &[u8],
+    q10_bytes: &[u8],
+    temperature_bytes: &[u8],
+    new_temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k = parse_number(k_bytes, "k")?;
+    let q10 = parse_number(q10_bytes, "Q10")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let new_temperature = parse_number(new_temperature_bytes, "new temperature")?;
+
+    check_non_negative("k", k)?;
+    check_positive("Q10", q10)?;
+    check_temperature(temperature)?;
+    check_temperature_of("new temperature", new_temperature)?;
+
+    let k_new = k * q10.powf((new_temperature - temperature) / 10.0);
+
+    let result = CalculationResult::new(k_new, "");
+
+    to_json(&result)
 }
 
 /// Calculate half-life for first-order reaction
 /// t_1/2 = ln(2) / k
+///
+/// The half-life is returned in the time unit of k (k in min⁻¹ gives minutes).
 #[wasm_func]
 pub fn calculate_half_life(
     k_bytes: &[u8],
     order_bytes: &[u8],
     initial_conc_bytes: &[u8],
+    time_unit_bytes: &[u8],
 ) -> Result<Vec<u8>, String> {
     let k: f64 = std::str::from_utf8(k_bytes)
         .map_err(|e| format!("Invalid UTF-8 in k: {}", e))?
@@ -388,6 +1739,13 @@ pub fn calculate_half_life(
         .map_err(|e| format!("Invalid UTF-8 in initial concentration: {}", e))?
         .parse()
         .unwrap_or(1.0);
+
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_positive("k", k)?;
+    if order != 1 {
+        check_positive("initial concentration", initial_conc)?;
+    }
     
     let half_life = match order {
         0 => initial_conc / (2.0 * k), // Zero order: t_1/2 = [A]0 / (2k)
@@ -396,7 +1754,15150 @@ pub fn calculate_half_life(
         _ => return Err(format!("Unsupported reaction order: {}", order)),
     };
     
-    let result = CalculationResult::new(half_life, "s");
+    let result = CalculationResult::new(half_life, time_unit);
     
-    Ok(serde_json::to_vec(&result).unwrap())
+    to_json(&result)
+}
+
+// ============================================================================
+// REACTION ENERGY PROFILES
+// ============================================================================
+
+/// Stationary point on a reaction-coordinate diagram
+#[derive(Serialize)]
+struct StationaryPoint {
+    x: f64,
+    energy: f64,
+    kind: String,
+    label: String,
+}
+
+/// Curve data for a reaction-coordinate diagram
+#[derive(Serialize)]
+struct EnergyProfile {
+    points: Vec<(f64, f64)>,
+    stationary_points: Vec<StationaryPoint>,
+    unit: String,
+}
+
+/// Generate reaction-coordinate diagram data for a (multi-step) mechanism
+///
+/// Each step i climbs from minimum i to transition state i and descends to
+/// minimum i+1. Transition state energies are E(minimum i) + Ea_i, so the
+/// activation energies are measured from the preceding well.
+///
+/// Arguments:
+/// - reactant_energy: Energy of the reactants (kJ/mol)
+/// - product_energy: Energy of the products (kJ/mol)
+/// - activation_energies_json: Array of activation energies, one per step (kJ/mol)
+/// - intermediates_json: Array of intermediate energies (length = steps - 1),
+///   or an empty array to space them evenly between reactants and products
+/// - labels_json: Array of labels for the minima (length = steps + 1), or empty for defaults
+/// - points_per_step: Number of curve points generated for each step
+///
+/// Returns: Curve points (x, E), the stationary points and the energy unit
+#[wasm_func]
+pub fn energy_profile(
+    reactant_energy_bytes: &[u8],
+    product_energy_bytes: &[u8],
+    activation_energies_json: &[u8],
+    intermediates_json: &[u8],
+    labels_json: &[u8],
+    points_per_step_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let reactant_energy = parse_number(reactant_energy_bytes, "reactant energy")?;
+    let product_energy = parse_number(product_energy_bytes, "product energy")?;
+    let activation_energies: Vec<f64> = parse_json(activation_energies_json, "activation energies")?;
+    let intermediates: Vec<f64> = parse_json(intermediates_json, "intermediate energies")?;
+    let labels: Vec<String> = parse_json(labels_json, "labels")?;
+    let points_per_step: usize = parse_arg(points_per_step_bytes, "points per step")?;
+
+    let steps = activation_energies.len();
+    if steps == 0 {
+        return Err("At least one activation energy is required".to_string());
+    }
+    if points_per_step < 3 {
+        return Err("At least 3 points per step are required".to_string());
+    }
+
+    // Energies of the minima: reactants, intermediates..., products
+    let mut minima = vec![reactant_energy];
+    if intermediates.is_empty() {
+        for i in 1..steps {
+            let fraction = i as f64 / steps as f64;
+            minima.push(reactant_energy + fraction * (product_energy - reactant_energy));
+        }
+    } else if intermediates.len() == steps - 1 {
+        minima.extend(intermediates);
+    } else {
+        return Err(format!(
+            "Expected {} intermediate energies for {} steps, got {}",
+            steps - 1,
+            steps,
+            intermediates.len()
+        ));
+    }
+    minima.push(product_energy);
+
+    let labels = if labels.is_empty() {
+        (0..=steps)
+            .map(|i| match i {
+                0 => "Reactants".to_string(),
+                i if i == steps => "Products".to_string(),
+                i => format!("I{}", i),
+            })
+            .collect()
+    } else if labels.len() == steps + 1 {
+        labels
+    } else {
+        return Err(format!(
+            "Expected {} labels for {} steps, got {}",
+            steps + 1,
+            steps,
+            labels.len()
+        ));
+    };
+
+    let mut points = Vec::with_capacity(steps * points_per_step + 1);
+    let mut stationary_points = Vec::with_capacity(2 * steps + 1);
+
+    for (i, ea) in activation_energies.iter().enumerate() {
+        let start = minima[i];
+        let end = minima[i + 1];
+        let ts = start + ea;
+        if ts < start.max(end) {
+            return Err(format!(
+                "Transition state of step {} ({:.2} kJ/mol) lies below an adjacent minimum",
+                i + 1,
+                ts
+            ));
+        }
+
+        stationary_points.push(StationaryPoint {
+            x: i as f64,
+            energy: start,
+            kind: "minimum".to_string(),
+            label: labels[i].clone(),
+        });
+        stationary_points.push(StationaryPoint {
+            x: i as f64 + 0.5,
+            energy: ts,
+            kind: "transition-state".to_string(),
+            label: if steps == 1 { "TS".to_string() } else { format!("TS{}", i + 1) },
+        });
+
+        // Cosine easing gives zero slope at every minimum and maximum
+        for j in 0..points_per_step {
+            let u = j as f64 / points_per_step as f64;
+            let energy = if u < 0.5 {
+                start + (ts - start) * (1.0 - (std::f64::consts::PI * 2.0 * u).cos()) / 2.0
+            } else {
+                end + (ts - end) * (1.0 - (std::f64::consts::PI * 2.0 * u).cos()) / 2.0
+            };
+            points.push((i as f64 + u, energy));
+        }
+    }
+
+    points.push((steps as f64, product_energy));
+    stationary_points.push(StationaryPoint {
+        x: steps as f64,
+        energy: product_energy,
+        kind: "minimum".to_string(),
+        label: labels[steps].clone(),
+    });
+
+    let profile = EnergyProfile {
+        points,
+        stationary_points,
+        unit: "kJ/mol".to_string(),
+    };
+
+    to_json(&profile)
+}
+
+// ============================================================================
+// KINETIC THEORY OF GASES
+// ============================================================================
+
+/// Complementary error function (Chebyshev fit, fractional error < 1.2e-7)
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    let ans = t * poly.exp();
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
+
+/// Distribution curve for a single temperature
+#[derive(Serialize)]
+struct DistributionCurve {
+    temperature: f64,
+    points: Vec<(f64, f64)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fraction_above_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shaded: Option<Vec<(f64, f64)>>,
+}
+
+/// Maxwell–Boltzmann curves for one or more temperatures
+#[derive(Serialize)]
+struct DistributionResult {
+    mode: String,
+    x_unit: String,
+    y_unit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    threshold: Option<f64>,
+    curves: Vec<DistributionCurve>,
+}
+
+/// Generate Maxwell–Boltzmann distribution curves
+///
+/// Speed mode: f(v) = 4π·(M/(2πRT))^(3/2)·v²·exp(-Mv²/(2RT))
+/// Energy mode: f(E) = 2·√(E/π)·(1/RT)^(3/2)·exp(-E/RT)
+///
+/// The fraction of molecules with kinetic energy above E_a is
+/// erfc(√x) + 2·√(x/π)·exp(-x) with x = E_a/(RT), in both modes.
+///
+/// Arguments:
+/// - molar_mass: Molar mass (g/mol), used in speed mode
+/// - temperatures_json: Array of temperatures (K)
+/// - mode: "speed" (x in m/s) or "energy" (x in kJ/mol)
+/// - threshold: Threshold energy (kJ/mol); values ≤ 0 disable the shaded region
+/// - points: Number of points per curve
+/// - max_value: Upper end of the x range; values ≤ 0 choose a range automatically
+#[wasm_func]
+pub fn maxwell_boltzmann_distribution(
+    molar_mass_bytes: &[u8],
+    temperatures_json: &[u8],
+    mode_bytes: &[u8],
+    threshold_bytes: &[u8],
+    points_bytes: &[u8],
+    max_value_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molar_mass = parse_number(molar_mass_bytes, "molar mass")?;
+    let temperatures: Vec<f64> = parse_json(temperatures_json, "temperatures")?;
+    let mode: String = parse_arg(mode_bytes, "mode")?;
+    let threshold = parse_number(threshold_bytes, "threshold")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let max_value = parse_number(max_value_bytes, "maximum value")?;
+
+    if temperatures.is_empty() {
+        return Err("At least one temperature is required".to_string());
+    }
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    let speed_mode = match mode.as_str() {
+        "speed" => true,
+        "energy" => false,
+        _ => return Err(format!("Unsupported distribution mode: {}", mode)),
+    };
+    if speed_mode {
+        check_positive("molar mass", molar_mass)?;
+    }
+
+    let m = molar_mass / 1000.0; // kg/mol
+    let t_max = temperatures.iter().cloned().fold(f64::MIN, f64::max);
+    let threshold = if threshold > 0.0 { Some(threshold) } else { None };
+
+    // Threshold expressed on the x axis of the selected mode
+    let threshold_x = threshold.map(|ea| {
+        if speed_mode {
+            (2.0 * ea * 1000.0 / m).sqrt()
+        } else {
+            ea
+        }
+    });
+
+    let x_max = if max_value > 0.0 {
+        max_value
+    } else if speed_mode {
+        // Four times the most probable speed at the highest temperature
+        let auto = 4.0 * (2.0 * R * t_max / m).sqrt();
+        threshold_x.map_or(auto, |v| auto.max(1.2 * v))
+    } else {
+        let auto = 8.0 * R * t_max / 1000.0;
+        threshold_x.map_or(auto, |e| auto.max(1.2 * e))
+    };
+
+    let density = |x: f64, temperature: f64| -> f64 {
+        if speed_mode {
+            let a = m / (2.0 * R * temperature);
+            4.0 * std::f64::consts::PI * (a / std::f64::consts::PI).powf(1.5) * x * x * (-a * x * x).exp()
+        } else {
+            // Energy in J/mol internally, density per kJ/mol
+            let e = x * 1000.0;
+            let rt = R * temperature;
+            1000.0 * 2.0 * (e / std::f64::consts::PI).sqrt() * rt.powf(-1.5) * (-e / rt).exp()
+        }
+    };
+
+    let mut curves = Vec::with_capacity(temperatures.len());
+    for &temperature in &temperatures {
+        check_temperature(temperature)?;
+
+        let curve: Vec<(f64, f64)> = (0..points)
+            .map(|i| {
+                let x = x_max * i as f64 / (points - 1) as f64;
+                (x, density(x, temperature))
+            })
+            .collect();
+
+        let (fraction_above_threshold, shaded) = match (threshold, threshold_x) {
+            (Some(ea), Some(x0)) => {
+                let x = ea * 1000.0 / (R * temperature);
+                let fraction = erfc(x.sqrt()) + 2.0 * (x / std::f64::consts::PI).sqrt() * (-x).exp();
+                let mut region = vec![(x0, density(x0, temperature))];
+                region.extend(curve.iter().filter(|(x, _)| *x > x0).cloned());
+                (Some(fraction), Some(region))
+            }
+            _ => (None, None),
+        };
+
+        curves.push(DistributionCurve {
+            temperature,
+            points: curve,
+            fraction_above_threshold,
+            shaded,
+        });
+    }
+
+    let result = DistributionResult {
+        mode,
+        x_unit: if speed_mode { "m/s" } else { "kJ/mol" }.to_string(),
+        y_unit: if speed_mode { "s/m" } else { "mol/kJ" }.to_string(),
+        threshold,
+        curves,
+    };
+
+    to_json(&result)
+}
+
+/// Characteristic molecular speeds of a gas
+#[derive(Serialize)]
+struct MolecularSpeeds {
+    /// Root-mean-square speed √(3RT/M)
+    rms: CalculationResult,
+    /// Mean speed √(8RT/(πM))
+    mean: CalculationResult,
+    /// Most probable speed √(2RT/M)
+    most_probable: CalculationResult,
+    molar_mass: f64,
+    temperature: f64,
+}
+
+/// Calculate the rms, mean and most probable speeds of a gas
+/// v_rms = √(3RT/M), v_mean = √(8RT/(πM)), v_mp = √(2RT/M)
+///
+/// Arguments:
+/// - molar_mass: Molar mass (g/mol)
+/// - T: Temperature (K)
+/// - unit: Speed unit: "m/s", "km/s" or "km/h"
+#[wasm_func]
+pub fn molecular_speeds(
+    molar_mass_bytes: &[u8],
+    temperature_bytes: &[u8],
+    unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molar_mass = parse_number(molar_mass_bytes, "molar mass")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let unit: String = parse_arg(unit_bytes, "speed unit")?;
+
+    check_positive("molar mass", molar_mass)?;
+    check_temperature(temperature)?;
+    let (per_unit, symbol) = match unit.as_str() {
+        "" | "m/s" => (1.0, "m/s"),
+        "km/s" => (1000.0, "km/s"),
+        "km/h" => (1.0 / 3.6, "km/h"),
+        _ => return Err(format!("Unknown speed unit: {} (expected m/s, km/s or km/h)", unit)),
+    };
+
+    let rt_over_m = R * temperature / (molar_mass / 1000.0);
+    let speed = |factor: f64| {
+        CalculationResult::new((factor * rt_over_m).sqrt() / per_unit, symbol)
+    };
+
+    let result = MolecularSpeeds {
+        rms: speed(3.0),
+        mean: speed(8.0 / std::f64::consts::PI),
+        most_probable: speed(2.0),
+        molar_mass,
+        temperature,
+    };
+
+    to_json(&result)
+}
+
+/// Collision statistics of one species in a gas or gas mixture
+#[derive(Serialize)]
+struct SpeciesCollisions {
+    /// Number density (m⁻³)
+    number_density: f64,
+    /// Mean speed √(8RT/(πM)) (m/s)
+    mean_speed: f64,
+    /// Collisions per molecule per second with all molecules (s⁻¹)
+    collision_frequency: CalculationResult,
+    /// Mean speed over collision frequency (m)
+    mean_free_path: CalculationResult,
+}
+
+/// Collision frequencies, mean free paths and collision densities
+#[derive(Serialize)]
+struct CollisionProperties {
+    species: Vec<SpeciesCollisions>,
+    /// Z_AA = σ·⟨v⟩·n²/√2 for a pure gas, or Z_AB = σ·⟨v_rel⟩·nA·nB for a mixture (m⁻³·s⁻¹)
+    collision_density: CalculationResult,
+    /// Cross-section of the counted collisions (m²)
+    cross_section: f64,
+    /// Mean relative speed of the counted collisions (m/s)
+    mean_relative_speed: f64,
+    /// σ·⟨v_rel⟩·NA, the collision-theory A factor (L/(mol·s))
+    pre_exponential: f64,
+}
+
+/// Calculate mean free paths, collision frequencies and collision densities
+///
+/// Pure gas: λ = kB·T/(√2·σ·p), z = √2·σ·⟨v⟩·n, Z_AA = σ·⟨v⟩·n²/√2.
+/// Binary mixture: σ_AB = π·((dA + dB)/2)², ⟨v_rel⟩ = √(8·kB·T/(π·μ)),
+/// Z_AB = σ_AB·⟨v_rel⟩·nA·nB, and each species collides with both itself and the
+/// other species. The collision density divided by nA·nB (times NA) is the
+/// pre-exponential factor of `calculate_rate_constant_collision`.
+///
+/// Arguments:
+/// - molar_masses_json: Molar masses (g/mol), one for a pure gas or two for a mixture
+/// - diameters_json: Collision diameters (nm), one per species
+/// - pressures_json: Partial pressures, one per species
+/// - T: Temperature (K)
+/// - pressure_unit: Unit of the pressures
+#[wasm_func]
+pub fn collision_properties(
+    molar_masses_json: &[u8],
+    diameters_json: &[u8],
+    pressures_json: &[u8],
+    temperature_bytes: &[u8],
+    pressure_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molar_masses: Vec<f64> = parse_json(molar_masses_json, "molar masses")?;
+    let diameters: Vec<f64> = parse_json(diameters_json, "collision diameters")?;
+    let pressures: Vec<f64> = parse_json(pressures_json, "pressures")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let pressure_unit: String = parse_arg(pressure_unit_bytes, "pressure unit")?;
+
+    let count = molar_masses.len();
+    if !(1..=2).contains(&count) || diameters.len() != count || pressures.len() != count {
+        return Err(
+            "Give one or two species with a molar mass, diameter and pressure each".to_string(),
+        );
+    }
+    for i in 0..count {
+        check_positive(&format!("molar mass of species {}", i + 1), molar_masses[i])?;
+        check_positive(&format!("collision diameter of species {}", i + 1), diameters[i])?;
+        check_positive(&format!("pressure of species {}", i + 1), pressures[i])?;
+    }
+    check_temperature(temperature)?;
+    let (pa_per_unit, _) = parse_pressure_unit(&pressure_unit)?;
+
+    let pi = std::f64::consts::PI;
+    // Cross-section and mean relative speed of an i–j pair
+    let pair = |i: usize, j: usize| {
+        let d = 0.5 * (diameters[i] + diameters[j]) * 1e-9;
+        let reduced_mass = molar_masses[i] * molar_masses[j] / (molar_masses[i] + molar_masses[j]);
+        let mu = reduced_mass / 1000.0 / AVOGADRO;
+        (pi * d * d, (8.0 * BOLTZMANN * temperature / (pi * mu)).sqrt())
+    };
+    let densities: Vec<f64> = pressures
+        .iter()
+        .map(|p| p * pa_per_unit / (BOLTZMANN * temperature))
+        .collect();
+
+    let species = (0..count)
+        .map(|i| {
+            let mean_speed = (8.0 * BOLTZMANN * temperature * AVOGADRO / (pi * molar_masses[i] / 1000.0)).sqrt();
+            let frequency: f64 = (0..count)
+                .map(|j| {
+                    let (sigma, relative) = pair(i, j);
+                    sigma * relative * densities[j]
+                })
+                .sum();
+            SpeciesCollisions {
+                number_density: densities[i],
+                mean_speed,
+                collision_frequency: CalculationResult::new(frequency, "s⁻¹"),
+                mean_free_path: CalculationResult::new(mean_speed / frequency, "m"),
+            }
+        })
+        .collect();
+
+    let (cross_section, mean_relative_speed) = pair(0, count - 1);
+    let collision_density = if count == 1 {
+        0.5 * cross_section * mean_relative_speed * densities[0] * densities[0]
+    } else {
+        cross_section * mean_relative_speed * densities[0] * densities[1]
+    };
+
+    let result = CollisionProperties {
+        species,
+        collision_density: CalculationResult::new(collision_density, "m⁻³·s⁻¹"),
+        cross_section,
+        mean_relative_speed,
+        // m³/(mol·s) → L/(mol·s)
+        pre_exponential: cross_section * mean_relative_speed * AVOGADRO * 1000.0,
+    };
+
+    to_json(&result)
+}
+
+/// Effusion of two gases, with an optional unknown molar mass or isotope enrichment
+#[derive(Deserialize)]
+struct EffusionInput {
+    /// Gas 1 (none when solved from the rate ratio)
+    gas: Option<MolarMassSpec>,
+    /// Reference gas 2
+    reference: MolarMassSpec,
+    /// Measured rate ratio r1/r2, used to find an unknown molar mass of gas 1
+    rate_ratio: Option<f64>,
+    /// Mole fraction of the lighter gas in the feed
+    initial_fraction: Option<f64>,
+    /// Mole fraction of the lighter gas wanted in the product
+    target_fraction: Option<f64>,
+}
+
+/// Isotope enrichment by repeated effusion stages
+#[derive(Serialize)]
+struct EffusionEnrichment {
+    /// Lighter-gas fraction after one ideal stage
+    single_stage_fraction: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stages: Option<f64>,
+}
+
+/// Relative effusion rates and times of two gases
+#[derive(Serialize)]
+struct EffusionResult {
+    /// Molar masses of gas 1 and gas 2 (g/mol)
+    molar_masses: (f64, f64),
+    /// r1/r2 = √(M2/M1)
+    rate_ratio: f64,
+    /// t1/t2 = √(M1/M2) for the same amount
+    time_ratio: f64,
+    /// Ideal single-stage separation factor α = √(M_heavy/M_light)
+    separation_factor: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enrichment: Option<EffusionEnrichment>,
+}
+
+/// Apply Graham's law of effusion
+/// r1/r2 = √(M2/M1), t1/t2 = √(M1/M2)
+///
+/// Without gas 1 its molar mass follows from a measured rate ratio: M1 = M2/(r1/r2)².
+/// Enrichment uses the ideal separation factor α = √(M_heavy/M_light) per stage, so
+/// n = ln[(x/(1 - x))/(x0/(1 - x0))]/ln α stages take the lighter-gas fraction from x0 to x.
+///
+/// Arguments:
+/// - input_json: Gases and options, see `EffusionInput`
+#[wasm_func]
+pub fn graham_effusion(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: EffusionInput = parse_json(input_json, "effusion input")?;
+
+    let m2 = input.reference.value()?;
+    let m1 = match (&input.gas, input.rate_ratio) {
+        (Some(gas), None) => gas.value()?,
+        (None, Some(ratio)) => {
+            check_positive("rate ratio", ratio)?;
+            m2 / (ratio * ratio)
+        }
+        _ => return Err("Give exactly one of gas and rate ratio".to_string()),
+    };
+    let separation_factor = (m1.max(m2) / m1.min(m2)).sqrt();
+
+    let fraction = |field: &str, x: f64| -> Result<f64, String> {
+        check_fraction(field, x)?;
+        Ok(x / (1.0 - x))
+    };
+    let enrichment = match (input.initial_fraction, input.target_fraction) {
+        (Some(x0), target) => {
+            if separation_factor == 1.0 {
+                return Err("Gases of equal molar mass cannot be separated by effusion".to_string());
+            }
+            let ratio = fraction("initial fraction", x0)? * separation_factor;
+            let stages = match target {
+                Some(x) => Some(
+                    (fraction("target fraction", x)? / fraction("initial fraction", x0)?).ln()
+                        / separation_factor.ln(),
+                ),
+                None => None,
+            };
+            Some(EffusionEnrichment {
+                single_stage_fraction: ratio / (1.0 + ratio),
+                stages,
+            })
+        }
+        (None, Some(_)) => return Err("A target fraction needs the initial fraction".to_string()),
+        (None, None) => None,
+    };
+
+    let result = EffusionResult {
+        molar_masses: (m1, m2),
+        rate_ratio: (m2 / m1).sqrt(),
+        time_ratio: (m1 / m2).sqrt(),
+        separation_factor,
+        enrichment,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// KINETICS SIMULATION
+// ============================================================================
+
+/// Time-course data for plotting concentration curves
+#[derive(Serialize)]
+struct TimeCourse {
+    times: Vec<f64>,
+    /// Species name → array of (t, concentration) pairs
+    curves: std::collections::BTreeMap<String, Vec<(f64, f64)>>,
+    /// Rows of (t, [species]...) in the order given by `species`
+    rows: Vec<Vec<f64>>,
+    species: Vec<String>,
+    time_unit: String,
+    concentration_unit: String,
+}
+
+impl TimeCourse {
+    /// Build a time course from sampled times and one concentration column per species
+    fn new(times: Vec<f64>, species: Vec<(&str, Vec<f64>)>) -> Self {
+        let rows = times
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| {
+                let mut row = vec![t];
+                row.extend(species.iter().map(|(_, values)| values[i]));
+                row
+            })
+            .collect();
+
+        let curves = species
+            .iter()
+            .map(|(name, values)| {
+                let pairs = times.iter().cloned().zip(values.iter().cloned()).collect();
+                (name.to_string(), pairs)
+            })
+            .collect();
+
+        Self {
+            times,
+            curves,
+            rows,
+            species: species.iter().map(|(name, _)| name.to_string()).collect(),
+            time_unit: "s".to_string(),
+            concentration_unit: "mol/L".to_string(),
+        }
+    }
+
+    /// Label the time axis with the unit the inputs were given in
+    fn with_time_unit(mut self, time_unit: &str) -> Self {
+        self.time_unit = time_unit.to_string();
+        self
+    }
+}
+
+/// Evenly spaced sample times from 0 to t_end (inclusive)
+fn sample_times(t_end: f64, points: usize) -> Vec<f64> {
+    (0..points)
+        .map(|i| t_end * i as f64 / (points - 1) as f64)
+        .collect()
+}
+
+/// Integrated rate law for A → B with rate = k[A]^n
+fn integrated_concentration(k: f64, order: f64, initial_conc: f64, t: f64) -> f64 {
+    if order == 1.0 {
+        initial_conc * (-k * t).exp()
+    } else {
+        // [A]^(1-n) = [A]0^(1-n) + (n-1)·k·t, clamped at complete consumption
+        let base = initial_conc.powf(1.0 - order) + (order - 1.0) * k * t;
+        if base <= 0.0 {
+            0.0
+        } else {
+            base.powf(1.0 / (1.0 - order))
+        }
+    }
+}
+
+/// Generate concentration-vs-time data for A → B with rate = k[A]^n
+///
+/// Arguments:
+/// - k: Rate constant
+/// - order: Reaction order n (any non-negative value, e.g. 0, 1, 2 or 1.5)
+/// - initial_conc: Initial concentration [A]0 (mol/L)
+/// - t_end: Final time (time unit); values ≤ 0 simulate five half-lives
+/// - points: Number of sample points
+/// - time_unit: Time unit of all times and rate constants (s, min, h, d or yr)
+///
+/// Returns: Time course with curves for A (reactant) and B (product)
+#[wasm_func]
+pub fn concentration_time_curve(
+    k_bytes: &[u8],
+    order_bytes: &[u8],
+    initial_conc_bytes: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k = parse_number(k_bytes, "k")?;
+    let order = parse_number(order_bytes, "order")?;
+    let initial_conc = parse_number(initial_conc_bytes, "initial concentration")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_positive("rate constant", k)?;
+    check_non_negative("reaction order", order)?;
+    check_positive("initial concentration", initial_conc)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let t_end = if t_end > 0.0 {
+        t_end
+    } else {
+        // t_1/2 = (2^(n-1) - 1) / ((n-1)·k·[A]0^(n-1)), ln(2)/k for n = 1
+        let half_life = if order == 1.0 {
+            2_f64.ln() / k
+        } else {
+            (2_f64.powf(order - 1.0) - 1.0) / ((order - 1.0) * k * initial_conc.powf(order - 1.0))
+        };
+        5.0 * half_life
+    };
+
+    let times = sample_times(t_end, points);
+    let a: Vec<f64> = times
+        .iter()
+        .map(|&t| integrated_concentration(k, order, initial_conc, t))
+        .collect();
+    let b: Vec<f64> = a.iter().map(|c| initial_conc - c).collect();
+
+    let course = TimeCourse::new(times, vec![("A", a), ("B", b)]).with_time_unit(time_unit);
+
+    to_json(&course)
+}
+
+/// Solve the dense linear system A·x = b by Gaussian elimination with partial pivoting
+fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Result<Vec<f64>, String> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap();
+        if a[pivot][col].abs() < 1e-300 {
+            return Err("Singular matrix in linear solve".to_string());
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            if factor != 0.0 {
+                for (x, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x -= factor * p;
+                }
+                b[row] -= factor * b[col];
+            }
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|c| a[row][c] * x[c]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Ok(x)
+}
+
+/// Elementary step of a reaction mechanism (mass-action kinetics)
+#[derive(Deserialize)]
+struct ElementaryStep {
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    k: f64,
+}
+
+/// Mechanism compiled to species indices
+struct Mechanism {
+    species: Vec<String>,
+    /// (species index, coefficient) pairs per step
+    reactants: Vec<Vec<(usize, f64)>>,
+    products: Vec<Vec<(usize, f64)>>,
+    rate_constants: Vec<f64>,
+}
+
+impl Mechanism {
+    fn new(steps: Vec<ElementaryStep>, initial: &HashMap<String, f64>) -> Result<Self, String> {
+        let mut species: Vec<String> = Vec::new();
+        let index_of = |name: &str, species: &mut Vec<String>| -> usize {
+            match species.iter().position(|s| s == name) {
+                Some(i) => i,
+                None => {
+                    species.push(name.to_string());
+                    species.len() - 1
+                }
+            }
+        };
+
+        let mut reactants = Vec::with_capacity(steps.len());
+        let mut products = Vec::with_capacity(steps.len());
+        let mut rate_constants = Vec::with_capacity(steps.len());
+
+        for (i, step) in steps.iter().enumerate() {
+            check_non_negative(&format!("rate constant of step {}", i + 1), step.k)?;
+            reactants.push(
+                step.reactants
+                    .iter()
+                    .map(|(name, coeff)| (index_of(name, &mut species), *coeff))
+                    .collect(),
+            );
+            products.push(
+                step.products
+                    .iter()
+                    .map(|(name, coeff)| (index_of(name, &mut species), *coeff))
+                    .collect(),
+            );
+            rate_constants.push(step.k);
+        }
+
+        // Species that only appear in the initial conditions are carried along unchanged
+        let mut extra: Vec<&String> = initial.keys().filter(|name| !species.contains(name)).collect();
+        extra.sort();
+        for name in extra {
+            species.push(name.clone());
+        }
+
+        Ok(Self {
+            species,
+            reactants,
+            products,
+            rate_constants,
+        })
+    }
+
+    /// Rate of each step: r = k·Π[c_i]^ν_i
+    fn rates(&self, c: &[f64]) -> Vec<f64> {
+        self.reactants
+            .iter()
+            .zip(&self.rate_constants)
+            .map(|(reactants, k)| {
+                reactants
+                    .iter()
+                    .fold(*k, |rate, &(i, nu)| rate * c[i].max(0.0).powf(nu))
+            })
+            .collect()
+    }
+
+    /// Time derivatives dc/dt
+    fn derivatives(&self, c: &[f64]) -> Vec<f64> {
+        let mut dcdt = vec![0.0; c.len()];
+        for (step, rate) in self.rates(c).into_iter().enumerate() {
+            for &(i, nu) in &self.reactants[step] {
+                dcdt[i] -= nu * rate;
+            }
+            for &(i, nu) in &self.products[step] {
+                dcdt[i] += nu * rate;
+            }
+        }
+        dcdt
+    }
+
+    /// Analytic Jacobian ∂(dc_i/dt)/∂c_j
+    fn jacobian(&self, c: &[f64]) -> Vec<Vec<f64>> {
+        let n = c.len();
+        let mut jac = vec![vec![0.0; n]; n];
+        for (step, reactants) in self.reactants.iter().enumerate() {
+            for (m, &(j, nu_j)) in reactants.iter().enumerate() {
+                // ∂r/∂c_j = k·ν_j·c_j^(ν_j-1)·Π_(i≠j) c_i^ν_i
+                let mut drdc = self.rate_constants[step] * nu_j * c[j].max(0.0).powf(nu_j - 1.0);
+                for (l, &(i, nu_i)) in reactants.iter().enumerate() {
+                    if l != m {
+                        drdc *= c[i].max(0.0).powf(nu_i);
+                    }
+                }
+                if !drdc.is_finite() {
+                    continue;
+                }
+                for &(i, nu) in reactants {
+                    jac[i][j] -= nu * drdc;
+                }
+                for &(i, nu) in &self.products[step] {
+                    jac[i][j] += nu * drdc;
+                }
+            }
+        }
+        jac
+    }
+}
+
+/// One ROS2 step (L-stable Rosenbrock, order 2 with embedded order-1 estimate)
+/// Returns the new state and the scaled error norm
+fn rosenbrock_step(mechanism: &Mechanism, c: &[f64], h: f64, rtol: f64, atol: f64) -> Result<(Vec<f64>, f64), String> {
+    let n = c.len();
+    let gamma = 1.0 + 1.0 / 2_f64.sqrt();
+    let jac = mechanism.jacobian(c);
+
+    // W = I - γ·h·J
+    let w: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if i == j { 1.0 } else { 0.0 } - gamma * h * jac[i][j])
+                .collect()
+        })
+        .collect();
+
+    let k1 = solve_linear(w.clone(), mechanism.derivatives(c))?;
+    let c1: Vec<f64> = (0..n).map(|i| c[i] + h * k1[i]).collect();
+    let f1 = mechanism.derivatives(&c1);
+    let k2 = solve_linear(w, (0..n).map(|i| f1[i] - 2.0 * k1[i]).collect())?;
+
+    let c_new: Vec<f64> = (0..n).map(|i| c[i] + h * (1.5 * k1[i] + 0.5 * k2[i])).collect();
+
+    // Difference to the linearly implicit Euler solution c + h·k1
+    let error = ((0..n)
+        .map(|i| {
+            let scale = atol + rtol * c[i].abs().max(c_new[i].abs());
+            (0.5 * h * (k1[i] + k2[i]) / scale).powi(2)
+        })
+        .sum::<f64>()
+        / n.max(1) as f64)
+        .sqrt();
+
+    Ok((c_new, error))
+}
+
+/// Integrate a reaction mechanism of elementary steps (stiff-capable)
+///
+/// Uses an adaptive L-stable Rosenbrock method (ROS2) with an analytic
+/// mass-action Jacobian, so both fast and slow steps can be mixed freely.
+///
+/// Arguments:
+/// - steps_json: Array of steps, each {"reactants": [(species, coeff)...],
+///   "products": [(species, coeff)...], "k": rate constant}
+/// - initial_json: Dictionary of initial concentrations (mol/L); missing species start at 0
+/// - t_end: Final time (time unit)
+/// - points: Number of output points
+/// - rtol: Relative tolerance of the step-size control
+/// - time_unit: Time unit of all times and rate constants (s, min, h, d or yr)
+///
+/// Returns: Time course with one curve per species
+#[wasm_func]
+pub fn simulate_mechanism(
+    steps_json: &[u8],
+    initial_json: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    rtol_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let steps: Vec<ElementaryStep> = parse_json(steps_json, "mechanism steps")?;
+    let initial: HashMap<String, f64> = parse_json(initial_json, "initial concentrations")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let rtol = parse_number(rtol_bytes, "tolerance")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    if steps.is_empty() {
+        return Err("At least one mechanism step is required".to_string());
+    }
+    check_positive("end time", t_end)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    check_positive("tolerance", rtol)?;
+    for (name, &c0) in &initial {
+        check_non_negative(&format!("initial concentration of {}", name), c0)?;
+    }
+
+    let mechanism = Mechanism::new(steps, &initial)?;
+    let atol = rtol * 1e-4 * initial.values().cloned().fold(0.0, f64::max).max(1e-12);
+    const MAX_STEPS: usize = 1_000_000;
+
+    let mut c: Vec<f64> = mechanism
+        .species
+        .iter()
+        .map(|name| initial.get(name).cloned().unwrap_or(0.0))
+        .collect();
+
+    let times = sample_times(t_end, points);
+    let mut columns: Vec<Vec<f64>> = vec![Vec::with_capacity(points); c.len()];
+    for (i, value) in c.iter().enumerate() {
+        columns[i].push(*value);
+    }
+
+    let mut t = 0.0;
+    let mut h = t_end / (points as f64 * 100.0);
+    let mut step_count = 0;
+
+    for &t_out in &times[1..] {
+        while t < t_out {
+            step_count += 1;
+            if step_count > MAX_STEPS {
+                return Err(format!("Integration did not converge before t = {}", t_out));
+            }
+
+            let h_try = h.min(t_out - t);
+            let (c_new, error) = rosenbrock_step(&mechanism, &c, h_try, rtol, atol)?;
+
+            if error <= 1.0 {
+                t = if h_try == t_out - t { t_out } else { t + h_try };
+                c = c_new.into_iter().map(|v| v.max(0.0)).collect();
+            }
+
+            // Standard step-size controller for an order-2 method
+            let factor = if error == 0.0 { 5.0 } else { (0.9 / error.sqrt()).clamp(0.2, 5.0) };
+            h = h_try * factor;
+            if h < 1e-14 * t_end {
+                return Err(format!("Step size underflow at t = {}", t));
+            }
+        }
+
+        for (i, value) in c.iter().enumerate() {
+            columns[i].push(*value);
+        }
+    }
+
+    let species: Vec<(&str, Vec<f64>)> = mechanism
+        .species
+        .iter()
+        .map(|name| name.as_str())
+        .zip(columns)
+        .collect();
+    let course = TimeCourse::new(times, species).with_time_unit(time_unit);
+
+    to_json(&course)
+}
+
+/// Time and concentration of an intermediate's maximum
+#[derive(Serialize)]
+struct IntermediateMaximum {
+    time: f64,
+    concentration: f64,
+}
+
+/// Closed-form consecutive-reaction result
+#[derive(Serialize)]
+struct ConsecutiveKinetics {
+    #[serde(flatten)]
+    course: TimeCourse,
+    /// Observed relaxation rates λ1 ≥ λ2 (s⁻¹)
+    eigenvalues: (f64, f64),
+    #[serde(skip_serializing_if = "Option::is_none")]
+    intermediate_max: Option<IntermediateMaximum>,
+}
+
+/// Analytic kinetics of A ⇌ B → C (k1, k-1, k2) starting from pure A
+///
+/// With κ = k-1 + k2 and λ1,2 = ½[(k1 + κ) ± √((k1 + κ)² - 4·k1·k2)]:
+/// [B] = k1·[A]0·(e^(-λ2·t) - e^(-λ1·t)) / (λ1 - λ2)
+/// [A] = [A]0·[(κ - λ2)·e^(-λ2·t) - (κ - λ1)·e^(-λ1·t)] / (λ1 - λ2)
+/// [C] = [A]0 - [A] - [B]
+///
+/// For k-1 = 0 this is the classic A → B → C result with λ = k1, k2,
+/// t_max = ln(k1/k2) / (k1 - k2).
+///
+/// Arguments:
+/// - k1: Rate constant of A → B (time unit⁻¹)
+/// - k2: Rate constant of B → C (time unit⁻¹)
+/// - k_reverse: Rate constant of B → A (time unit⁻¹), 0 for an irreversible first step
+/// - initial_conc: Initial concentration of A (mol/L)
+/// - t_end: Final time (time unit); values ≤ 0 choose five slow relaxation times
+/// - points: Number of sample points
+/// - time_unit: Time unit of all times and rate constants (s, min, h, d or yr)
+#[wasm_func]
+pub fn consecutive_reaction_kinetics(
+    k1_bytes: &[u8],
+    k2_bytes: &[u8],
+    k_reverse_bytes: &[u8],
+    initial_conc_bytes: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k1 = parse_number(k1_bytes, "k1")?;
+    let k2 = parse_number(k2_bytes, "k2")?;
+    let k_reverse = parse_number(k_reverse_bytes, "k-1")?;
+    let initial_conc = parse_number(initial_conc_bytes, "initial concentration")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_positive("k1", k1)?;
+    check_non_negative("k2", k2)?;
+    check_non_negative("k-1", k_reverse)?;
+    check_positive("initial concentration", initial_conc)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let kappa = k_reverse + k2;
+    let sum = k1 + kappa;
+    let lambda1 = 0.5 * (sum + (sum * sum - 4.0 * k1 * k2).max(0.0).sqrt());
+    let lambda2 = k1 * k2 / lambda1; // avoids cancellation in the "-" root
+    let degenerate = (lambda1 - lambda2).abs() <= 1e-12 * lambda1;
+
+    let concentrations = |t: f64| -> (f64, f64) {
+        if degenerate {
+            let decay = (-lambda1 * t).exp();
+            (
+                initial_conc * decay * (1.0 + (kappa - lambda1) * t),
+                k1 * initial_conc * t * decay,
+            )
+        } else {
+            let e1 = (-lambda1 * t).exp();
+            let e2 = (-lambda2 * t).exp();
+            (
+                initial_conc * ((kappa - lambda2) * e2 - (kappa - lambda1) * e1) / (lambda1 - lambda2),
+                k1 * initial_conc * (e2 - e1) / (lambda1 - lambda2),
+            )
+        }
+    };
+
+    // [B] peaks where λ1·e^(-λ1·t) = λ2·e^(-λ2·t); without a B → C drain it never peaks
+    let intermediate_max = if lambda2 > 0.0 {
+        let time = if degenerate {
+            1.0 / lambda1
+        } else {
+            (lambda1 / lambda2).ln() / (lambda1 - lambda2)
+        };
+        Some(IntermediateMaximum {
+            time,
+            concentration: concentrations(time).1,
+        })
+    } else {
+        None
+    };
+
+    let t_end = if t_end > 0.0 {
+        t_end
+    } else if lambda2 > 0.0 {
+        5.0 / lambda2
+    } else {
+        5.0 / lambda1
+    };
+
+    let times = sample_times(t_end, points);
+    let (a, b): (Vec<f64>, Vec<f64>) = times.iter().map(|&t| concentrations(t)).unzip();
+    let c: Vec<f64> = a
+        .iter()
+        .zip(&b)
+        .map(|(a, b)| (initial_conc - a - b).max(0.0))
+        .collect();
+
+    let result = ConsecutiveKinetics {
+        course: TimeCourse::new(times, vec![("A", a), ("B", b), ("C", c)]).with_time_unit(time_unit),
+        eigenvalues: (lambda1, lambda2),
+        intermediate_max,
+    };
+
+    to_json(&result)
+}
+
+/// Parallel (competing) first-order reaction result
+#[derive(Serialize)]
+struct ParallelKinetics {
+    #[serde(flatten)]
+    course: TimeCourse,
+    /// Effective overall rate constant k1 + k2 (s⁻¹)
+    k_overall: f64,
+    /// Fraction of A converted to B and to C
+    branching_ratios: (f64, f64),
+    /// Constant product ratio [B]/[C] = k1/k2 (null when k2 = 0)
+    selectivity: Option<f64>,
+    half_life: f64,
+}
+
+/// Analytic kinetics of parallel first-order reactions A → B (k1) and A → C (k2)
+///
+/// [A] = [A]0·e^(-(k1+k2)·t)
+/// [B] = k1/(k1+k2)·([A]0 - [A]), [C] = k2/(k1+k2)·([A]0 - [A])
+///
+/// Arguments:
+/// - k1: Rate constant of A → B (time unit⁻¹)
+/// - k2: Rate constant of A → C (time unit⁻¹)
+/// - initial_conc: Initial concentration of A (mol/L)
+/// - t_end: Final time (time unit); values ≤ 0 simulate five half-lives
+/// - points: Number of sample points
+/// - time_unit: Time unit of all times and rate constants (s, min, h, d or yr)
+#[wasm_func]
+pub fn parallel_reaction_kinetics(
+    k1_bytes: &[u8],
+    k2_bytes: &[u8],
+    initial_conc_bytes: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k1 = parse_number(k1_bytes, "k1")?;
+    let k2 = parse_number(k2_bytes, "k2")?;
+    let initial_conc = parse_number(initial_conc_bytes, "initial concentration")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_non_negative("k1", k1)?;
+    check_non_negative("k2", k2)?;
+    check_positive("k1 + k2", k1 + k2)?;
+    check_positive("initial concentration", initial_conc)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let k_overall = k1 + k2;
+    let half_life = 2_f64.ln() / k_overall;
+    let t_end = if t_end > 0.0 { t_end } else { 5.0 * half_life };
+    let (fraction_b, fraction_c) = (k1 / k_overall, k2 / k_overall);
+
+    let times = sample_times(t_end, points);
+    let a: Vec<f64> = times
+        .iter()
+        .map(|&t| initial_conc * (-k_overall * t).exp())
+        .collect();
+    let b: Vec<f64> = a.iter().map(|a| fraction_b * (initial_conc - a)).collect();
+    let c: Vec<f64> = a.iter().map(|a| fraction_c * (initial_conc - a)).collect();
+
+    let result = ParallelKinetics {
+        course: TimeCourse::new(times, vec![("A", a), ("B", b), ("C", c)]).with_time_unit(time_unit),
+        k_overall,
+        branching_ratios: (fraction_b, fraction_c),
+        selectivity: if k2 > 0.0 { Some(k1 / k2) } else { None },
+        half_life,
+    };
+
+    to_json(&result)
+}
+
+/// Reversible first-order relaxation result
+#[derive(Serialize)]
+struct RelaxationKinetics {
+    #[serde(flatten)]
+    course: TimeCourse,
+    /// Relaxation time τ = 1/(kf + kr) (s)
+    relaxation_time: f64,
+    /// Equilibrium constant K = kf/kr = [B]eq/[A]eq
+    equilibrium_constant: f64,
+    equilibrium_a: f64,
+    equilibrium_b: f64,
+}
+
+/// Analytic kinetics of the reversible first-order reaction A ⇌ B
+///
+/// [A] = [A]eq + ([A]0 - [A]eq)·e^(-t/τ), τ = 1/(kf + kr)
+/// [A]eq = kr·([A]0 + [B]0)/(kf + kr), K = kf/kr
+///
+/// Arguments:
+/// - kf: Forward rate constant (time unit⁻¹)
+/// - kr: Reverse rate constant (time unit⁻¹)
+/// - initial_a: Initial concentration of A (mol/L)
+/// - initial_b: Initial concentration of B (mol/L)
+/// - t_end: Final time (time unit); values ≤ 0 simulate five relaxation times
+/// - points: Number of sample points
+/// - time_unit: Time unit of all times and rate constants (s, min, h, d or yr)
+#[wasm_func]
+pub fn reversible_reaction_kinetics(
+    kf_bytes: &[u8],
+    kr_bytes: &[u8],
+    initial_a_bytes: &[u8],
+    initial_b_bytes: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let kf = parse_number(kf_bytes, "kf")?;
+    let kr = parse_number(kr_bytes, "kr")?;
+    let initial_a = parse_number(initial_a_bytes, "initial concentration of A")?;
+    let initial_b = parse_number(initial_b_bytes, "initial concentration of B")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_positive("kf", kf)?;
+    check_positive("kr", kr)?;
+    check_non_negative("initial concentration of A", initial_a)?;
+    check_non_negative("initial concentration of B", initial_b)?;
+    check_positive("total initial concentration", initial_a + initial_b)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let tau = 1.0 / (kf + kr);
+    let total = initial_a + initial_b;
+    let equilibrium_a = kr * total / (kf + kr);
+    let equilibrium_b = total - equilibrium_a;
+    let t_end = if t_end > 0.0 { t_end } else { 5.0 * tau };
+
+    let times = sample_times(t_end, points);
+    let a: Vec<f64> = times
+        .iter()
+        .map(|&t| equilibrium_a + (initial_a - equilibrium_a) * (-t / tau).exp())
+        .collect();
+    let b: Vec<f64> = a.iter().map(|a| total - a).collect();
+
+    let result = RelaxationKinetics {
+        course: TimeCourse::new(times, vec![("A", a), ("B", b)]).with_time_unit(time_unit),
+        relaxation_time: tau,
+        equilibrium_constant: kf / kr,
+        equilibrium_a,
+        equilibrium_b,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ENZYME KINETICS
+// ============================================================================
+
+/// Least-squares straight line y = slope·x + intercept
+#[derive(Serialize)]
+struct LinearFit {
+    slope: f64,
+    intercept: f64,
+    r_squared: f64,
+}
+
+/// Ordinary least-squares fit of a straight line
+fn linear_regression(xs: &[f64], ys: &[f64]) -> Result<LinearFit, String> {
+    let n = xs.len();
+    if n < 2 || ys.len() != n {
+        return Err("Linear regression needs at least 2 (x, y) points".to_string());
+    }
+
+    let mean_x = xs.iter().sum::<f64>() / n as f64;
+    let mean_y = ys.iter().sum::<f64>() / n as f64;
+    let sxx: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = xs.iter().zip(ys).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let syy: f64 = ys.iter().map(|y| (y - mean_y).powi(2)).sum();
+
+    if sxx == 0.0 {
+        return Err("Linear regression needs at least two distinct x values".to_string());
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r_squared = if syy == 0.0 { 1.0 } else { (sxy * sxy / (sxx * syy)).min(1.0) };
+
+    Ok(LinearFit {
+        slope,
+        intercept,
+        r_squared,
+    })
+}
+
+/// Michaelis–Menten initial rate v = Vmax·[S]/(Km + [S])
+///
+/// Arguments:
+/// - vmax: Maximum rate Vmax
+/// - km: Michaelis constant Km (same concentration unit as [S])
+/// - substrate: Substrate concentration [S]
+#[wasm_func]
+pub fn michaelis_menten_rate(
+    vmax_bytes: &[u8],
+    km_bytes: &[u8],
+    substrate_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let vmax = parse_number(vmax_bytes, "Vmax")?;
+    let km = parse_number(km_bytes, "Km")?;
+    let substrate = parse_number(substrate_bytes, "substrate concentration")?;
+
+    check_non_negative("Vmax", vmax)?;
+    check_positive("Km", km)?;
+    check_non_negative("substrate concentration", substrate)?;
+
+    let v = vmax * substrate / (km + substrate);
+
+    let result = CalculationResult::new(v, "");
+
+    to_json(&result)
+}
+
+/// Michaelis–Menten parameter fit and linearised data
+#[derive(Serialize)]
+struct MichaelisMentenFit {
+    vmax: f64,
+    km: f64,
+    vmax_error: f64,
+    km_error: f64,
+    r_squared: f64,
+    /// (1/[S], 1/v) points and their straight-line fit
+    lineweaver_burk: Vec<(f64, f64)>,
+    lineweaver_burk_fit: LinearFit,
+    /// (v/[S], v) points and their straight-line fit
+    eadie_hofstee: Vec<(f64, f64)>,
+    eadie_hofstee_fit: LinearFit,
+    /// Fitted ([S], v) curve for plotting
+    curve: Vec<(f64, f64)>,
+}
+
+/// Fit Vmax and Km to ([S], v) data by nonlinear least squares
+///
+/// The Lineweaver–Burk line (1/v = Km/Vmax·1/[S] + 1/Vmax) provides the starting
+/// guess, which is refined with Levenberg–Marquardt on the untransformed data.
+/// Standard errors come from the covariance matrix s²·(JᵀJ)⁻¹.
+///
+/// Arguments:
+/// - data_json: Array of ([S], v) pairs
+/// - points: Number of points in the fitted curve
+#[wasm_func]
+pub fn fit_michaelis_menten(
+    data_json: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let data: Vec<(f64, f64)> = parse_json(data_json, "rate data")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    if data.len() < 3 {
+        return Err("At least 3 ([S], v) points are required".to_string());
+    }
+    for (i, &(s, v)) in data.iter().enumerate() {
+        check_positive(&format!("[S] of point {}", i + 1), s)?;
+        check_positive(&format!("rate of point {}", i + 1), v)?;
+    }
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let lineweaver_burk: Vec<(f64, f64)> = data.iter().map(|&(s, v)| (1.0 / s, 1.0 / v)).collect();
+    let eadie_hofstee: Vec<(f64, f64)> = data.iter().map(|&(s, v)| (v / s, v)).collect();
+    let (lb_x, lb_y): (Vec<f64>, Vec<f64>) = lineweaver_burk.iter().cloned().unzip();
+    let (eh_x, eh_y): (Vec<f64>, Vec<f64>) = eadie_hofstee.iter().cloned().unzip();
+    let lineweaver_burk_fit = linear_regression(&lb_x, &lb_y)?;
+    let eadie_hofstee_fit = linear_regression(&eh_x, &eh_y)?;
+
+    // Starting guess from the double-reciprocal plot, with a fallback for noisy data
+    let s_max = data.iter().map(|d| d.0).fold(0.0, f64::max);
+    let v_max_obs = data.iter().map(|d| d.1).fold(0.0, f64::max);
+    let (mut vmax, mut km) = if lineweaver_burk_fit.intercept > 0.0 && lineweaver_burk_fit.slope > 0.0 {
+        (
+            1.0 / lineweaver_burk_fit.intercept,
+            lineweaver_burk_fit.slope / lineweaver_burk_fit.intercept,
+        )
+    } else {
+        (v_max_obs, s_max / 2.0)
+    };
+
+    let ssr = |vmax: f64, km: f64| -> f64 {
+        data.iter()
+            .map(|&(s, v)| (v - vmax * s / (km + s)).powi(2))
+            .sum()
+    };
+
+    // Levenberg–Marquardt on (Vmax, Km)
+    let mut lambda = 1e-3;
+    let mut current = ssr(vmax, km);
+    for _ in 0..200 {
+        let (mut jtj, mut jtr) = ([[0.0; 2]; 2], [0.0; 2]);
+        for &(s, v) in &data {
+            let denom = km + s;
+            let residual = v - vmax * s / denom;
+            let grad = [s / denom, -vmax * s / (denom * denom)];
+            for i in 0..2 {
+                jtr[i] += grad[i] * residual;
+                for j in 0..2 {
+                    jtj[i][j] += grad[i] * grad[j];
+                }
+            }
+        }
+
+        let a = [
+            [jtj[0][0] * (1.0 + lambda), jtj[0][1]],
+            [jtj[1][0], jtj[1][1] * (1.0 + lambda)],
+        ];
+        let det = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+        if det.abs() < 1e-300 {
+            break;
+        }
+        let step = [
+            (a[1][1] * jtr[0] - a[0][1] * jtr[1]) / det,
+            (a[0][0] * jtr[1] - a[1][0] * jtr[0]) / det,
+        ];
+
+        let (trial_vmax, trial_km) = (vmax + step[0], km + step[1]);
+        let trial = if trial_km > 0.0 { ssr(trial_vmax, trial_km) } else { f64::INFINITY };
+
+        if trial < current {
+            let converged = (current - trial) <= 1e-15 * current.max(1e-300);
+            vmax = trial_vmax;
+            km = trial_km;
+            current = trial;
+            lambda = (lambda / 10.0).max(1e-12);
+            if converged {
+                break;
+            }
+        } else {
+            lambda *= 10.0;
+            if lambda > 1e12 {
+                break;
+            }
+        }
+    }
+
+    // Parameter standard errors from s²·(JᵀJ)⁻¹
+    let mut jtj = [[0.0; 2]; 2];
+    for &(s, _) in &data {
+        let denom = km + s;
+        let grad = [s / denom, -vmax * s / (denom * denom)];
+        for i in 0..2 {
+            for j in 0..2 {
+                jtj[i][j] += grad[i] * grad[j];
+            }
+        }
+    }
+    let det = jtj[0][0] * jtj[1][1] - jtj[0][1] * jtj[1][0];
+    let variance = current / (data.len() - 2) as f64;
+    let (vmax_error, km_error) = if det.abs() > 1e-300 {
+        (
+            (variance * jtj[1][1] / det).sqrt(),
+            (variance * jtj[0][0] / det).sqrt(),
+        )
+    } else {
+        (f64::NAN, f64::NAN)
+    };
+
+    let mean_v = data.iter().map(|d| d.1).sum::<f64>() / data.len() as f64;
+    let sst: f64 = data.iter().map(|d| (d.1 - mean_v).powi(2)).sum();
+    let r_squared = if sst == 0.0 { 1.0 } else { 1.0 - current / sst };
+
+    let curve = sample_times(s_max, points)
+        .into_iter()
+        .map(|s| (s, vmax * s / (km + s)))
+        .collect();
+
+    let result = MichaelisMentenFit {
+        vmax,
+        km,
+        vmax_error,
+        km_error,
+        r_squared,
+        lineweaver_burk,
+        lineweaver_burk_fit,
+        eadie_hofstee,
+        eadie_hofstee_fit,
+        curve,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// MECHANISM APPROXIMATIONS
+// ============================================================================
+
+/// Step of a linear mechanism with forward and (optional) reverse rate constants
+#[derive(Deserialize)]
+struct MechanismStep {
+    kf: f64,
+    #[serde(default)]
+    kr: f64,
+}
+
+/// Limiting rate constant when a given step is rate-determining
+#[derive(Serialize)]
+struct StepLimit {
+    step: usize,
+    k_limit: f64,
+    /// Share of the overall "resistance" 1/k_obs contributed by this step
+    contribution: f64,
+}
+
+/// Effective rate constants of a linear mechanism
+#[derive(Serialize)]
+struct EffectiveRateConstant {
+    steady_state: f64,
+    pre_equilibrium: f64,
+    /// Relative deviation of the pre-equilibrium value from the steady-state value
+    pre_equilibrium_error: f64,
+    rate_determining_step: usize,
+    limits: Vec<StepLimit>,
+}
+
+/// Effective rate constant of a linear mechanism R ⇌ I1 ⇌ ... ⇌ In → P
+///
+/// Steady-state approximation for all intermediates:
+/// 1/k_obs = Σ_j (Π_(i<j) k-i) / (Π_(i≤j) ki)
+/// e.g. k_obs = k1·k2/(k-1 + k2) for two steps.
+///
+/// Pre-equilibrium approximation (all steps before the last equilibrated):
+/// k_obs = K1·K2·...·k_n
+///
+/// Each term of the sum is the limiting k_obs when that step is rate-determining;
+/// the largest term identifies the rate-determining step.
+///
+/// Arguments:
+/// - steps_json: Array of {"kf": ..., "kr": ...}; the reverse constant of the last step is ignored
+#[wasm_func]
+pub fn effective_rate_constant(steps_json: &[u8]) -> Result<Vec<u8>, String> {
+    let steps: Vec<MechanismStep> = parse_json(steps_json, "mechanism steps")?;
+
+    if steps.is_empty() {
+        return Err("At least one mechanism step is required".to_string());
+    }
+    for (i, step) in steps.iter().enumerate() {
+        check_positive(&format!("kf of step {}", i + 1), step.kf)?;
+        check_non_negative(&format!("kr of step {}", i + 1), step.kr)?;
+    }
+
+    // term_j = (k-1·...·k-(j-1)) / (k1·...·kj)
+    let mut terms = Vec::with_capacity(steps.len());
+    let mut reverse_product = 1.0;
+    let mut forward_product = 1.0;
+    for (j, step) in steps.iter().enumerate() {
+        if j > 0 {
+            reverse_product *= steps[j - 1].kr;
+        }
+        forward_product *= step.kf;
+        terms.push(reverse_product / forward_product);
+    }
+
+    let resistance: f64 = terms.iter().sum();
+    let steady_state = 1.0 / resistance;
+    let pre_equilibrium = 1.0 / terms[terms.len() - 1];
+
+    let rate_determining_step = terms
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(j, _)| j + 1)
+        .unwrap();
+
+    let limits = terms
+        .iter()
+        .enumerate()
+        .map(|(j, term)| StepLimit {
+            step: j + 1,
+            k_limit: if *term > 0.0 { 1.0 / term } else { f64::INFINITY },
+            contribution: term / resistance,
+        })
+        .collect();
+
+    let result = EffectiveRateConstant {
+        steady_state,
+        pre_equilibrium,
+        pre_equilibrium_error: (pre_equilibrium - steady_state) / steady_state,
+        rate_determining_step,
+        limits,
+    };
+
+    to_json(&result)
+}
+
+/// Lindemann–Hinshelwood unimolecular rate constant
+#[derive(Serialize)]
+struct LindemannResult {
+    /// Effective first-order constant at the requested [M] (s⁻¹)
+    k_uni: f64,
+    /// High-pressure limit k1·k2/k-1 (s⁻¹)
+    k_infinity: f64,
+    /// Low-pressure limit k1·[M] (s⁻¹)
+    k_zero: f64,
+    /// Third-body concentration used (mol/L)
+    concentration: f64,
+    /// [M] at which k_uni = k_infinity/2, k2/k-1 (mol/L)
+    half_concentration: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    half_pressure: Option<f64>,
+    /// Fall-off curve: (log10 [M], log10 k_uni) pairs
+    fall_off: Vec<(f64, f64)>,
+}
+
+/// Unimolecular rate constant from the Lindemann–Hinshelwood mechanism
+///
+/// A + M ⇌ A* + M (k1, k-1), A* → P (k2):
+/// k_uni = k1·k2·[M] / (k-1·[M] + k2)
+///
+/// Arguments:
+/// - k1: Activation rate constant (L/(mol·s))
+/// - k_reverse: Deactivation rate constant k-1 (L/(mol·s))
+/// - k2: Unimolecular reaction rate constant of A* (s⁻¹)
+/// - concentration: Third-body concentration [M] (mol/L), ignored when a pressure is given
+/// - pressure: Total pressure (bar); values ≤ 0 use the concentration instead
+/// - temperature: Temperature (K), used to convert pressures via [M] = P/(RT)
+/// - points: Number of fall-off curve points (spanning 10⁻³ to 10³ × [M]½)
+#[wasm_func]
+pub fn lindemann_rate_constant(
+    k1_bytes: &[u8],
+    k_reverse_bytes: &[u8],
+    k2_bytes: &[u8],
+    concentration_bytes: &[u8],
+    pressure_bytes: &[u8],
+    temperature_bytes: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k1 = parse_number(k1_bytes, "k1")?;
+    let k_reverse = parse_number(k_reverse_bytes, "k-1")?;
+    let k2 = parse_number(k2_bytes, "k2")?;
+    let concentration = parse_number(concentration_bytes, "third-body concentration")?;
+    let pressure = parse_number(pressure_bytes, "pressure")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    check_positive("k1", k1)?;
+    check_positive("k-1", k_reverse)?;
+    check_positive("k2", k2)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    if pressure > 0.0 {
+        check_temperature(temperature)?;
+    }
+
+    let concentration = if pressure > 0.0 {
+        pressure / (R_LITRE_BAR * temperature)
+    } else {
+        concentration
+    };
+    check_positive("third-body concentration or pressure", concentration)?;
+
+    let k_uni_at = |m: f64| k1 * k2 * m / (k_reverse * m + k2);
+    let half_concentration = k2 / k_reverse;
+
+    let log_half = half_concentration.log10();
+    let fall_off = (0..points)
+        .map(|i| {
+            let log_m = log_half - 3.0 + 6.0 * i as f64 / (points - 1) as f64;
+            (log_m, k_uni_at(10_f64.powf(log_m)).log10())
+        })
+        .collect();
+
+    let result = LindemannResult {
+        k_uni: k_uni_at(concentration),
+        k_infinity: k1 * k2 / k_reverse,
+        k_zero: k1 * concentration,
+        concentration,
+        half_concentration,
+        half_pressure: if temperature > 0.0 {
+            Some(half_concentration * R_LITRE_BAR * temperature)
+        } else {
+            None
+        },
+        fall_off,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// RATE LAW DETERMINATION
+// ============================================================================
+
+/// Render a number as Unicode superscript digits (e.g. -2 → ⁻²)
+fn superscript(n: i64) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            c => c,
+        })
+        .collect()
+}
+
+/// Unit of a rate constant for a given overall order (concentrations in mol/L, time in s)
+fn rate_constant_unit(overall_order: f64) -> String {
+    let exponent = 1.0 - overall_order;
+    if exponent == 0.0 {
+        "s⁻¹".to_string()
+    } else if exponent == 1.0 {
+        "M·s⁻¹".to_string()
+    } else if exponent.fract() == 0.0 {
+        format!("M{}·s⁻¹", superscript(exponent as i64))
+    } else {
+        format!("M^({})·s⁻¹", exponent)
+    }
+}
+
+/// Format a reaction order for a rate-law expression
+fn format_order(order: f64) -> String {
+    if order.fract() == 0.0 {
+        superscript(order as i64)
+    } else {
+        format!("^({}/2)", (order * 2.0).round() as i64)
+    }
+}
+
+/// Single initial-rate experiment
+#[derive(Deserialize)]
+struct RateExperiment {
+    concentrations: HashMap<String, f64>,
+    rate: f64,
+}
+
+/// Order with respect to a single species
+#[derive(Serialize)]
+struct SpeciesOrder {
+    species: String,
+    order: f64,
+    raw_order: f64,
+}
+
+/// Result of a rate-law determination
+#[derive(Serialize)]
+struct RateLaw {
+    orders: Vec<SpeciesOrder>,
+    overall_order: f64,
+    rate_constant: CalculationResult,
+    rate_law: String,
+    r_squared: f64,
+}
+
+/// Determine a rate law from initial-rate experiments
+///
+/// Fits ln(rate) = ln k + Σ n_i·ln[X_i] by least squares over all experiments,
+/// rounds each order n_i to the nearest half-integer and recomputes k as the
+/// mean of rate / Π[X_i]^n_i.
+///
+/// Arguments:
+/// - experiments_json: Array of {"concentrations": {species: mol/L}, "rate": mol/(L·s)}
+#[wasm_func]
+pub fn determine_rate_law(experiments_json: &[u8]) -> Result<Vec<u8>, String> {
+    let experiments: Vec<RateExperiment> = parse_json(experiments_json, "experiments")?;
+
+    let mut species: Vec<String> = Vec::new();
+    for experiment in &experiments {
+        let mut names: Vec<&String> = experiment.concentrations.keys().collect();
+        names.sort();
+        for name in names {
+            if !species.contains(name) {
+                species.push(name.clone());
+            }
+        }
+    }
+
+    if species.is_empty() {
+        return Err("No species concentrations given".to_string());
+    }
+    if experiments.len() < species.len() + 1 {
+        return Err(format!(
+            "At least {} experiments are required for {} species",
+            species.len() + 1,
+            species.len()
+        ));
+    }
+
+    // Design matrix rows: [1, ln c1, ln c2, ...]
+    let mut rows = Vec::with_capacity(experiments.len());
+    let mut ln_rates = Vec::with_capacity(experiments.len());
+    for (i, experiment) in experiments.iter().enumerate() {
+        check_positive(&format!("rate of experiment {}", i + 1), experiment.rate)?;
+        let mut row = vec![1.0];
+        for name in &species {
+            let c = experiment
+                .concentrations
+                .get(name)
+                .ok_or_else(|| format!("Experiment {} has no concentration for {}", i + 1, name))?;
+            check_positive(&format!("[{}] in experiment {}", name, i + 1), *c)?;
+            row.push(c.ln());
+        }
+        rows.push(row);
+        ln_rates.push(experiment.rate.ln());
+    }
+
+    // Normal equations XᵀX·β = Xᵀy
+    let p = species.len() + 1;
+    let xtx: Vec<Vec<f64>> = (0..p)
+        .map(|i| (0..p).map(|j| rows.iter().map(|r| r[i] * r[j]).sum()).collect())
+        .collect();
+    let xty: Vec<f64> = (0..p)
+        .map(|i| rows.iter().zip(&ln_rates).map(|(r, y)| r[i] * y).sum())
+        .collect();
+    let beta = solve_linear(xtx, xty)
+        .map_err(|_| "Concentrations do not vary independently enough to determine all orders".to_string())?;
+
+    let mean_y = ln_rates.iter().sum::<f64>() / ln_rates.len() as f64;
+    let ss_tot: f64 = ln_rates.iter().map(|y| (y - mean_y).powi(2)).sum();
+    let ss_res: f64 = rows
+        .iter()
+        .zip(&ln_rates)
+        .map(|(r, y)| (y - r.iter().zip(&beta).map(|(x, b)| x * b).sum::<f64>()).powi(2))
+        .sum();
+    let r_squared = if ss_tot == 0.0 { 1.0 } else { 1.0 - ss_res / ss_tot };
+
+    let orders: Vec<SpeciesOrder> = species
+        .iter()
+        .zip(&beta[1..])
+        .map(|(name, raw)| SpeciesOrder {
+            species: name.clone(),
+            order: (raw * 2.0).round() / 2.0,
+            raw_order: *raw,
+        })
+        .collect();
+    let overall_order: f64 = orders.iter().map(|o| o.order).sum();
+
+    // k from each experiment with the rounded orders
+    let k = experiments
+        .iter()
+        .map(|experiment| {
+            let product: f64 = orders
+                .iter()
+                .map(|o| experiment.concentrations[&o.species].powf(o.order))
+                .product();
+            experiment.rate / product
+        })
+        .sum::<f64>()
+        / experiments.len() as f64;
+
+    let terms: String = orders
+        .iter()
+        .filter(|o| o.order != 0.0)
+        .map(|o| {
+            if o.order == 1.0 {
+                format!("[{}]", o.species)
+            } else {
+                format!("[{}]{}", o.species, format_order(o.order))
+            }
+        })
+        .collect();
+
+    let result = RateLaw {
+        rate_constant: CalculationResult::new(k, &rate_constant_unit(overall_order)),
+        rate_law: format!("rate = k{}", terms),
+        orders,
+        overall_order,
+        r_squared,
+    };
+
+    to_json(&result)
+}
+
+/// Integrated rate-law candidate tested against concentration–time data
+#[derive(Serialize)]
+struct OrderCandidate {
+    order: i32,
+    rate_constant: CalculationResult,
+    r_squared: f64,
+    /// Linearised data: [A], ln[A] or 1/[A] against t
+    linearized: Vec<(f64, f64)>,
+    fit: LinearFit,
+}
+
+/// Result of integrated rate-law fitting
+#[derive(Serialize)]
+struct OrderFit {
+    best_order: i32,
+    candidates: Vec<OrderCandidate>,
+}
+
+/// Fit zeroth-, first- and second-order integrated rate laws to (t, [A]) data
+///
+/// - Zero order: [A] = [A]0 - k·t
+/// - First order: ln[A] = ln[A]0 - k·t
+/// - Second order: 1/[A] = 1/[A]0 + k·t
+///
+/// The candidate with the highest R² is reported as the best order.
+///
+/// Arguments:
+/// - data_json: Array of (t, [A]) pairs (s, mol/L)
+#[wasm_func]
+pub fn fit_reaction_order(data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let data: Vec<(f64, f64)> = parse_json(data_json, "concentration data")?;
+
+    if data.len() < 3 {
+        return Err("At least 3 (t, [A]) points are required".to_string());
+    }
+    for (i, &(_, c)) in data.iter().enumerate() {
+        check_positive(&format!("concentration of point {}", i + 1), c)?;
+    }
+
+    let times: Vec<f64> = data.iter().map(|d| d.0).collect();
+
+    let mut candidates = Vec::with_capacity(3);
+    for order in 0..=2 {
+        let ys: Vec<f64> = data
+            .iter()
+            .map(|&(_, c)| match order {
+                0 => c,
+                1 => c.ln(),
+                _ => 1.0 / c,
+            })
+            .collect();
+        // Zero and first order decay with slope -k, second order rises with slope +k
+        let sign = if order == 2 { 1.0 } else { -1.0 };
+        let fit = linear_regression(&times, &ys)?;
+        candidates.push(OrderCandidate {
+            order,
+            rate_constant: CalculationResult::new(sign * fit.slope, &rate_constant_unit(order as f64)),
+            r_squared: fit.r_squared,
+            linearized: times.iter().cloned().zip(ys).collect(),
+            fit,
+        });
+    }
+
+    let best_order = candidates
+        .iter()
+        .max_by(|a, b| a.r_squared.total_cmp(&b.r_squared))
+        .map(|c| c.order)
+        .unwrap();
+
+    let result = OrderFit {
+        best_order,
+        candidates,
+    };
+
+    to_json(&result)
+}
+
+/// Linear interpolation in sorted (x, y) data
+fn interpolate(data: &[(f64, f64)], x: f64) -> Option<f64> {
+    let i = data.windows(2).position(|w| w[0].0 <= x && x <= w[1].0)?;
+    let ((x0, y0), (x1, y1)) = (data[i], data[i + 1]);
+    if x1 == x0 {
+        Some(y0)
+    } else {
+        Some(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+    }
+}
+
+/// First-order rate constant from one of the infinity-free methods
+#[derive(Serialize)]
+struct InfinityFreeFit {
+    rate_constant: CalculationResult,
+    r_squared: f64,
+    points: Vec<(f64, f64)>,
+    fit: LinearFit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    infinity_value: Option<f64>,
+}
+
+/// Results of the Guggenheim and Kezdy–Swinbourne analyses
+#[derive(Serialize)]
+struct InfinityFreeAnalysis {
+    delta: f64,
+    guggenheim: InfinityFreeFit,
+    kezdy_swinbourne: InfinityFreeFit,
+}
+
+/// First-order rate constant without an infinity reading
+///
+/// Guggenheim: ln|y(t+Δ) - y(t)| = const - k·t
+/// Kezdy–Swinbourne: y(t+Δ) = y∞·(1 - e^(-kΔ)) + e^(-kΔ)·y(t), so k = -ln(slope)/Δ
+/// and y∞ = intercept/(1 - slope)
+///
+/// y(t+Δ) is linearly interpolated, so the data need not be evenly spaced.
+///
+/// Arguments:
+/// - data_json: Array of (t, y) pairs, y being any property linear in concentration
+/// - delta: Fixed time shift Δ (ideally 2–3 half-lives); values ≤ 0 use half the time span
+/// - time_unit: Time unit of t and Δ (s, min, h, d or yr)
+#[wasm_func]
+pub fn first_order_without_infinity(
+    data_json: &[u8],
+    delta_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let mut data: Vec<(f64, f64)> = parse_json(data_json, "time-series data")?;
+    let delta = parse_number(delta_bytes, "time shift")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+    let rate_unit = format!("{}⁻¹", time_unit);
+
+    data.sort_by(|a, b| a.0.total_cmp(&b.0));
+    if data.len() < 4 {
+        return Err("At least 4 (t, y) points are required".to_string());
+    }
+
+    let (t_first, t_last) = (data[0].0, data[data.len() - 1].0);
+    let delta = if delta > 0.0 { delta } else { (t_last - t_first) / 2.0 };
+
+    let pairs: Vec<(f64, f64, f64)> = data
+        .iter()
+        .filter(|(t, _)| t + delta <= t_last)
+        .filter_map(|&(t, y)| interpolate(&data, t + delta).map(|y_shift| (t, y, y_shift)))
+        .collect();
+    if pairs.len() < 2 {
+        return Err("Time shift Δ is too long for the data range".to_string());
+    }
+
+    // Guggenheim
+    let gugg_points: Vec<(f64, f64)> = pairs
+        .iter()
+        .filter(|(_, y, y_shift)| y_shift != y)
+        .map(|&(t, y, y_shift)| (t, (y_shift - y).abs().ln()))
+        .collect();
+    let (gx, gy): (Vec<f64>, Vec<f64>) = gugg_points.iter().cloned().unzip();
+    let gugg_fit = linear_regression(&gx, &gy)?;
+
+    // Kezdy–Swinbourne
+    let ks_points: Vec<(f64, f64)> = pairs.iter().map(|&(_, y, y_shift)| (y, y_shift)).collect();
+    let (kx, ky): (Vec<f64>, Vec<f64>) = ks_points.iter().cloned().unzip();
+    let ks_fit = linear_regression(&kx, &ky)?;
+    if ks_fit.slope <= 0.0 || ks_fit.slope >= 1.0 {
+        return Err("Kezdy–Swinbourne slope outside (0, 1); data are not first order".to_string());
+    }
+
+    let result = InfinityFreeAnalysis {
+        delta,
+        guggenheim: InfinityFreeFit {
+            rate_constant: CalculationResult::new(-gugg_fit.slope, &rate_unit),
+            r_squared: gugg_fit.r_squared,
+            points: gugg_points,
+            fit: gugg_fit,
+            infinity_value: None,
+        },
+        kezdy_swinbourne: InfinityFreeFit {
+            rate_constant: CalculationResult::new(-ks_fit.slope.ln() / delta, &rate_unit),
+            r_squared: ks_fit.r_squared,
+            points: ks_points,
+            infinity_value: Some(ks_fit.intercept / (1.0 - ks_fit.slope)),
+            fit: ks_fit,
+        },
+    };
+
+    to_json(&result)
+}
+
+/// Least-squares polynomial coefficients c0 + c1·x + ... + cd·x^d
+fn polynomial_fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<Vec<f64>, String> {
+    if xs.len() <= degree {
+        return Err(format!("A degree-{} polynomial needs at least {} points", degree, degree + 1));
+    }
+
+    let p = degree + 1;
+    let powers: Vec<Vec<f64>> = xs.iter().map(|x| (0..p).map(|k| x.powi(k as i32)).collect()).collect();
+    let xtx: Vec<Vec<f64>> = (0..p)
+        .map(|i| (0..p).map(|j| powers.iter().map(|r| r[i] * r[j]).sum()).collect())
+        .collect();
+    let xty: Vec<f64> = (0..p)
+        .map(|i| powers.iter().zip(ys).map(|(r, y)| r[i] * y).sum())
+        .collect();
+
+    solve_linear(xtx, xty)
+}
+
+/// Instantaneous rate at a single time
+#[derive(Serialize)]
+struct InstantaneousRate {
+    time: f64,
+    concentration: f64,
+    /// dc/dt (negative for a reactant)
+    derivative: f64,
+    /// |dc/dt|
+    rate: f64,
+}
+
+/// Numerically differentiated concentration data
+#[derive(Serialize)]
+struct RateExtraction {
+    method: String,
+    initial_rate: f64,
+    initial_derivative: f64,
+    rates: Vec<InstantaneousRate>,
+}
+
+/// Extract initial and instantaneous rates from (t, concentration) data
+///
+/// "polynomial": a least-squares polynomial of the given degree is fitted to the
+/// `window` data points nearest each requested time (centred on that time for
+/// numerical conditioning) and differentiated analytically, which smooths noise.
+/// "finite-difference": central differences at the data points (one-sided at
+/// the ends), linearly interpolated to the requested times.
+///
+/// Arguments:
+/// - data_json: Array of (t, c) pairs
+/// - times_json: Array of times at which rates are wanted (may be empty)
+/// - method: "polynomial" or "finite-difference"
+/// - window: Number of points per local polynomial fit
+/// - degree: Polynomial degree
+#[wasm_func]
+pub fn extract_rates(
+    data_json: &[u8],
+    times_json: &[u8],
+    method_bytes: &[u8],
+    window_bytes: &[u8],
+    degree_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let mut data: Vec<(f64, f64)> = parse_json(data_json, "concentration data")?;
+    let times: Vec<f64> = parse_json(times_json, "times")?;
+    let method: String = parse_arg(method_bytes, "method")?;
+    let window: usize = parse_arg(window_bytes, "window")?;
+    let degree: usize = parse_arg(degree_bytes, "degree")?;
+
+    data.sort_by(|a, b| a.0.total_cmp(&b.0));
+    if data.len() < 3 {
+        return Err("At least 3 (t, c) points are required".to_string());
+    }
+
+    let polynomial = match method.as_str() {
+        "polynomial" => true,
+        "finite-difference" => false,
+        _ => return Err(format!("Unsupported differentiation method: {}", method)),
+    };
+    if polynomial && window <= degree {
+        return Err("Window must contain more points than the polynomial degree".to_string());
+    }
+    let window = window.min(data.len());
+
+    // Central differences at the data points, one-sided at both ends
+    let n = data.len();
+    let derivatives: Vec<(f64, f64)> = (0..n)
+        .map(|i| {
+            let (a, b) = match i {
+                0 => (0, 1),
+                i if i == n - 1 => (n - 2, n - 1),
+                i => (i - 1, i + 1),
+            };
+            (data[i].0, (data[b].1 - data[a].1) / (data[b].0 - data[a].0))
+        })
+        .collect();
+
+    // Returns (c, dc/dt) at time t
+    let evaluate = |t: f64| -> Result<(f64, f64), String> {
+        if polynomial {
+            let mut nearest: Vec<&(f64, f64)> = data.iter().collect();
+            nearest.sort_by(|a, b| (a.0 - t).abs().total_cmp(&(b.0 - t).abs()));
+            nearest.truncate(window);
+            let xs: Vec<f64> = nearest.iter().map(|d| d.0 - t).collect();
+            let ys: Vec<f64> = nearest.iter().map(|d| d.1).collect();
+            let coeffs = polynomial_fit(&xs, &ys, degree)?;
+            Ok((coeffs[0], coeffs.get(1).cloned().unwrap_or(0.0)))
+        } else {
+            let concentration = interpolate(&data, t)
+                .ok_or_else(|| format!("Time {} lies outside the data range", t))?;
+            let derivative = interpolate(&derivatives, t)
+                .ok_or_else(|| format!("Time {} lies outside the data range", t))?;
+            Ok((concentration, derivative))
+        }
+    };
+
+    let (_, initial_derivative) = evaluate(data[0].0)?;
+    let rates = times
+        .iter()
+        .map(|&time| {
+            let (concentration, derivative) = evaluate(time)?;
+            Ok(InstantaneousRate {
+                time,
+                concentration,
+                derivative,
+                rate: derivative.abs(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let result = RateExtraction {
+        method,
+        initial_rate: initial_derivative.abs(),
+        initial_derivative,
+        rates,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// CATALYSIS
+// ============================================================================
+
+/// Turnover number and frequency of a catalyst
+#[derive(Serialize)]
+struct TurnoverResult {
+    turnover_number: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    turnover_frequency: Option<CalculationResult>,
+}
+
+/// Calculate turnover number (TON) and turnover frequency (TOF)
+/// TON = n(product) / n(catalyst), TOF = TON / t
+///
+/// Arguments:
+/// - product_moles: Amount of product formed (mol)
+/// - catalyst_moles: Amount of catalyst (active sites) (mol)
+/// - time: Reaction time; values ≤ 0 omit the TOF
+/// - time_unit: Time unit of the reaction time and the TOF (s, min, h, d or yr)
+#[wasm_func]
+pub fn calculate_turnover(
+    product_moles_bytes: &[u8],
+    catalyst_moles_bytes: &[u8],
+    time_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let product_moles = parse_number(product_moles_bytes, "product amount")?;
+    let catalyst_moles = parse_number(catalyst_moles_bytes, "catalyst amount")?;
+    let time = parse_number(time_bytes, "time")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_positive("catalyst amount", catalyst_moles)?;
+    check_non_negative("product amount", product_moles)?;
+
+    let ton = product_moles / catalyst_moles;
+    let result = TurnoverResult {
+        turnover_number: ton,
+        turnover_frequency: if time > 0.0 {
+            Some(CalculationResult::new(ton / time, &format!("{}⁻¹", time_unit)))
+        } else {
+            None
+        },
+    };
+
+    to_json(&result)
+}
+
+/// Rate comparison between catalysed and uncatalysed pathways
+#[derive(Serialize)]
+struct CatalysisComparison {
+    /// k(catalysed) / k(uncatalysed)
+    rate_enhancement: f64,
+    /// Ea(uncatalysed) - Ea(catalysed) (kJ/mol)
+    activation_energy_lowering: f64,
+    temperature: f64,
+}
+
+/// Compare catalysed and uncatalysed rates from their activation energies
+/// k_cat/k_uncat = (A_cat/A_uncat)·exp((Ea_uncat - Ea_cat)/(R·T))
+///
+/// Arguments:
+/// - ea_uncatalyzed: Activation energy without catalyst (kJ/mol)
+/// - ea_catalyzed: Activation energy with catalyst (kJ/mol)
+/// - T: Temperature (K)
+/// - a_ratio: Ratio of pre-exponential factors A_cat/A_uncat
+#[wasm_func]
+pub fn compare_catalyzed_rate(
+    ea_uncatalyzed_bytes: &[u8],
+    ea_catalyzed_bytes: &[u8],
+    temperature_bytes: &[u8],
+    a_ratio_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let ea_uncatalyzed = parse_number(ea_uncatalyzed_bytes, "uncatalysed Ea")?;
+    let ea_catalyzed = parse_number(ea_catalyzed_bytes, "catalysed Ea")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let a_ratio = parse_number(a_ratio_bytes, "A ratio")?;
+
+    check_temperature(temperature)?;
+    check_positive("A ratio", a_ratio)?;
+
+    let lowering = ea_uncatalyzed - ea_catalyzed;
+    let result = CatalysisComparison {
+        rate_enhancement: a_ratio * (lowering * 1000.0 / (R * temperature)).exp(),
+        activation_energy_lowering: lowering,
+        temperature,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// KINETIC ISOTOPE EFFECTS
+// ============================================================================
+
+/// Zero-point-energy estimate of a primary kinetic isotope effect
+#[derive(Serialize)]
+struct IsotopeEffect {
+    /// kH/kD
+    value: f64,
+    unit: String,
+    hydrogen_frequency: f64,
+    /// X–D stretching frequency (cm⁻¹)
+    deuterium_frequency: f64,
+    /// ZPE(X–H) - ZPE(X–D) (kJ/mol)
+    zero_point_energy_difference: f64,
+}
+
+/// Estimate the primary kinetic isotope effect kH/kD from a stretching frequency
+///
+/// Assumes the X–H/X–D stretch is fully lost in the transition state:
+/// ν_D = ν_H·√(μ_H/μ_D), ΔZPE = ½·h·c·(ν_H - ν_D), kH/kD = exp(ΔZPE/(kB·T))
+///
+/// Arguments:
+/// - frequency: X–H stretching wavenumber (cm⁻¹)
+/// - T: Temperature (K)
+/// - heavy_atom_mass: Mass of the atom bonded to H/D (u), 12 for C–H
+#[wasm_func]
+pub fn calculate_kinetic_isotope_effect(
+    frequency_bytes: &[u8],
+    temperature_bytes: &[u8],
+    heavy_atom_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let frequency = parse_number(frequency_bytes, "frequency")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let heavy_atom_mass = parse_number(heavy_atom_mass_bytes, "heavy atom mass")?;
+
+    const M_H: f64 = 1.007825; // u
+    const M_D: f64 = 2.014102; // u
+
+    check_positive("frequency", frequency)?;
+    check_temperature(temperature)?;
+    check_positive("heavy atom mass", heavy_atom_mass)?;
+
+    let mu_h = heavy_atom_mass * M_H / (heavy_atom_mass + M_H);
+    let mu_d = heavy_atom_mass * M_D / (heavy_atom_mass + M_D);
+    let deuterium_frequency = frequency * (mu_h / mu_d).sqrt();
+    let delta_wavenumber = 0.5 * (frequency - deuterium_frequency);
+
+    let result = IsotopeEffect {
+        value: (HC_OVER_K * delta_wavenumber / temperature).exp(),
+        unit: "".to_string(),
+        hydrogen_frequency: frequency,
+        deuterium_frequency,
+        zero_point_energy_difference: R * HC_OVER_K * delta_wavenumber / 1000.0, // NA·h·c·Δν̃
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// RADIOACTIVE DECAY
+// ============================================================================
+
+/// Radioactive decay state after a given time
+#[derive(Serialize)]
+struct DecayResult {
+    remaining: f64,
+    fraction_remaining: f64,
+    /// Decay constant λ = ln(2)/t_1/2
+    decay_constant: CalculationResult,
+    /// Mean lifetime τ = 1/λ
+    mean_lifetime: CalculationResult,
+    initial_activity: CalculationResult,
+    activity: CalculationResult,
+    /// Activity in curies (1 Ci = 3.7×10¹⁰ Bq)
+    activity_ci: f64,
+}
+
+/// Calculate radioactive decay: N(t) = N0·e^(-λt), A = λ·N
+///
+/// Arguments:
+/// - n0: Initial number of nuclei
+/// - half_life: Half-life
+/// - time: Elapsed time
+/// - time_unit: Time unit of the half-life, elapsed time and decay constant
+///   (s, min, h, d or yr); activities are always in Bq
+#[wasm_func]
+pub fn calculate_radioactive_decay(
+    n0_bytes: &[u8],
+    half_life_bytes: &[u8],
+    time_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let n0 = parse_number(n0_bytes, "initial nuclei")?;
+    let half_life = parse_number(half_life_bytes, "half-life")?;
+    let time = parse_number(time_bytes, "time")?;
+    let (seconds, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    const BQ_PER_CI: f64 = 3.7e10;
+
+    check_non_negative("initial number of nuclei", n0)?;
+    check_positive("half-life", half_life)?;
+    check_non_negative("time", time)?;
+
+    let lambda = 2_f64.ln() / half_life;
+    let fraction = (-lambda * time).exp();
+    let remaining = n0 * fraction;
+    let lambda_per_second = lambda / seconds;
+    let activity = lambda_per_second * remaining;
+
+    let result = DecayResult {
+        remaining,
+        fraction_remaining: fraction,
+        decay_constant: CalculationResult::new(lambda, &format!("{}⁻¹", time_unit)),
+        mean_lifetime: CalculationResult::new(1.0 / lambda, time_unit),
+        initial_activity: CalculationResult::new(lambda_per_second * n0, "Bq"),
+        activity: CalculationResult::new(activity, "Bq"),
+        activity_ci: activity / BQ_PER_CI,
+    };
+
+    to_json(&result)
+}
+
+/// Calculate the age of a sample from its remaining fraction (e.g. carbon dating)
+/// t = t_1/2·ln(1/f) / ln(2)
+///
+/// Arguments:
+/// - fraction: Remaining fraction N/N0 (or activity ratio A/A0), 0 < f ≤ 1
+/// - half_life: Half-life
+/// - time_unit: Time unit of the half-life and the age (s, min, h, d or yr)
+#[wasm_func]
+pub fn calculate_radioactive_age(
+    fraction_bytes: &[u8],
+    half_life_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let fraction = parse_number(fraction_bytes, "remaining fraction")?;
+    let half_life = parse_number(half_life_bytes, "half-life")?;
+    let (_, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    check_domain("remaining fraction", fraction, fraction > 0.0 && fraction <= 1.0, "in (0, 1]")?;
+    check_positive("half-life", half_life)?;
+
+    let age = half_life * (1.0 / fraction).ln() / 2_f64.ln();
+
+    let result = CalculationResult::new(age, time_unit);
+
+    to_json(&result)
+}
+
+/// Equilibrium between consecutive members of a decay chain
+#[derive(Serialize)]
+struct ChainEquilibrium {
+    parent: String,
+    daughter: String,
+    /// "secular", "transient" or "none"
+    equilibrium: String,
+    /// Limiting daughter/parent activity ratio λd/(λd - λp), if equilibrium is reached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    activity_ratio: Option<f64>,
+}
+
+/// Bateman decay-chain solution
+#[derive(Serialize)]
+struct DecayChain {
+    /// Activity of each member (Bq)
+    #[serde(flatten)]
+    activities: TimeCourse,
+    /// Number of nuclei of each member
+    nuclei: TimeCourse,
+    decay_constants: Vec<f64>,
+    equilibria: Vec<ChainEquilibrium>,
+}
+
+/// Solve a sequential decay chain 1 → 2 → ... → n with the Bateman equations,
+/// starting from a pure parent:
+/// N_n(t) = N0·(λ1···λ(n-1))·Σ_i e^(-λi·t) / Π_(j≠i)(λj - λi)
+///
+/// Consecutive pairs are classified as secular equilibrium (t_1/2,parent ≥ 100·t_1/2,daughter),
+/// transient equilibrium (parent longer-lived) or no equilibrium.
+///
+/// Arguments:
+/// - half_lives: JSON array of half-lives; the last member may be null (stable)
+/// - names: JSON array of member names (empty for parent/daughter/granddaughter...)
+/// - n0: Initial number of parent nuclei
+/// - t_end: Final time; values ≤ 0 choose ten half-lives of the longest-lived daughter
+/// - points: Number of sample points
+/// - time_unit: Time unit of half-lives, times and decay constants (s, min, h, d or yr);
+///   activities are always in Bq
+#[wasm_func]
+pub fn decay_chain(
+    half_lives_bytes: &[u8],
+    names_bytes: &[u8],
+    n0_bytes: &[u8],
+    t_end_bytes: &[u8],
+    points_bytes: &[u8],
+    time_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let half_lives: Vec<Option<f64>> = parse_json(half_lives_bytes, "half-lives")?;
+    let names: Vec<String> = parse_json(names_bytes, "names")?;
+    let n0 = parse_number(n0_bytes, "initial nuclei")?;
+    let t_end = parse_number(t_end_bytes, "end time")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let (seconds, time_unit) = parse_time_unit(time_unit_bytes)?;
+
+    const SECULAR_RATIO: f64 = 100.0;
+
+    let members = half_lives.len();
+    if members < 2 {
+        return Err("A decay chain needs at least 2 members".to_string());
+    }
+    if !names.is_empty() && names.len() != members {
+        return Err(format!(
+            "Expected {} names, got {}",
+            members,
+            names.len()
+        ));
+    }
+    check_non_negative("initial number of nuclei", n0)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let mut lambdas = Vec::with_capacity(members);
+    for (i, half_life) in half_lives.iter().enumerate() {
+        match half_life {
+            Some(t) => {
+                check_positive(&format!("half-life of member {}", i + 1), *t)?;
+                lambdas.push(2_f64.ln() / t);
+            }
+            None if i == members - 1 => lambdas.push(0.0),
+            None => return Err("Only the last chain member may be stable".to_string()),
+        }
+    }
+    for i in 0..members {
+        for j in (i + 1)..members {
+            if (lambdas[i] - lambdas[j]).abs() <= 1e-12 * lambdas[i].max(lambdas[j]) {
+                return Err("Bateman solution requires distinct half-lives".to_string());
+            }
+        }
+    }
+
+    let names: Vec<String> = if names.is_empty() {
+        (0..members)
+            .map(|i| match i {
+                0 => "parent".to_string(),
+                1 => "daughter".to_string(),
+                2 => "granddaughter".to_string(),
+                _ => format!("member {}", i + 1),
+            })
+            .collect()
+    } else {
+        names
+    };
+
+    let t_end = if t_end > 0.0 {
+        t_end
+    } else {
+        let slowest_daughter = lambdas[1..]
+            .iter()
+            .filter(|&&l| l > 0.0)
+            .fold(f64::INFINITY, |acc, &l| acc.min(l));
+        if slowest_daughter.is_finite() {
+            10.0 * 2_f64.ln() / slowest_daughter
+        } else {
+            10.0 * 2_f64.ln() / lambdas[0]
+        }
+    };
+
+    let nuclei_at = |n: usize, t: f64| -> f64 {
+        let prefactor: f64 = lambdas[..n].iter().product();
+        let sum: f64 = (0..=n)
+            .map(|i| {
+                let denominator: f64 = (0..=n)
+                    .filter(|&j| j != i)
+                    .map(|j| lambdas[j] - lambdas[i])
+                    .product();
+                (-lambdas[i] * t).exp() / denominator
+            })
+            .sum();
+        (n0 * prefactor * sum).max(0.0)
+    };
+
+    let times = sample_times(t_end, points);
+    let nuclei: Vec<Vec<f64>> = (0..members)
+        .map(|n| times.iter().map(|&t| nuclei_at(n, t)).collect())
+        .collect();
+    let activities: Vec<Vec<f64>> = nuclei
+        .iter()
+        .zip(&lambdas)
+        .map(|(values, &lambda)| values.iter().map(|n| lambda / seconds * n).collect())
+        .collect();
+
+    let equilibria = (0..members - 1)
+        .filter(|&i| lambdas[i + 1] > 0.0)
+        .map(|i| {
+            let (parent, daughter) = (lambdas[i], lambdas[i + 1]);
+            let (equilibrium, activity_ratio) = if daughter >= SECULAR_RATIO * parent {
+                ("secular", Some(daughter / (daughter - parent)))
+            } else if daughter > parent {
+                ("transient", Some(daughter / (daughter - parent)))
+            } else {
+                ("none", None)
+            };
+            ChainEquilibrium {
+                parent: names[i].clone(),
+                daughter: names[i + 1].clone(),
+                equilibrium: equilibrium.to_string(),
+                activity_ratio,
+            }
+        })
+        .collect();
+
+    let column = |values: &[Vec<f64>]| -> Vec<(&str, Vec<f64>)> {
+        names
+            .iter()
+            .map(String::as_str)
+            .zip(values.iter().cloned())
+            .collect()
+    };
+
+    let mut activity_course = TimeCourse::new(times.clone(), column(&activities)).with_time_unit(time_unit);
+    activity_course.concentration_unit = "Bq".to_string();
+    let mut nuclei_course = TimeCourse::new(times, column(&nuclei)).with_time_unit(time_unit);
+    nuclei_course.concentration_unit = "nuclei".to_string();
+
+    let result = DecayChain {
+        activities: activity_course,
+        nuclei: nuclei_course,
+        decay_constants: lambdas,
+        equilibria,
+    };
+
+    to_json(&result)
+}
+
+/// Atomic mass unit energy equivalent (MeV)
+const MEV_PER_U: f64 = 931.494_102_42;
+/// MeV per nucleus → kJ per mole of nuclei
+const KJ_PER_MOL_PER_MEV: f64 = ELECTRON_VOLT * 1e6 * AVOGADRO / 1000.0;
+
+/// Atomic masses (u) of common nuclides: (symbol, Z, A, mass)
+const NUCLIDE_MASSES: [(&str, u32, u32, f64); 69] = [
+    ("H", 1, 1, 1.007_825_032), ("H", 1, 2, 2.014_101_778), ("H", 1, 3, 3.016_049_281),
+    ("He", 2, 3, 3.016_029_322), ("He", 2, 4, 4.002_603_254), ("Li", 3, 6, 6.015_122_887),
+    ("Li", 3, 7, 7.016_003_437), ("Be", 4, 7, 7.016_928_717), ("Be", 4, 8, 8.005_305_102),
+    ("Be", 4, 9, 9.012_183_065), ("B", 5, 10, 10.012_936_95), ("B", 5, 11, 11.009_305_36),
+    ("C", 6, 11, 11.011_433_6), ("C", 6, 12, 12.0), ("C", 6, 13, 13.003_354_835),
+    ("C", 6, 14, 14.003_241_988), ("N", 7, 13, 13.005_738_61), ("N", 7, 14, 14.003_074_004),
+    ("N", 7, 15, 15.000_108_899), ("O", 8, 15, 15.003_065_6), ("O", 8, 16, 15.994_914_620),
+    ("O", 8, 17, 16.999_131_757), ("O", 8, 18, 17.999_159_613), ("F", 9, 18, 18.000_938_0),
+    ("F", 9, 19, 18.998_403_163), ("Ne", 10, 20, 19.992_440_176), ("Na", 11, 22, 21.994_436_4),
+    ("Na", 11, 23, 22.989_769_282), ("Mg", 12, 24, 23.985_041_697),
+    ("Al", 13, 27, 26.981_538_53), ("Si", 14, 28, 27.976_926_535),
+    ("P", 15, 31, 30.973_761_998), ("P", 15, 32, 31.973_907_64), ("S", 16, 32, 31.972_071_174),
+    ("Cl", 17, 35, 34.968_852_682), ("Cl", 17, 37, 36.965_902_602),
+    ("Ar", 18, 40, 39.962_383_124), ("K", 19, 40, 39.963_998_166),
+    ("Ca", 20, 40, 39.962_590_863), ("Fe", 26, 56, 55.934_936_33), ("Fe", 26, 58, 57.933_274_43),
+    ("Co", 27, 59, 58.933_194_29), ("Co", 27, 60, 59.933_816_30), ("Ni", 28, 60, 59.930_785_88),
+    ("Ni", 28, 62, 61.928_345_37), ("Cu", 29, 63, 62.929_597_72), ("Kr", 36, 92, 91.926_173_09),
+    ("Rb", 37, 87, 86.909_180_53), ("Sr", 38, 87, 86.908_877_50), ("Sr", 38, 90, 89.907_727_87),
+    ("Sr", 38, 94, 93.915_355_6), ("Y", 39, 90, 89.907_143_7), ("Zr", 40, 90, 89.904_697_7),
+    ("I", 53, 131, 130.906_126_3), ("Xe", 54, 131, 130.905_084_1),
+    ("Xe", 54, 140, 139.921_645_8), ("Cs", 55, 137, 136.907_089_2),
+    ("Ba", 56, 137, 136.905_827_1), ("Ba", 56, 141, 140.914_403_3),
+    ("Pb", 82, 206, 205.974_465_7), ("Pb", 82, 208, 207.976_652_5),
+    ("Po", 84, 210, 209.982_873_7), ("Rn", 86, 222, 222.017_578_2),
+    ("Ra", 88, 226, 226.025_410_3), ("Th", 90, 232, 232.038_055_8),
+    ("Th", 90, 234, 234.043_601_4), ("U", 92, 235, 235.043_930_1), ("U", 92, 238, 238.050_788_4),
+    ("Pu", 94, 239, 239.052_163_6),
+];
+
+/// Neutron mass (u)
+const NEUTRON_MASS: f64 = 1.008_664_916;
+
+/// Nucleus or particle taking part in a nuclear reaction
+#[derive(Clone)]
+struct Nucleus {
+    name: String,
+    /// Charge (protons, or ±1 for electrons and positrons)
+    z: f64,
+    /// Mass number
+    a: f64,
+    /// Atomic mass (u); for bare particles the particle mass
+    atomic_mass: f64,
+    /// Whether the mass includes Z electrons
+    neutral_atom: bool,
+}
+
+impl Nucleus {
+    /// Mass of the bare nucleus or particle (u), ignoring electron binding energies
+    fn nuclear_mass(&self) -> f64 {
+        if self.neutral_atom {
+            self.atomic_mass - self.z * ELECTRON_MOLAR_MASS
+        } else {
+            self.atomic_mass
+        }
+    }
+
+    fn parse(name: &str) -> Result<Nucleus, String> {
+        let text = name.trim();
+        let particle = |z: f64, a: f64, mass: f64| Nucleus {
+            name: text.to_string(),
+            z,
+            a,
+            atomic_mass: mass,
+            neutral_atom: false,
+        };
+        let alias = match text {
+            "n" => return Ok(particle(0.0, 1.0, NEUTRON_MASS)),
+            "e-" | "e" | "β-" | "beta-" => return Ok(particle(-1.0, 0.0, ELECTRON_MOLAR_MASS)),
+            "e+" | "β+" | "beta+" => return Ok(particle(1.0, 0.0, ELECTRON_MOLAR_MASS)),
+            "γ" | "gamma" | "ν" | "nu" | "anti-nu" | "ν̄" => return Ok(particle(0.0, 0.0, 0.0)),
+            "p" => "H-1",
+            "d" | "D" => "H-2",
+            "t" | "T" => "H-3",
+            "α" | "alpha" => "He-4",
+            other => other,
+        };
+
+        // "U-235", "U235", "235U" or "^235U"
+        let body = alias.trim_start_matches('^');
+        let symbol: String = body.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+        let digits: String = body.chars().filter(|c| c.is_ascii_digit()).collect();
+        let mass_number: u32 = digits
+            .parse()
+            .map_err(|_| format!("Missing mass number in nuclide: {}", name))?;
+        NUCLIDE_MASSES
+            .iter()
+            .find(|(s, _, a, _)| *s == symbol && *a == mass_number)
+            .map(|&(_, z, a, mass)| Nucleus {
+                name: format!("{}-{}", symbol, a),
+                z: z as f64,
+                a: a as f64,
+                atomic_mass: mass,
+                neutral_atom: true,
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Nuclide {} is not in the mass table; give {{\"z\", \"a\", \"mass\"}} instead",
+                    name
+                )
+            })
+    }
+}
+
+/// Nuclide given by name or by its atomic mass
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NuclideSpec {
+    Name(String),
+    Custom {
+        #[serde(default)]
+        name: Option<String>,
+        z: f64,
+        a: f64,
+        /// Atomic mass (u)
+        mass: f64,
+    },
+}
+
+impl NuclideSpec {
+    fn resolve(&self) -> Result<Nucleus, String> {
+        match self {
+            NuclideSpec::Name(name) => Nucleus::parse(name),
+            NuclideSpec::Custom { name, z, a, mass } => {
+                check_non_negative("Z", *z)?;
+                check_domain("A", *a, *a >= *z, "at least Z")?;
+                check_positive("nuclide mass", *mass)?;
+                Ok(Nucleus {
+                    name: name.clone().unwrap_or_else(|| format!("Z={} A={}", z, a)),
+                    z: *z,
+                    a: *a,
+                    atomic_mass: *mass,
+                    neutral_atom: true,
+                })
+            }
+        }
+    }
+}
+
+/// Binding energy of a nucleus
+#[derive(Serialize)]
+struct BindingEnergy {
+    nuclide: String,
+    z: f64,
+    a: f64,
+    neutrons: f64,
+    /// Atomic mass (u)
+    atomic_mass: f64,
+    /// Mass defect Z·m(¹H) + N·mₙ - M (u)
+    mass_defect: f64,
+    /// Total binding energy (MeV)
+    value: f64,
+    unit: String,
+    per_nucleon: CalculationResult,
+    /// Binding energy per mole of nuclei
+    molar: CalculationResult,
+}
+
+/// Calculate the mass defect and binding energy of a nucleus from its atomic mass
+/// Δm = Z·m(¹H) + N·mₙ - M(atom), BE = Δm·c² (931.494 MeV/u)
+///
+/// Arguments:
+/// - nuclide_json: Nuclide name ("U-235", "235U", "Fe56") or {"z", "a", "mass" (u)}
+#[wasm_func]
+pub fn nuclear_binding_energy(nuclide_json: &[u8]) -> Result<Vec<u8>, String> {
+    let spec: NuclideSpec = parse_json(nuclide_json, "nuclide")?;
+    let nucleus = spec.resolve()?;
+
+    if !nucleus.neutral_atom || nucleus.a < 1.0 {
+        return Err(format!("{} is not a nuclide", nucleus.name));
+    }
+    let hydrogen = NUCLIDE_MASSES[0].3;
+    let neutrons = nucleus.a - nucleus.z;
+    let mass_defect = nucleus.z * hydrogen + neutrons * NEUTRON_MASS - nucleus.atomic_mass;
+    let binding = mass_defect * MEV_PER_U;
+
+    let result = BindingEnergy {
+        nuclide: nucleus.name,
+        z: nucleus.z,
+        a: nucleus.a,
+        neutrons,
+        atomic_mass: nucleus.atomic_mass,
+        mass_defect,
+        value: binding,
+        unit: "MeV".to_string(),
+        per_nucleon: CalculationResult::new(binding / nucleus.a, "MeV"),
+        molar: CalculationResult::new(binding * KJ_PER_MOL_PER_MEV, "kJ/mol"),
+    };
+
+    to_json(&result)
+}
+
+/// Nuclide with an optional multiplicity, e.g. ["n", 3]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NuclearSpecies {
+    Single(NuclideSpec),
+    Counted(NuclideSpec, f64),
+}
+
+/// Energy released by a nuclear reaction
+#[derive(Serialize)]
+struct NuclearQValue {
+    /// Q = (Σm_reactants - Σm_products)·c² (MeV)
+    value: f64,
+    unit: String,
+    /// Mass lost (u)
+    mass_change: f64,
+    molar: CalculationResult,
+    /// "exoergic" (Q > 0) or "endoergic"
+    kind: String,
+    /// Projectile kinetic energy needed for an endoergic two-body reaction, with the first
+    /// reactant as the projectile (MeV)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    threshold_energy: Option<f64>,
+}
+
+/// Calculate the Q-value of a nuclear reaction from nuclear masses
+/// Q = (Σm_reactants - Σm_products)·c²
+///
+/// Charge and mass number must balance. Electrons and positrons are written explicitly
+/// ("e-", "e+"); neutrinos and γ rays are massless.
+///
+/// Arguments:
+/// - reactants_json: Array of nuclides, or [nuclide, count] pairs
+/// - products_json: Array of nuclides, or [nuclide, count] pairs
+#[wasm_func]
+pub fn nuclear_q_value(reactants_json: &[u8], products_json: &[u8]) -> Result<Vec<u8>, String> {
+    let reactants: Vec<NuclearSpecies> = parse_json(reactants_json, "reactants")?;
+    let products: Vec<NuclearSpecies> = parse_json(products_json, "products")?;
+
+    let resolve = |side: &[NuclearSpecies]| -> Result<Vec<(Nucleus, f64)>, String> {
+        side.iter()
+            .map(|species| match species {
+                NuclearSpecies::Single(spec) => Ok((spec.resolve()?, 1.0)),
+                NuclearSpecies::Counted(spec, count) => Ok((spec.resolve()?, *count)),
+            })
+            .collect()
+    };
+    let reactants = resolve(&reactants)?;
+    let products = resolve(&products)?;
+    if reactants.is_empty() || products.is_empty() {
+        return Err("Reactions need reactants and products".to_string());
+    }
+
+    let total = |side: &[(Nucleus, f64)], f: &dyn Fn(&Nucleus) -> f64| -> f64 {
+        side.iter().map(|(n, count)| f(n) * count).sum()
+    };
+    let charge = (total(&reactants, &|n| n.z), total(&products, &|n| n.z));
+    let nucleons = (total(&reactants, &|n| n.a), total(&products, &|n| n.a));
+    if (charge.0 - charge.1).abs() > 1e-9 || (nucleons.0 - nucleons.1).abs() > 1e-9 {
+        return Err(format!(
+            "Unbalanced nuclear reaction: charge {} → {}, mass number {} → {}",
+            charge.0, charge.1, nucleons.0, nucleons.1
+        ));
+    }
+
+    let mass_change = total(&reactants, &|n| n.nuclear_mass())
+        - total(&products, &|n| n.nuclear_mass());
+    let q = mass_change * MEV_PER_U;
+    let threshold_energy = match reactants.as_slice() {
+        [(projectile, 1.0), (target, 1.0)] if q < 0.0 => {
+            Some(-q * (projectile.nuclear_mass() + target.nuclear_mass()) / target.nuclear_mass())
+        }
+        _ => None,
+    };
+
+    let result = NuclearQValue {
+        value: q,
+        unit: "MeV".to_string(),
+        mass_change,
+        molar: CalculationResult::new(q * KJ_PER_MOL_PER_MEV, "kJ/mol"),
+        kind: if q >= 0.0 { "exoergic" } else { "endoergic" }.to_string(),
+        threshold_energy,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// CHEMICAL FORMULAS
+// ============================================================================
+
+/// Parsed chemical formula: element counts, ionic charge and optional state symbol
+#[derive(Serialize, Debug, Clone)]
+struct Formula {
+    /// Element → count, in order of first appearance
+    elements: Vec<(String, f64)>,
+    charge: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+}
+
+impl Formula {
+    fn count(&self, element: &str) -> f64 {
+        self.elements
+            .iter()
+            .find(|(symbol, _)| symbol == element)
+            .map_or(0.0, |(_, count)| *count)
+    }
+}
+
+/// Read an optional (possibly decimal) count, defaulting to 1
+fn read_count(chars: &[char], pos: &mut usize) -> f64 {
+    let start = *pos;
+    while *pos < chars.len() && chars[*pos].is_ascii_digit() {
+        *pos += 1;
+    }
+    if *pos < chars.len() && chars[*pos] == '.' && !is_hydrate_dot(chars, start, *pos) {
+        *pos += 1;
+        while *pos < chars.len() && chars[*pos].is_ascii_digit() {
+            *pos += 1;
+        }
+    }
+    if *pos == start {
+        1.0
+    } else {
+        chars[start..*pos].iter().collect::<String>().parse().unwrap_or(1.0)
+    }
+}
+
+/// Whether the '.' at `dot` separates a hydrate, as in "CuSO4.5H2O" or "MgSO4.H2O", rather
+/// than being the decimal point of a count such as "Fe0.95O" or "YBa2Cu3O6.5"
+fn is_hydrate_dot(chars: &[char], count_start: usize, dot: usize) -> bool {
+    let mut end = dot + 1;
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+    let starts_unit = chars
+        .get(end)
+        .is_some_and(|&c| c.is_ascii_uppercase() || matches!(c, '(' | '[' | '^'));
+    let integer_part = chars[count_start..dot].iter().collect::<String>();
+    starts_unit && (end == dot + 1 || !matches!(integer_part.as_str(), "" | "0"))
+}
+
+/// Digit of an isotope mass number, written plainly after a caret or as a superscript
+fn mass_number_digit(c: char) -> Option<char> {
+    if c.is_ascii_digit() {
+        return Some(c);
+    }
+    "⁰¹²³⁴⁵⁶⁷⁸⁹"
+        .chars()
+        .position(|s| s == c)
+        .map(|i| char::from(b'0' + i as u8))
+}
+
+/// Parse a formula body (no charge or state) with nested groups and hydrate dots
+fn parse_formula_body(body: &str) -> Result<Vec<(String, f64)>, String> {
+    let chars: Vec<char> = body.chars().collect();
+    let mut pos = 0;
+    // Stack of groups; each group accumulates element counts
+    let mut stack: Vec<Vec<(String, f64)>> = vec![Vec::new()];
+    let mut hydrate_factor = 1.0;
+
+    let add = |group: &mut Vec<(String, f64)>, symbol: &str, count: f64| {
+        match group.iter_mut().find(|(s, _)| s == symbol) {
+            Some((_, total)) => *total += count,
+            None => group.push((symbol.to_string(), count)),
+        }
+    };
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_ascii_uppercase() || c == '^' || mass_number_digit(c).is_some() {
+            // Isotope labels prefix the symbol with a mass number: "^13C" or "¹³C"
+            if c == '^' {
+                pos += 1;
+            }
+            let mut symbol = String::new();
+            while let Some(digit) = chars.get(pos).and_then(|&c| mass_number_digit(c)) {
+                symbol.push(digit);
+                pos += 1;
+            }
+            let label = !symbol.is_empty();
+            if !chars.get(pos).is_some_and(|c| c.is_ascii_uppercase()) {
+                return Err(format!("Isotope label without an element in formula: {}", body));
+            }
+            symbol.push(chars[pos]);
+            pos += 1;
+            while pos < chars.len() && chars[pos].is_ascii_lowercase() {
+                symbol.push(chars[pos]);
+                pos += 1;
+            }
+            if label && atomic_mass(&symbol).is_none() {
+                return Err(format!("Unknown isotope: {}", symbol));
+            }
+            let count = read_count(&chars, &mut pos) * hydrate_factor;
+            add(stack.last_mut().unwrap(), &symbol, count);
+        } else if c == '(' || c == '[' {
+            stack.push(Vec::new());
+            pos += 1;
+        } else if c == ')' || c == ']' {
+            pos += 1;
+            let multiplier = read_count(&chars, &mut pos);
+            let group = stack.pop().unwrap();
+            let parent = stack
+                .last_mut()
+                .ok_or_else(|| format!("Unbalanced brackets in formula: {}", body))?;
+            for (symbol, count) in group {
+                add(parent, &symbol, count * multiplier);
+            }
+        } else if matches!(c, '·' | '•' | '⋅' | '*' | '.') {
+            // Hydrate / adduct: "CuSO4·5H2O" or "CuSO4.5H2O"
+            pos += 1;
+            hydrate_factor = read_count(&chars, &mut pos);
+        } else if c.is_whitespace() {
+            pos += 1;
+        } else {
+            return Err(format!("Unexpected character '{}' in formula: {}", c, body));
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err(format!("Unbalanced brackets in formula: {}", body));
+    }
+    let elements = stack.pop().unwrap();
+    if elements.is_empty() {
+        return Err(format!("No elements in formula: {}", body));
+    }
+    Ok(elements)
+}
+
+/// Split a trailing state annotation off a species name, normalising "(gas)", "(G)",
+/// "[aq]", " (cr)" and similar to "g", "l", "s" or "aq"
+fn split_state(species: &str) -> (&str, Option<&'static str>) {
+    let text = species.trim();
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        let Some(inner) = text.strip_suffix(close) else {
+            continue;
+        };
+        let Some(start) = inner.rfind(open) else {
+            continue;
+        };
+        let state = match inner[start + 1..].trim().to_lowercase().as_str() {
+            "g" | "gas" | "vap" | "vapor" | "vapour" => "g",
+            "l" | "liq" | "liquid" => "l",
+            "s" | "c" | "cr" | "solid" | "crystal" | "crystalline" => "s",
+            "aq" | "aqueous" | "soln" => "aq",
+            _ => continue,
+        };
+        return (inner[..start].trim_end(), Some(state));
+    }
+    (text, None)
+}
+
+/// Replace Unicode subscript digits with ASCII digits, e.g. "H₂O" → "H2O"
+fn ascii_subscripts(text: &str) -> String {
+    text.chars()
+        .map(|c| match "₀₁₂₃₄₅₆₇₈₉".chars().position(|s| s == c) {
+            Some(i) => char::from(b'0' + i as u8),
+            None => c,
+        })
+        .collect()
+}
+
+/// Species name with ASCII digits and a normalised state, e.g. "H₂O (liquid)" → "H2O(l)"
+fn species_key(species: &str) -> String {
+    let text = ascii_subscripts(species);
+    let (formula, state) = split_state(&text);
+    match state {
+        Some(state) => format!("{}({})", formula, state),
+        None => formula.to_string(),
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != *cb)).min(above + 1).min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Look up a species in a data table
+///
+/// Exact keys are used as they are; otherwise subscript digits and state spellings are
+/// normalised ("H₂O (liquid)" finds "H2O(l)") and names match regardless of case ("Water"
+/// finds "water"). If that fails the error suggests up to three
+/// keys: the same formula in other states, case variants and names within two edits.
+fn lookup_species<'a, T>(
+    data: &'a HashMap<String, T>,
+    species: &str,
+    role: &str,
+) -> Result<&'a T, String> {
+    if let Some(entry) = data.get(species) {
+        return Ok(entry);
+    }
+    let key = species_key(species);
+    let mut normalized = data.iter().filter(|(k, _)| species_key(k) == key);
+    if let (Some((_, entry)), None) = (normalized.next(), normalized.next()) {
+        return Ok(entry);
+    }
+    // Names such as "water" (see alias-data) are matched regardless of case
+    let name = species.trim().to_lowercase();
+    if let Some((_, entry)) = data
+        .iter()
+        .filter(|(k, _)| k.to_lowercase() == name && parse_formula(k).is_err())
+        .min_by_key(|(k, _)| k.as_str())
+    {
+        return Ok(entry);
+    }
+
+    let formula = |k: &str| split_state(k).0.to_string();
+    let query_formula = formula(&key);
+    let lower = key.to_lowercase();
+    let mut suggestions: Vec<(usize, &String)> = data
+        .keys()
+        .filter_map(|k| {
+            let candidate = species_key(k);
+            let score = if candidate.to_lowercase() == lower {
+                0
+            } else if formula(&candidate) == query_formula {
+                1
+            } else {
+                match edit_distance(&candidate.to_lowercase(), &lower) {
+                    d @ 1..=2 => d + 1,
+                    _ => return None,
+                }
+            };
+            Some((score, k))
+        })
+        .collect();
+    suggestions.sort();
+
+    let mut message = format!("No data found for {}: {}", role, species);
+    if !suggestions.is_empty() {
+        let names: Vec<&str> = suggestions.iter().take(3).map(|(_, k)| k.as_str()).collect();
+        message += &format!(". Did you mean {}?", names.join(", "));
+    }
+    Err(message)
+}
+
+/// Parse a chemical formula such as "Fe2O3(s)", "Cu2+(aq)", "SO4^2-" or "CuSO4·5H2O"
+///
+/// Charges are written as a trailing sign, optionally preceded by a magnitude. A bare
+/// magnitude is read as the charge only for monatomic ions ("Fe3+"); for polyatomic ions
+/// it is an atom count ("NH4+", "MnO4-"), so multiply charged polyatomic ions need a caret
+/// ("SO4^2-", "Hg2^2+"). Isotope labels prefix the symbol with a caret and mass number
+/// or a superscript mass number ("^13CO2", "¹⁵NH3"); D and T stand for ²H and ³H.
+fn parse_formula(formula: &str) -> Result<Formula, String> {
+    let (body, state) = split_state(formula);
+    let mut text = ascii_subscripts(body);
+    let state = state.map(String::from);
+
+    let mut charge = 0.0;
+    if let Some(sign_char) = text.chars().last().filter(|c| *c == '+' || *c == '-') {
+        let sign = if sign_char == '+' { 1.0 } else { -1.0 };
+        text.pop();
+        // A caret followed by letters is an isotope label, not a charge
+        if let Some(caret) = text
+            .rfind('^')
+            .filter(|&i| text[i + 1..].chars().all(|c| c.is_ascii_digit()))
+        {
+            let magnitude = &text[caret + 1..];
+            charge = sign
+                * if magnitude.is_empty() {
+                    1.0
+                } else {
+                    magnitude
+                        .parse::<f64>()
+                        .map_err(|_| format!("Invalid charge in formula: {}", formula))?
+                };
+            text.truncate(caret);
+        } else {
+            let digits_start = text
+                .rfind(|c: char| !c.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            let head = &text[..digits_start];
+            let monatomic = head.len() <= 2
+                && head.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+                && head.chars().skip(1).all(|c| c.is_ascii_lowercase());
+            if monatomic && digits_start < text.len() {
+                charge = sign * text[digits_start..].parse::<f64>().unwrap();
+                text.truncate(digits_start);
+            } else {
+                charge = sign;
+            }
+        }
+    }
+
+    Ok(Formula {
+        elements: parse_formula_body(&text)?,
+        charge,
+        state,
+    })
+}
+
+/// Standard atomic weights (g/mol); mass number of the longest-lived isotope for elements
+/// without a stable one. D and T are accepted for deuterium and tritium.
+const ATOMIC_MASSES: [(&str, f64); 120] = [
+    ("H", 1.008), ("D", 2.0141), ("T", 3.0160), ("He", 4.0026), ("Li", 6.94),
+    ("Be", 9.0122), ("B", 10.81), ("C", 12.011), ("N", 14.007), ("O", 15.999),
+    ("F", 18.998), ("Ne", 20.180), ("Na", 22.990), ("Mg", 24.305), ("Al", 26.982),
+    ("Si", 28.085), ("P", 30.974), ("S", 32.06), ("Cl", 35.45), ("Ar", 39.95),
+    ("K", 39.098), ("Ca", 40.078), ("Sc", 44.956), ("Ti", 47.867), ("V", 50.942),
+    ("Cr", 51.996), ("Mn", 54.938), ("Fe", 55.845), ("Co", 58.933), ("Ni", 58.693),
+    ("Cu", 63.546), ("Zn", 65.38), ("Ga", 69.723), ("Ge", 72.630), ("As", 74.922),
+    ("Se", 78.971), ("Br", 79.904), ("Kr", 83.798), ("Rb", 85.468), ("Sr", 87.62),
+    ("Y", 88.906), ("Zr", 91.224), ("Nb", 92.906), ("Mo", 95.95), ("Tc", 97.0),
+    ("Ru", 101.07), ("Rh", 102.91), ("Pd", 106.42), ("Ag", 107.87), ("Cd", 112.41),
+    ("In", 114.82), ("Sn", 118.71), ("Sb", 121.76), ("Te", 127.60), ("I", 126.90),
+    ("Xe", 131.29), ("Cs", 132.91), ("Ba", 137.33), ("La", 138.91), ("Ce", 140.12),
+    ("Pr", 140.91), ("Nd", 144.24), ("Pm", 145.0), ("Sm", 150.36), ("Eu", 151.96),
+    ("Gd", 157.25), ("Tb", 158.93), ("Dy", 162.50), ("Ho", 164.93), ("Er", 167.26),
+    ("Tm", 168.93), ("Yb", 173.05), ("Lu", 174.97), ("Hf", 178.49), ("Ta", 180.95),
+    ("W", 183.84), ("Re", 186.21), ("Os", 190.23), ("Ir", 192.22), ("Pt", 195.08),
+    ("Au", 196.97), ("Hg", 200.59), ("Tl", 204.38), ("Pb", 207.2), ("Bi", 208.98),
+    ("Po", 209.0), ("At", 210.0), ("Rn", 222.0), ("Fr", 223.0), ("Ra", 226.0),
+    ("Ac", 227.0), ("Th", 232.04), ("Pa", 231.04), ("U", 238.03), ("Np", 237.0),
+    ("Pu", 244.0), ("Am", 243.0), ("Cm", 247.0), ("Bk", 247.0), ("Cf", 251.0),
+    ("Es", 252.0), ("Fm", 257.0), ("Md", 258.0), ("No", 259.0), ("Lr", 266.0),
+    ("Rf", 267.0), ("Db", 268.0), ("Sg", 269.0), ("Bh", 270.0), ("Hs", 269.0),
+    ("Mt", 278.0), ("Ds", 281.0), ("Rg", 282.0), ("Cn", 285.0), ("Nh", 286.0),
+    ("Fl", 289.0), ("Mc", 290.0), ("Lv", 293.0), ("Ts", 294.0), ("Og", 294.0),
+];
+
+/// Atomic weight of an element, or the exact mass of an isotope label such as "13C"
+fn atomic_mass(symbol: &str) -> Option<f64> {
+    if let Some((element, mass_number)) = split_isotope_label(symbol) {
+        return isotope_mass(element, mass_number);
+    }
+    ATOMIC_MASSES
+        .iter()
+        .find(|(s, _)| *s == symbol)
+        .map(|(_, mass)| *mass)
+}
+
+/// Stable isotopes: (symbol, mass number, atomic mass (u), natural abundance (%))
+const ISOTOPES: [(&str, u32, f64, f64); 62] = [
+    ("H", 1, 1.007_825_032, 99.9885), ("H", 2, 2.014_101_778, 0.0115),
+    ("He", 3, 3.016_029_320, 0.000_134), ("He", 4, 4.002_603_254, 99.999_866),
+    ("Li", 6, 6.015_122_887, 7.59), ("Li", 7, 7.016_003_437, 92.41),
+    ("B", 10, 10.012_936_95, 19.9), ("B", 11, 11.009_305_36, 80.1),
+    ("C", 12, 12.0, 98.93), ("C", 13, 13.003_354_835, 1.07),
+    ("N", 14, 14.003_074_004, 99.636), ("N", 15, 15.000_108_899, 0.364),
+    ("O", 16, 15.994_914_620, 99.757), ("O", 17, 16.999_131_757, 0.038),
+    ("O", 18, 17.999_159_613, 0.205), ("F", 19, 18.998_403_163, 100.0),
+    ("Na", 23, 22.989_769_282, 100.0), ("Mg", 24, 23.985_041_697, 78.99),
+    ("Mg", 25, 24.985_836_976, 10.00), ("Mg", 26, 25.982_592_968, 11.01),
+    ("Al", 27, 26.981_538_53, 100.0), ("Si", 28, 27.976_926_535, 92.223),
+    ("Si", 29, 28.976_494_665, 4.685), ("Si", 30, 29.973_770_136, 3.092),
+    ("P", 31, 30.973_761_998, 100.0), ("S", 32, 31.972_071_174, 94.99),
+    ("S", 33, 32.971_458_910, 0.75), ("S", 34, 33.967_867_004, 4.25),
+    ("S", 36, 35.967_080_71, 0.01), ("Cl", 35, 34.968_852_682, 75.76),
+    ("Cl", 37, 36.965_902_602, 24.24), ("K", 39, 38.963_706_486, 93.2581),
+    ("K", 40, 39.963_998_166, 0.0117), ("K", 41, 40.961_825_258, 6.7302),
+    ("Ca", 40, 39.962_590_863, 96.941), ("Ca", 42, 41.958_617_83, 0.647),
+    ("Ca", 43, 42.958_766_44, 0.135), ("Ca", 44, 43.955_481_6, 2.086),
+    ("Ca", 48, 47.952_522_76, 0.187), ("Fe", 54, 53.939_608_99, 5.845),
+    ("Fe", 56, 55.934_936_33, 91.754), ("Fe", 57, 56.935_392_84, 2.119),
+    ("Fe", 58, 57.933_274_43, 0.282), ("Cu", 63, 62.929_597_72, 69.15),
+    ("Cu", 65, 64.927_789_70, 30.85), ("Zn", 64, 63.929_142_01, 49.17),
+    ("Zn", 66, 65.926_033_81, 27.73), ("Zn", 67, 66.927_127_75, 4.04),
+    ("Zn", 68, 67.924_844_55, 18.45), ("Br", 79, 78.918_337_6, 50.69),
+    ("Br", 81, 80.916_289_7, 49.31), ("Ag", 107, 106.905_091_6, 51.839),
+    ("Ag", 109, 108.904_755_3, 48.161), ("I", 127, 126.904_471_9, 100.0),
+    ("Pb", 204, 203.973_044_0, 1.4), ("Pb", 206, 205.974_465_7, 24.1),
+    ("Pb", 207, 206.975_897_3, 22.1), ("Pb", 208, 207.976_652_5, 52.4),
+    ("Th", 232, 232.038_055_8, 100.0), ("U", 234, 234.040_952_3, 0.0054),
+    ("U", 235, 235.043_930_1, 0.7204), ("U", 238, 238.050_788_4, 99.2742),
+];
+
+/// Split an isotope label such as "13C" into its element and mass number
+fn split_isotope_label(label: &str) -> Option<(&str, u32)> {
+    let split = label.find(|c: char| !c.is_ascii_digit())?;
+    (split > 0).then(|| (&label[split..], label[..split].parse().unwrap()))
+}
+
+/// Exact mass (u) of an isotope from the stable-isotope table, or from the nuclide table
+/// for radioisotopes
+fn isotope_mass(element: &str, mass_number: u32) -> Option<f64> {
+    ISOTOPES
+        .iter()
+        .find(|(s, a, _, _)| *s == element && *a == mass_number)
+        .map(|(_, _, mass, _)| *mass)
+        .or_else(|| {
+            NUCLIDE_MASSES
+                .iter()
+                .find(|(s, _, a, _)| *s == element && *a == mass_number)
+                .map(|(_, _, _, mass)| *mass)
+        })
+}
+
+impl Formula {
+    /// Molar mass from the standard atomic weights (g/mol)
+    fn molar_mass(&self) -> Result<f64, String> {
+        self.elements
+            .iter()
+            .map(|(symbol, count)| {
+                atomic_mass(symbol)
+                    .map(|mass| mass * count)
+                    .ok_or_else(|| format!("Unknown element: {}", symbol))
+            })
+            .sum()
+    }
+}
+
+/// Molar mass given directly (g/mol) or as a formula
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MolarMassSpec {
+    Value(f64),
+    Formula(String),
+}
+
+impl MolarMassSpec {
+    fn value(&self) -> Result<f64, String> {
+        let mass = match self {
+            MolarMassSpec::Value(mass) => *mass,
+            MolarMassSpec::Formula(formula) => parse_formula(formula)?.molar_mass()?,
+        };
+        check_positive("molar mass", mass)?;
+        Ok(mass)
+    }
+}
+
+/// Share of one element in a formula
+#[derive(Serialize)]
+struct ElementShare {
+    element: String,
+    count: f64,
+    /// Mass contributed per mole of formula units (g/mol)
+    mass: f64,
+    mass_percent: f64,
+}
+
+/// Composition of a parsed chemical formula
+#[derive(Serialize)]
+struct FormulaComposition {
+    formula: String,
+    elements: Vec<ElementShare>,
+    charge: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    /// Total number of atoms per formula unit
+    atoms: f64,
+    molar_mass: CalculationResult,
+}
+
+impl FormulaComposition {
+    fn new(text: &str) -> Result<Self, String> {
+        let formula = parse_formula(text)?;
+        let molar_mass = formula.molar_mass()?;
+        let mut atoms = 0.0;
+        let elements = formula
+            .elements
+            .iter()
+            .map(|(symbol, count)| {
+                atoms += count;
+                let mass = atomic_mass(symbol).unwrap() * count;
+                ElementShare {
+                    element: symbol.clone(),
+                    count: *count,
+                    mass,
+                    mass_percent: mass / molar_mass * 100.0,
+                }
+            })
+            .collect();
+        Ok(Self {
+            formula: text.trim().to_string(),
+            elements,
+            charge: formula.charge,
+            state: formula.state,
+            atoms,
+            molar_mass: CalculationResult::new(molar_mass, "g/mol"),
+        })
+    }
+}
+
+/// Parse a chemical formula into its element composition and molar mass
+///
+/// Supports nested brackets ("Ca3(PO4)2", "[Cu(NH3)4]SO4"), hydrates and adducts
+/// ("CuSO4·5H2O"), charges ("Fe3+", "SO4^2-") and state symbols ("NaCl(aq)"). Electron
+/// masses of ions are neglected.
+///
+/// Arguments:
+/// - formula: Chemical formula
+#[wasm_func]
+pub fn formula_composition(formula_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let formula: String = parse_arg(formula_bytes, "formula")?;
+
+    let result = FormulaComposition::new(&formula)?;
+
+    to_json(&result)
+}
+
+/// Calculate the molar mass of a chemical formula from standard atomic weights
+///
+/// Arguments:
+/// - formula: Chemical formula
+#[wasm_func]
+pub fn molar_mass(formula_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let formula: String = parse_arg(formula_bytes, "formula")?;
+
+    let result = CalculationResult::new(parse_formula(&formula)?.molar_mass()?, "g/mol");
+
+    to_json(&result)
+}
+
+/// Exact mass (u), mass number and natural abundance (fraction) of the monoisotopic
+/// species of a formula symbol: the label itself, ²H/³H for D/T, or the most abundant
+/// isotope of an element. Labelled positions count as fully enriched.
+fn monoisotopic_species(symbol: &str) -> Result<(f64, u32, f64), String> {
+    let (element, mass_number) = match symbol {
+        "D" => ("H", 2),
+        "T" => ("H", 3),
+        _ => match split_isotope_label(symbol) {
+            Some(label) => label,
+            None => {
+                return ISOTOPES
+                    .iter()
+                    .filter(|(s, _, _, _)| *s == symbol)
+                    .max_by(|x, y| x.3.total_cmp(&y.3))
+                    .map(|&(_, a, mass, abundance)| (mass, a, abundance / 100.0))
+                    .ok_or_else(|| format!("No isotope data for element: {}", symbol));
+            }
+        },
+    };
+    isotope_mass(element, mass_number)
+        .map(|mass| (mass, mass_number, 1.0))
+        .ok_or_else(|| format!("Unknown isotope: {}{}", mass_number, element))
+}
+
+/// Isotopically labelled position in a formula
+#[derive(Serialize)]
+struct IsotopeLabel {
+    isotope: String,
+    count: f64,
+    /// Exact mass of one atom (u)
+    mass: f64,
+}
+
+#[derive(Serialize)]
+struct IsotopicMasses {
+    formula: String,
+    /// Standard atomic weights, with labelled positions at their exact masses (g/mol)
+    average_mass: CalculationResult,
+    /// Most abundant isotope of every unlabelled element (u)
+    monoisotopic_mass: CalculationResult,
+    /// Sum of the mass numbers of the monoisotopic species
+    nominal_mass: f64,
+    /// Natural abundance of the monoisotopic species (fraction)
+    monoisotopic_abundance: f64,
+    labels: Vec<IsotopeLabel>,
+    /// Monoisotopic mass gained by labelling relative to the unlabelled compound (u)
+    label_shift: f64,
+}
+
+/// Calculate average, monoisotopic and nominal masses of a formula, which may carry isotope
+/// labels such as "^13CO2", "¹⁵NH3" or "D2O"
+///
+/// Arguments:
+/// - formula: Chemical formula
+#[wasm_func]
+pub fn isotopic_masses(formula_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let text: String = parse_arg(formula_bytes, "formula")?;
+    let formula = parse_formula(&text)?;
+
+    let mut monoisotopic = 0.0;
+    let mut nominal = 0.0;
+    let mut abundance = 1.0;
+    let mut unlabelled = 0.0;
+    let mut labels = Vec::new();
+    for (symbol, count) in &formula.elements {
+        let (mass, mass_number, fraction) = monoisotopic_species(symbol)?;
+        monoisotopic += mass * count;
+        nominal += mass_number as f64 * count;
+        abundance *= fraction.powf(*count);
+
+        let element = match symbol.as_str() {
+            "D" | "T" => "H",
+            _ => split_isotope_label(symbol).map_or(symbol.as_str(), |(element, _)| element),
+        };
+        unlabelled += monoisotopic_species(element)?.0 * count;
+        if element != symbol {
+            labels.push(IsotopeLabel {
+                isotope: format!("{}{}", mass_number, element),
+                count: *count,
+                mass,
+            });
+        }
+    }
+
+    let result = IsotopicMasses {
+        formula: text.trim().to_string(),
+        average_mass: CalculationResult::new(formula.molar_mass()?, "g/mol"),
+        monoisotopic_mass: CalculationResult::new(monoisotopic, "u"),
+        nominal_mass: nominal,
+        monoisotopic_abundance: abundance,
+        labels,
+        label_shift: monoisotopic - unlabelled,
+    };
+
+    to_json(&result)
+}
+
+/// Isotope of an element
+#[derive(Serialize)]
+struct IsotopeEntry {
+    mass_number: u32,
+    /// Atomic mass (u)
+    mass: f64,
+    /// Natural abundance (%)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    abundance: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct ElementIsotopes {
+    element: String,
+    /// Standard atomic weight (g/mol)
+    atomic_weight: f64,
+    isotopes: Vec<IsotopeEntry>,
+    /// Radioisotopes of the nuclide table without a tabulated natural abundance
+    radioisotopes: Vec<IsotopeEntry>,
+    /// Abundance-weighted mean of the stable isotope masses (u)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    isotopic_average: Option<f64>,
+}
+
+/// Look up the isotopes of an element with their exact masses and natural abundances
+///
+/// Arguments:
+/// - element: Element symbol
+#[wasm_func]
+pub fn isotope_data(element_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let element: String = parse_arg(element_bytes, "element")?;
+
+    let atomic_weight =
+        atomic_mass(&element).ok_or_else(|| format!("Unknown element: {}", element))?;
+    let isotopes: Vec<IsotopeEntry> = ISOTOPES
+        .iter()
+        .filter(|(s, _, _, _)| *s == element)
+        .map(|&(_, mass_number, mass, abundance)| IsotopeEntry {
+            mass_number,
+            mass,
+            abundance: Some(abundance),
+        })
+        .collect();
+    let radioisotopes = NUCLIDE_MASSES
+        .iter()
+        .filter(|(s, _, a, _)| *s == element && isotopes.iter().all(|i| i.mass_number != *a))
+        .map(|&(_, _, mass_number, mass)| IsotopeEntry {
+            mass_number,
+            mass,
+            abundance: None,
+        })
+        .collect();
+
+    let mut total = 0.0;
+    let mut weighted = 0.0;
+    for (_, _, mass, abundance) in ISOTOPES.iter().filter(|(s, _, _, _)| *s == element) {
+        total += abundance;
+        weighted += abundance * mass;
+    }
+
+    let result = ElementIsotopes {
+        element,
+        atomic_weight,
+        isotopic_average: (total > 0.0).then(|| weighted / total),
+        isotopes,
+        radioisotopes,
+    };
+
+    to_json(&result)
+}
+
+/// Change in moles of gas over a reaction, Δn(gas) = Σν(g, products) - Σν(g, reactants),
+/// with the species whose state is not annotated
+fn gas_mole_change(
+    reactants: &[(String, f64)],
+    products: &[(String, f64)],
+) -> (f64, f64, f64, Vec<String>) {
+    let mut unspecified = Vec::new();
+    let mut gas = |side: &[(String, f64)]| {
+        let mut total = 0.0;
+        for (species, coefficient) in side {
+            match split_state(species).1 {
+                Some("g") => total += coefficient,
+                Some(_) => {}
+                None => unspecified.push(species.clone()),
+            }
+        }
+        total
+    };
+    let gas_reactants = gas(reactants);
+    let gas_products = gas(products);
+    (gas_products - gas_reactants, gas_reactants, gas_products, unspecified)
+}
+
+/// Species name split into its formula and normalised state
+#[derive(Serialize)]
+struct SpeciesState {
+    species: String,
+    formula: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    /// Formula with the normalised state appended, e.g. "H2O(g)"
+    normalized: String,
+}
+
+impl SpeciesState {
+    fn new(species: &str) -> Self {
+        let (formula, state) = split_state(species);
+        Self {
+            species: species.to_string(),
+            formula: formula.to_string(),
+            state: state.map(String::from),
+            normalized: match state {
+                Some(state) => format!("{}({})", formula, state),
+                None => formula.to_string(),
+            },
+        }
+    }
+}
+
+/// Normalise the state annotation of a species name, e.g. "H2O (gas)" → "H2O(g)"
+///
+/// Arguments:
+/// - species: Species name with an optional state annotation
+#[wasm_func]
+pub fn normalize_species(species_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let species: String = parse_arg(species_bytes, "species")?;
+
+    to_json(&SpeciesState::new(&species))
+}
+
+/// Gas mole change of a reaction and the conversions built on it
+#[derive(Serialize)]
+struct GasMoleChange {
+    /// Δn(gas)
+    value: f64,
+    gas_reactants: f64,
+    gas_products: f64,
+    reactants: Vec<SpeciesState>,
+    products: Vec<SpeciesState>,
+    /// Species without a state annotation, not counted as gases
+    unspecified: Vec<String>,
+    /// Δn·RT, so that ΔH = ΔU + Δn·RT (kJ/mol)
+    rt_delta_n: CalculationResult,
+    /// (RT/P°)^Δn with c° = 1 mol/L, so that Kp = Kc·(RT/P°)^Δn
+    kp_over_kc: f64,
+    /// Standard-state pressure P° of Kp ("bar" or "atm")
+    standard_pressure: String,
+    /// -Δn·R·ln(P°/1 bar), to add to a reaction entropy from 1 bar data (J/(mol·K))
+    entropy_shift: CalculationResult,
+}
+
+/// Calculate Δn(gas) of a reaction from the state annotations of its species
+///
+/// Arguments:
+/// - reactants_json: Array of (species, coefficient) tuples
+/// - products_json: Array of (species, coefficient) tuples
+/// - T: Temperature for the ΔH–ΔU and Kp–Kc conversions (K)
+/// - standard_pressure: Standard-state pressure P°, "bar" or "atm"
+#[wasm_func]
+pub fn reaction_gas_change(
+    reactants_json: &[u8],
+    products_json: &[u8],
+    temperature_bytes: &[u8],
+    standard_pressure_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let reactants: Vec<(String, f64)> = parse_json(reactants_json, "reactants")?;
+    let products: Vec<(String, f64)> = parse_json(products_json, "products")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let standard_pressure: String = parse_arg(standard_pressure_bytes, "standard pressure")?;
+
+    check_temperature(temperature)?;
+    let (standard_bar, standard_symbol) = match standard_pressure.as_str() {
+        "" | "bar" => (1.0, "bar"),
+        "atm" => (1.01325, "atm"),
+        _ => {
+            return Err(format!(
+                "Unknown standard-state pressure: {} (expected bar or atm)",
+                standard_pressure
+            ))
+        }
+    };
+    let (delta_n, gas_reactants, gas_products, unspecified) =
+        gas_mole_change(&reactants, &products);
+    let states = |side: &[(String, f64)]| side.iter().map(|(s, _)| SpeciesState::new(s)).collect();
+
+    let result = GasMoleChange {
+        value: delta_n,
+        gas_reactants,
+        gas_products,
+        reactants: states(&reactants),
+        products: states(&products),
+        unspecified,
+        rt_delta_n: CalculationResult::new(delta_n * R * temperature / 1000.0, "kJ/mol"),
+        kp_over_kc: (R_LITRE_BAR * temperature / standard_bar).powf(delta_n),
+        standard_pressure: standard_symbol.to_string(),
+        entropy_shift: CalculationResult::new(-delta_n * R * standard_bar.ln(), "J/(mol·K)"),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// STOICHIOMETRY
+// ============================================================================
+
+/// Check that a reaction conserves every element and the charge
+fn check_balance(reactants: &[(String, f64)], products: &[(String, f64)]) -> Result<(), String> {
+    let mut totals: Vec<(String, f64)> = Vec::new();
+    let mut charge = 0.0;
+    for (side, sign) in [(reactants, -1.0), (products, 1.0)] {
+        for (species, coefficient) in side {
+            let formula = parse_formula(species)?;
+            charge += sign * coefficient * formula.charge;
+            for (element, count) in formula.elements {
+                let change = sign * coefficient * count;
+                match totals.iter_mut().find(|(e, _)| *e == element) {
+                    Some((_, total)) => *total += change,
+                    None => totals.push((element, change)),
+                }
+            }
+        }
+    }
+    let unbalanced: Vec<String> = totals
+        .iter()
+        .filter(|(_, total)| total.abs() > 1e-6)
+        .map(|(element, total)| format!("{} ({:+})", element, total))
+        .collect();
+    if !unbalanced.is_empty() {
+        return Err(format!(
+            "Reaction is not balanced: {} (products - reactants)",
+            unbalanced.join(", ")
+        ));
+    }
+    if charge.abs() > 1e-6 {
+        return Err(format!("Reaction is not charge balanced: {:+} (products - reactants)", charge));
+    }
+    Ok(())
+}
+
+/// Check that an ionic reaction is charge balanced
+///
+/// Species whose names are not formulas (e.g. custom table keys) make the charge unknown,
+/// in which case the check is skipped.
+fn check_charge_balance(
+    reactants: &[(String, f64)],
+    products: &[(String, f64)],
+) -> Result<(), String> {
+    let mut charge = 0.0;
+    for (side, sign) in [(reactants, -1.0), (products, 1.0)] {
+        for (species, coefficient) in side {
+            match parse_formula(species) {
+                Ok(formula) => charge += sign * coefficient * formula.charge,
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+    if charge.abs() > 1e-6 {
+        return Err(format!("Reaction is not charge balanced: {:+} (products - reactants)", charge));
+    }
+    Ok(())
+}
+
+/// Parse an amount unit, returning its size in grams or moles and whether it is a mass
+fn parse_amount_unit(unit: &str) -> Result<(f64, bool), String> {
+    match unit {
+        "" | "g" => Ok((1.0, true)),
+        "mg" => Ok((1e-3, true)),
+        "kg" => Ok((1e3, true)),
+        "t" => Ok((1e6, true)),
+        "mol" => Ok((1.0, false)),
+        "mmol" => Ok((1e-3, false)),
+        "kmol" => Ok((1e3, false)),
+        _ => Err(format!(
+            "Unknown amount unit: {} (expected g, mg, kg, t, mol, mmol or kmol)",
+            unit
+        )),
+    }
+}
+
+/// Amount of a species, in grams unless a unit is given
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SpeciesAmount {
+    WithUnit(String, f64, String),
+    Mass(String, f64),
+}
+
+impl SpeciesAmount {
+    /// Species name and amount in moles
+    fn moles(&self) -> Result<(&str, f64), String> {
+        let (species, value, unit) = match self {
+            SpeciesAmount::WithUnit(species, value, unit) => (species, *value, unit.as_str()),
+            SpeciesAmount::Mass(species, value) => (species, *value, "g"),
+        };
+        check_non_negative(&format!("amount of {}", species), value)?;
+        let (factor, mass) = parse_amount_unit(unit)?;
+        let moles = if mass {
+            value * factor / parse_formula(species)?.molar_mass()?
+        } else {
+            value * factor
+        };
+        Ok((species, moles))
+    }
+}
+
+#[derive(Deserialize)]
+struct StoichiometryInput {
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    /// Known amounts of reactants (or, without any, of one product)
+    amounts: Vec<SpeciesAmount>,
+    /// Product the yield refers to (default: the first product)
+    #[serde(default)]
+    product: Option<String>,
+    /// Isolated mass of that product (g)
+    #[serde(default)]
+    actual_yield: Option<f64>,
+}
+
+/// Amounts of one species over the reaction
+#[derive(Serialize)]
+struct SpeciesStoichiometry {
+    species: String,
+    coefficient: f64,
+    molar_mass: f64,
+    /// Amount available before the reaction, if given (mol, g)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_moles: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_mass: Option<f64>,
+    /// Amount consumed (reactants) or formed (products) at full conversion (mol, g)
+    moles: f64,
+    mass: f64,
+    /// Excess left over, for reactants with a given amount (mol, g)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remaining_moles: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remaining_mass: Option<f64>,
+    limiting: bool,
+}
+
+#[derive(Serialize)]
+struct Stoichiometry {
+    /// Extent of reaction at full conversion (mol)
+    extent: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limiting_reagent: Option<String>,
+    reactants: Vec<SpeciesStoichiometry>,
+    products: Vec<SpeciesStoichiometry>,
+    product: String,
+    /// Theoretical yield of the product (g)
+    theoretical_yield: CalculationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_yield: Option<f64>,
+}
+
+/// Solve the stoichiometry of a balanced reaction
+///
+/// With reactant amounts, the limiting reagent has the smallest nᵢ/νᵢ, which sets the extent
+/// ξ; every species then changes by νᵢ·ξ. A product amount alone gives the reactants
+/// required to make it.
+///
+/// Arguments:
+/// - input_json: {"reactants", "products", "amounts": [(species, value, unit)...],
+///   "product", "actual_yield"}
+#[wasm_func]
+pub fn reaction_stoichiometry(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: StoichiometryInput = parse_json(input_json, "input")?;
+
+    if input.reactants.is_empty() || input.products.is_empty() {
+        return Err("Reactions need reactants and products".to_string());
+    }
+    for (species, coefficient) in input.reactants.iter().chain(&input.products) {
+        check_positive(&format!("coefficient of {}", species), *coefficient)?;
+    }
+    check_balance(&input.reactants, &input.products)?;
+
+    let mut given: Vec<(&str, f64)> = Vec::new();
+    for amount in &input.amounts {
+        given.push(amount.moles()?);
+    }
+    let amount_of = |species: &str| given.iter().find(|(s, _)| *s == species).map(|(_, n)| *n);
+    for (species, _) in &given {
+        if !input.reactants.iter().chain(&input.products).any(|(s, _)| s == species) {
+            return Err(format!("{} does not take part in the reaction", species));
+        }
+    }
+
+    // Extent: smallest nᵢ/νᵢ over the given reactants, otherwise from a given product
+    let mut limiting = None;
+    let mut extent = f64::INFINITY;
+    for (species, coefficient) in &input.reactants {
+        if let Some(moles) = amount_of(species) {
+            if moles / coefficient < extent {
+                extent = moles / coefficient;
+                limiting = Some(species.clone());
+            }
+        }
+    }
+    if limiting.is_none() {
+        extent = input
+            .products
+            .iter()
+            .find_map(|(species, coefficient)| amount_of(species).map(|n| n / coefficient))
+            .ok_or("Give the amount of at least one reactant or product")?;
+    }
+
+    let species_row = |species: &str, coefficient: f64, reactant: bool| {
+        let molar_mass = parse_formula(species)?.molar_mass()?;
+        let initial = amount_of(species).filter(|_| reactant);
+        let moles = coefficient * extent;
+        let remaining = initial.map(|n| (n - moles).max(0.0));
+        Ok(SpeciesStoichiometry {
+            species: species.to_string(),
+            coefficient,
+            molar_mass,
+            initial_moles: initial,
+            initial_mass: initial.map(|n| n * molar_mass),
+            moles,
+            mass: moles * molar_mass,
+            remaining_moles: remaining,
+            remaining_mass: remaining.map(|n| n * molar_mass),
+            limiting: limiting.as_deref() == Some(species),
+        })
+    };
+    let reactants = input
+        .reactants
+        .iter()
+        .map(|(s, c)| species_row(s, *c, true))
+        .collect::<Result<Vec<_>, String>>()?;
+    let products = input
+        .products
+        .iter()
+        .map(|(s, c)| species_row(s, *c, false))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let product = input.product.unwrap_or_else(|| input.products[0].0.clone());
+    let theoretical = products
+        .iter()
+        .find(|p| p.species == product)
+        .map(|p| p.mass)
+        .ok_or_else(|| format!("{} is not a product of the reaction", product))?;
+
+    let result = Stoichiometry {
+        extent,
+        limiting_reagent: limiting,
+        reactants,
+        products,
+        product,
+        theoretical_yield: CalculationResult::new(theoretical, "g"),
+        percent_yield: input.actual_yield.map(|actual| actual / theoretical * 100.0),
+    };
+
+    to_json(&result)
+}
+
+#[derive(Deserialize)]
+struct GreenMetricsInput {
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    /// Desired product (default: the first product)
+    #[serde(default)]
+    product: Option<String>,
+    /// Masses of reactants actually used (g)
+    #[serde(default)]
+    reactant_masses: Vec<(String, f64)>,
+    /// Isolated mass of the desired product (g)
+    #[serde(default)]
+    product_mass: Option<f64>,
+    /// Solvents, catalysts and other auxiliary materials used (g)
+    #[serde(default)]
+    auxiliary_mass: f64,
+}
+
+#[derive(Serialize)]
+struct GreenMetrics {
+    product: String,
+    /// ν·M(product) / Σν·M(reactants) (%)
+    atom_economy: f64,
+    /// Mass of by-products per mole of reaction (g)
+    by_product_mass: f64,
+    /// m(product) / Σm(reactants) (%)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reaction_mass_efficiency: Option<f64>,
+    /// (Σm(inputs) - m(product)) / m(product)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e_factor: Option<f64>,
+    /// Σm(inputs) / m(product) = E-factor + 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    process_mass_intensity: Option<f64>,
+}
+
+/// Calculate green-chemistry metrics of a balanced reaction: atom economy from molar masses,
+/// and reaction mass efficiency, E-factor and process mass intensity from the masses used
+///
+/// Arguments:
+/// - input_json: {"reactants", "products", "product", "reactant_masses", "product_mass",
+///   "auxiliary_mass"}
+#[wasm_func]
+pub fn green_metrics(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: GreenMetricsInput = parse_json(input_json, "input")?;
+
+    if input.reactants.is_empty() || input.products.is_empty() {
+        return Err("Reactions need reactants and products".to_string());
+    }
+    check_balance(&input.reactants, &input.products)?;
+
+    let product = input.product.unwrap_or_else(|| input.products[0].0.clone());
+    let (_, coefficient) = input
+        .products
+        .iter()
+        .find(|(species, _)| *species == product)
+        .ok_or_else(|| format!("{} is not a product of the reaction", product))?;
+    let product_mass = coefficient * parse_formula(&product)?.molar_mass()?;
+    let mut reactant_mass = 0.0;
+    for (species, coefficient) in &input.reactants {
+        reactant_mass += coefficient * parse_formula(species)?.molar_mass()?;
+    }
+
+    let mut used = 0.0;
+    for (species, mass) in &input.reactant_masses {
+        if !input.reactants.iter().any(|(s, _)| s == species) {
+            return Err(format!("{} is not a reactant", species));
+        }
+        check_non_negative(&format!("mass of {}", species), *mass)?;
+        used += mass;
+    }
+    check_non_negative("auxiliary mass", input.auxiliary_mass)?;
+    let isolated = input.product_mass.filter(|m| *m > 0.0);
+    let inputs = used + input.auxiliary_mass;
+
+    let result = GreenMetrics {
+        product,
+        atom_economy: product_mass / reactant_mass * 100.0,
+        by_product_mass: reactant_mass - product_mass,
+        reaction_mass_efficiency: isolated
+            .filter(|_| used > 0.0)
+            .map(|m| m / used * 100.0),
+        e_factor: isolated.filter(|_| inputs > 0.0).map(|m| (inputs - m) / m),
+        process_mass_intensity: isolated.filter(|_| inputs > 0.0).map(|m| inputs / m),
+    };
+
+    to_json(&result)
+}
+
+#[derive(Deserialize)]
+struct CombustionAnalysis {
+    /// Mass of sample burned (g)
+    sample_mass: f64,
+    /// Mass of CO₂ collected (g)
+    co2_mass: f64,
+    /// Mass of H₂O collected (g)
+    h2o_mass: f64,
+}
+
+#[derive(Deserialize)]
+struct EmpiricalFormulaInput {
+    /// (element, mass) pairs: mass percentages or grams of each element
+    #[serde(default)]
+    composition: Vec<(String, f64)>,
+    /// Combustion analysis of a compound containing C, H and at most one further element
+    #[serde(default)]
+    combustion: Option<CombustionAnalysis>,
+    /// Element making up the rest of a combustion sample (default: O)
+    #[serde(default)]
+    remainder: Option<String>,
+    /// Molar mass of the compound (g/mol)
+    #[serde(default)]
+    molar_mass: Option<f64>,
+    /// Largest deviation from a whole number accepted for a subscript (default: 0.1)
+    #[serde(default)]
+    tolerance: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct ElementRatio {
+    element: String,
+    mass: f64,
+    moles: f64,
+    /// Moles relative to the smallest amount
+    ratio: f64,
+    subscript: u32,
+}
+
+#[derive(Serialize)]
+struct EmpiricalFormula {
+    empirical_formula: String,
+    empirical_mass: CalculationResult,
+    elements: Vec<ElementRatio>,
+    /// Factor the mole ratios were multiplied by to reach whole numbers
+    multiplier: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    molecular_formula: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    molecular_mass: Option<CalculationResult>,
+    /// Molecular formula units per empirical formula unit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    formula_units: Option<u32>,
+}
+
+/// Write element counts in Hill order: C first, then H, then the rest alphabetically
+fn hill_formula(counts: &[(String, u32)]) -> String {
+    let mut sorted: Vec<&(String, u32)> = counts.iter().collect();
+    let has_carbon = counts.iter().any(|(element, _)| element == "C");
+    sorted.sort_by_key(|(element, _)| match element.as_str() {
+        "C" if has_carbon => (0, String::new()),
+        "H" if has_carbon => (1, String::new()),
+        _ => (2, element.clone()),
+    });
+    sorted
+        .iter()
+        .map(|(element, count)| match count {
+            1 => element.clone(),
+            n => format!("{}{}", element, n),
+        })
+        .collect()
+}
+
+/// Determine the empirical formula from percent composition or combustion analysis, and the
+/// molecular formula when the molar mass is known
+///
+/// Mole ratios are divided by the smallest amount and multiplied by 1-10 until every
+/// subscript lies within the tolerance of a whole number.
+///
+/// Arguments:
+/// - input_json: {"composition", "combustion", "remainder", "molar_mass", "tolerance"}
+#[wasm_func]
+pub fn empirical_formula(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: EmpiricalFormulaInput = parse_json(input_json, "input")?;
+
+    let mut masses = input.composition.clone();
+    if let Some(analysis) = &input.combustion {
+        check_positive("sample mass", analysis.sample_mass)?;
+        check_non_negative("CO₂ mass", analysis.co2_mass)?;
+        check_non_negative("H₂O mass", analysis.h2o_mass)?;
+        let carbon = atomic_mass("C").unwrap();
+        let hydrogen = atomic_mass("H").unwrap();
+        let co2 = parse_formula("CO2")?.molar_mass()?;
+        let h2o = parse_formula("H2O")?.molar_mass()?;
+        let c_mass = analysis.co2_mass * carbon / co2;
+        let h_mass = analysis.h2o_mass * 2.0 * hydrogen / h2o;
+        let rest = analysis.sample_mass - c_mass - h_mass;
+        if rest < -0.01 * analysis.sample_mass {
+            return Err("CO₂ and H₂O contain more mass than the sample".to_string());
+        }
+        masses.push(("C".to_string(), c_mass));
+        masses.push(("H".to_string(), h_mass));
+        if rest > 0.005 * analysis.sample_mass {
+            masses.push((input.remainder.clone().unwrap_or_else(|| "O".to_string()), rest));
+        }
+    }
+    if masses.is_empty() {
+        return Err("Give a composition or combustion analysis".to_string());
+    }
+
+    let mut moles = Vec::with_capacity(masses.len());
+    for (element, mass) in &masses {
+        let atomic = atomic_mass(element).ok_or_else(|| format!("Unknown element: {}", element))?;
+        check_positive(&format!("mass of {}", element), *mass)?;
+        if moles.iter().any(|(e, _, _): &(String, f64, f64)| e == element) {
+            return Err(format!("{} is listed more than once", element));
+        }
+        moles.push((element.clone(), *mass, mass / atomic));
+    }
+    let smallest = moles.iter().map(|(_, _, n)| *n).fold(f64::INFINITY, f64::min);
+    let tolerance = input.tolerance.unwrap_or(0.1);
+    // Every ratio lies within 0.5 of a whole number, so larger tolerances accept anything
+    check_domain("tolerance", tolerance, tolerance > 0.0 && tolerance < 0.5, "between 0 and 0.5")?;
+
+    let multiplier = (1..=10)
+        .find(|m| {
+            moles.iter().all(|(_, _, n)| {
+                let scaled = n / smallest * *m as f64;
+                (scaled - scaled.round()).abs() <= tolerance
+            })
+        })
+        .ok_or("Mole ratios are not close to small whole numbers; check the data")?;
+
+    let elements: Vec<ElementRatio> = moles
+        .iter()
+        .map(|(element, mass, n)| ElementRatio {
+            element: element.clone(),
+            mass: *mass,
+            moles: *n,
+            ratio: n / smallest,
+            subscript: (n / smallest * multiplier as f64).round() as u32,
+        })
+        .collect();
+    let counts: Vec<(String, u32)> =
+        elements.iter().map(|e| (e.element.clone(), e.subscript)).collect();
+    let empirical_mass: f64 = counts
+        .iter()
+        .map(|(element, count)| atomic_mass(element).unwrap() * *count as f64)
+        .sum();
+
+    let formula_units = match input.molar_mass {
+        Some(molar_mass) if molar_mass > 0.0 => {
+            let units = (molar_mass / empirical_mass).round();
+            if units < 1.0 {
+                return Err("Molar mass is smaller than the empirical formula mass".to_string());
+            }
+            Some(units as u32)
+        }
+        _ => None,
+    };
+
+    let result = EmpiricalFormula {
+        empirical_formula: hill_formula(&counts),
+        empirical_mass: CalculationResult::new(empirical_mass, "g/mol"),
+        elements,
+        multiplier,
+        molecular_formula: formula_units.map(|units| {
+            let scaled: Vec<(String, u32)> =
+                counts.iter().map(|(e, c)| (e.clone(), c * units)).collect();
+            hill_formula(&scaled)
+        }),
+        molecular_mass: formula_units
+            .map(|units| CalculationResult::new(empirical_mass * units as f64, "g/mol")),
+        formula_units,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ELECTROCHEMISTRY
+// ============================================================================
+
+/// Reaction quotient given directly or as activities with stoichiometric coefficients
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ReactionQuotient {
+    Value(f64),
+    Activities {
+        #[serde(default)]
+        reactants: Vec<(f64, f64)>,
+        #[serde(default)]
+        products: Vec<(f64, f64)>,
+    },
+}
+
+impl ReactionQuotient {
+    /// Q = Π a(products)^ν / Π a(reactants)^ν
+    fn value(&self) -> Result<f64, String> {
+        let q = match self {
+            ReactionQuotient::Value(q) => *q,
+            ReactionQuotient::Activities { reactants, products } => {
+                for &(activity, _) in reactants.iter().chain(products.iter()) {
+                    check_positive("activity", activity)?;
+                }
+                let product: f64 = products.iter().map(|&(a, nu)| a.powf(nu)).product();
+                let reactant: f64 = reactants.iter().map(|&(a, nu)| a.powf(nu)).product();
+                product / reactant
+            }
+        };
+        check_positive("reaction quotient", q)?;
+        Ok(q)
+    }
+}
+
+/// Cell or electrode potential under non-standard conditions
+#[derive(Serialize)]
+struct NernstResult {
+    value: f64,
+    unit: String,
+    standard_potential: f64,
+    reaction_quotient: f64,
+    /// Nernst slope per decade of Q (V)
+    slope: f64,
+    /// "exact" (RT/nF) or "shortcut" (0.0592/n)
+    method: String,
+}
+
+/// Calculate a potential with the Nernst equation
+/// E = E° - (RT/nF)·ln Q, or E = E° - (0.0592/n)·log10 Q at 298.15 K
+///
+/// Arguments:
+/// - e_standard: Standard potential E° (V)
+/// - n: Number of electrons transferred
+/// - q_json: Reaction quotient as a number, or {"reactants": [(activity, coeff)...],
+///   "products": [(activity, coeff)...]}
+/// - T: Temperature (K)
+/// - method: "exact" or "shortcut" (0.0592 V/n per decade, ignores T)
+#[wasm_func]
+pub fn calculate_nernst_potential(
+    e_standard_bytes: &[u8],
+    n_bytes: &[u8],
+    q_json: &[u8],
+    temperature_bytes: &[u8],
+    method_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let e_standard = parse_number(e_standard_bytes, "standard potential")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let quotient: ReactionQuotient = parse_json(q_json, "reaction quotient")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let method: String = parse_arg(method_bytes, "method")?;
+
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+    let q = quotient.value()?;
+
+    let slope = match method.as_str() {
+        "exact" => R * temperature * 10_f64.ln() / (n * F),
+        "shortcut" => 0.0592 / n,
+        _ => return Err(format!("Unknown Nernst method: {} (expected exact or shortcut)", method)),
+    };
+    let potential = e_standard - slope * q.log10();
+
+    let result = NernstResult {
+        value: potential,
+        unit: "V".to_string(),
+        standard_potential: e_standard,
+        reaction_quotient: q,
+        slope,
+        method,
+    };
+
+    to_json(&result)
+}
+
+/// Reduction half-reaction: oxidized + n e⁻ → reduced
+#[derive(Serialize, Deserialize, Clone)]
+struct HalfReaction {
+    #[serde(rename = "E0")]
+    e_standard: f64,
+    #[serde(default)]
+    n: f64,
+    #[serde(default)]
+    oxidized: Vec<(String, f64)>,
+    #[serde(default)]
+    reduced: Vec<(String, f64)>,
+}
+
+impl HalfReaction {
+    /// Written-out half-reaction, as a reduction or as the reverse oxidation
+    fn equation(&self, reduction: bool) -> Option<String> {
+        if self.n <= 0.0 || (self.oxidized.is_empty() && self.reduced.is_empty()) {
+            return None;
+        }
+        let electrons = if self.n == 1.0 {
+            "e⁻".to_string()
+        } else {
+            format!("{} e⁻", self.n)
+        };
+        let ox = format_equation_side(&self.oxidized);
+        let red = format_equation_side(&self.reduced);
+        Some(if reduction {
+            format!("{} + {} → {}", ox, electrons, red)
+        } else {
+            format!("{} → {} + {}", red, ox, electrons)
+        })
+    }
+}
+
+/// Half-reaction given by identifier, as an explicit half-reaction or as a bare E°
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HalfReactionSpec {
+    Potential(f64),
+    Id(String),
+    Explicit(HalfReaction),
+}
+
+impl HalfReactionSpec {
+    /// Resolve to a labelled half-reaction, looking identifiers up in the potential table
+    fn resolve(
+        &self,
+        fallback_label: &str,
+        data: &HashMap<String, HalfReaction>,
+    ) -> Result<(String, HalfReaction), String> {
+        match self {
+            HalfReactionSpec::Potential(e_standard) => Ok((
+                fallback_label.to_string(),
+                HalfReaction {
+                    e_standard: *e_standard,
+                    n: 0.0,
+                    oxidized: Vec::new(),
+                    reduced: Vec::new(),
+                },
+            )),
+            HalfReactionSpec::Id(id) => data
+                .get(id)
+                .map(|half| (id.clone(), half.clone()))
+                .ok_or_else(|| format!("No reduction potential found for couple: {}", id)),
+            HalfReactionSpec::Explicit(half) => Ok((fallback_label.to_string(), half.clone())),
+        }
+    }
+}
+
+/// Format one side of an equation, e.g. "2 Ag+(aq) + Cu(s)"
+fn format_equation_side(species: &[(String, f64)]) -> String {
+    species
+        .iter()
+        .map(|(name, coeff)| {
+            if *coeff == 1.0 {
+                name.clone()
+            } else {
+                format!("{} {}", coeff, name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Add coeff·species to one side of an equation, merging repeated species
+fn add_species(side: &mut Vec<(String, f64)>, species: &[(String, f64)], factor: f64) {
+    for (name, coeff) in species {
+        match side.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, total)) => *total += coeff * factor,
+            None => side.push((name.clone(), coeff * factor)),
+        }
+    }
+}
+
+/// Greatest common divisor of two positive integers
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// One electrode of a galvanic cell
+#[derive(Serialize)]
+struct Electrode {
+    label: String,
+    e_standard: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<f64>,
+    /// Electrode process as written (reduction at the cathode, oxidation at the anode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    half_reaction: Option<String>,
+}
+
+/// Standard cell potential and overall reaction of a galvanic cell
+#[derive(Serialize)]
+struct CellPotential {
+    value: f64,
+    unit: String,
+    cathode: Electrode,
+    anode: Electrode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    electrons_transferred: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equation: Option<String>,
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+}
+
+/// Combine a cathode and an anode half-reaction into a cell with its overall equation
+fn build_cell(
+    cathode_label: String,
+    cathode: HalfReaction,
+    anode_label: String,
+    anode: HalfReaction,
+) -> CellPotential {
+    let balanced = cathode.n > 0.0
+        && anode.n > 0.0
+        && !(cathode.oxidized.is_empty() && cathode.reduced.is_empty())
+        && !(anode.oxidized.is_empty() && anode.reduced.is_empty());
+
+    let electrons = if cathode.n > 0.0 && anode.n > 0.0 {
+        if cathode.n.fract() == 0.0 && anode.n.fract() == 0.0 {
+            let (a, b) = (cathode.n as u64, anode.n as u64);
+            Some((a / gcd(a, b) * b) as f64)
+        } else {
+            Some(cathode.n * anode.n)
+        }
+    } else {
+        None
+    };
+
+    let mut reactants = Vec::new();
+    let mut products = Vec::new();
+    let mut equation = None;
+    if let (true, Some(total)) = (balanced, electrons) {
+        let (cathode_factor, anode_factor) = (total / cathode.n, total / anode.n);
+        add_species(&mut reactants, &cathode.oxidized, cathode_factor);
+        add_species(&mut reactants, &anode.reduced, anode_factor);
+        add_species(&mut products, &cathode.reduced, cathode_factor);
+        add_species(&mut products, &anode.oxidized, anode_factor);
+
+        // Cancel spectator species appearing on both sides
+        for (name, coeff) in reactants.iter_mut() {
+            if let Some((_, other)) = products.iter_mut().find(|(p, _)| p == name) {
+                let common = coeff.min(*other);
+                *coeff -= common;
+                *other -= common;
+            }
+        }
+        reactants.retain(|(_, coeff)| *coeff > 1e-12);
+        products.retain(|(_, coeff)| *coeff > 1e-12);
+
+        equation = Some(format!(
+            "{} → {}",
+            format_equation_side(&reactants),
+            format_equation_side(&products)
+        ));
+    }
+
+    CellPotential {
+        value: cathode.e_standard - anode.e_standard,
+        unit: "V".to_string(),
+        cathode: Electrode {
+            half_reaction: cathode.equation(true),
+            label: cathode_label,
+            e_standard: cathode.e_standard,
+            n: (cathode.n > 0.0).then_some(cathode.n),
+        },
+        anode: Electrode {
+            half_reaction: anode.equation(false),
+            label: anode_label,
+            e_standard: anode.e_standard,
+            n: (anode.n > 0.0).then_some(anode.n),
+        },
+        electrons_transferred: electrons,
+        equation,
+        reactants,
+        products,
+    }
+}
+
+/// Combine two reduction half-reactions into a galvanic cell
+/// E°cell = E°cathode - E°anode, the couple with the higher E° being the cathode
+///
+/// When both half-reactions list their species and electron counts, the overall
+/// equation is balanced by scaling both to the least common multiple of electrons
+/// and cancelling species that appear on both sides (H⁺, H₂O, OH⁻...).
+///
+/// Arguments:
+/// - first_json, second_json: Half-reaction identifier, explicit half-reaction
+///   {"E0", "n", "oxidized", "reduced"} or bare E° (V)
+/// - data_json: Reduction potential table used to resolve identifiers
+#[wasm_func]
+pub fn calculate_cell_potential(
+    first_json: &[u8],
+    second_json: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let first: HalfReactionSpec = parse_json(first_json, "first half-reaction")?;
+    let second: HalfReactionSpec = parse_json(second_json, "second half-reaction")?;
+    let data: HashMap<String, HalfReaction> = parse_json(data_json, "reduction potential data")?;
+
+    let first = first.resolve("half-reaction 1", &data)?;
+    let second = second.resolve("half-reaction 2", &data)?;
+    let ((cathode_label, cathode), (anode_label, anode)) =
+        if first.1.e_standard >= second.1.e_standard {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+    let result = build_cell(cathode_label, cathode, anode_label, anode);
+
+    to_json(&result)
+}
+
+/// Reduction potential table entry with its written-out half-reaction
+#[derive(Serialize)]
+struct ReductionPotential {
+    #[serde(flatten)]
+    data: HalfReaction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    half_reaction: Option<String>,
+}
+
+/// Get the standard reduction potential of a couple
+#[wasm_func]
+pub fn get_reduction_potential(
+    couple_bytes: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let couple: String = parse_arg(couple_bytes, "couple")?;
+    let data: HashMap<String, HalfReaction> = parse_json(data_json, "reduction potential data")?;
+
+    let half = data
+        .get(&couple)
+        .ok_or_else(|| format!("No reduction potential found for couple: {}", couple))?;
+
+    let result = ReductionPotential {
+        half_reaction: half.equation(true),
+        data: half.clone(),
+    };
+
+    to_json(&result)
+}
+
+/// Standard potential, Gibbs energy and equilibrium constant of a redox reaction
+#[derive(Serialize)]
+struct StandardRedoxQuantities {
+    e_standard: CalculationResult,
+    delta_g: CalculationResult,
+    /// Omitted when K overflows; ln K and log10 K are always given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equilibrium_constant: Option<f64>,
+    ln_k: f64,
+    log10_k: f64,
+    n: f64,
+    temperature: f64,
+}
+
+/// Interconvert E°, ΔG° and K for a reaction transferring n electrons
+/// ΔG° = -nFE°, ΔG° = -RT·ln K, E° = (RT/nF)·ln K
+///
+/// Arguments:
+/// - quantity: Which quantity is given: "E0" (V), "delta_G" (kJ/mol) or "K"
+/// - value: Value of the given quantity
+/// - n: Number of electrons transferred
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn convert_standard_redox_quantities(
+    quantity_bytes: &[u8],
+    value_bytes: &[u8],
+    n_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let quantity: String = parse_arg(quantity_bytes, "quantity")?;
+    let value = parse_number(value_bytes, "value")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+
+    let e_standard = match quantity.as_str() {
+        "E0" => value,
+        // ΔG° in kJ/mol → J/mol
+        "delta_G" => -value * 1000.0 / (n * F),
+        "K" => {
+            check_positive("equilibrium constant", value)?;
+            R * temperature * value.ln() / (n * F)
+        }
+        _ => {
+            return Err(format!(
+                "Unknown quantity: {} (expected E0, delta_G or K)",
+                quantity
+            ))
+        }
+    };
+
+    let delta_g = -n * F * e_standard / 1000.0;
+    let ln_k = n * F * e_standard / (R * temperature);
+    let k = ln_k.exp();
+
+    let result = StandardRedoxQuantities {
+        e_standard: CalculationResult::new(e_standard, "V"),
+        delta_g: CalculationResult::new(delta_g, "kJ/mol"),
+        equilibrium_constant: k.is_finite().then_some(k),
+        ln_k,
+        log10_k: ln_k / 10_f64.ln(),
+        n,
+        temperature,
+    };
+
+    to_json(&result)
+}
+
+/// Electrolysis problem: exactly one of current, time and amount is left unknown
+#[derive(Deserialize)]
+struct ElectrolysisInput {
+    /// Electrons transferred per ion or molecule
+    electrons: f64,
+    current: Option<f64>,
+    time: Option<f64>,
+    mass: Option<f64>,
+    volume: Option<f64>,
+    moles: Option<f64>,
+    molar_mass: Option<f64>,
+    /// Report the amount as an ideal-gas volume
+    #[serde(default)]
+    gas: bool,
+    /// Current efficiency (fraction of the charge producing the product)
+    #[serde(default = "default_efficiency")]
+    efficiency: f64,
+    #[serde(default = "default_temperature")]
+    temperature: f64,
+    /// Gas pressure (bar)
+    #[serde(default = "default_pressure")]
+    pressure: f64,
+    #[serde(default)]
+    time_unit: String,
+}
+
+fn default_efficiency() -> f64 {
+    1.0
+}
+
+fn default_temperature() -> f64 {
+    298.15
+}
+
+fn default_pressure() -> f64 {
+    1.0
+}
+
+/// Faraday's-law electrolysis result
+#[derive(Serialize)]
+struct ElectrolysisResult {
+    /// "amount", "time" or "current"
+    solved_for: String,
+    current: CalculationResult,
+    time: CalculationResult,
+    charge: CalculationResult,
+    moles_electrons: f64,
+    moles: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mass: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas_volume: Option<CalculationResult>,
+}
+
+/// Faraday's laws of electrolysis: n = η·I·t / (z·F)
+///
+/// Give any two of current, time and amount (moles, mass with molar mass, or
+/// ideal-gas volume at T and P) and the third is calculated.
+///
+/// Arguments:
+/// - input_json: Electrolysis problem, see `ElectrolysisInput`
+#[wasm_func]
+pub fn faraday_electrolysis(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: ElectrolysisInput = parse_json(input_json, "electrolysis input")?;
+
+    let (seconds, time_unit) = parse_time_unit(input.time_unit.as_bytes())?;
+
+    check_positive("electrons per ion", input.electrons)?;
+    check_domain(
+        "current efficiency",
+        input.efficiency,
+        input.efficiency > 0.0 && input.efficiency <= 1.0,
+        "in (0, 1]",
+    )?;
+    if let Some(m) = input.molar_mass {
+        check_positive("molar mass", m)?;
+    }
+    let gas = input.gas || input.volume.is_some();
+    if gas {
+        check_temperature(input.temperature)?;
+        check_positive("pressure", input.pressure)?;
+    }
+
+    let given_moles = match (input.moles, input.mass, input.volume) {
+        (Some(n), None, None) => Some(n),
+        (None, Some(mass), None) => {
+            let molar_mass = input
+                .molar_mass
+                .ok_or("A molar mass is required to convert the mass")?;
+            Some(mass / molar_mass)
+        }
+        (None, None, Some(volume)) => {
+            Some(input.pressure * volume / (R_LITRE_BAR * input.temperature))
+        }
+        (None, None, None) => None,
+        _ => return Err("Give the amount as only one of moles, mass or volume".to_string()),
+    };
+
+    let per_charge = input.efficiency / (input.electrons * F); // mol/C
+    let (solved_for, current, time_s, moles) = match (input.current, input.time, given_moles) {
+        (Some(i), Some(t), None) => ("amount", i, t * seconds, i * t * seconds * per_charge),
+        (Some(i), None, Some(n)) => {
+            check_positive("current", i)?;
+            ("time", i, n / (i * per_charge), n)
+        }
+        (None, Some(t), Some(n)) => {
+            check_positive("time", t)?;
+            ("current", n / (t * seconds * per_charge), t * seconds, n)
+        }
+        _ => {
+            return Err(
+                "Exactly one of current, time and amount must be left unspecified".to_string(),
+            )
+        }
+    };
+    check_non_negative("current", current)?;
+    check_non_negative("time", time_s / seconds)?;
+    check_non_negative("amount", moles)?;
+
+    let charge = current * time_s;
+    let result = ElectrolysisResult {
+        solved_for: solved_for.to_string(),
+        current: CalculationResult::new(current, "A"),
+        time: CalculationResult::new(time_s / seconds, time_unit),
+        charge: CalculationResult::new(charge, "C"),
+        moles_electrons: charge / F,
+        moles,
+        mass: input
+            .molar_mass
+            .map(|m| CalculationResult::new(moles * m, "g")),
+        gas_volume: gas.then(|| {
+            CalculationResult::new(moles * R_LITRE_BAR * input.temperature / input.pressure, "L")
+        }),
+    };
+
+    to_json(&result)
+}
+
+/// Electrolysis energy problem, with Typst-side names in snake_case
+#[derive(Deserialize)]
+struct ElectrolysisEnergyInput {
+    /// Electrons transferred per ion or molecule
+    electrons: f64,
+    /// Mass of product (g)
+    mass: f64,
+    molar_mass: f64,
+    /// Cell voltage (V)
+    voltage: f64,
+    /// Additional overpotential on top of the cell voltage (V)
+    #[serde(default)]
+    overpotential: f64,
+    /// Current efficiency (fraction of the charge producing the product)
+    #[serde(default = "default_efficiency")]
+    efficiency: f64,
+    /// Electricity price per kWh
+    price: Option<f64>,
+    #[serde(default)]
+    currency: String,
+}
+
+/// Energy and cost of producing a mass of product by electrolysis
+#[derive(Serialize)]
+struct ElectrolysisEnergy {
+    moles: f64,
+    charge: CalculationResult,
+    /// Applied voltage, cell voltage plus overpotential
+    applied_voltage: CalculationResult,
+    /// Electrical energy consumed
+    energy: CalculationResult,
+    /// Energy per kg of product
+    specific_energy: CalculationResult,
+    /// Energy at the cell voltage with 100 % current efficiency
+    theoretical_energy: CalculationResult,
+    /// theoretical_energy / energy
+    energy_efficiency: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cost: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cost_per_kg: Option<CalculationResult>,
+}
+
+/// Electrical energy and cost to produce a mass of product by electrolysis
+/// E = z·F·n·(V + η_over) / η_current
+///
+/// Arguments:
+/// - input_json: Electrolysis energy problem, see `ElectrolysisEnergyInput`
+#[wasm_func]
+pub fn electrolysis_energy(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: ElectrolysisEnergyInput = parse_json(input_json, "electrolysis energy input")?;
+
+    const J_PER_KWH: f64 = 3.6e6;
+
+    check_positive("electrons per ion", input.electrons)?;
+    check_non_negative("mass", input.mass)?;
+    check_positive("molar mass", input.molar_mass)?;
+    check_positive("cell voltage", input.voltage)?;
+    check_non_negative("overpotential", input.overpotential)?;
+    check_domain(
+        "current efficiency",
+        input.efficiency,
+        input.efficiency > 0.0 && input.efficiency <= 1.0,
+        "in (0, 1]",
+    )?;
+    if let Some(price) = input.price {
+        check_non_negative("price", price)?;
+    }
+
+    let moles = input.mass / input.molar_mass;
+    let theoretical_charge = input.electrons * F * moles;
+    let charge = theoretical_charge / input.efficiency;
+    let applied = input.voltage + input.overpotential;
+    let energy = charge * applied / J_PER_KWH;
+    let theoretical = theoretical_charge * input.voltage / J_PER_KWH;
+    // Per kg does not depend on the mass produced
+    let specific = input.electrons * F * 1000.0 / input.molar_mass / input.efficiency * applied
+        / J_PER_KWH;
+
+    let currency = input.currency.as_str();
+    let result = ElectrolysisEnergy {
+        moles,
+        charge: CalculationResult::new(charge, "C"),
+        applied_voltage: CalculationResult::new(applied, "V"),
+        energy: CalculationResult::new(energy, "kWh"),
+        specific_energy: CalculationResult::new(specific, "kWh/kg"),
+        theoretical_energy: CalculationResult::new(theoretical, "kWh"),
+        energy_efficiency: input.voltage * input.efficiency / applied,
+        cost: input.price.map(|p| CalculationResult::new(energy * p, currency)),
+        cost_per_kg: input.price.map(|p| {
+            let unit = if currency.is_empty() {
+                "per kg".to_string()
+            } else {
+                format!("{}/kg", currency)
+            };
+            CalculationResult::new(specific * p, &unit)
+        }),
+    };
+
+    to_json(&result)
+}
+
+/// Thermodynamic and voltage efficiencies of a fuel cell
+#[derive(Serialize)]
+struct FuelCellEfficiency {
+    /// Maximum (thermodynamic) efficiency ΔG/ΔH
+    value: f64,
+    unit: String,
+    delta_h: f64,
+    delta_g: f64,
+    /// E°rev = -ΔG/nF
+    reversible_voltage: CalculationResult,
+    /// E_tn = -ΔH/nF, the voltage at which all of ΔH appears as electrical work
+    thermoneutral_voltage: CalculationResult,
+    /// V / E°rev
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voltage_efficiency: Option<f64>,
+    /// V / E_tn, the fraction of ΔH delivered as electrical energy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overall_efficiency: Option<f64>,
+}
+
+/// Thermodynamic efficiency of a fuel cell
+/// ε = ΔG/ΔH, E°rev = -ΔG/nF, E_tn = -ΔH/nF
+///
+/// Arguments:
+/// - delta_h: Reaction enthalpy (kJ/mol)
+/// - delta_g: Reaction Gibbs energy (kJ/mol)
+/// - n: Number of electrons transferred
+/// - voltage: Operating cell voltage (V), 0 if unknown
+#[wasm_func]
+pub fn fuel_cell_efficiency(
+    delta_h_bytes: &[u8],
+    delta_g_bytes: &[u8],
+    n_bytes: &[u8],
+    voltage_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let delta_h = parse_number(delta_h_bytes, "reaction enthalpy")?;
+    let delta_g = parse_number(delta_g_bytes, "reaction Gibbs energy")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let voltage = parse_number(voltage_bytes, "voltage")?;
+
+    check_domain("ΔH", delta_h, delta_h < 0.0, "negative")?;
+    check_domain("ΔG", delta_g, delta_g < 0.0, "negative")?;
+    check_positive("number of electrons", n)?;
+    check_non_negative("voltage", voltage)?;
+
+    let reversible = -delta_g * 1000.0 / (n * F);
+    let thermoneutral = -delta_h * 1000.0 / (n * F);
+    let operating = (voltage > 0.0).then_some(voltage);
+
+    let result = FuelCellEfficiency {
+        value: delta_g / delta_h,
+        unit: String::new(),
+        delta_h,
+        delta_g,
+        reversible_voltage: CalculationResult::new(reversible, "V"),
+        thermoneutral_voltage: CalculationResult::new(thermoneutral, "V"),
+        voltage_efficiency: operating.map(|v| v / reversible),
+        overall_efficiency: operating.map(|v| v / thermoneutral),
+    };
+
+    to_json(&result)
+}
+
+/// Theoretical capacity and specific energy of a battery couple
+#[derive(Serialize)]
+struct BatteryEnergetics {
+    cathode: String,
+    anode: String,
+    /// Standard cell potential (V)
+    cell_potential: f64,
+    n: f64,
+    equation: String,
+    /// Mass of the counted reactants per mole of reaction as written (g/mol)
+    reactant_mass: CalculationResult,
+    specific_capacity: CalculationResult,
+    specific_energy: CalculationResult,
+}
+
+/// Theoretical specific energy of battery couples
+/// Q = nF / (3.6·Σνᵢ·Mᵢ) (mAh/g), w = Q·E°cell (Wh/kg)
+///
+/// Each couple is combined like `calculate_cell_potential` (the higher E° is the cathode);
+/// the reactant masses come from the balanced cell reaction and the atomic weights.
+///
+/// Arguments:
+/// - pairs_json: Array of half-reaction pairs, each half-reaction an identifier or an explicit
+///   half-reaction {"E0", "n", "oxidized", "reduced"}
+/// - data_json: Reduction potential table used to resolve identifiers
+/// - exclude_json: Reactants not counted in the mass, e.g. ["O2"] for metal–air cells
+#[wasm_func]
+pub fn battery_specific_energy(
+    pairs_json: &[u8],
+    data_json: &[u8],
+    exclude_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let pairs: Vec<(HalfReactionSpec, HalfReactionSpec)> = parse_json(pairs_json, "couples")?;
+    let data: HashMap<String, HalfReaction> = parse_json(data_json, "reduction potential data")?;
+    let exclude: Vec<String> = parse_json(exclude_json, "excluded species")?;
+
+    let excluded = |name: &str| {
+        exclude.iter().any(|ex| {
+            name == ex
+                || name
+                    .strip_prefix(ex.as_str())
+                    .is_some_and(|rest| rest.starts_with('('))
+        })
+    };
+
+    let mut rows = Vec::with_capacity(pairs.len());
+    for (first, second) in &pairs {
+        let (first_label, first) = first.resolve("first", &data)?;
+        let (second_label, second) = second.resolve("second", &data)?;
+        let cell = if first.e_standard >= second.e_standard {
+            build_cell(first_label, first, second_label, second)
+        } else {
+            build_cell(second_label, second, first_label, first)
+        };
+        let (Some(n), Some(equation)) = (cell.electrons_transferred, cell.equation) else {
+            return Err(format!(
+                "Couple {} / {} needs species and electron counts to find the reactant mass",
+                cell.cathode.label, cell.anode.label
+            ));
+        };
+
+        let mut mass = 0.0;
+        for (name, coeff) in cell.reactants.iter().filter(|(name, _)| !excluded(name)) {
+            mass += coeff * parse_formula(name)?.molar_mass()?;
+        }
+        if mass <= 0.0 {
+            return Err(format!(
+                "No reactant mass left for {} / {}",
+                cell.cathode.label, cell.anode.label
+            ));
+        }
+
+        let capacity = n * F / (3.6 * mass); // mAh/g
+        rows.push(BatteryEnergetics {
+            cathode: cell.cathode.label,
+            anode: cell.anode.label,
+            cell_potential: cell.value,
+            n,
+            equation,
+            reactant_mass: CalculationResult::new(mass, "g/mol"),
+            specific_capacity: CalculationResult::new(capacity, "mAh/g"),
+            specific_energy: CalculationResult::new(capacity * cell.value, "Wh/kg"),
+        });
+    }
+
+    to_json(&rows)
+}
+
+/// Concentration cell potential
+#[derive(Serialize)]
+struct ConcentrationCell {
+    value: f64,
+    unit: String,
+    /// c(cathode) / c(anode)
+    concentration_ratio: f64,
+    /// Temperature coefficient dE/dT = (R/nF)·ln(c_cathode/c_anode) (V/K)
+    temperature_coefficient: f64,
+    /// false when the labelled cathode is actually the anode (E < 0)
+    spontaneous: bool,
+}
+
+/// Calculate the potential of a concentration cell
+/// E = (RT/nF)·ln(c_cathode/c_anode), E° = 0
+///
+/// Arguments:
+/// - c_cathode: Concentration (or activity) in the cathode compartment
+/// - c_anode: Concentration (or activity) in the anode compartment
+/// - n: Number of electrons transferred
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn calculate_concentration_cell(
+    c_cathode_bytes: &[u8],
+    c_anode_bytes: &[u8],
+    n_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let c_cathode = parse_number(c_cathode_bytes, "cathode concentration")?;
+    let c_anode = parse_number(c_anode_bytes, "anode concentration")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_positive("cathode concentration", c_cathode)?;
+    check_positive("anode concentration", c_anode)?;
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+
+    let ratio = c_cathode / c_anode;
+    let temperature_coefficient = R * ratio.ln() / (n * F);
+    let potential = temperature_coefficient * temperature;
+
+    let result = ConcentrationCell {
+        value: potential,
+        unit: "V".to_string(),
+        concentration_ratio: ratio,
+        temperature_coefficient,
+        spontaneous: potential >= 0.0,
+    };
+
+    to_json(&result)
+}
+
+/// Reduction potential between two (not necessarily adjacent) Latimer species
+#[derive(Serialize)]
+struct SkipPotential {
+    oxidized: String,
+    reduced: String,
+    n: f64,
+    e_standard: f64,
+}
+
+/// Stability of an interior Latimer species towards disproportionation
+#[derive(Serialize)]
+struct DisproportionationCheck {
+    species: String,
+    /// E° for formation of the species from its oxidized neighbour (V)
+    e_left: f64,
+    /// E° for reduction of the species to its reduced neighbour (V)
+    e_right: f64,
+    /// E°(right) - E°(left); positive values favour disproportionation (V)
+    driving_force: f64,
+    disproportionates: bool,
+}
+
+/// Frost-diagram point nE° vs oxidation state
+#[derive(Serialize)]
+struct FrostPoint {
+    species: String,
+    oxidation_state: f64,
+    /// Volt-equivalent N·E°(X(N)/X(0)) (V)
+    volt_equivalent: f64,
+}
+
+/// Analysis of a Latimer diagram
+#[derive(Serialize)]
+struct LatimerAnalysis {
+    skip_potentials: Vec<SkipPotential>,
+    disproportionation: Vec<DisproportionationCheck>,
+    frost: Vec<FrostPoint>,
+    /// Species at the reference point (volt-equivalent 0) of the Frost diagram
+    frost_reference: String,
+}
+
+/// Analyse a Latimer diagram: skip potentials, disproportionation and Frost coordinates
+///
+/// Skip potential: E°(i→j) = Σ n_k·E°_k / Σ n_k, with n_k the change in oxidation state
+/// of step k. An interior species disproportionates when E°(right) > E°(left).
+/// Frost volt-equivalents are referenced to the species with oxidation state 0, or to the
+/// lowest oxidation state if the element itself is not listed.
+///
+/// Arguments:
+/// - species_json: Array of (species, oxidation state), from highest to lowest oxidation state
+/// - potentials_json: Array of stepwise E° (V) between consecutive species
+#[wasm_func]
+pub fn latimer_diagram(species_json: &[u8], potentials_json: &[u8]) -> Result<Vec<u8>, String> {
+    let species: Vec<(String, f64)> = parse_json(species_json, "Latimer species")?;
+    let potentials: Vec<f64> = parse_json(potentials_json, "stepwise potentials")?;
+
+    if species.len() < 2 {
+        return Err("A Latimer diagram needs at least 2 species".to_string());
+    }
+    if potentials.len() != species.len() - 1 {
+        return Err(format!(
+            "Expected {} stepwise potentials, got {}",
+            species.len() - 1,
+            potentials.len()
+        ));
+    }
+    if species.windows(2).any(|w| w[1].1 >= w[0].1) {
+        return Err("Species must be ordered by strictly decreasing oxidation state".to_string());
+    }
+
+    // Volt-equivalent of each species relative to the last one: y(i) - y(i+1) = n·E°
+    let mut cumulative = vec![0.0; species.len()];
+    for i in (0..species.len() - 1).rev() {
+        let n = species[i].1 - species[i + 1].1;
+        cumulative[i] = cumulative[i + 1] + n * potentials[i];
+    }
+
+    let mut skip_potentials = Vec::new();
+    for i in 0..species.len() {
+        for j in (i + 1)..species.len() {
+            let n = species[i].1 - species[j].1;
+            skip_potentials.push(SkipPotential {
+                oxidized: species[i].0.clone(),
+                reduced: species[j].0.clone(),
+                n,
+                e_standard: (cumulative[i] - cumulative[j]) / n,
+            });
+        }
+    }
+
+    let disproportionation = (1..species.len() - 1)
+        .map(|i| {
+            let (e_left, e_right) = (potentials[i - 1], potentials[i]);
+            DisproportionationCheck {
+                species: species[i].0.clone(),
+                e_left,
+                e_right,
+                driving_force: e_right - e_left,
+                disproportionates: e_right > e_left,
+            }
+        })
+        .collect();
+
+    let reference = species
+        .iter()
+        .position(|(_, ox)| *ox == 0.0)
+        .unwrap_or(species.len() - 1);
+    let frost = species
+        .iter()
+        .zip(&cumulative)
+        .map(|((name, ox), y)| FrostPoint {
+            species: name.clone(),
+            oxidation_state: *ox,
+            volt_equivalent: y - cumulative[reference],
+        })
+        .collect();
+
+    let result = LatimerAnalysis {
+        skip_potentials,
+        disproportionation,
+        frost,
+        frost_reference: species[reference].0.clone(),
+    };
+
+    to_json(&result)
+}
+
+/// Standard Gibbs energy of formation, ignoring any other thermodynamic fields
+#[derive(Deserialize)]
+struct GibbsFormation {
+    #[serde(rename = "delta_Gf")]
+    delta_gf: f64,
+}
+
+/// Boundary between two predominance regions of a Pourbaix diagram
+#[derive(Serialize)]
+struct PourbaixBoundary {
+    species: (String, String),
+    /// "horizontal" (pure redox), "vertical" (pure acid–base) or "sloped"
+    kind: String,
+    /// End points (pH, E)
+    points: [(f64, f64); 2],
+    /// dE/dpH (V per pH unit); null for vertical lines
+    slope: Option<f64>,
+}
+
+/// Predominance region of one species
+#[derive(Serialize)]
+struct PourbaixRegion {
+    species: String,
+    /// Approximate centroid (pH, E) for placing a label; omitted if the species never predominates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label_point: Option<(f64, f64)>,
+}
+
+/// Simplified Pourbaix (E–pH) diagram
+#[derive(Serialize)]
+struct PourbaixDiagram {
+    boundaries: Vec<PourbaixBoundary>,
+    regions: Vec<PourbaixRegion>,
+    /// O₂/H₂O and H⁺/H₂ lines at 1 bar: name → end points (pH, E)
+    water_lines: std::collections::BTreeMap<String, [(f64, f64); 2]>,
+    ph_range: (f64, f64),
+    e_range: (f64, f64),
+    concentration: f64,
+    temperature: f64,
+}
+
+/// Generate E–pH boundary lines for the species of one element
+///
+/// Each species XₖOₐH_b^z is formed from the element as
+/// k X + a H₂O → XₖOₐH_b^z + (2a - b) H⁺ + (z + 2a - b) e⁻, so per atom of X its
+/// transformed Gibbs energy is
+/// Φ = [ΔGf° + RT·ln a - a·ΔGf°(H₂O) - (2a - b)·RT·ln10·pH - (z + 2a - b)·F·E] / k.
+/// The predominant species minimises Φ; boundaries are where two minima coincide.
+/// Dissolved species have activity `concentration`, condensed phases and gases unity.
+///
+/// Arguments:
+/// - element: Element symbol, e.g. "Fe"
+/// - species_json: Array of species keys (formulas with state, e.g. "Fe2O3(s)", "Fe2+(aq)")
+/// - data_json: Thermodynamic data with delta_Gf (kJ/mol) for each species and H2O(l);
+///   the pure element may be omitted (ΔGf° = 0)
+/// - concentration: Activity of dissolved species
+/// - ph_range_json, e_range_json: Plot window (pH_min, pH_max) and (E_min, E_max) in V
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn pourbaix_diagram(
+    element_bytes: &[u8],
+    species_json: &[u8],
+    data_json: &[u8],
+    concentration_bytes: &[u8],
+    ph_range_json: &[u8],
+    e_range_json: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let element: String = parse_arg(element_bytes, "element")?;
+    let species: Vec<String> = parse_json(species_json, "species")?;
+    let data: HashMap<String, serde_json::Value> = parse_json(data_json, "thermodynamic data")?;
+    let concentration = parse_number(concentration_bytes, "concentration")?;
+    let (ph_min, ph_max): (f64, f64) = parse_json(ph_range_json, "pH range")?;
+    let (e_min, e_max): (f64, f64) = parse_json(e_range_json, "potential range")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    const TOL: f64 = 1e-6; // J/mol
+
+    if species.len() < 2 {
+        return Err("At least 2 species are required".to_string());
+    }
+    check_positive("concentration", concentration)?;
+    if ph_min >= ph_max || e_min >= e_max {
+        return Err("Ranges must be given as (min, max)".to_string());
+    }
+    check_temperature(temperature)?;
+
+    let gibbs = |key: &str| -> Option<Result<f64, String>> {
+        data.get(key).map(|value| {
+            serde_json::from_value::<GibbsFormation>(value.clone())
+                .map(|g| g.delta_gf * 1000.0)
+                .map_err(|e| format!("Invalid data for {}: {}", key, e))
+        })
+    };
+    let g_water = gibbs("H2O(l)").unwrap_or(Ok(-237.13e3))?;
+    let rt = R * temperature;
+    let rt_ln10 = rt * 10_f64.ln();
+
+    // Φ_i = g + alpha·pH + beta·E (J per mol of element atoms)
+    let mut phi = Vec::with_capacity(species.len());
+    for key in &species {
+        let formula = parse_formula(key)?;
+        let k = formula.count(&element);
+        if k <= 0.0 {
+            return Err(format!("Species {} does not contain {}", key, element));
+        }
+        if let Some((other, _)) = formula
+            .elements
+            .iter()
+            .find(|(symbol, _)| symbol != &element && symbol != "O" && symbol != "H")
+        {
+            return Err(format!(
+                "Species {} contains {}, which cannot be balanced with H₂O, H⁺ and e⁻",
+                key, other
+            ));
+        }
+        let g = match gibbs(key) {
+            Some(g) => g?,
+            None if formula.elements.len() == 1 && formula.charge == 0.0 => 0.0,
+            None => return Err(format!("No data found for species: {}", key)),
+        };
+        let (a, b, z) = (formula.count("O"), formula.count("H"), formula.charge);
+        let activity = if formula.state.as_deref() == Some("aq") {
+            concentration
+        } else {
+            1.0
+        };
+        phi.push((
+            (g + rt * activity.ln() - a * g_water) / k,
+            -(2.0 * a - b) * rt_ln10 / k,
+            -(z + 2.0 * a - b) * F / k,
+        ));
+    }
+
+    let value = |i: usize, ph: f64, e: f64| phi[i].0 + phi[i].1 * ph + phi[i].2 * e;
+
+    let mut boundaries = Vec::new();
+    for i in 0..species.len() {
+        for j in (i + 1)..species.len() {
+            let (dg, da, db) = (phi[i].0 - phi[j].0, phi[i].1 - phi[j].1, phi[i].2 - phi[j].2);
+            // Parameterise the line Φi = Φj as (pH, E) = origin + t·direction
+            let (origin, direction, t_range, kind) = if db.abs() > 1e-9 {
+                if da.abs() < 1e-9 {
+                    ((0.0, -dg / db), (1.0, 0.0), (ph_min, ph_max), "horizontal")
+                } else {
+                    ((0.0, -dg / db), (1.0, -da / db), (ph_min, ph_max), "sloped")
+                }
+            } else if da.abs() > 1e-9 {
+                ((-dg / da, 0.0), (0.0, 1.0), (e_min, e_max), "vertical")
+            } else {
+                continue;
+            };
+
+            // Clip to the window and to where i (= j) lies on the lower envelope
+            let (mut lo, mut hi) = t_range;
+            let mut constrain = |c0: f64, c1: f64| {
+                // c0 + c1·t ≤ 0
+                if c1.abs() < 1e-12 {
+                    if c0 > TOL {
+                        hi = f64::NEG_INFINITY;
+                    }
+                } else if c1 > 0.0 {
+                    hi = hi.min((TOL - c0) / c1);
+                } else {
+                    lo = lo.max((TOL - c0) / c1);
+                }
+            };
+            constrain(e_min - origin.1, -direction.1);
+            constrain(origin.1 - e_max, direction.1);
+            constrain(ph_min - origin.0, -direction.0);
+            constrain(origin.0 - ph_max, direction.0);
+            for m in (0..species.len()).filter(|&m| m != i && m != j) {
+                let c0 = value(i, origin.0, origin.1) - value(m, origin.0, origin.1);
+                let c1 = (phi[i].1 - phi[m].1) * direction.0 + (phi[i].2 - phi[m].2) * direction.1;
+                constrain(c0, c1);
+            }
+
+            if hi - lo > 1e-9 {
+                let point = |t: f64| (origin.0 + t * direction.0, origin.1 + t * direction.1);
+                boundaries.push(PourbaixBoundary {
+                    species: (species[i].clone(), species[j].clone()),
+                    kind: kind.to_string(),
+                    points: [point(lo), point(hi)],
+                    slope: (kind != "vertical").then_some(direction.1),
+                });
+            }
+        }
+    }
+
+    // Label points: centroid of grid cells where each species predominates
+    const GRID: usize = 80;
+    let mut sums = vec![(0.0, 0.0, 0usize); species.len()];
+    for p in 0..=GRID {
+        for q in 0..=GRID {
+            let ph = ph_min + (ph_max - ph_min) * p as f64 / GRID as f64;
+            let e = e_min + (e_max - e_min) * q as f64 / GRID as f64;
+            let best = (0..species.len())
+                .min_by(|&a, &b| value(a, ph, e).total_cmp(&value(b, ph, e)))
+                .unwrap();
+            sums[best].0 += ph;
+            sums[best].1 += e;
+            sums[best].2 += 1;
+        }
+    }
+    let regions = species
+        .iter()
+        .zip(&sums)
+        .map(|(name, &(ph, e, count))| PourbaixRegion {
+            species: name.clone(),
+            label_point: (count > 0).then(|| (ph / count as f64, e / count as f64)),
+        })
+        .collect();
+
+    // O₂ + 4H⁺ + 4e⁻ → 2H₂O and 2H⁺ + 2e⁻ → H₂ at 1 bar
+    let water_slope = -rt_ln10 / F;
+    let e_oxygen = -g_water / (2.0 * F);
+    let mut water_lines = std::collections::BTreeMap::new();
+    water_lines.insert(
+        "oxygen".to_string(),
+        [(ph_min, e_oxygen + water_slope * ph_min), (ph_max, e_oxygen + water_slope * ph_max)],
+    );
+    water_lines.insert(
+        "hydrogen".to_string(),
+        [(ph_min, water_slope * ph_min), (ph_max, water_slope * ph_max)],
+    );
+
+    let result = PourbaixDiagram {
+        boundaries,
+        regions,
+        water_lines,
+        ph_range: (ph_min, ph_max),
+        e_range: (e_min, e_max),
+        concentration,
+        temperature,
+    };
+
+    to_json(&result)
+}
+
+/// Species written in cell notation, e.g. "Zn2+(aq, 0.010 M)" → ("Zn2+(aq)", 0.010)
+fn parse_cell_species(token: &str) -> Result<(String, f64), String> {
+    let token = token.trim();
+    let Some(open) = token.rfind('(').filter(|_| token.ends_with(')')) else {
+        return Ok((token.to_string(), 1.0));
+    };
+    let base = token[..open].trim();
+    let mut state = None;
+    let mut activity = 1.0;
+    for part in token[open + 1..token.len() - 1].split(',').map(str::trim) {
+        let mut words = part.split_whitespace();
+        let first = words.next().unwrap_or("");
+        if let Ok(value) = first.parse::<f64>() {
+            // Concentrations in mol/L, pressures in bar (atm converted)
+            activity = match words.next() {
+                Some("atm") => value * 1.01325,
+                Some("mM") => value / 1000.0,
+                _ => value,
+            };
+        } else if ["aq", "s", "l", "g", "cr"].contains(&first) {
+            state = Some(first);
+        } else {
+            return Err(format!("Unrecognised phase or amount '{}' in {}", part, token));
+        }
+    }
+    check_positive(&format!("activity in {}", token), activity)?;
+    let name = match state {
+        Some(state) => format!("{}({})", base, state),
+        None => base.to_string(),
+    };
+    Ok((name, activity))
+}
+
+/// Find the table couple whose species are all present in a half-cell
+///
+/// H⁺, H₂O and OH⁻ need not be written; among matching couples the one sharing the most
+/// species with the half-cell wins.
+fn match_couple(
+    half_cell: &[(String, f64)],
+    data: &HashMap<String, HalfReaction>,
+) -> Option<(String, HalfReaction)> {
+    const SPECTATORS: [&str; 3] = ["H+(aq)", "H2O(l)", "OH-(aq)"];
+    let present = |name: &str| half_cell.iter().any(|(s, _)| s == name);
+
+    let mut keys: Vec<&String> = data.keys().collect();
+    keys.sort();
+    let mut best: Option<(usize, &String)> = None;
+    for key in keys {
+        let half = &data[key];
+        let species: Vec<&str> = half
+            .oxidized
+            .iter()
+            .chain(half.reduced.iter())
+            .map(|(s, _)| s.as_str())
+            .collect();
+        if species.is_empty()
+            || !species
+                .iter()
+                .all(|s| SPECTATORS.contains(s) || present(s))
+        {
+            continue;
+        }
+        let score = species.iter().filter(|s| present(s)).count();
+        if score > 0 && best.is_none_or(|(best_score, _)| score > best_score) {
+            best = Some((score, key));
+        }
+    }
+    best.map(|(_, key)| (key.clone(), data[key].clone()))
+}
+
+/// Cell described by conventional notation, with the potential under the stated conditions
+#[derive(Serialize)]
+struct CellNotationResult {
+    #[serde(flatten)]
+    cell: CellPotential,
+    /// Nernst potential using the activities written in the notation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    potential: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reaction_quotient: Option<f64>,
+    /// Whether the cell reaction runs as written (E > 0)
+    spontaneous: bool,
+}
+
+/// Parse galvanic cell notation and evaluate the cell
+///
+/// "anode | anode solution || cathode solution | cathode": phases are separated by "|",
+/// species in one phase by ",", and the salt bridge by "||". Species may carry a state and
+/// an activity, e.g. "Zn2+(aq, 0.010 M)" or "H2(g, 0.5 bar)". Each half-cell is matched to a
+/// couple in the reduction potential table.
+///
+/// Arguments:
+/// - notation: Cell notation string
+/// - data_json: Reduction potential table
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn parse_cell_notation(
+    notation_bytes: &[u8],
+    data_json: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let notation: String = parse_arg(notation_bytes, "cell notation")?;
+    let data: HashMap<String, HalfReaction> = parse_json(data_json, "reduction potential data")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_temperature(temperature)?;
+    let halves: Vec<&str> = notation.split("||").collect();
+    if halves.len() != 2 {
+        return Err("Cell notation needs exactly one salt bridge '||'".to_string());
+    }
+    // Split on phase boundaries and commas outside parentheses
+    let parse_half = |half: &str| -> Result<Vec<(String, f64)>, String> {
+        let mut tokens = Vec::new();
+        let (mut depth, mut start) = (0i32, 0);
+        for (i, c) in half.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '|' | ',' if depth == 0 => {
+                    tokens.push(&half[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        tokens.push(&half[start..]);
+        tokens
+            .into_iter()
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(parse_cell_species)
+            .collect()
+    };
+    let anode_species = parse_half(halves[0])?;
+    let cathode_species = parse_half(halves[1])?;
+
+    let (anode_label, anode) = match_couple(&anode_species, &data)
+        .ok_or_else(|| format!("No couple in the table matches the anode: {}", halves[0].trim()))?;
+    let (cathode_label, cathode) = match_couple(&cathode_species, &data).ok_or_else(|| {
+        format!("No couple in the table matches the cathode: {}", halves[1].trim())
+    })?;
+
+    let cell = build_cell(cathode_label, cathode, anode_label, anode);
+
+    let activity = |name: &str| {
+        anode_species
+            .iter()
+            .chain(cathode_species.iter())
+            .find(|(s, _)| s == name)
+            .map_or(1.0, |(_, a)| *a)
+    };
+    let (potential, reaction_quotient) = match cell.electrons_transferred {
+        Some(n) if !cell.reactants.is_empty() || !cell.products.is_empty() => {
+            let ln_q: f64 = cell
+                .products
+                .iter()
+                .map(|(s, nu)| nu * activity(s).ln())
+                .sum::<f64>()
+                - cell
+                    .reactants
+                    .iter()
+                    .map(|(s, nu)| nu * activity(s).ln())
+                    .sum::<f64>();
+            let e = cell.value - R * temperature * ln_q / (n * F);
+            (Some(CalculationResult::new(e, "V")), Some(ln_q.exp()))
+        }
+        _ => (None, None),
+    };
+
+    let result = CellNotationResult {
+        spontaneous: potential.as_ref().map_or(cell.value, |e| e.value) > 0.0,
+        cell,
+        potential,
+        reaction_quotient,
+    };
+
+    to_json(&result)
+}
+
+/// Species written in a redox equation; side is +1 for reactants and -1 for products
+#[derive(Clone)]
+struct RedoxSpecies {
+    name: String,
+    formula: Formula,
+    side: f64,
+}
+
+/// Species and coefficients on one side of an equation
+type EquationSide = Vec<(String, f64)>;
+
+/// Coefficients of a balanced redox equation
+///
+/// Given species carry their coefficient on the side they were written; H₂O, H⁺, OH⁻ and
+/// e⁻ are signed, positive on the reactant side.
+#[derive(Clone)]
+struct RedoxCoefficients {
+    species: Vec<f64>,
+    water: f64,
+    protons: f64,
+    hydroxide: f64,
+    electrons: f64,
+}
+
+impl RedoxCoefficients {
+    fn scaled(&self, factor: f64) -> Self {
+        RedoxCoefficients {
+            species: self.species.iter().map(|c| c * factor).collect(),
+            water: self.water * factor,
+            protons: self.protons * factor,
+            hydroxide: self.hydroxide * factor,
+            electrons: self.electrons * factor,
+        }
+    }
+
+    /// Neutralise H⁺ with OH⁻ added to both sides
+    fn neutralise_protons(&mut self) {
+        self.water += self.protons;
+        self.hydroxide -= self.protons;
+        self.protons = 0.0;
+    }
+
+    /// Reactant and product lists; aux holds the names used for H₂O, H⁺ and OH⁻
+    fn sides(
+        &self,
+        species: &[RedoxSpecies],
+        aux: &[String; 3],
+    ) -> (EquationSide, EquationSide) {
+        let mut reactants = Vec::new();
+        let mut products = Vec::new();
+        for (s, &coeff) in species.iter().zip(self.species.iter()) {
+            if coeff > 1e-9 {
+                let side = if s.side > 0.0 { &mut reactants } else { &mut products };
+                side.push((s.name.clone(), coeff));
+            }
+        }
+        for (name, coeff) in [
+            (aux[1].as_str(), self.protons),
+            (aux[2].as_str(), self.hydroxide),
+            (aux[0].as_str(), self.water),
+            ("e⁻", self.electrons),
+        ] {
+            if coeff > 1e-9 {
+                reactants.push((name.to_string(), coeff));
+            } else if coeff < -1e-9 {
+                products.push((name.to_string(), -coeff));
+            }
+        }
+        (reactants, products)
+    }
+}
+
+/// Basis vector of a one-dimensional null space, or the nullity when it is not one
+fn null_space_vector(matrix: &[Vec<f64>], columns: usize) -> Result<Vec<f64>, usize> {
+    let mut m = matrix.to_vec();
+    let mut pivots = Vec::new();
+    for col in 0..columns {
+        let row = pivots.len();
+        if row == m.len() {
+            break;
+        }
+        let best = (row..m.len())
+            .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+            .unwrap();
+        if m[best][col].abs() < 1e-9 {
+            continue;
+        }
+        m.swap(row, best);
+        let pivot = m[row][col];
+        let pivot_row: Vec<f64> = m[row].iter().map(|v| v / pivot).collect();
+        for (r, values) in m.iter_mut().enumerate() {
+            let factor = values[col];
+            if r != row && factor != 0.0 {
+                for (v, p) in values.iter_mut().zip(pivot_row.iter()) {
+                    *v -= factor * p;
+                }
+            }
+        }
+        m[row] = pivot_row;
+        pivots.push(col);
+    }
+
+    let free: Vec<usize> = (0..columns).filter(|c| !pivots.contains(c)).collect();
+    if free.len() != 1 {
+        return Err(free.len());
+    }
+    let mut vector = vec![0.0; columns];
+    vector[free[0]] = 1.0;
+    for (row, &col) in pivots.iter().enumerate() {
+        vector[col] = -m[row][free[0]];
+    }
+    Ok(vector)
+}
+
+/// Scale a vector to the smallest whole numbers with the same ratios
+fn integer_coefficients(vector: &[f64]) -> Option<Vec<f64>> {
+    let smallest = vector
+        .iter()
+        .map(|v| v.abs())
+        .filter(|v| *v > 1e-9)
+        .fold(f64::INFINITY, f64::min);
+    if !smallest.is_finite() {
+        return None;
+    }
+    (1..=1000).find_map(|k| {
+        let scaled: Vec<f64> = vector.iter().map(|v| v * k as f64 / smallest).collect();
+        scaled
+            .iter()
+            .all(|v| (v - v.round()).abs() < 1e-6)
+            .then(|| scaled.iter().map(|v| v.round() + 0.0).collect())
+    })
+}
+
+/// Balance a group of species with H₂O, H⁺ and (optionally) e⁻ in acidic solution
+///
+/// Returns None when the coefficients are not unique. Species made only of H and O (H₂O₂,
+/// O₂) can trade atoms with water, so for those the solution without added H₂O is tried.
+fn balance_redox_group(
+    species: &[RedoxSpecies],
+    members: &[usize],
+    with_electrons: bool,
+) -> Result<Option<RedoxCoefficients>, String> {
+    let only_h_o = members.iter().any(|&i| {
+        species[i]
+            .formula
+            .elements
+            .iter()
+            .all(|(el, _)| el == "H" || el == "O")
+    });
+    match solve_redox_group(species, members, with_electrons, true, true)? {
+        None if only_h_o => solve_redox_group(species, members, with_electrons, false, true),
+        balanced => Ok(balanced),
+    }
+}
+
+/// Solve the element and charge balance of a group of species
+fn solve_redox_group(
+    species: &[RedoxSpecies],
+    members: &[usize],
+    with_electrons: bool,
+    with_water: bool,
+    with_protons: bool,
+) -> Result<Option<RedoxCoefficients>, String> {
+    let mut columns: Vec<(Vec<(String, f64)>, f64)> = members
+        .iter()
+        .map(|&i| {
+            let s = &species[i];
+            let elements = s
+                .formula
+                .elements
+                .iter()
+                .map(|(el, n)| (el.clone(), n * s.side))
+                .collect();
+            (elements, s.formula.charge * s.side)
+        })
+        .collect();
+    if with_water {
+        columns.push((vec![("H".to_string(), 2.0), ("O".to_string(), 1.0)], 0.0));
+    }
+    if with_protons {
+        columns.push((vec![("H".to_string(), 1.0)], 1.0));
+    }
+    if with_electrons {
+        columns.push((Vec::new(), -1.0));
+    }
+
+    let mut elements: Vec<&String> = Vec::new();
+    for (counts, _) in &columns {
+        for (el, _) in counts {
+            if !elements.contains(&el) {
+                elements.push(el);
+            }
+        }
+    }
+    let mut matrix: Vec<Vec<f64>> = elements
+        .iter()
+        .map(|el| {
+            columns
+                .iter()
+                .map(|(counts, _)| {
+                    counts
+                        .iter()
+                        .filter(|(symbol, _)| symbol == *el)
+                        .map(|(_, n)| n)
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    matrix.push(columns.iter().map(|(_, charge)| *charge).collect());
+
+    let vector = match null_space_vector(&matrix, columns.len()) {
+        Ok(vector) => vector,
+        Err(_) => return Ok(None),
+    };
+    let mut vector = integer_coefficients(&vector)
+        .ok_or_else(|| "Equation has no whole-number solution".to_string())?;
+    if vector[0] < 0.0 {
+        vector.iter_mut().for_each(|v| *v = -*v + 0.0);
+    }
+    if let Some(&i) = members.iter().zip(vector.iter()).find(|(_, c)| **c <= 0.0).map(|(i, _)| i) {
+        return Err(format!(
+            "Equation cannot be balanced with {} on the side where it is written",
+            species[i].name
+        ));
+    }
+
+    let mut coefficients = vec![0.0; species.len()];
+    for (&i, &c) in members.iter().zip(vector.iter()) {
+        coefficients[i] = c;
+    }
+    let mut aux = vector[members.len()..].iter().copied();
+    Ok(Some(RedoxCoefficients {
+        species: coefficients,
+        water: if with_water { aux.next().unwrap() } else { 0.0 },
+        protons: if with_protons { aux.next().unwrap() } else { 0.0 },
+        hydroxide: 0.0,
+        electrons: if with_electrons { aux.next().unwrap() } else { 0.0 },
+    }))
+}
+
+/// Balance a whole equation without electrons, keeping the H₂O, H⁺ and OH⁻ written in it
+///
+/// `written` holds those species with their kind (0 for H₂O, 1 for H⁺, 2 for OH⁻). They stay
+/// on the side where they are written and are folded into the water, proton and hydroxide
+/// coefficients; H₂O and H⁺ are only added when none is written.
+fn balance_written_equation(
+    species: &[RedoxSpecies],
+    written: &[(usize, RedoxSpecies)],
+) -> Result<Option<RedoxCoefficients>, String> {
+    let mut all = species.to_vec();
+    all.extend(written.iter().map(|(_, s)| s.clone()));
+    let members: Vec<usize> = (0..all.len()).collect();
+    let has = |kinds: &[usize]| written.iter().any(|(kind, _)| kinds.contains(kind));
+    let Some(mut whole) = solve_redox_group(&all, &members, false, !has(&[0]), !has(&[1, 2]))?
+    else {
+        return Ok(None);
+    };
+    let tail = whole.species.split_off(species.len());
+    for ((kind, s), coeff) in written.iter().zip(tail) {
+        let coeff = coeff * s.side;
+        match kind {
+            0 => whole.water += coeff,
+            1 => whole.protons += coeff,
+            _ => whole.hydroxide += coeff,
+        }
+    }
+    Ok(Some(whole))
+}
+
+/// Half-reaction obtained while balancing, in the shape of a potential table entry
+#[derive(Serialize)]
+struct BalancedHalfReaction {
+    /// "reduction" or "oxidation" as it occurs in the equation
+    process: String,
+    equation: String,
+    n: f64,
+    oxidized: Vec<(String, f64)>,
+    reduced: Vec<(String, f64)>,
+}
+
+/// Balanced redox equation
+#[derive(Serialize)]
+struct RedoxBalance {
+    equation: String,
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    /// Electrons transferred in the equation as balanced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<f64>,
+    medium: String,
+    half_reactions: Vec<BalancedHalfReaction>,
+}
+
+/// Balance a redox half-reaction or full redox equation in acidic or basic solution
+///
+/// Species are separated by " + " and the sides by "→", "->" or "="; H₂O, H⁺, OH⁻ and e⁻
+/// are added as needed. A full equation is split into its two half-reactions by the
+/// elements other than H and O the species share; these are balanced separately and
+/// combined so the electrons cancel, which gives n. Equations that cannot be split
+/// (disproportionation, comproportionation) are balanced as a whole without n, keeping any
+/// H₂O, H⁺ and OH⁻ written in them, unless e⁻ is written or they only balance as a
+/// half-reaction.
+///
+/// Arguments:
+/// - equation: Unbalanced equation, e.g. "MnO4- + Fe2+ -> Mn2+ + Fe3+"
+/// - medium: "acidic" or "basic"
+#[wasm_func]
+pub fn balance_redox_equation(equation_bytes: &[u8], medium_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let equation: String = parse_arg(equation_bytes, "equation")?;
+    let medium: String = parse_arg(medium_bytes, "medium")?;
+
+    let basic = match medium.as_str() {
+        "acidic" | "acid" => false,
+        "basic" | "base" | "alkaline" => true,
+        _ => return Err(format!("Unknown medium: {} (expected acidic or basic)", medium)),
+    };
+
+    let (left, right) = ["→", "⟶", "->", "="]
+        .iter()
+        .find_map(|arrow| equation.split_once(arrow))
+        .ok_or_else(|| "Equation needs an arrow (→, -> or =) between the sides".to_string())?;
+
+    let mut species = Vec::new();
+    let mut written = Vec::new();
+    let mut electrons_written = false;
+    for (text, side) in [(left, 1.0), (right, -1.0)] {
+        for token in text.split(" + ") {
+            let name = token
+                .trim()
+                .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+                .trim();
+            if name.is_empty() {
+                return Err(format!("Missing species in equation: {}", equation));
+            }
+            if ["e", "e-", "e⁻"].contains(&name) {
+                electrons_written = true;
+                continue;
+            }
+            let formula = parse_formula(name)?;
+            let (h, o, z) = (formula.count("H"), formula.count("O"), formula.charge);
+            // H₂O, H⁺ or OH⁻
+            let auxiliary = if formula.elements.len() <= 2 {
+                [(2.0, 1.0, 0.0), (1.0, 0.0, 1.0), (1.0, 1.0, -1.0)]
+                    .iter()
+                    .position(|&counts| counts == (h, o, z))
+            } else {
+                None
+            };
+            let entry = RedoxSpecies { name: name.to_string(), formula, side };
+            match auxiliary {
+                Some(kind) => written.push((kind, entry)),
+                None => species.push(entry),
+            }
+        }
+    }
+    if species.is_empty() {
+        return Err("Equation contains no species besides H₂O, H⁺, OH⁻ and e⁻".to_string());
+    }
+
+    let aux = if species.iter().any(|s| s.formula.state.is_some()) {
+        ["H2O(l)", "H+(aq)", "OH-(aq)"]
+    } else {
+        ["H2O", "H+", "OH-"]
+    }
+    .map(String::from);
+
+    // Group species sharing an element other than H and O; species made only of H and O
+    // are grouped among themselves
+    let keys: Vec<Vec<String>> = species
+        .iter()
+        .map(|s| {
+            let heavy: Vec<String> = s
+                .formula
+                .elements
+                .iter()
+                .filter(|(el, _)| el != "H" && el != "O")
+                .map(|(el, _)| el.clone())
+                .collect();
+            if heavy.is_empty() {
+                s.formula.elements.iter().map(|(el, _)| format!("*{}", el)).collect()
+            } else {
+                heavy
+            }
+        })
+        .collect();
+    let mut group: Vec<usize> = (0..species.len()).collect();
+    for i in 0..species.len() {
+        for j in 0..i {
+            if keys[i].iter().any(|key| keys[j].contains(key)) {
+                let (old, new) = (group[i], group[j]);
+                group.iter_mut().filter(|g| **g == old).for_each(|g| *g = new);
+            }
+        }
+    }
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for i in 0..species.len() {
+        match groups.iter_mut().find(|members| group[members[0]] == group[i]) {
+            Some(members) => members.push(i),
+            None => groups.push(vec![i]),
+        }
+    }
+    let two_halves = groups.len() == 2
+        && groups.iter().all(|members| {
+            members.iter().any(|&i| species[i].side > 0.0)
+                && members.iter().any(|&i| species[i].side < 0.0)
+        });
+
+    let half_reaction = |coefficients: &RedoxCoefficients| {
+        let mut half = coefficients.clone();
+        if basic {
+            half.neutralise_protons();
+        }
+        let (reactants, products) = half.sides(&species, &aux);
+        let equation = format!(
+            "{} → {}",
+            format_equation_side(&reactants),
+            format_equation_side(&products)
+        );
+        let strip = |side: Vec<(String, f64)>| -> Vec<(String, f64)> {
+            side.into_iter().filter(|(name, _)| name != "e⁻").collect()
+        };
+        let reduction = half.electrons > 0.0;
+        let (oxidized, reduced) = if reduction {
+            (strip(reactants), strip(products))
+        } else {
+            (strip(products), strip(reactants))
+        };
+        BalancedHalfReaction {
+            process: if reduction { "reduction" } else { "oxidation" }.to_string(),
+            equation,
+            n: half.electrons.abs(),
+            oxidized,
+            reduced,
+        }
+    };
+
+    let (mut overall, n, half_reactions) = if two_halves {
+        let mut halves = Vec::new();
+        for members in &groups {
+            let half = balance_redox_group(&species, members, true)?
+                .ok_or_else(|| "Half-reaction cannot be balanced uniquely".to_string())?;
+            halves.push(half);
+        }
+        halves.sort_by(|a, b| b.electrons.total_cmp(&a.electrons));
+        let (reduction, oxidation) = (&halves[0], &halves[1]);
+        if reduction.electrons <= 0.0 || oxidation.electrons >= 0.0 {
+            return Err(
+                "Equation is not a redox reaction: one species must be reduced and another oxidized"
+                    .to_string(),
+            );
+        }
+        let (a, b) = (reduction.electrons as u64, (-oxidation.electrons) as u64);
+        let lcm = a / gcd(a, b) * b;
+        let (x, y) = (reduction.scaled((lcm / a) as f64), oxidation.scaled((lcm / b) as f64));
+        let mut overall = RedoxCoefficients {
+            species: x.species.iter().zip(y.species.iter()).map(|(p, q)| p + q).collect(),
+            water: x.water + y.water,
+            protons: x.protons + y.protons,
+            hydroxide: 0.0,
+            electrons: 0.0,
+        };
+        let divisor = overall
+            .species
+            .iter()
+            .chain([overall.water, overall.protons, lcm as f64].iter())
+            .map(|c| c.abs() as u64)
+            .filter(|c| *c > 0)
+            .fold(0, gcd);
+        overall = overall.scaled(1.0 / divisor as f64);
+        let n = lcm as f64 / divisor as f64;
+        (overall, Some(n), vec![half_reaction(reduction), half_reaction(oxidation)])
+    } else {
+        let members: Vec<usize> = (0..species.len()).collect();
+        let whole = if electrons_written {
+            None
+        } else {
+            // Written as a full equation, e.g. "H2O2 -> H2O + O2"; half-reactions written
+            // without electrons have no solution here and are balanced below
+            balance_written_equation(&species, &written).unwrap_or(None)
+        };
+        match whole {
+            Some(whole) => (whole, None, Vec::new()),
+            None => match balance_redox_group(&species, &members, true)? {
+                Some(half) if half.electrons != 0.0 => {
+                    let n = half.electrons.abs();
+                    let halves = vec![half_reaction(&half)];
+                    (half, Some(n), halves)
+                }
+                _ => {
+                    let whole = balance_redox_group(&species, &members, false)?
+                        .ok_or_else(|| "Equation cannot be balanced uniquely".to_string())?;
+                    (whole, None, Vec::new())
+                }
+            },
+        }
+    };
+
+    if basic {
+        overall.neutralise_protons();
+    }
+    let (reactants, products) = overall.sides(&species, &aux);
+    let result = RedoxBalance {
+        equation: format!(
+            "{} → {}",
+            format_equation_side(&reactants),
+            format_equation_side(&products)
+        ),
+        reactants,
+        products,
+        n,
+        medium: if basic { "basic" } else { "acidic" }.to_string(),
+        half_reactions,
+    };
+
+    to_json(&result)
+}
+
+/// Anodic and cathodic Butler–Volmer branches: j = j0·[exp((1-α)nfη) - exp(-αnfη)], f = F/RT
+fn butler_volmer_branches(eta: f64, j0: f64, alpha: f64, nf: f64) -> (f64, f64) {
+    (
+        j0 * ((1.0 - alpha) * nf * eta).exp(),
+        -j0 * (-alpha * nf * eta).exp(),
+    )
+}
+
+/// Current density at one overpotential
+#[derive(Serialize)]
+struct ButlerVolmerResult {
+    /// Net current density (anodic positive)
+    value: f64,
+    unit: String,
+    anodic: f64,
+    cathodic: f64,
+    /// Tafel slopes (V/decade); the cathodic slope is negative
+    anodic_tafel_slope: f64,
+    cathodic_tafel_slope: f64,
+    /// R_ct = RT/(nF·j0) (Ω·cm²)
+    charge_transfer_resistance: CalculationResult,
+}
+
+/// Validate Butler–Volmer parameters shared by the electrode-kinetics functions
+fn check_electrode_kinetics(alpha: f64, n: f64, temperature: f64) -> Result<(), String> {
+    check_fraction("transfer coefficient", alpha)?;
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+    Ok(())
+}
+
+/// Current density from overpotential with the Butler–Volmer equation
+/// j = j0·[exp((1-α)nFη/RT) - exp(-αnFη/RT)]
+///
+/// Arguments:
+/// - eta: Overpotential η (V)
+/// - j0: Exchange current density (A/cm²)
+/// - alpha: Cathodic transfer coefficient α
+/// - n: Number of electrons in the rate-determining step
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn calculate_butler_volmer(
+    eta_bytes: &[u8],
+    j0_bytes: &[u8],
+    alpha_bytes: &[u8],
+    n_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let eta = parse_number(eta_bytes, "overpotential")?;
+    let j0 = parse_number(j0_bytes, "exchange current density")?;
+    let alpha = parse_number(alpha_bytes, "transfer coefficient")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_positive("exchange current density", j0)?;
+    check_electrode_kinetics(alpha, n, temperature)?;
+
+    let nf = n * F / (R * temperature);
+    let (anodic, cathodic) = butler_volmer_branches(eta, j0, alpha, nf);
+
+    let result = ButlerVolmerResult {
+        value: anodic + cathodic,
+        unit: "A/cm²".to_string(),
+        anodic,
+        cathodic,
+        anodic_tafel_slope: 10_f64.ln() / ((1.0 - alpha) * nf),
+        cathodic_tafel_slope: -10_f64.ln() / (alpha * nf),
+        charge_transfer_resistance: CalculationResult::new(1.0 / (nf * j0), "Ω·cm²"),
+    };
+
+    to_json(&result)
+}
+
+/// Butler–Volmer polarisation curve
+#[derive(Serialize)]
+struct PolarizationCurve {
+    /// (η, j) points for the net current and the two branches
+    net: Vec<(f64, f64)>,
+    anodic: Vec<(f64, f64)>,
+    cathodic: Vec<(f64, f64)>,
+    /// (η, log10|j|) points for a Tafel plot
+    tafel: Vec<(f64, f64)>,
+}
+
+/// Sample a Butler–Volmer polarisation curve over an overpotential range
+///
+/// Arguments:
+/// - j0: Exchange current density (A/cm²)
+/// - alpha: Cathodic transfer coefficient α
+/// - n: Number of electrons in the rate-determining step
+/// - range_json: Overpotential range (η_min, η_max) in V
+/// - points: Number of points
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn butler_volmer_curve(
+    j0_bytes: &[u8],
+    alpha_bytes: &[u8],
+    n_bytes: &[u8],
+    range_json: &[u8],
+    points_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let j0 = parse_number(j0_bytes, "exchange current density")?;
+    let alpha = parse_number(alpha_bytes, "transfer coefficient")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let (eta_min, eta_max): (f64, f64) = parse_json(range_json, "overpotential range")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_positive("exchange current density", j0)?;
+    check_electrode_kinetics(alpha, n, temperature)?;
+    if eta_min >= eta_max {
+        return Err("Overpotential range must be given as (min, max)".to_string());
+    }
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let nf = n * F / (R * temperature);
+    let mut curve = PolarizationCurve {
+        net: Vec::with_capacity(points),
+        anodic: Vec::with_capacity(points),
+        cathodic: Vec::with_capacity(points),
+        tafel: Vec::with_capacity(points),
+    };
+    for t in sample_times(1.0, points) {
+        let eta = eta_min + (eta_max - eta_min) * t;
+        let (anodic, cathodic) = butler_volmer_branches(eta, j0, alpha, nf);
+        let net = anodic + cathodic;
+        curve.net.push((eta, net));
+        curve.anodic.push((eta, anodic));
+        curve.cathodic.push((eta, cathodic));
+        if net != 0.0 {
+            curve.tafel.push((eta, net.abs().log10()));
+        }
+    }
+
+    to_json(&curve)
+}
+
+/// Straight-line fit of one Tafel branch
+#[derive(Serialize)]
+struct TafelBranch {
+    /// dη/dlog10|j| (V/decade)
+    tafel_slope: CalculationResult,
+    /// Transfer coefficient of this branch: 1-α for the anodic, α for the cathodic branch
+    transfer_coefficient: f64,
+    /// Extrapolation of the branch to η = 0
+    exchange_current_density: CalculationResult,
+    r_squared: f64,
+    points: usize,
+}
+
+/// Tafel analysis of both branches
+#[derive(Serialize)]
+struct TafelAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    anodic: Option<TafelBranch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cathodic: Option<TafelBranch>,
+    /// Geometric mean of the branch intercepts, or the single available intercept
+    exchange_current_density: CalculationResult,
+    /// Cathodic transfer coefficient α, from the cathodic branch when available
+    alpha: f64,
+}
+
+/// Extract Tafel slopes and the exchange current density from (η, log10|j|) data
+///
+/// Points with |η| below the threshold are ignored, since the Tafel approximation only
+/// holds well away from equilibrium. Each branch is fitted as log10|j| = log10 j0 + η/b.
+///
+/// Arguments:
+/// - data_json: Array of (η, log10|j|) pairs (V, j in A/cm²)
+/// - n: Number of electrons in the rate-determining step
+/// - T: Temperature (K)
+/// - min_overpotential: Smallest |η| included in the fit (V)
+#[wasm_func]
+pub fn fit_tafel(
+    data_json: &[u8],
+    n_bytes: &[u8],
+    temperature_bytes: &[u8],
+    min_overpotential_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let data: Vec<(f64, f64)> = parse_json(data_json, "Tafel data")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let min_overpotential = parse_number(min_overpotential_bytes, "minimum overpotential")?;
+
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+
+    let nf = n * F / (R * temperature);
+    let fit_branch = |sign: f64| -> Result<Option<TafelBranch>, String> {
+        let (etas, log_j): (Vec<f64>, Vec<f64>) = data
+            .iter()
+            .filter(|&&(eta, _)| eta * sign > 0.0 && eta.abs() >= min_overpotential)
+            .cloned()
+            .unzip();
+        if etas.len() < 2 {
+            return Ok(None);
+        }
+        let fit = linear_regression(&etas, &log_j)?;
+        if fit.slope * sign <= 0.0 {
+            return Err("Tafel branch must rise in |j| away from η = 0".to_string());
+        }
+        let slope = 1.0 / fit.slope;
+        Ok(Some(TafelBranch {
+            tafel_slope: CalculationResult::new(slope, "V/decade"),
+            transfer_coefficient: 10_f64.ln() / (slope.abs() * nf),
+            exchange_current_density: CalculationResult::new(10_f64.powf(fit.intercept), "A/cm²"),
+            r_squared: fit.r_squared,
+            points: etas.len(),
+        }))
+    };
+    let anodic = fit_branch(1.0)?;
+    let cathodic = fit_branch(-1.0)?;
+
+    let (j0, alpha) = match (&anodic, &cathodic) {
+        (Some(a), Some(c)) => (
+            (a.exchange_current_density.value * c.exchange_current_density.value).sqrt(),
+            c.transfer_coefficient,
+        ),
+        (Some(a), None) => (a.exchange_current_density.value, 1.0 - a.transfer_coefficient),
+        (None, Some(c)) => (c.exchange_current_density.value, c.transfer_coefficient),
+        (None, None) => {
+            return Err(
+                "At least 2 points beyond the minimum overpotential are needed on one branch"
+                    .to_string(),
+            )
+        }
+    };
+
+    let result = TafelAnalysis {
+        anodic,
+        cathodic,
+        exchange_current_density: CalculationResult::new(j0, "A/cm²"),
+        alpha,
+    };
+
+    to_json(&result)
+}
+
+/// Exchange current density fitted to a polarisation curve
+#[derive(Serialize)]
+struct ExchangeCurrentFit {
+    exchange_current_density: CalculationResult,
+    /// Cathodic transfer coefficient α
+    alpha: f64,
+    j0_error: f64,
+    alpha_error: f64,
+    r_squared: f64,
+    charge_transfer_resistance: CalculationResult,
+    /// j0 from the slope of the linear region near η = 0, when it has enough points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linear_estimate: Option<CalculationResult>,
+    /// Fitted (η, j) curve for plotting
+    curve: Vec<(f64, f64)>,
+}
+
+/// Fit j0 and α of the Butler–Volmer equation to (η, j) data
+///
+/// The micro-polarisation slope dj/dη = nF·j0/RT of the points with |η| ≤ linear_limit gives
+/// a starting j0 (the Tafel-free estimate), which is refined together with α by
+/// Levenberg–Marquardt on the full curve. Standard errors come from s²·(JᵀJ)⁻¹.
+///
+/// Arguments:
+/// - data_json: Array of (η, j) pairs (V, A/cm²), anodic current positive
+/// - n: Number of electrons in the rate-determining step
+/// - T: Temperature (K)
+/// - linear_limit: Largest |η| of the linear region (V)
+/// - points: Number of points in the fitted curve
+#[wasm_func]
+pub fn fit_exchange_current(
+    data_json: &[u8],
+    n_bytes: &[u8],
+    temperature_bytes: &[u8],
+    linear_limit_bytes: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let data: Vec<(f64, f64)> = parse_json(data_json, "polarisation data")?;
+    let n = parse_number(n_bytes, "electron count")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let linear_limit = parse_number(linear_limit_bytes, "linear limit")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    if data.len() < 3 {
+        return Err("At least 3 (η, j) points are required".to_string());
+    }
+    check_positive("number of electrons", n)?;
+    check_temperature(temperature)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let nf = n * F / (R * temperature);
+
+    // Linear (micro-polarisation) region: j ≈ j0·nf·η
+    let (near_eta, near_j): (Vec<f64>, Vec<f64>) = data
+        .iter()
+        .filter(|&&(eta, _)| eta.abs() <= linear_limit)
+        .cloned()
+        .unzip();
+    let linear_estimate = if near_eta.len() >= 2 {
+        linear_regression(&near_eta, &near_j)
+            .ok()
+            .filter(|fit| fit.slope > 0.0)
+            .map(|fit| fit.slope / nf)
+    } else {
+        None
+    };
+
+    // Fallback start: the point furthest from equilibrium with α = 0.5
+    let (far_eta, far_j) = data
+        .iter()
+        .cloned()
+        .max_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+        .unwrap();
+    let fallback = {
+        let (a, c) = butler_volmer_branches(far_eta, 1.0, 0.5, nf);
+        (far_j / (a + c)).abs()
+    };
+    let start = linear_estimate.unwrap_or(fallback);
+    if !(start.is_finite() && start > 0.0) {
+        return Err("Could not estimate a starting exchange current density".to_string());
+    }
+
+    // Residuals are scaled by the largest |j| so the fit is insensitive to units
+    let scale = data.iter().map(|d| d.1.abs()).fold(0.0, f64::max).max(1e-300);
+    let model = |ln_j0: f64, alpha: f64, eta: f64| {
+        let (a, c) = butler_volmer_branches(eta, ln_j0.exp(), alpha, nf);
+        a + c
+    };
+    let ssr = |ln_j0: f64, alpha: f64| -> f64 {
+        data.iter()
+            .map(|&(eta, j)| ((j - model(ln_j0, alpha, eta)) / scale).powi(2))
+            .sum()
+    };
+    // Gradient of the scaled model with respect to (ln j0, α)
+    let gradient = |ln_j0: f64, alpha: f64, eta: f64| {
+        let (a, c) = butler_volmer_branches(eta, ln_j0.exp(), alpha, nf);
+        [(a + c) / scale, -nf * eta * (a + c) / scale]
+    };
+
+    // Levenberg–Marquardt on (ln j0, α)
+    let (mut ln_j0, mut alpha) = (start.ln(), 0.5);
+    let mut lambda = 1e-3;
+    let mut current = ssr(ln_j0, alpha);
+    for _ in 0..200 {
+        let (mut jtj, mut jtr) = ([[0.0; 2]; 2], [0.0; 2]);
+        for &(eta, j) in &data {
+            let residual = (j - model(ln_j0, alpha, eta)) / scale;
+            let grad = gradient(ln_j0, alpha, eta);
+            for i in 0..2 {
+                jtr[i] += grad[i] * residual;
+                for k in 0..2 {
+                    jtj[i][k] += grad[i] * grad[k];
+                }
+            }
+        }
+
+        let a = [
+            [jtj[0][0] * (1.0 + lambda), jtj[0][1]],
+            [jtj[1][0], jtj[1][1] * (1.0 + lambda)],
+        ];
+        let det = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+        if det.abs() < 1e-300 {
+            break;
+        }
+        let step = [
+            (a[1][1] * jtr[0] - a[0][1] * jtr[1]) / det,
+            (a[0][0] * jtr[1] - a[1][0] * jtr[0]) / det,
+        ];
+
+        let (trial_ln_j0, trial_alpha) = (ln_j0 + step[0], alpha + step[1]);
+        let trial = if trial_alpha > 0.0 && trial_alpha < 1.0 {
+            ssr(trial_ln_j0, trial_alpha)
+        } else {
+            f64::INFINITY
+        };
+
+        if trial < current {
+            let converged = (current - trial) <= 1e-15 * current.max(1e-300);
+            ln_j0 = trial_ln_j0;
+            alpha = trial_alpha;
+            current = trial;
+            lambda = (lambda / 10.0).max(1e-12);
+            if converged {
+                break;
+            }
+        } else {
+            lambda *= 10.0;
+            if lambda > 1e12 {
+                break;
+            }
+        }
+    }
+
+    // Standard errors from s²·(JᵀJ)⁻¹; d(j0) = j0·d(ln j0)
+    let mut jtj = [[0.0; 2]; 2];
+    for &(eta, _) in &data {
+        let grad = gradient(ln_j0, alpha, eta);
+        for i in 0..2 {
+            for k in 0..2 {
+                jtj[i][k] += grad[i] * grad[k];
+            }
+        }
+    }
+    let det = jtj[0][0] * jtj[1][1] - jtj[0][1] * jtj[1][0];
+    let variance = current / (data.len() - 2).max(1) as f64;
+    let j0 = ln_j0.exp();
+    let (j0_error, alpha_error) = if det.abs() > 1e-300 {
+        (
+            j0 * (variance * jtj[1][1] / det).sqrt(),
+            (variance * jtj[0][0] / det).sqrt(),
+        )
+    } else {
+        (f64::NAN, f64::NAN)
+    };
+
+    let mean_j = data.iter().map(|d| d.1).sum::<f64>() / data.len() as f64;
+    let sst: f64 = data.iter().map(|d| ((d.1 - mean_j) / scale).powi(2)).sum();
+    let r_squared = if sst == 0.0 { 1.0 } else { 1.0 - current / sst };
+
+    let eta_min = data.iter().map(|d| d.0).fold(f64::INFINITY, f64::min);
+    let eta_max = data.iter().map(|d| d.0).fold(f64::NEG_INFINITY, f64::max);
+    let curve = sample_times(1.0, points)
+        .into_iter()
+        .map(|t| {
+            let eta = eta_min + (eta_max - eta_min) * t;
+            (eta, model(ln_j0, alpha, eta))
+        })
+        .collect();
+
+    let result = ExchangeCurrentFit {
+        exchange_current_density: CalculationResult::new(j0, "A/cm²"),
+        alpha,
+        j0_error,
+        alpha_error,
+        r_squared,
+        charge_transfer_resistance: CalculationResult::new(1.0 / (nf * j0), "Ω·cm²"),
+        linear_estimate: linear_estimate.map(|j| CalculationResult::new(j, "A/cm²")),
+        curve,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// SOLUTION CONCENTRATIONS
+// ============================================================================
+
+/// Concentration conversion between molarity, molality, mass percent and mole fraction
+#[derive(Deserialize)]
+struct ConcentrationInput {
+    value: f64,
+    /// "molarity", "molality", "mass-percent" or "mole-fraction"
+    from: String,
+    /// Solute molar mass (g/mol) or formula
+    solute: MolarMassSpec,
+    /// Solvent molar mass (g/mol) or formula (default: water)
+    #[serde(default)]
+    solvent: Option<MolarMassSpec>,
+    /// Solution density (g/mL), needed for molarity
+    #[serde(default)]
+    density: Option<f64>,
+}
+
+/// One solution composition in every concentration scale
+#[derive(Serialize)]
+struct Concentrations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    molarity: Option<CalculationResult>,
+    molality: CalculationResult,
+    /// Solute mass / solution mass (%)
+    mass_percent: f64,
+    /// Solute amount / total amount
+    mole_fraction: f64,
+    /// Solute mass per volume of solution (g/L)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mass_concentration: Option<CalculationResult>,
+    solute_molar_mass: CalculationResult,
+    solvent_molar_mass: CalculationResult,
+}
+
+/// Convert a solution concentration into molarity, molality, mass percent and mole fraction
+///
+/// Everything is referred to 1 kg of solvent holding b mol of solute. Molarity needs the
+/// solution density, both to convert from it and to report it.
+///
+/// Arguments:
+/// - input_json: {"value", "from", "solute", "solvent", "density"}
+#[wasm_func]
+pub fn convert_concentration(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: ConcentrationInput = parse_json(input_json, "input")?;
+
+    let solute = input.solute.value()?;
+    let solvent = match &input.solvent {
+        Some(spec) => spec.value()?,
+        None => parse_formula("H2O")?.molar_mass()?,
+    };
+    if let Some(density) = input.density {
+        check_positive("density", density)?;
+    }
+    check_non_negative("concentration", input.value)?;
+
+    // Solute amount per kilogram of solvent (mol/kg)
+    let molality = match input.from.as_str() {
+        "molality" => input.value,
+        "mass-percent" => {
+            check_domain("mass percent", input.value, input.value < 100.0, "below 100")?;
+            input.value / (100.0 - input.value) * 1000.0 / solute
+        }
+        "mole-fraction" => {
+            check_domain("mole fraction", input.value, input.value < 1.0, "below 1")?;
+            input.value / (1.0 - input.value) * 1000.0 / solvent
+        }
+        "molarity" => {
+            let density = input
+                .density
+                .ok_or("Converting from molarity needs the solution density")?;
+            // One litre of solution: 1000·ρ g, of which c·M is solute
+            let solvent_mass = 1000.0 * density - input.value * solute;
+            if solvent_mass <= 0.0 {
+                return Err("Solute mass exceeds the mass of one litre of solution".to_string());
+            }
+            input.value / solvent_mass * 1000.0
+        }
+        _ => {
+            return Err(format!(
+                "Unknown concentration: {} (expected molarity, molality, mass-percent or \
+                 mole-fraction)",
+                input.from
+            ))
+        }
+    };
+
+    let solute_mass = molality * solute;
+    let solution_mass = 1000.0 + solute_mass;
+    // Solution volume per kilogram of solvent (L)
+    let volume = input.density.map(|rho| solution_mass / rho / 1000.0);
+
+    let result = Concentrations {
+        molarity: volume.map(|v| CalculationResult::new(molality / v, "mol/L")),
+        molality: CalculationResult::new(molality, "mol/kg"),
+        mass_percent: solute_mass / solution_mass * 100.0,
+        mole_fraction: molality / (molality + 1000.0 / solvent),
+        mass_concentration: volume.map(|v| CalculationResult::new(solute_mass / v, "g/L")),
+        solute_molar_mass: CalculationResult::new(solute, "g/mol"),
+        solvent_molar_mass: CalculationResult::new(solvent, "g/mol"),
+    };
+
+    to_json(&result)
+}
+
+/// Dilution problem: any three of c₁, V₁, c₂ and V₂
+#[derive(Deserialize)]
+struct DilutionInput {
+    initial_concentration: Option<f64>,
+    initial_volume: Option<f64>,
+    final_concentration: Option<f64>,
+    final_volume: Option<f64>,
+    #[serde(default)]
+    volume_unit: String,
+}
+
+/// Solved dilution
+#[derive(Serialize)]
+struct Dilution {
+    /// "initial_concentration", "initial_volume", "final_concentration" or "final_volume"
+    solved_for: String,
+    initial_concentration: f64,
+    initial_volume: CalculationResult,
+    final_concentration: f64,
+    final_volume: CalculationResult,
+    /// V₂/V₁ = c₁/c₂
+    dilution_factor: f64,
+    /// Solvent to add, V₂ - V₁ (assuming additive volumes)
+    solvent_added: CalculationResult,
+}
+
+/// Solve the dilution equation c₁·V₁ = c₂·V₂ for the missing quantity
+///
+/// Concentrations may be in any unit and come back in the same unit.
+///
+/// Arguments:
+/// - input_json: Dilution, see `DilutionInput`
+#[wasm_func]
+pub fn dilution(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: DilutionInput = parse_json(input_json, "dilution input")?;
+
+    let (_, volume_symbol) = parse_volume_unit(&input.volume_unit)?;
+    let given = [
+        input.initial_concentration,
+        input.initial_volume,
+        input.final_concentration,
+        input.final_volume,
+    ];
+    let fields = ["initial concentration", "initial volume", "final concentration", "final volume"];
+    for (field, value) in fields.iter().zip(&given) {
+        if let Some(value) = value {
+            check_positive(field, *value)?;
+        }
+    }
+
+    let (solved_for, c1, v1, c2, v2) = match given {
+        [None, Some(v1), Some(c2), Some(v2)] => ("initial_concentration", c2 * v2 / v1, v1, c2, v2),
+        [Some(c1), None, Some(c2), Some(v2)] => ("initial_volume", c1, c2 * v2 / c1, c2, v2),
+        [Some(c1), Some(v1), None, Some(v2)] => ("final_concentration", c1, v1, c1 * v1 / v2, v2),
+        [Some(c1), Some(v1), Some(c2), None] => ("final_volume", c1, v1, c2, c1 * v1 / c2),
+        _ => {
+            return Err(
+                "Exactly one of the two concentrations and two volumes must be left unspecified"
+                    .to_string(),
+            )
+        }
+    };
+    if v2 < v1 {
+        return Err("Dilution cannot make the solution more concentrated".to_string());
+    }
+
+    let result = Dilution {
+        solved_for: solved_for.to_string(),
+        initial_concentration: c1,
+        initial_volume: CalculationResult::new(v1, volume_symbol),
+        final_concentration: c2,
+        final_volume: CalculationResult::new(v2, volume_symbol),
+        dilution_factor: v2 / v1,
+        solvent_added: CalculationResult::new(v2 - v1, volume_symbol),
+    };
+
+    to_json(&result)
+}
+
+/// Solutions of one solute mixed together
+#[derive(Deserialize)]
+struct MixingInput {
+    /// (concentration in mol/L, volume) of each solution
+    solutions: Vec<(f64, f64)>,
+    /// Final volume after making up with solvent (default: sum of the volumes)
+    #[serde(default)]
+    final_volume: Option<f64>,
+    #[serde(default)]
+    volume_unit: String,
+}
+
+#[derive(Serialize)]
+struct Mixture {
+    concentration: CalculationResult,
+    amount: CalculationResult,
+    volume: CalculationResult,
+    /// Solvent added to reach the final volume
+    solvent_added: CalculationResult,
+}
+
+/// Calculate the concentration after mixing solutions of the same solute
+/// c = Σcᵢ·Vᵢ / V, with V = ΣVᵢ unless a larger final volume is given
+///
+/// Arguments:
+/// - input_json: {"solutions", "final_volume", "volume_unit"}
+#[wasm_func]
+pub fn mix_solutions(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: MixingInput = parse_json(input_json, "mixing input")?;
+
+    let (litres_per_unit, volume_symbol) = parse_volume_unit(&input.volume_unit)?;
+    if input.solutions.is_empty() {
+        return Err("Give at least one solution".to_string());
+    }
+
+    let mut amount = 0.0;
+    let mut combined = 0.0;
+    for (concentration, volume) in &input.solutions {
+        check_non_negative("concentration", *concentration)?;
+        check_positive("volume", *volume)?;
+        amount += concentration * volume * litres_per_unit;
+        combined += volume;
+    }
+    let volume = input.final_volume.unwrap_or(combined);
+    if volume < combined {
+        return Err("Final volume is smaller than the combined volume".to_string());
+    }
+
+    let result = Mixture {
+        concentration: CalculationResult::new(amount / (volume * litres_per_unit), "mol/L"),
+        amount: CalculationResult::new(amount, "mol"),
+        volume: CalculationResult::new(volume, volume_symbol),
+        solvent_added: CalculationResult::new(volume - combined, volume_symbol),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ACID–BASE EQUILIBRIA
+// ============================================================================
+
+/// Solve a charge balance for [H⁺] (mol/L)
+///
+/// `excess` must increase with [H⁺] (positive minus negative charge); the root is bracketed
+/// between pH -2 and 16 and found by bisection on log10[H⁺].
+fn solve_proton_balance(excess: impl Fn(f64) -> f64) -> Result<f64, String> {
+    let (mut lo, mut hi) = (-16.0_f64, 2.0_f64);
+    if excess(10_f64.powf(lo)) > 0.0 || excess(10_f64.powf(hi)) < 0.0 {
+        return Err("Charge balance has no solution between pH -2 and 16".to_string());
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if excess(10_f64.powf(mid)) > 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+        if hi - lo < 1e-13 {
+            break;
+        }
+    }
+    Ok(10_f64.powf(0.5 * (lo + hi)))
+}
+
+/// pH from an approximate formula and its deviation from the exact result
+#[derive(Serialize)]
+struct PhApproximation {
+    ph: f64,
+    /// pH(approximate) - pH(exact)
+    error: f64,
+    /// Relative error in [H⁺] or [OH⁻] (%)
+    relative_error: f64,
+}
+
+/// pH of a single acid or base solution
+#[derive(Serialize)]
+struct PhResult {
+    /// pH from the exact charge balance, including water autoionisation
+    value: f64,
+    unit: String,
+    poh: f64,
+    h_concentration: f64,
+    oh_concentration: f64,
+    /// Fraction of the acid or base that has reacted with water
+    degree_of_dissociation: f64,
+    /// Neglecting water: the quadratic solution (weak) or [H⁺] = C (strong)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    without_water: Option<PhApproximation>,
+    /// Weak acid or base: [H⁺] = √(Ka·C)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    square_root: Option<PhApproximation>,
+    /// Whether the √(K·C) approximation meets the 5 % rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    five_percent_rule: Option<bool>,
+}
+
+/// Calculate the pH of a strong or weak monoprotic acid or base
+///
+/// The exact result solves the full charge balance: for a weak acid
+/// [H⁺]³ + Ka[H⁺]² - (Ka·C + Kw)[H⁺] - Ka·Kw = 0, for a strong acid
+/// [H⁺] = (C + √(C² + 4Kw))/2, and analogously in [OH⁻] for bases. The usual
+/// approximations are reported with their errors.
+///
+/// Arguments:
+/// - concentration: Analytical concentration C (mol/L)
+/// - kind: "acid" or "base"
+/// - k: Ka or Kb; 0 for a strong acid or base
+/// - kw: Ion product of water
+#[wasm_func]
+pub fn calculate_ph(
+    concentration_bytes: &[u8],
+    kind_bytes: &[u8],
+    k_bytes: &[u8],
+    kw_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let c = parse_number(concentration_bytes, "concentration")?;
+    let kind: String = parse_arg(kind_bytes, "kind")?;
+    let k = parse_number(k_bytes, "dissociation constant")?;
+    let kw = parse_number(kw_bytes, "Kw")?;
+
+    let base = match kind.as_str() {
+        "acid" => false,
+        "base" => true,
+        _ => return Err(format!("Unknown kind: {} (expected acid or base)", kind)),
+    };
+    check_non_negative("concentration", c)?;
+    check_non_negative("dissociation constant", k)?;
+    check_positive("Kw", kw)?;
+
+    // Work in x = [H⁺] for acids and x = [OH⁻] for bases
+    let strong = k == 0.0;
+    let x = if strong {
+        (c + (c * c + 4.0 * kw).sqrt()) / 2.0
+    } else {
+        solve_proton_balance(|x| x - k * c / (k + x) - kw / x)?
+    };
+    let dissociated = if strong { c } else { k * c / (k + x) };
+
+    let pkw = -kw.log10();
+    let approximation = |approx: f64| PhApproximation {
+        ph: if base { pkw + approx.log10() } else { -approx.log10() },
+        error: if base { approx.log10() - x.log10() } else { x.log10() - approx.log10() },
+        relative_error: 100.0 * (approx - x) / x,
+    };
+    let (without_water, square_root, five_percent_rule) = if c == 0.0 {
+        (None, None, None)
+    } else if strong {
+        (Some(approximation(c)), None, None)
+    } else {
+        let quadratic = (-k + (k * k + 4.0 * k * c).sqrt()) / 2.0;
+        let root = (k * c).sqrt();
+        (
+            Some(approximation(quadratic)),
+            Some(approximation(root)),
+            Some(root / c <= 0.05),
+        )
+    };
+
+    let (h, oh) = if base { (kw / x, x) } else { (x, kw / x) };
+    let result = PhResult {
+        value: -h.log10(),
+        unit: String::new(),
+        poh: -oh.log10(),
+        h_concentration: h,
+        oh_concentration: oh,
+        degree_of_dissociation: if c > 0.0 { dissociated / c } else { 0.0 },
+        without_water,
+        square_root,
+        five_percent_rule,
+    };
+
+    to_json(&result)
+}
+
+/// Fractions α_j of H_nA, H_(n-1)A⁻, ..., Aⁿ⁻ at a given [H⁺]
+fn alpha_fractions(h: f64, kas: &[f64]) -> Vec<f64> {
+    // Terms h^(n-j)·K1···Kj, scaled by h^-n to avoid overflow
+    let mut terms = Vec::with_capacity(kas.len() + 1);
+    let mut term = 1.0;
+    terms.push(term);
+    for ka in kas {
+        term *= ka / h;
+        terms.push(term);
+    }
+    let total: f64 = terms.iter().sum();
+    if total.is_finite() {
+        terms.iter().map(|t| t / total).collect()
+    } else {
+        // Fully deprotonated beyond floating-point range
+        let mut alphas = vec![0.0; terms.len()];
+        alphas[kas.len()] = 1.0;
+        alphas
+    }
+}
+
+/// Average number of protons lost, Σ j·α_j
+fn mean_deprotonation(h: f64, kas: &[f64]) -> f64 {
+    alpha_fractions(h, kas)
+        .iter()
+        .enumerate()
+        .map(|(j, alpha)| j as f64 * alpha)
+        .sum()
+}
+
+/// Default species names H3A, H2A⁻, HA²⁻, A³⁻ for an n-protic acid
+fn protonation_names(n: usize) -> Vec<String> {
+    (0..=n)
+        .map(|j| {
+            let protons = n - j;
+            let acid = match protons {
+                0 => "A".to_string(),
+                1 => "HA".to_string(),
+                p => format!("H{}A", p),
+            };
+            match j {
+                0 => acid,
+                1 => format!("{}-", acid),
+                charge => format!("{}^{}-", acid, charge),
+            }
+        })
+        .collect()
+}
+
+/// One protonation state at the solution pH
+#[derive(Serialize)]
+struct SpeciesFraction {
+    species: String,
+    alpha: f64,
+    concentration: f64,
+}
+
+/// Speciation of a polyprotic acid
+#[derive(Serialize)]
+struct PolyproticSpeciation {
+    /// Solution pH
+    value: f64,
+    unit: String,
+    h_concentration: f64,
+    pka: Vec<f64>,
+    fractions: Vec<SpeciesFraction>,
+    dominant: String,
+    /// (pH, α) points for each species, in the order of `fractions`
+    curves: Vec<Vec<(f64, f64)>>,
+}
+
+/// Calculate the pH and speciation of a polyprotic acid solution
+///
+/// The acid is dissolved in the protonation state `form` (0 = H_nA, 1 = NaH_(n-1)A, ...),
+/// the counter-ions being spectators, and the exact charge balance
+/// [H⁺] + form·C = C·Σ j·α_j + Kw/[H⁺] is solved.
+///
+/// Arguments:
+/// - kas_json: Stepwise dissociation constants [Ka1, Ka2, ...]
+/// - concentration: Analytical concentration C (mol/L)
+/// - form: Number of protons already removed in the dissolved substance
+/// - names_json: Species names from H_nA to Aⁿ⁻, or [] for generic names
+/// - kw: Ion product of water
+/// - ph_range_json: (pH_min, pH_max) for the α curves
+/// - points: Number of points per curve
+#[wasm_func]
+pub fn polyprotic_speciation(
+    kas_json: &[u8],
+    concentration_bytes: &[u8],
+    form_bytes: &[u8],
+    names_json: &[u8],
+    kw_bytes: &[u8],
+    ph_range_json: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let kas: Vec<f64> = parse_json(kas_json, "dissociation constants")?;
+    let c = parse_number(concentration_bytes, "concentration")?;
+    let form: usize = parse_arg(form_bytes, "form")?;
+    let names: Vec<String> = parse_json(names_json, "species names")?;
+    let kw = parse_number(kw_bytes, "Kw")?;
+    let (ph_min, ph_max): (f64, f64) = parse_json(ph_range_json, "pH range")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    if kas.is_empty() {
+        return Err("At least one dissociation constant is required".to_string());
+    }
+    for (i, ka) in kas.iter().enumerate() {
+        check_positive(&format!("Ka{}", i + 1), *ka)?;
+    }
+    if form > kas.len() {
+        return Err(format!("Form must be between 0 and {}", kas.len()));
+    }
+    let names = if names.is_empty() {
+        protonation_names(kas.len())
+    } else if names.len() == kas.len() + 1 {
+        names
+    } else {
+        return Err(format!("Expected {} species names", kas.len() + 1));
+    };
+    check_non_negative("concentration", c)?;
+    check_positive("Kw", kw)?;
+    if ph_min >= ph_max {
+        return Err("pH range must be given as (min, max)".to_string());
+    }
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let h = solve_proton_balance(|h| {
+        h + form as f64 * c - c * mean_deprotonation(h, &kas) - kw / h
+    })?;
+    let alphas = alpha_fractions(h, &kas);
+    let dominant = alphas
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(j, _)| names[j].clone())
+        .unwrap();
+
+    let mut curves = vec![Vec::with_capacity(points); kas.len() + 1];
+    for t in sample_times(1.0, points) {
+        let ph = ph_min + (ph_max - ph_min) * t;
+        for (curve, alpha) in curves.iter_mut().zip(alpha_fractions(10_f64.powf(-ph), &kas)) {
+            curve.push((ph, alpha));
+        }
+    }
+
+    let result = PolyproticSpeciation {
+        value: -h.log10(),
+        unit: String::new(),
+        h_concentration: h,
+        pka: kas.iter().map(|ka| -ka.log10()).collect(),
+        fractions: names
+            .into_iter()
+            .zip(alphas)
+            .map(|(species, alpha)| SpeciesFraction {
+                species,
+                alpha,
+                concentration: alpha * c,
+            })
+            .collect(),
+        dominant,
+        curves,
+    };
+
+    to_json(&result)
+}
+
+/// Exact [H⁺] of a monoprotic conjugate pair with spectator ions
+///
+/// Charge balance [H⁺] + (cations - anions) = C·Ka/(Ka + [H⁺]) + Kw/[H⁺], where C is the
+/// total acid + conjugate base concentration.
+fn conjugate_pair_h(ka: f64, total: f64, net_spectators: f64, kw: f64) -> Result<f64, String> {
+    solve_proton_balance(|h| h + net_spectators - total * ka / (ka + h) - kw / h)
+}
+
+/// Buffer capacity β = dC_base/dpH = 2.303·(Kw/[H⁺] + [H⁺] + C·Ka·[H⁺]/(Ka + [H⁺])²)
+fn buffer_capacity_at(ka: f64, total: f64, h: f64, kw: f64) -> f64 {
+    10_f64.ln() * (kw / h + h + total * ka * h / (ka + h).powi(2))
+}
+
+/// pH of a buffer
+#[derive(Serialize)]
+struct BufferPh {
+    /// Henderson–Hasselbalch pH
+    value: f64,
+    unit: String,
+    /// pH from the exact charge balance
+    exact: f64,
+    /// [A⁻]/[HA]
+    ratio: f64,
+    /// Buffer capacity at the exact pH (mol/(L·pH))
+    capacity: f64,
+}
+
+/// Calculate the pH of a buffer with the Henderson–Hasselbalch equation
+/// pH = pKa + log10([A⁻]/[HA])
+///
+/// Arguments:
+/// - pka: pKa of the weak acid
+/// - c_acid: Concentration (or amount) of the weak acid HA
+/// - c_base: Concentration (or amount) of the conjugate base A⁻
+/// - kw: Ion product of water
+#[wasm_func]
+pub fn calculate_buffer_ph(
+    pka_bytes: &[u8],
+    c_acid_bytes: &[u8],
+    c_base_bytes: &[u8],
+    kw_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let pka = parse_number(pka_bytes, "pKa")?;
+    let c_acid = parse_number(c_acid_bytes, "acid concentration")?;
+    let c_base = parse_number(c_base_bytes, "base concentration")?;
+    let kw = parse_number(kw_bytes, "Kw")?;
+
+    check_positive("acid concentration", c_acid)?;
+    check_positive("base concentration", c_base)?;
+    check_positive("Kw", kw)?;
+
+    let ka = 10_f64.powf(-pka);
+    let total = c_acid + c_base;
+    let h = conjugate_pair_h(ka, total, c_base, kw)?;
+
+    let result = BufferPh {
+        value: pka + (c_base / c_acid).log10(),
+        unit: String::new(),
+        exact: -h.log10(),
+        ratio: c_base / c_acid,
+        capacity: buffer_capacity_at(ka, total, h, kw),
+    };
+
+    to_json(&result)
+}
+
+/// Composition needed for a target buffer pH
+#[derive(Serialize)]
+struct BufferRecipe {
+    /// Required [A⁻]/[HA]
+    ratio: f64,
+    /// Fraction of the buffer present as A⁻
+    base_fraction: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    acid_concentration: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_concentration: Option<f64>,
+    /// Whether the target lies within pKa ± 1
+    effective: bool,
+}
+
+/// Calculate the base/acid ratio needed for a target pH
+/// [A⁻]/[HA] = 10^(pH - pKa)
+///
+/// Arguments:
+/// - pka: pKa of the weak acid
+/// - target_ph: Desired pH
+/// - total: Total buffer concentration [HA] + [A⁻] (mol/L), 0 to skip the split
+#[wasm_func]
+pub fn calculate_buffer_ratio(
+    pka_bytes: &[u8],
+    target_ph_bytes: &[u8],
+    total_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let pka = parse_number(pka_bytes, "pKa")?;
+    let target_ph = parse_number(target_ph_bytes, "target pH")?;
+    let total = parse_number(total_bytes, "total concentration")?;
+
+    check_non_negative("total concentration", total)?;
+
+    let ratio = 10_f64.powf(target_ph - pka);
+    let base_fraction = ratio / (1.0 + ratio);
+    let split = (total > 0.0).then_some(total);
+
+    let result = BufferRecipe {
+        ratio,
+        base_fraction,
+        acid_concentration: split.map(|c| c * (1.0 - base_fraction)),
+        base_concentration: split.map(|c| c * base_fraction),
+        effective: (target_ph - pka).abs() <= 1.0,
+    };
+
+    to_json(&result)
+}
+
+/// Buffer addition problem
+#[derive(Deserialize)]
+struct BufferAdditionInput {
+    pka: f64,
+    /// Amount of weak acid HA (mol)
+    acid: f64,
+    /// Amount of conjugate base A⁻ (mol)
+    base: f64,
+    /// Buffer volume (L)
+    volume: f64,
+    /// Strong acid added (mol)
+    #[serde(default)]
+    strong_acid: f64,
+    /// Strong base added (mol)
+    #[serde(default)]
+    strong_base: f64,
+    /// Volume added with the strong acid or base (L)
+    #[serde(default)]
+    added_volume: f64,
+    #[serde(default = "default_kw")]
+    kw: f64,
+}
+
+fn default_kw() -> f64 {
+    1e-14
+}
+
+/// Buffer pH before and after adding strong acid or base
+#[derive(Serialize)]
+struct BufferAddition {
+    ph_before: f64,
+    ph_after: f64,
+    delta_ph: f64,
+    /// Amounts after neutralisation (mol)
+    acid_after: f64,
+    base_after: f64,
+    /// Henderson–Hasselbalch estimate, while both buffer components remain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    henderson_hasselbalch: Option<f64>,
+    /// Whether the addition used up one of the buffer components
+    capacity_exceeded: bool,
+}
+
+/// Calculate the pH change of a buffer on adding strong acid or base
+///
+/// Both pH values come from the exact charge balance, so additions beyond the buffer
+/// capacity give the pH of the excess strong acid or base.
+///
+/// Arguments:
+/// - input_json: Buffer addition problem, see `BufferAdditionInput`
+#[wasm_func]
+pub fn buffer_addition(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: BufferAdditionInput = parse_json(input_json, "buffer addition")?;
+
+    check_non_negative("acid", input.acid)?;
+    check_non_negative("base", input.base)?;
+    check_positive("acid + base", input.acid + input.base)?;
+    check_positive("volume", input.volume)?;
+    check_non_negative("added volume", input.added_volume)?;
+    check_non_negative("strong acid", input.strong_acid)?;
+    check_non_negative("strong base", input.strong_base)?;
+    check_positive("Kw", input.kw)?;
+
+    let ka = 10_f64.powf(-input.pka);
+    let total = input.acid + input.base;
+    let before = conjugate_pair_h(ka, total / input.volume, input.base / input.volume, input.kw)?;
+
+    let volume = input.volume + input.added_volume;
+    let net_spectators = (input.base + input.strong_base - input.strong_acid) / volume;
+    let after = conjugate_pair_h(ka, total / volume, net_spectators, input.kw)?;
+
+    let shift = input.strong_base - input.strong_acid;
+    let acid_after = (input.acid - shift).clamp(0.0, total);
+    let base_after = total - acid_after;
+    let capacity_exceeded = acid_after <= 0.0 || base_after <= 0.0;
+
+    let result = BufferAddition {
+        ph_before: -before.log10(),
+        ph_after: -after.log10(),
+        delta_ph: before.log10() - after.log10(),
+        acid_after,
+        base_after,
+        henderson_hasselbalch: (!capacity_exceeded)
+            .then(|| input.pka + (base_after / acid_after).log10()),
+        capacity_exceeded,
+    };
+
+    to_json(&result)
+}
+
+/// Buffer capacity of a conjugate pair
+#[derive(Serialize)]
+struct BufferCapacity {
+    value: f64,
+    unit: String,
+    /// Largest capacity, reached at pH = pKa
+    maximum: f64,
+    /// (pH, β) points over pKa ± 3 for plotting
+    curve: Vec<(f64, f64)>,
+}
+
+/// Calculate the buffer capacity β
+/// β = 2.303·(Kw/[H⁺] + [H⁺] + C·Ka·[H⁺]/(Ka + [H⁺])²)
+///
+/// Arguments:
+/// - pka: pKa of the weak acid
+/// - total: Total buffer concentration [HA] + [A⁻] (mol/L)
+/// - ph: pH at which β is evaluated
+/// - kw: Ion product of water
+/// - points: Number of points in the β curve
+#[wasm_func]
+pub fn calculate_buffer_capacity(
+    pka_bytes: &[u8],
+    total_bytes: &[u8],
+    ph_bytes: &[u8],
+    kw_bytes: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let pka = parse_number(pka_bytes, "pKa")?;
+    let total = parse_number(total_bytes, "total concentration")?;
+    let ph = parse_number(ph_bytes, "pH")?;
+    let kw = parse_number(kw_bytes, "Kw")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    check_non_negative("total concentration", total)?;
+    check_positive("Kw", kw)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+
+    let ka = 10_f64.powf(-pka);
+    let beta = |ph: f64| buffer_capacity_at(ka, total, 10_f64.powf(-ph), kw);
+    let curve = sample_times(1.0, points)
+        .into_iter()
+        .map(|t| {
+            let x = pka - 3.0 + 6.0 * t;
+            (x, beta(x))
+        })
+        .collect();
+
+    let result = BufferCapacity {
+        value: beta(ph),
+        unit: "mol/(L·pH)".to_string(),
+        maximum: beta(pka),
+        curve,
+    };
+
+    to_json(&result)
+}
+
+/// Titration of an acid or base analyte with a strong base or acid
+#[derive(Deserialize)]
+struct TitrationInput {
+    /// "acid" (titrated with strong base) or "base" (titrated with strong acid)
+    analyte: String,
+    concentration: f64,
+    /// Analyte volume (mL)
+    volume: f64,
+    titrant_concentration: f64,
+    /// Stepwise Ka (acid) or Kb (base) values; empty for a strong acid or base
+    #[serde(default)]
+    k: Vec<f64>,
+    /// Largest titrant volume (mL); 0 for 1.5 × the last equivalence volume
+    #[serde(default)]
+    max_volume: f64,
+    #[serde(default = "default_titration_points")]
+    points: usize,
+    #[serde(default = "default_kw")]
+    kw: f64,
+}
+
+fn default_titration_points() -> usize {
+    200
+}
+
+/// Annotated point on a titration curve
+#[derive(Serialize)]
+struct TitrationMarker {
+    volume: f64,
+    ph: f64,
+    label: String,
+}
+
+/// Titration curve data
+#[derive(Serialize)]
+struct TitrationCurve {
+    /// (titrant volume in mL, pH) points, refined around the equivalence points
+    points: Vec<(f64, f64)>,
+    initial_ph: f64,
+    equivalence_points: Vec<TitrationMarker>,
+    /// Points where pH ≈ pKa (acid) or pOH ≈ pKb (base)
+    half_equivalence_points: Vec<TitrationMarker>,
+}
+
+/// Generate a pH titration curve for mono- or polyprotic, strong or weak acids and bases
+///
+/// Every point solves the exact charge balance of the diluted mixture, so the curve is
+/// correct before, at and after each equivalence point. A weak base B is treated through
+/// its conjugate acid system (Ka = Kw/Kb).
+///
+/// Arguments:
+/// - input_json: Titration problem, see `TitrationInput`
+#[wasm_func]
+pub fn titration_curve(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: TitrationInput = parse_json(input_json, "titration")?;
+
+    let base = match input.analyte.as_str() {
+        "acid" => false,
+        "base" => true,
+        _ => return Err(format!("Unknown analyte: {} (expected acid or base)", input.analyte)),
+    };
+    check_positive("concentration", input.concentration)?;
+    check_positive("volume", input.volume)?;
+    check_positive("titrant concentration", input.titrant_concentration)?;
+    for (i, k) in input.k.iter().enumerate() {
+        check_positive(&format!("dissociation constant {}", i + 1), *k)?;
+    }
+    if input.points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    check_positive("Kw", input.kw)?;
+
+    let kw = input.kw;
+    // Acid system of the analyte: Ka values from the fully protonated form down
+    let kas: Vec<f64> = if base {
+        input.k.iter().rev().map(|kb| kw / kb).collect()
+    } else {
+        input.k.clone()
+    };
+    let protons = kas.len().max(1);
+    let moles = input.concentration * input.volume;
+    let equivalence: Vec<f64> = (1..=protons)
+        .map(|i| i as f64 * moles / input.titrant_concentration)
+        .collect();
+    let max_volume = if input.max_volume > 0.0 {
+        input.max_volume
+    } else {
+        1.5 * equivalence[protons - 1]
+    };
+
+    let ph_at = |v: f64| -> Result<f64, String> {
+        let total = input.volume + v;
+        let c_analyte = moles / total;
+        let c_titrant = input.titrant_concentration * v / total;
+        // Net spectator charge: titrant ions plus the analyte's own counter-charge
+        let (spectators, form) = if base {
+            (-c_titrant, protons as f64)
+        } else {
+            (c_titrant, 0.0)
+        };
+        let h = solve_proton_balance(|h| {
+            let lost = if kas.is_empty() {
+                if base { 0.0 } else { 1.0 }
+            } else {
+                mean_deprotonation(h, &kas)
+            };
+            h + spectators + c_analyte * (form - lost) - kw / h
+        })?;
+        Ok(-h.log10())
+    };
+
+    // Uniform grid plus the equivalence regions, where the pH changes fastest
+    let mut volumes: Vec<f64> = sample_times(max_volume, input.points);
+    for &v_eq in &equivalence {
+        for offset in [-0.05, -0.02, -0.01, -0.005, 0.0, 0.005, 0.01, 0.02, 0.05] {
+            let v = v_eq * (1.0 + offset);
+            if v <= max_volume {
+                volumes.push(v);
+            }
+        }
+    }
+    volumes.sort_by(f64::total_cmp);
+    volumes.dedup_by(|a, b| (*a - *b).abs() <= 1e-9 * max_volume);
+
+    let points = volumes
+        .iter()
+        .map(|&v| ph_at(v).map(|ph| (v, ph)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut equivalence_points = Vec::with_capacity(protons);
+    let mut half_equivalence_points = Vec::new();
+    for (i, &v_eq) in equivalence.iter().enumerate() {
+        equivalence_points.push(TitrationMarker {
+            volume: v_eq,
+            ph: ph_at(v_eq)?,
+            label: format!("Equivalence point {}", i + 1),
+        });
+        if !kas.is_empty() {
+            let v_half = v_eq - 0.5 * equivalence[0];
+            let label = if base {
+                format!("pOH = pKb{}", i + 1)
+            } else {
+                format!("pH = pKa{}", i + 1)
+            };
+            half_equivalence_points.push(TitrationMarker {
+                volume: v_half,
+                ph: ph_at(v_half)?,
+                label,
+            });
+        }
+    }
+
+    let result = TitrationCurve {
+        initial_ph: ph_at(0.0)?,
+        points,
+        equivalence_points,
+        half_equivalence_points,
+    };
+
+    to_json(&result)
+}
+
+/// Acid dissociation constant table entry
+#[derive(Deserialize, Clone)]
+struct AcidData {
+    name: String,
+    #[serde(rename = "pKa")]
+    pka: Vec<f64>,
+    /// Protonation states from the fully protonated to the fully deprotonated form
+    species: Vec<String>,
+}
+
+/// Acid–base constants of a tabulated substance, relative to the queried species
+#[derive(Serialize)]
+struct AcidConstants {
+    /// Table key of the acid system
+    formula: String,
+    name: String,
+    /// The queried species itself
+    species: String,
+    /// "acid", "base" or "amphiprotic"
+    kind: String,
+    /// Protons lost by the queried species relative to the fully protonated form
+    form: usize,
+    /// Stepwise pKa and Ka values of the whole system
+    #[serde(rename = "pKa")]
+    pka_values: Vec<f64>,
+    #[serde(rename = "Ka")]
+    ka_values: Vec<f64>,
+    /// Stepwise pKb and Kb values, starting from the fully deprotonated form
+    #[serde(rename = "pKb")]
+    pkb_values: Vec<f64>,
+    #[serde(rename = "Kb")]
+    kb_values: Vec<f64>,
+    /// Protonation states of the system
+    forms: Vec<String>,
+    /// pKa/Ka of the queried species acting as an acid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pka: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ka: Option<f64>,
+    /// pKb/Kb of the queried species acting as a base
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pkb: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kb: Option<f64>,
+}
+
+/// Look up acid–base constants by formula, by any protonation state or by name
+///
+/// The table key is tried first, then the species lists (so "H2PO4-" finds
+/// phosphoric acid), then a case-insensitive name match. Base constants use
+/// pKa + pKb = pKw at the given Kw.
+///
+/// Arguments:
+/// - query: Formula, species or name, e.g. "CH3COOH", "HCO3-", "ammonia"
+/// - data_json: Acid dissociation constant table
+/// - kw: Ion product of water
+#[wasm_func]
+pub fn get_acid_constant(
+    query_bytes: &[u8],
+    data_json: &[u8],
+    kw_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let query: String = parse_arg(query_bytes, "query")?;
+    let data: HashMap<String, AcidData> = parse_json(data_json, "acid dissociation data")?;
+    let kw = parse_number(kw_bytes, "Kw")?;
+    check_positive("Kw", kw)?;
+
+    // Sorted keys keep lookups of species shared by several entries deterministic
+    let mut keys: Vec<&String> = data.keys().collect();
+    keys.sort();
+    let lowercase = query.to_lowercase();
+    let (formula, entry, species) = data
+        .get_key_value(&query)
+        .map(|(key, entry)| (key, entry, key.clone()))
+        .or_else(|| {
+            keys.iter().find_map(|key| {
+                let entry = &data[*key];
+                entry
+                    .species
+                    .iter()
+                    .find(|s| **s == query)
+                    .map(|s| (*key, entry, s.clone()))
+            })
+        })
+        .or_else(|| {
+            keys.iter()
+                .find(|key| data[**key].name.to_lowercase() == lowercase)
+                .map(|key| (*key, &data[*key], (*key).clone()))
+        })
+        .ok_or_else(|| format!("No acid dissociation constants found for: {}", query))?;
+
+    let n = entry.pka.len();
+    if n == 0 || entry.species.len() != n + 1 {
+        return Err(format!(
+            "Entry {} needs one more species than pKa values",
+            formula
+        ));
+    }
+    let form = entry
+        .species
+        .iter()
+        .position(|s| *s == species)
+        .ok_or_else(|| format!("Entry {} does not list its own formula as a species", formula))?;
+
+    let pkw = -kw.log10();
+    let pkb_values: Vec<f64> = entry.pka.iter().rev().map(|pka| pkw - pka).collect();
+    let kind = if form == 0 {
+        "acid"
+    } else if form == n {
+        "base"
+    } else {
+        "amphiprotic"
+    };
+    let pka = (form < n).then(|| entry.pka[form]);
+    let pkb = (form > 0).then(|| pkw - entry.pka[form - 1]);
+
+    let result = AcidConstants {
+        formula: formula.clone(),
+        name: entry.name.clone(),
+        species,
+        kind: kind.to_string(),
+        form,
+        ka_values: entry.pka.iter().map(|p| 10f64.powf(-p)).collect(),
+        pka_values: entry.pka.clone(),
+        kb_values: pkb_values.iter().map(|p| 10f64.powf(-p)).collect(),
+        pkb_values,
+        forms: entry.species.clone(),
+        ka: pka.map(|p| 10f64.powf(-p)),
+        pka,
+        kb: pkb.map(|p| 10f64.powf(-p)),
+        pkb,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// SOLUBILITY EQUILIBRIA
+// ============================================================================
+
+/// Solubility product table entry
+#[derive(Deserialize, Clone)]
+struct SolubilityData {
+    #[serde(default)]
+    name: String,
+    /// Optional when only the stoichiometry is needed
+    #[serde(rename = "Ksp", default)]
+    ksp: Option<f64>,
+    /// Dissolved ions with their stoichiometric coefficients
+    ions: Vec<(String, f64)>,
+}
+
+/// Salt given by formula (looked up in the Ksp table) or explicitly
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SaltSpec {
+    Id(String),
+    Explicit(SolubilityData),
+}
+
+impl SaltSpec {
+    /// Resolve to a label and checked table entry
+    fn resolve(
+        &self,
+        data: &HashMap<String, SolubilityData>,
+    ) -> Result<(String, SolubilityData), String> {
+        let (label, salt) = match self {
+            SaltSpec::Id(id) => data
+                .get(id)
+                .map(|salt| (id.clone(), salt.clone()))
+                .ok_or_else(|| format!("No solubility product found for salt: {}", id))?,
+            SaltSpec::Explicit(salt) => {
+                let label = if salt.name.is_empty() {
+                    salt.ions.iter().map(|(ion, _)| ion.as_str()).collect::<Vec<_>>().join(" + ")
+                } else {
+                    salt.name.clone()
+                };
+                (label, salt.clone())
+            }
+        };
+
+        if salt.ions.is_empty() {
+            return Err(format!("Salt {} needs ions", label));
+        }
+        for (ion, nu) in &salt.ions {
+            check_positive(&format!("coefficient of {} in {}", ion, label), *nu)?;
+        }
+        if let Some(ksp) = salt.ksp {
+            check_positive(&format!("Ksp of {}", label), ksp)?;
+        }
+        // Electroneutrality check when every ion formula can be read
+        let charges: Result<Vec<f64>, String> = salt
+            .ions
+            .iter()
+            .map(|(ion, nu)| parse_formula(ion).map(|f| nu * f.charge))
+            .collect();
+        if let Ok(charges) = charges {
+            if charges.iter().sum::<f64>().abs() > 1e-9 {
+                return Err(format!("Ions of {} are not electrically neutral", label));
+            }
+        }
+
+        Ok((label, salt))
+    }
+
+    /// Molar mass of a salt given by its formula (g/mol)
+    fn molar_mass(&self) -> Option<f64> {
+        match self {
+            SaltSpec::Id(id) => parse_formula(id).and_then(|f| f.molar_mass()).ok(),
+            SaltSpec::Explicit(_) => None,
+        }
+    }
+}
+
+/// Concentration of one ion in solution
+#[derive(Serialize)]
+struct IonConcentration {
+    species: String,
+    concentration: f64,
+}
+
+/// Solve Π(a·x + c)^ν = Ksp for x > 0 by bisection on log10 x, given (ν, a, c) per ion
+fn solve_ion_product(ions: &[(f64, f64, f64)], ksp: f64) -> Option<f64> {
+    let excess = |x: f64| -> f64 {
+        ions.iter().map(|(nu, a, c)| nu * (a * x + c).ln()).sum::<f64>() - ksp.ln()
+    };
+    let (mut lo, mut hi) = (-80.0_f64, 3.0_f64);
+    if excess(10_f64.powf(lo)) > 0.0 {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if excess(10_f64.powf(mid)) > 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(10_f64.powf(0.5 * (lo + hi)))
+}
+
+/// Molar solubility result
+#[derive(Serialize)]
+struct MolarSolubility {
+    value: f64,
+    unit: String,
+    salt: String,
+    ksp: f64,
+    /// Solubility in g/L, when the salt formula is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mass_solubility: Option<f64>,
+    ion_concentrations: Vec<IonConcentration>,
+    /// Solubility in pure water (mol/L)
+    pure_water: f64,
+    /// Pure-water solubility divided by the solubility with common ions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppression: Option<f64>,
+}
+
+/// Calculate the molar solubility of a salt MₘXₙ from its Ksp
+/// Ksp = Π(νᵢ·s + cᵢ)^νᵢ, s = (Ksp/Π νᵢ^νᵢ)^(1/Σνᵢ) in pure water
+///
+/// Common ions already in solution (cᵢ) suppress the solubility; the equation is then
+/// solved numerically. Activities are taken equal to concentrations and hydrolysis of
+/// the ions is ignored.
+///
+/// Arguments:
+/// - salt_json: Salt formula from the table, or {Ksp, ions: [[ion, ν], ...]}
+/// - common_json: Concentrations of common ions already present {ion: mol/L}
+/// - data_json: Solubility product table
+#[wasm_func]
+pub fn molar_solubility(
+    salt_json: &[u8],
+    common_json: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let spec: SaltSpec = parse_json(salt_json, "salt")?;
+    let common: HashMap<String, f64> = parse_json(common_json, "common ions")?;
+    let data: HashMap<String, SolubilityData> = parse_json(data_json, "solubility product data")?;
+
+    let (label, salt) = spec.resolve(&data)?;
+    let ksp = salt.ksp.ok_or_else(|| format!("No Ksp given for salt: {}", label))?;
+    check_positive("Ksp", ksp)?;
+    if let Some(ion) = common.keys().find(|ion| !salt.ions.iter().any(|(i, _)| i == *ion)) {
+        return Err(format!("{} is not an ion of {}", ion, label));
+    }
+    for (ion, c) in &common {
+        check_non_negative(&format!("concentration of {}", ion), *c)?;
+    }
+
+    let total: f64 = salt.ions.iter().map(|(_, nu)| nu).sum();
+    let product: f64 = salt.ions.iter().map(|(_, nu)| nu.powf(*nu)).product();
+    let pure_water = (ksp / product).powf(1.0 / total);
+
+    let present: Vec<f64> = salt
+        .ions
+        .iter()
+        .map(|(ion, _)| common.get(ion).copied().unwrap_or(0.0))
+        .collect();
+    let s = if present.iter().all(|c| *c == 0.0) {
+        pure_water
+    } else {
+        let ions: Vec<(f64, f64, f64)> = salt
+            .ions
+            .iter()
+            .zip(&present)
+            .map(|((_, nu), c)| (*nu, *nu, *c))
+            .collect();
+        solve_ion_product(&ions, ksp).ok_or_else(|| {
+            format!("Common-ion concentrations already exceed the Ksp of {}", label)
+        })?
+    };
+
+    let result = MolarSolubility {
+        value: s,
+        unit: "mol/L".to_string(),
+        mass_solubility: spec.molar_mass().map(|m| s * m),
+        ion_concentrations: salt
+            .ions
+            .iter()
+            .zip(&present)
+            .map(|((ion, nu), c)| IonConcentration {
+                species: ion.clone(),
+                concentration: nu * s + c,
+            })
+            .collect(),
+        suppression: (!common.is_empty()).then(|| pure_water / s),
+        salt: label,
+        ksp,
+        pure_water,
+    };
+
+    to_json(&result)
+}
+
+/// Solubility product derived from a measured solubility
+#[derive(Serialize)]
+struct SolubilityProduct {
+    value: f64,
+    unit: String,
+    pksp: f64,
+    ion_concentrations: Vec<IonConcentration>,
+    /// Table value, when the salt was looked up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tabulated: Option<f64>,
+}
+
+/// Calculate Ksp from the molar solubility of a salt
+/// Ksp = Π(νᵢ·s)^νᵢ
+///
+/// Arguments:
+/// - solubility: Molar solubility s (mol/L)
+/// - salt_json: Salt formula from the table, or {ions: [[ion, ν], ...]}
+/// - data_json: Solubility product table
+#[wasm_func]
+pub fn ksp_from_solubility(
+    solubility_bytes: &[u8],
+    salt_json: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let s = parse_number(solubility_bytes, "solubility")?;
+    let spec: SaltSpec = parse_json(salt_json, "salt")?;
+    let data: HashMap<String, SolubilityData> = parse_json(data_json, "solubility product data")?;
+
+    check_positive("solubility", s)?;
+    let (_, salt) = spec.resolve(&data)?;
+
+    let ksp: f64 = salt.ions.iter().map(|(_, nu)| (nu * s).powf(*nu)).product();
+
+    let result = SolubilityProduct {
+        value: ksp,
+        unit: String::new(),
+        pksp: -ksp.log10(),
+        ion_concentrations: salt
+            .ions
+            .iter()
+            .map(|(ion, nu)| IonConcentration {
+                species: ion.clone(),
+                concentration: nu * s,
+            })
+            .collect(),
+        tabulated: match spec {
+            SaltSpec::Id(_) => salt.ksp,
+            SaltSpec::Explicit(_) => None,
+        },
+    };
+
+    to_json(&result)
+}
+
+/// Precipitation prediction from the ion product
+#[derive(Serialize)]
+struct PrecipitationPrediction {
+    /// Ion product Qsp
+    ion_product: f64,
+    ksp: f64,
+    /// log10(Qsp/Ksp): negative when unsaturated, positive when supersaturated
+    saturation_index: f64,
+    /// "unsaturated", "saturated" or "supersaturated"
+    state: String,
+    precipitates: bool,
+    /// Salt precipitated until Qsp = Ksp (mol/L)
+    precipitated: f64,
+    /// Ion concentrations left in solution at equilibrium
+    remaining: Vec<IonConcentration>,
+}
+
+/// Predict whether a salt precipitates by comparing Qsp with Ksp
+/// Qsp = Π[ion]^ν; precipitation occurs when Qsp > Ksp
+///
+/// For a supersaturated solution the amount precipitated is found by solving
+/// Π(cᵢ − νᵢ·x)^νᵢ = Ksp.
+///
+/// Arguments:
+/// - salt_json: Salt formula from the table, or {Ksp, ions: [[ion, ν], ...]}
+/// - concentrations_json: Ion concentrations after mixing {ion: mol/L}
+/// - data_json: Solubility product table
+#[wasm_func]
+pub fn predict_precipitation(
+    salt_json: &[u8],
+    concentrations_json: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let spec: SaltSpec = parse_json(salt_json, "salt")?;
+    let concentrations: HashMap<String, f64> =
+        parse_json(concentrations_json, "ion concentrations")?;
+    let data: HashMap<String, SolubilityData> = parse_json(data_json, "solubility product data")?;
+
+    let (label, salt) = spec.resolve(&data)?;
+    let ksp = salt.ksp.ok_or_else(|| format!("No Ksp given for salt: {}", label))?;
+    check_positive("Ksp", ksp)?;
+    let present = salt
+        .ions
+        .iter()
+        .map(|(ion, _)| {
+            concentrations
+                .get(ion)
+                .copied()
+                .filter(|c| *c >= 0.0)
+                .ok_or_else(|| format!("Missing or negative concentration of ion: {}", ion))
+        })
+        .collect::<Result<Vec<f64>, String>>()?;
+
+    let ion_product: f64 = salt
+        .ions
+        .iter()
+        .zip(&present)
+        .map(|((_, nu), c)| c.powf(*nu))
+        .product();
+    let saturation_index = (ion_product / ksp).log10();
+    let state = if saturation_index.abs() < 1e-6 {
+        "saturated"
+    } else if saturation_index < 0.0 {
+        "unsaturated"
+    } else {
+        "supersaturated"
+    };
+
+    // Precipitate until Qsp = Ksp, parametrised by the remaining limiting ion r:
+    // x = (c_L − r)/ν_L, and every other ion keeps its excess plus νᵢ·r/ν_L
+    let mut precipitated = 0.0;
+    let mut remaining = present.clone();
+    if state == "supersaturated" {
+        let (limit, _) = salt
+            .ions
+            .iter()
+            .zip(&present)
+            .enumerate()
+            .map(|(i, ((_, nu), c))| (i, c / nu))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        let nu_limit = salt.ions[limit].1;
+        let c_limit = present[limit];
+        let ions: Vec<(f64, f64, f64)> = salt
+            .ions
+            .iter()
+            .zip(&present)
+            .map(|((_, nu), c)| (*nu, nu / nu_limit, (c - nu * c_limit / nu_limit).max(0.0)))
+            .collect();
+        let r = solve_ion_product(&ions, ksp).unwrap_or(0.0).min(c_limit);
+        precipitated = (c_limit - r) / nu_limit;
+        remaining = ions.iter().map(|(_, ratio, excess)| excess + ratio * r).collect();
+        remaining[limit] = r;
+    }
+
+    let result = PrecipitationPrediction {
+        ion_product,
+        ksp,
+        saturation_index,
+        state: state.to_string(),
+        precipitates: state == "supersaturated",
+        precipitated,
+        remaining: salt
+            .ions
+            .iter()
+            .zip(remaining)
+            .map(|((ion, _), concentration)| IonConcentration {
+                species: ion.clone(),
+                concentration,
+            })
+            .collect(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ACTIVITY COEFFICIENTS
+// ============================================================================
+
+/// Relative permittivity of water (Malmberg–Maryott, 0–100 °C)
+fn water_permittivity(temperature: f64) -> f64 {
+    let t = temperature - 273.15;
+    87.740 - 0.40008 * t + 9.398e-4 * t.powi(2) - 1.410e-6 * t.powi(3)
+}
+
+/// Debye–Hückel constants A (L^1/2·mol^-1/2) and B (Å^-1·L^1/2·mol^-1/2) for water
+fn debye_huckel_constants(temperature: f64) -> (f64, f64) {
+    let epsilon_t = water_permittivity(temperature) * temperature;
+    (1.82483e6 * epsilon_t.powf(-1.5), 50.2916 * epsilon_t.powf(-0.5))
+}
+
+/// Activity coefficient model
+#[derive(Clone, Copy)]
+enum ActivityModel {
+    Limiting,
+    Extended,
+    Davies,
+}
+
+impl ActivityModel {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "limiting" => Ok(ActivityModel::Limiting),
+            "extended" => Ok(ActivityModel::Extended),
+            "davies" => Ok(ActivityModel::Davies),
+            _ => Err(format!(
+                "Unknown activity model: {} (expected limiting, extended or davies)",
+                name
+            )),
+        }
+    }
+
+    /// Upper ionic strength (mol/L) up to which the model is commonly trusted
+    fn validity_limit(self) -> f64 {
+        match self {
+            ActivityModel::Limiting => 0.005,
+            ActivityModel::Extended => 0.1,
+            ActivityModel::Davies => 0.5,
+        }
+    }
+
+    /// log10 γ of an ion of charge z and size a (Å) at ionic strength I
+    fn log_gamma(self, z: f64, size: f64, ionic_strength: f64, temperature: f64) -> f64 {
+        let (a, b) = debye_huckel_constants(temperature);
+        let root = ionic_strength.sqrt();
+        match self {
+            ActivityModel::Limiting => -a * z * z * root,
+            ActivityModel::Extended => -a * z * z * root / (1.0 + b * size * root),
+            ActivityModel::Davies => -a * z * z * (root / (1.0 + root) - 0.3 * ionic_strength),
+        }
+    }
+}
+
+/// Ion given by its charge or by a formula such as "SO4^2-"
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IonSpec {
+    Charge(f64),
+    Formula(String),
+}
+
+impl IonSpec {
+    fn charge(&self) -> Result<f64, String> {
+        match self {
+            IonSpec::Charge(z) => Ok(*z),
+            IonSpec::Formula(formula) => parse_formula(formula).map(|f| f.charge),
+        }
+    }
+}
+
+/// Contribution of one ion to the ionic strength
+#[derive(Serialize)]
+struct IonicContribution {
+    species: String,
+    concentration: f64,
+    charge: f64,
+    contribution: f64,
+}
+
+/// Ionic strength of a solution
+#[derive(Serialize)]
+struct IonicStrength {
+    value: f64,
+    unit: String,
+    ions: Vec<IonicContribution>,
+    /// Σcᵢzᵢ; zero for an electrically neutral composition
+    charge_balance: f64,
+}
+
+/// Calculate the ionic strength of a solution
+/// I = ½·Σcᵢzᵢ²
+///
+/// Arguments:
+/// - composition_json: Array of [formula, mol/L] pairs; charges are read from the formulas
+#[wasm_func]
+pub fn ionic_strength(composition_json: &[u8]) -> Result<Vec<u8>, String> {
+    let composition: Vec<(String, f64)> = parse_json(composition_json, "composition")?;
+
+    let ions = composition
+        .into_iter()
+        .map(|(species, concentration)| {
+            check_non_negative(&format!("concentration of {}", species), concentration)?;
+            let charge = parse_formula(&species)?.charge;
+            Ok(IonicContribution {
+                contribution: 0.5 * concentration * charge * charge,
+                species,
+                concentration,
+                charge,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let result = IonicStrength {
+        value: ions.iter().map(|ion| ion.contribution).sum(),
+        unit: "mol/L".to_string(),
+        charge_balance: ions.iter().map(|ion| ion.concentration * ion.charge).sum(),
+        ions,
+    };
+
+    to_json(&result)
+}
+
+/// Activity coefficient of a single ion
+#[derive(Serialize)]
+struct ActivityCoefficient {
+    value: f64,
+    log_gamma: f64,
+    charge: f64,
+    ionic_strength: f64,
+    /// Debye–Hückel A and B at the given temperature
+    a: f64,
+    b: f64,
+    /// Whether I lies within the usual range of the model
+    within_range: bool,
+}
+
+/// Calculate an activity coefficient from the ionic strength
+/// Limiting law: log γ = -A·z²·√I
+/// Extended law: log γ = -A·z²·√I/(1 + B·a·√I)
+/// Davies: log γ = -A·z²·(√I/(1 + √I) - 0.3·I)
+///
+/// A and B follow from the permittivity of water at the given temperature
+/// (A ≈ 0.51, B ≈ 0.33 Å⁻¹ at 25 °C).
+///
+/// Arguments:
+/// - ion_json: Charge number or ion formula
+/// - ionic_strength: Ionic strength (mol/L)
+/// - model: "limiting", "extended" or "davies"
+/// - size: Effective ion size a (Å), used by the extended law
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn activity_coefficient(
+    ion_json: &[u8],
+    ionic_strength_bytes: &[u8],
+    model_bytes: &[u8],
+    size_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let ion: IonSpec = parse_json(ion_json, "ion")?;
+    let ionic_strength = parse_number(ionic_strength_bytes, "ionic strength")?;
+    let model: String = parse_arg(model_bytes, "model")?;
+    let size = parse_number(size_bytes, "ion size")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    let model = ActivityModel::parse(&model)?;
+    check_non_negative("ionic strength", ionic_strength)?;
+    check_positive("ion size", size)?;
+    check_temperature(temperature)?;
+
+    let charge = ion.charge()?;
+    let log_gamma = model.log_gamma(charge, size, ionic_strength, temperature);
+    let (a, b) = debye_huckel_constants(temperature);
+
+    let result = ActivityCoefficient {
+        value: 10_f64.powf(log_gamma),
+        log_gamma,
+        charge,
+        ionic_strength,
+        a,
+        b,
+        within_range: ionic_strength <= model.validity_limit(),
+    };
+
+    to_json(&result)
+}
+
+/// Activity coefficient of one species in an equilibrium
+#[derive(Serialize)]
+struct SpeciesActivity {
+    species: String,
+    coefficient: f64,
+    charge: f64,
+    gamma: f64,
+}
+
+/// Concentration equilibrium constant corrected for activities
+#[derive(Serialize)]
+struct ActivityCorrectedConstant {
+    /// Concentration-based constant Kc at the given ionic strength
+    value: f64,
+    /// Thermodynamic (activity-based) constant K°
+    thermodynamic: f64,
+    /// Πγᵢ^νᵢ (products positive, reactants negative)
+    activity_quotient: f64,
+    /// log10(Kc/K°)
+    log_shift: f64,
+    species: Vec<SpeciesActivity>,
+    within_range: bool,
+}
+
+/// Convert a thermodynamic equilibrium constant to the concentration constant at an ionic strength
+/// K° = Kc·Πγᵢ^νᵢ, so Kc = K°/Πγᵢ^νᵢ
+///
+/// Neutral species have γ = 1. Ion sizes for the extended law default to 3 Å.
+///
+/// Arguments:
+/// - k: Thermodynamic equilibrium constant K°
+/// - reactants_json: Array of [formula, coefficient] pairs
+/// - products_json: Array of [formula, coefficient] pairs
+/// - ionic_strength: Ionic strength (mol/L)
+/// - model: "limiting", "extended" or "davies"
+/// - sizes_json: Ion sizes {formula: Å} for the extended law
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn activity_corrected_constant(
+    k_bytes: &[u8],
+    reactants_json: &[u8],
+    products_json: &[u8],
+    ionic_strength_bytes: &[u8],
+    model_bytes: &[u8],
+    sizes_json: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let k = parse_number(k_bytes, "equilibrium constant")?;
+    let reactants: Vec<(String, f64)> = parse_json(reactants_json, "reactants")?;
+    let products: Vec<(String, f64)> = parse_json(products_json, "products")?;
+    let ionic_strength = parse_number(ionic_strength_bytes, "ionic strength")?;
+    let model: String = parse_arg(model_bytes, "model")?;
+    let sizes: HashMap<String, f64> = parse_json(sizes_json, "ion sizes")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    let model = ActivityModel::parse(&model)?;
+    check_positive("equilibrium constant", k)?;
+    check_non_negative("ionic strength", ionic_strength)?;
+    check_temperature(temperature)?;
+
+    let species = reactants
+        .iter()
+        .map(|(formula, nu)| (formula, -nu))
+        .chain(products.iter().map(|(formula, nu)| (formula, *nu)))
+        .map(|(formula, coefficient)| {
+            let charge = parse_formula(formula)?.charge;
+            let size = sizes.get(formula).copied().unwrap_or(3.0);
+            let log_gamma = model.log_gamma(charge, size, ionic_strength, temperature);
+            Ok(SpeciesActivity {
+                species: formula.clone(),
+                coefficient,
+                charge,
+                gamma: 10_f64.powf(log_gamma),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let log_quotient: f64 = species
+        .iter()
+        .map(|s| s.coefficient * s.gamma.log10())
+        .sum();
+
+    let result = ActivityCorrectedConstant {
+        value: k / 10_f64.powf(log_quotient),
+        thermodynamic: k,
+        activity_quotient: 10_f64.powf(log_quotient),
+        log_shift: -log_quotient,
+        species,
+        within_range: ionic_strength <= model.validity_limit(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// COLLIGATIVE PROPERTIES
+// ============================================================================
+
+/// Cryoscopic and ebullioscopic data of a solvent
+#[derive(Deserialize, Clone)]
+struct SolventData {
+    /// Cryoscopic constant (K·kg/mol)
+    #[serde(rename = "Kf", default)]
+    kf: Option<f64>,
+    /// Ebullioscopic constant (K·kg/mol)
+    #[serde(rename = "Kb", default)]
+    kb: Option<f64>,
+    /// Normal freezing point (K)
+    #[serde(rename = "Tf", default)]
+    tf: Option<f64>,
+    /// Normal boiling point (K)
+    #[serde(rename = "Tb", default)]
+    tb: Option<f64>,
+}
+
+/// Solvent given by name (looked up in the solvent table) or explicitly
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SolventSpec {
+    Id(String),
+    Explicit(SolventData),
+}
+
+impl SolventSpec {
+    fn resolve(
+        &self,
+        data: &HashMap<String, SolventData>,
+    ) -> Result<(String, SolventData), String> {
+        match self {
+            SolventSpec::Id(id) => data
+                .get(id)
+                .map(|solvent| (id.clone(), solvent.clone()))
+                .ok_or_else(|| format!("No colligative constants found for solvent: {}", id)),
+            SolventSpec::Explicit(solvent) => Ok(("solvent".to_string(), solvent.clone())),
+        }
+    }
+}
+
+/// Phase transition of the solvent used by a colligative property
+enum Transition {
+    Freezing,
+    Boiling,
+}
+
+impl Transition {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "freezing" => Ok(Transition::Freezing),
+            "boiling" => Ok(Transition::Boiling),
+            _ => Err(format!("Unknown transition: {} (expected freezing or boiling)", name)),
+        }
+    }
+
+    /// Colligative constant and normal transition temperature of a solvent
+    fn constants(&self, label: &str, solvent: &SolventData) -> Result<(f64, Option<f64>), String> {
+        let (k, t, symbol) = match self {
+            Transition::Freezing => (solvent.kf, solvent.tf, "Kf"),
+            Transition::Boiling => (solvent.kb, solvent.tb, "Kb"),
+        };
+        let k = k.ok_or_else(|| format!("No {} given for {}", symbol, label))?;
+        check_positive(&format!("{} of {}", symbol, label), k)?;
+        Ok((k, t))
+    }
+}
+
+/// Freezing-point depression or boiling-point elevation
+#[derive(Serialize)]
+struct TransitionShift {
+    /// Magnitude of the temperature change (K)
+    value: f64,
+    unit: String,
+    solvent: String,
+    /// Kf or Kb used (K·kg/mol)
+    constant: f64,
+    vant_hoff_factor: f64,
+    /// Freezing or boiling point of the solution (K), when the solvent's is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+}
+
+/// Calculate the freezing-point depression or boiling-point elevation of a solution
+/// ΔTf = i·Kf·b, ΔTb = i·Kb·b
+///
+/// Arguments:
+/// - transition: "freezing" or "boiling"
+/// - molality: Solute molality b (mol/kg)
+/// - i: van't Hoff factor
+/// - solvent_json: Solvent name from the table, or {Kf, Kb, Tf, Tb}
+/// - data_json: Solvent table
+#[wasm_func]
+pub fn colligative_temperature_shift(
+    transition_bytes: &[u8],
+    molality_bytes: &[u8],
+    i_bytes: &[u8],
+    solvent_json: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let transition: String = parse_arg(transition_bytes, "transition")?;
+    let molality = parse_number(molality_bytes, "molality")?;
+    let i = parse_number(i_bytes, "van't Hoff factor")?;
+    let spec: SolventSpec = parse_json(solvent_json, "solvent")?;
+    let data: HashMap<String, SolventData> = parse_json(data_json, "solvent data")?;
+
+    let transition = Transition::parse(&transition)?;
+    check_non_negative("molality", molality)?;
+    check_positive("van't Hoff factor", i)?;
+    let (label, solvent) = spec.resolve(&data)?;
+    let (k, normal) = transition.constants(&label, &solvent)?;
+
+    let shift = i * k * molality;
+    let result = TransitionShift {
+        value: shift,
+        unit: "K".to_string(),
+        temperature: normal.map(|t| match transition {
+            Transition::Freezing => t - shift,
+            Transition::Boiling => t + shift,
+        }),
+        solvent: label,
+        constant: k,
+        vant_hoff_factor: i,
+    };
+
+    to_json(&result)
+}
+
+/// Osmotic pressure of a solution
+#[derive(Serialize)]
+struct OsmoticPressure {
+    value: f64,
+    unit: String,
+    atm: f64,
+    bar: f64,
+    /// Osmolarity i·c (osmol/L)
+    osmolarity: f64,
+}
+
+/// Calculate the osmotic pressure of a dilute solution (van't Hoff equation)
+/// Π = i·c·R·T
+///
+/// Arguments:
+/// - concentration: Solute concentration (mol/L)
+/// - i: van't Hoff factor
+/// - T: Temperature (K)
+#[wasm_func]
+pub fn osmotic_pressure(
+    concentration_bytes: &[u8],
+    i_bytes: &[u8],
+    temperature_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let concentration = parse_number(concentration_bytes, "concentration")?;
+    let i = parse_number(i_bytes, "van't Hoff factor")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+
+    check_non_negative("concentration", concentration)?;
+    check_positive("van't Hoff factor", i)?;
+    check_temperature(temperature)?;
+
+    // mol/L → mol/m³ gives Pa; report kPa
+    let pressure = i * concentration * 1000.0 * R * temperature / 1000.0;
+    let result = OsmoticPressure {
+        value: pressure,
+        unit: "kPa".to_string(),
+        atm: pressure / 101.325,
+        bar: pressure / 100.0,
+        osmolarity: i * concentration,
+    };
+
+    to_json(&result)
+}
+
+/// Molar mass determined from a colligative measurement
+#[derive(Serialize)]
+struct ColligativeMolarMass {
+    value: f64,
+    unit: String,
+    /// Amount of solute (mol)
+    moles: f64,
+    /// Molality (mol/kg) or concentration (mol/L) implied by the measurement
+    concentration: f64,
+}
+
+/// Colligative molar mass determination
+#[derive(Deserialize)]
+struct ColligativeMolarMassInput {
+    /// "freezing", "boiling" or "osmotic"
+    method: String,
+    /// ΔT (K) or Π (kPa)
+    change: f64,
+    /// Mass of solute (g)
+    solute_mass: f64,
+    /// Mass of solvent (g) for ΔT, or solution volume (L) for Π
+    amount: f64,
+    #[serde(default = "default_vant_hoff_factor")]
+    i: f64,
+    #[serde(default = "default_solvent")]
+    solvent: SolventSpec,
+    #[serde(default = "default_temperature")]
+    temperature: f64,
+}
+
+fn default_vant_hoff_factor() -> f64 {
+    1.0
+}
+
+fn default_solvent() -> SolventSpec {
+    SolventSpec::Id("water".to_string())
+}
+
+/// Determine the molar mass of a solute from a colligative measurement
+/// Cryoscopy/ebullioscopy: M = i·K·m_solute/(ΔT·m_solvent)
+/// Osmometry: M = i·m_solute·R·T/(Π·V)
+///
+/// Arguments:
+/// - input_json: Measurement, see `ColligativeMolarMassInput`
+/// - data_json: Solvent table
+#[wasm_func]
+pub fn colligative_molar_mass(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: ColligativeMolarMassInput = parse_json(input_json, "measurement")?;
+    let data: HashMap<String, SolventData> = parse_json(data_json, "solvent data")?;
+
+    check_positive("measured change", input.change)?;
+    check_positive("solute mass", input.solute_mass)?;
+    check_positive("amount", input.amount)?;
+    check_positive("van't Hoff factor", input.i)?;
+
+    let (moles, concentration) = if input.method == "osmotic" {
+        check_temperature(input.temperature)?;
+        // Π (kPa) = i·c·R·T with c in mol/L
+        let concentration = input.change / (input.i * R * input.temperature);
+        (concentration * input.amount, concentration)
+    } else {
+        let transition = Transition::parse(&input.method).map_err(|_| {
+            format!(
+                "Unknown method: {} (expected freezing, boiling or osmotic)",
+                input.method
+            )
+        })?;
+        let (label, solvent) = input.solvent.resolve(&data)?;
+        let (k, _) = transition.constants(&label, &solvent)?;
+        let molality = input.change / (input.i * k);
+        (molality * input.amount / 1000.0, molality)
+    };
+
+    let result = ColligativeMolarMass {
+        value: input.solute_mass / moles,
+        unit: "g/mol".to_string(),
+        moles,
+        concentration,
+    };
+
+    to_json(&result)
+}
+
+/// van't Hoff factor of a partly dissociated electrolyte
+#[derive(Serialize)]
+struct VantHoffFactor {
+    value: f64,
+    ions: f64,
+    dissociation: f64,
+}
+
+/// Convert between the van't Hoff factor and the degree of dissociation
+/// i = 1 + α·(ν - 1)
+///
+/// Arguments:
+/// - ions: Number of ions ν per formula unit
+/// - known: Which quantity is given: "dissociation" (α) or "i"
+/// - value: Value of the given quantity
+#[wasm_func]
+pub fn vant_hoff_factor(
+    ions_bytes: &[u8],
+    known_bytes: &[u8],
+    value_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let ions = parse_number(ions_bytes, "ion count")?;
+    let known: String = parse_arg(known_bytes, "known quantity")?;
+    let value = parse_number(value_bytes, "value")?;
+
+    check_domain("ion count", ions, ions >= 1.0, "at least 1")?;
+
+    let result = match known.as_str() {
+        "dissociation" => {
+            check_domain(
+                "degree of dissociation",
+                value,
+                (0.0..=1.0).contains(&value),
+                "between 0 and 1",
+            )?;
+            VantHoffFactor {
+                value: 1.0 + value * (ions - 1.0),
+                ions,
+                dissociation: value,
+            }
+        }
+        "i" => {
+            if ions == 1.0 {
+                return Err("A non-electrolyte has no degree of dissociation".to_string());
+            }
+            VantHoffFactor {
+                value,
+                ions,
+                dissociation: (value - 1.0) / (ions - 1.0),
+            }
+        }
+        _ => return Err(format!("Unknown quantity: {} (expected dissociation or i)", known)),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// VAPOR–LIQUID EQUILIBRIA
+// ============================================================================
+
+/// Component of an ideal liquid mixture
+#[derive(Deserialize)]
+struct RaoultComponent {
+    /// Vapor pressure of the pure component
+    pressure: f64,
+    /// Mole fraction in the liquid
+    fraction: f64,
+}
+
+/// Partial pressure and vapor composition of one component
+#[derive(Serialize)]
+struct RaoultPartial {
+    name: String,
+    liquid_fraction: f64,
+    partial_pressure: f64,
+    vapor_fraction: f64,
+}
+
+/// Vapor above an ideal liquid mixture
+#[derive(Serialize)]
+struct RaoultMixture {
+    /// Total vapor pressure, in the unit of the pure-component pressures
+    value: f64,
+    components: Vec<RaoultPartial>,
+}
+
+/// Calculate the vapor pressure and vapor composition of an ideal mixture (Raoult's law)
+/// pᵢ = xᵢ·pᵢ*, P = Σpᵢ, yᵢ = pᵢ/P
+///
+/// Arguments:
+/// - components_json: Array of [name, {pressure, fraction}] pairs; fractions must sum to 1
+#[wasm_func]
+pub fn raoult_mixture(components_json: &[u8]) -> Result<Vec<u8>, String> {
+    let components: Vec<(String, RaoultComponent)> = parse_json(components_json, "components")?;
+
+    if components.is_empty() {
+        return Err("At least one component is required".to_string());
+    }
+    for (name, c) in &components {
+        check_non_negative(&format!("vapor pressure of {}", name), c.pressure)?;
+        check_domain(
+            &format!("mole fraction of {}", name),
+            c.fraction,
+            (0.0..=1.0).contains(&c.fraction),
+            "between 0 and 1",
+        )?;
+    }
+    let sum: f64 = components.iter().map(|(_, c)| c.fraction).sum();
+    if (sum - 1.0).abs() > 1e-6 {
+        return Err(format!("Mole fractions must sum to 1 (got {})", sum));
+    }
+
+    let total: f64 = components.iter().map(|(_, c)| c.fraction * c.pressure).sum();
+    check_positive("total vapor pressure", total)?;
+
+    let result = RaoultMixture {
+        value: total,
+        components: components
+            .into_iter()
+            .map(|(name, c)| RaoultPartial {
+                name,
+                liquid_fraction: c.fraction,
+                partial_pressure: c.fraction * c.pressure,
+                vapor_fraction: c.fraction * c.pressure / total,
+            })
+            .collect(),
+    };
+
+    to_json(&result)
+}
+
+/// Isothermal P–x–y diagram of an ideal binary mixture
+#[derive(Serialize)]
+struct PxyDiagram {
+    /// (x₁, P) points of the bubble-point line
+    bubble: Vec<(f64, f64)>,
+    /// (y₁, P) points of the dew-point line
+    dew: Vec<(f64, f64)>,
+    /// Bubble and dew pressures at the given liquid composition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bubble_pressure: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dew_pressure: Option<f64>,
+}
+
+/// Generate P–x–y data for an ideal binary mixture
+/// Bubble line: P = x₁·p₁* + (1 - x₁)·p₂*; dew line: 1/P = y₁/p₁* + (1 - y₁)/p₂*
+///
+/// Arguments:
+/// - p1: Vapor pressure of pure component 1
+/// - p2: Vapor pressure of pure component 2
+/// - points: Number of points per line
+/// - composition: Mole fraction of component 1 for single-point bubble and dew pressures
+///   (negative to skip)
+#[wasm_func]
+pub fn pxy_diagram(
+    p1_bytes: &[u8],
+    p2_bytes: &[u8],
+    points_bytes: &[u8],
+    composition_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let p1 = parse_number(p1_bytes, "vapor pressure 1")?;
+    let p2 = parse_number(p2_bytes, "vapor pressure 2")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let composition = parse_number(composition_bytes, "composition")?;
+
+    check_positive("vapor pressure of component 1", p1)?;
+    check_positive("vapor pressure of component 2", p2)?;
+    if points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    check_domain("composition", composition, composition <= 1.0, "at most 1")?;
+
+    let bubble_at = |x: f64| x * p1 + (1.0 - x) * p2;
+    let dew_at = |y: f64| 1.0 / (y / p1 + (1.0 - y) / p2);
+    let fractions = sample_times(1.0, points);
+
+    let result = PxyDiagram {
+        bubble: fractions.iter().map(|&x| (x, bubble_at(x))).collect(),
+        dew: fractions.iter().map(|&y| (y, dew_at(y))).collect(),
+        bubble_pressure: (composition >= 0.0).then(|| bubble_at(composition)),
+        dew_pressure: (composition >= 0.0).then(|| dew_at(composition)),
+    };
+
+    to_json(&result)
+}
+
+/// Gas solubility from Henry's law
+#[derive(Serialize)]
+struct HenrySolubility {
+    value: f64,
+    unit: String,
+    /// Henry constant at the given temperature (mol/(L·bar))
+    henry_constant: f64,
+    /// Solubility in g/L, when the molar mass is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mass_solubility: Option<f64>,
+}
+
+/// Calculate the solubility of a gas from Henry's law
+/// c = kH·p, kH(T) = kH°·exp(C·(1/T - 1/298.15))
+///
+/// Arguments:
+/// - kh: Henry solubility constant at 298.15 K (mol/(L·bar))
+/// - pressure: Partial pressure of the gas (bar)
+/// - C: Temperature coefficient -ΔsolH/R (K); 0 to ignore the temperature dependence
+/// - T: Temperature (K)
+/// - molar_mass: Molar mass of the gas (g/mol); 0 to skip the mass solubility
+#[wasm_func]
+pub fn henry_solubility(
+    kh_bytes: &[u8],
+    pressure_bytes: &[u8],
+    coefficient_bytes: &[u8],
+    temperature_bytes: &[u8],
+    molar_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let kh = parse_number(kh_bytes, "Henry constant")?;
+    let pressure = parse_number(pressure_bytes, "partial pressure")?;
+    let coefficient = parse_number(coefficient_bytes, "temperature coefficient")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let molar_mass = parse_number(molar_mass_bytes, "molar mass")?;
+
+    check_positive("Henry constant", kh)?;
+    check_temperature(temperature)?;
+    check_non_negative("pressure", pressure)?;
+    check_non_negative("molar mass", molar_mass)?;
+
+    let kh_t = kh * (coefficient * (1.0 / temperature - 1.0 / 298.15)).exp();
+    let concentration = kh_t * pressure;
+
+    let result = HenrySolubility {
+        value: concentration,
+        unit: "mol/L".to_string(),
+        henry_constant: kh_t,
+        mass_solubility: (molar_mass > 0.0).then_some(concentration * molar_mass),
+    };
+
+    to_json(&result)
+}
+
+#[derive(Deserialize)]
+struct SolubilityTemperatureInput {
+    /// Solubility at T1 (any unit; s2 is returned in the same unit)
+    s1: f64,
+    /// Temperature of the known solubility (K)
+    t1: f64,
+    /// Second temperature (K)
+    t2: f64,
+    /// Solubility at T2, to extract ΔHsoln
+    #[serde(default)]
+    s2: Option<f64>,
+    /// Enthalpy of solution (kJ/mol), to predict s2
+    #[serde(default)]
+    delta_h: Option<f64>,
+    /// Ions released per formula unit; Ksp ∝ s^ν for a salt, 1 for molecular solutes
+    #[serde(default = "default_solubility_ions")]
+    ions: f64,
+}
+
+fn default_solubility_ions() -> f64 {
+    1.0
+}
+
+/// Solubilities at two temperatures linked by the enthalpy of solution
+#[derive(Serialize)]
+struct SolubilityTemperature {
+    s1: f64,
+    t1: f64,
+    s2: f64,
+    t2: f64,
+    /// s2/s1
+    ratio: f64,
+    /// Enthalpy of solution ΔHsoln (kJ/mol)
+    delta_h: f64,
+    unit: String,
+    /// The quantity that was calculated: "solubility" or "delta_h"
+    solved: String,
+}
+
+/// Predict a solubility at a new temperature, or ΔHsoln from solubilities at two
+/// temperatures, with the van 't Hoff equation
+/// ν·ln(s2/s1) = -(ΔHsoln/R)·(1/T2 - 1/T1)
+///
+/// Arguments:
+/// - input_json: s1, T1, T2, ν and either s2 or ΔHsoln
+#[wasm_func]
+pub fn solubility_temperature(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: SolubilityTemperatureInput = parse_json(input_json, "solubility input")?;
+
+    check_temperature_of("T1", input.t1)?;
+    check_temperature_of("T2", input.t2)?;
+    check_positive("solubility at T1", input.s1)?;
+    if let Some(s2) = input.s2 {
+        check_positive("solubility at T2", s2)?;
+    }
+    check_positive("number of ions", input.ions)?;
+
+    let inverse_difference = 1.0 / input.t2 - 1.0 / input.t1;
+    let (s2, delta_h, solved) = match (input.s2, input.delta_h) {
+        (None, Some(delta_h)) => {
+            let ln_ratio = -delta_h * 1000.0 / R * inverse_difference / input.ions;
+            (input.s1 * ln_ratio.exp(), delta_h, "solubility")
+        }
+        (Some(s2), None) => {
+            if input.t1 == input.t2 {
+                return Err("ΔHsoln needs solubilities at two different temperatures".to_string());
+            }
+            let delta_h = -R * input.ions * (s2 / input.s1).ln() / inverse_difference / 1000.0;
+            (s2, delta_h, "delta_h")
+        }
+        (Some(_), Some(_)) => {
+            return Err("Give either the solubility at T2 or ΔHsoln, not both".to_string())
+        }
+        (None, None) => return Err("The solubility at T2 or ΔHsoln is required".to_string()),
+    };
+
+    let result = SolubilityTemperature {
+        s1: input.s1,
+        t1: input.t1,
+        s2,
+        t2: input.t2,
+        ratio: s2 / input.s1,
+        delta_h,
+        unit: "kJ/mol".to_string(),
+        solved: solved.to_string(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// COMPLEX-ION EQUILIBRIA
+// ============================================================================
+
+/// Formation constant table entry
+#[derive(Deserialize, Clone)]
+struct ComplexData {
+    metal: String,
+    ligand: String,
+    /// Overall formation constants log10 βₙ for n = 1, 2, ...
+    log_beta: Vec<f64>,
+    /// Complex names for n = 1, 2, ...; defaults to ML, ML2, ...
+    #[serde(default)]
+    complexes: Vec<String>,
+}
+
+impl ComplexData {
+    /// Species names from the free metal through the highest complex
+    fn species(&self) -> Vec<String> {
+        std::iter::once(self.metal.clone())
+            .chain((1..=self.log_beta.len()).map(|n| {
+                self.complexes.get(n - 1).cloned().unwrap_or_else(|| {
+                    if n == 1 {
+                        "ML".to_string()
+                    } else {
+                        format!("ML{}", n)
+                    }
+                })
+            }))
+            .collect()
+    }
+
+    /// Fractions αₙ = βₙ·[L]ⁿ/Σβᵢ·[L]ⁱ of the metal in each species (β₀ = 1)
+    fn fractions(&self, ligand: f64) -> Vec<f64> {
+        let log_l = ligand.log10();
+        let logs: Vec<f64> = std::iter::once(0.0)
+            .chain(
+                self.log_beta
+                    .iter()
+                    .enumerate()
+                    .map(|(i, log_beta)| log_beta + (i + 1) as f64 * log_l),
+            )
+            .collect();
+        // Scale by the largest term so strong complexes do not overflow
+        let max = logs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let terms: Vec<f64> = logs.iter().map(|l| 10_f64.powf(l - max)).collect();
+        let sum: f64 = terms.iter().sum();
+        terms.iter().map(|t| t / sum).collect()
+    }
+
+    /// Mean number of ligands bound per metal ion
+    fn mean_ligand_number(&self, ligand: f64) -> f64 {
+        self.fractions(ligand)
+            .iter()
+            .enumerate()
+            .map(|(n, alpha)| n as f64 * alpha)
+            .sum()
+    }
+}
+
+/// Metal–ligand system given by key (looked up in the formation constant table) or explicitly
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ComplexSpec {
+    Id(String),
+    Explicit(ComplexData),
+}
+
+impl ComplexSpec {
+    fn resolve(&self, data: &HashMap<String, ComplexData>) -> Result<ComplexData, String> {
+        let system = match self {
+            ComplexSpec::Id(id) => data
+                .get(id)
+                .cloned()
+                .ok_or_else(|| format!("No formation constants found for system: {}", id))?,
+            ComplexSpec::Explicit(system) => system.clone(),
+        };
+        if system.log_beta.is_empty() {
+            return Err(format!(
+                "System {}/{} needs at least one formation constant",
+                system.metal, system.ligand
+            ));
+        }
+        Ok(system)
+    }
+}
+
+/// Stepwise and overall formation constants of a complex series
+#[derive(Serialize)]
+struct FormationConstants {
+    /// Stepwise log10 Kₙ
+    log_k: Vec<f64>,
+    /// Overall log10 βₙ = Σ log10 Kᵢ
+    log_beta: Vec<f64>,
+    k: Vec<f64>,
+    beta: Vec<f64>,
+}
+
+/// Convert between stepwise (Kₙ) and overall (βₙ = K₁·K₂·…·Kₙ) formation constants
+///
+/// Arguments:
+/// - values_json: Constants K₁, K₂, ... or β₁, β₂, ...
+/// - kind: "stepwise" or "overall"
+/// - logarithmic: "true" when the values are log10 constants
+#[wasm_func]
+pub fn convert_formation_constants(
+    values_json: &[u8],
+    kind_bytes: &[u8],
+    logarithmic_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let values: Vec<f64> = parse_json(values_json, "formation constants")?;
+    let kind: String = parse_arg(kind_bytes, "kind")?;
+    let logarithmic: bool = parse_arg(logarithmic_bytes, "logarithmic flag")?;
+
+    if values.is_empty() {
+        return Err("At least one formation constant is required".to_string());
+    }
+    if !logarithmic {
+        for (i, value) in values.iter().enumerate() {
+            check_positive(&format!("formation constant {}", i + 1), *value)?;
+        }
+    }
+    let logs: Vec<f64> = if logarithmic {
+        values
+    } else {
+        values.iter().map(|v| v.log10()).collect()
+    };
+
+    let (log_k, log_beta) = match kind.as_str() {
+        "stepwise" => {
+            let log_beta = logs
+                .iter()
+                .scan(0.0, |sum, log_k| {
+                    *sum += log_k;
+                    Some(*sum)
+                })
+                .collect();
+            (logs, log_beta)
+        }
+        "overall" => {
+            let log_k = logs
+                .iter()
+                .enumerate()
+                .map(|(i, log_beta)| if i == 0 { *log_beta } else { log_beta - logs[i - 1] })
+                .collect();
+            (log_k, logs)
+        }
+        _ => return Err(format!("Unknown kind: {} (expected stepwise or overall)", kind)),
+    };
+
+    let result = FormationConstants {
+        k: log_k.iter().map(|l| 10_f64.powf(*l)).collect(),
+        beta: log_beta.iter().map(|l| 10_f64.powf(*l)).collect(),
+        log_k,
+        log_beta,
+    };
+
+    to_json(&result)
+}
+
+/// Metal speciation in a metal–ligand system
+#[derive(Serialize)]
+struct ComplexSpeciation {
+    metal: String,
+    ligand: String,
+    free_ligand: f64,
+    /// Fraction and concentration of each species, from the free metal upwards
+    fractions: Vec<SpeciesFraction>,
+    dominant: String,
+    /// Mean number of bound ligands n̄
+    mean_ligand_number: f64,
+    /// (log10[L], α) points per species, in the order of `fractions`
+    curves: Vec<Vec<(f64, f64)>>,
+}
+
+/// Calculate metal speciation at a given free-ligand concentration
+/// αₙ = βₙ·[L]ⁿ/(1 + Σβᵢ·[L]ⁱ)
+///
+/// Arguments:
+/// - system_json: System key from the table (e.g. "Cu2+/NH3"), or
+///   {metal, ligand, log_beta, complexes}
+/// - free_ligand: Free ligand concentration [L] (mol/L)
+/// - total_metal: Total metal concentration for the species concentrations (mol/L)
+/// - data_json: Formation constant table
+/// - range_json: [min, max] of log10[L] for the curves
+/// - points: Number of points per curve
+#[wasm_func]
+pub fn complex_speciation(
+    system_json: &[u8],
+    free_ligand_bytes: &[u8],
+    total_metal_bytes: &[u8],
+    data_json: &[u8],
+    range_json: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let spec: ComplexSpec = parse_json(system_json, "metal–ligand system")?;
+    let free_ligand = parse_number(free_ligand_bytes, "free ligand concentration")?;
+    let total_metal = parse_number(total_metal_bytes, "total metal concentration")?;
+    let data: HashMap<String, ComplexData> = parse_json(data_json, "formation constant data")?;
+    let range: (f64, f64) = parse_json(range_json, "log[L] range")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    check_positive("free ligand concentration", free_ligand)?;
+    check_non_negative("total metal concentration", total_metal)?;
+    if points < 2 || range.1 <= range.0 {
+        return Err("Curves need at least 2 points and an increasing range".to_string());
+    }
+    let system = spec.resolve(&data)?;
+    let names = system.species();
+
+    let fractions: Vec<SpeciesFraction> = names
+        .iter()
+        .zip(system.fractions(free_ligand))
+        .map(|(species, alpha)| SpeciesFraction {
+            species: species.clone(),
+            alpha,
+            concentration: alpha * total_metal,
+        })
+        .collect();
+    let dominant = fractions
+        .iter()
+        .max_by(|a, b| a.alpha.total_cmp(&b.alpha))
+        .map(|f| f.species.clone())
+        .unwrap();
+
+    let grid: Vec<(f64, Vec<f64>)> = sample_times(range.1 - range.0, points)
+        .into_iter()
+        .map(|offset| {
+            let log_l = range.0 + offset;
+            (log_l, system.fractions(10_f64.powf(log_l)))
+        })
+        .collect();
+    let curves = (0..names.len())
+        .map(|i| grid.iter().map(|(log_l, alphas)| (*log_l, alphas[i])).collect())
+        .collect();
+
+    let result = ComplexSpeciation {
+        mean_ligand_number: system.mean_ligand_number(free_ligand),
+        metal: system.metal,
+        ligand: system.ligand,
+        free_ligand,
+        fractions,
+        dominant,
+        curves,
+    };
+
+    to_json(&result)
+}
+
+/// Solubility of a salt raised by complexation of its metal ion
+#[derive(Serialize)]
+struct ComplexationSolubility {
+    value: f64,
+    unit: String,
+    /// Solubility without ligand (mol/L)
+    pure_water: f64,
+    /// Ratio of the two solubilities
+    enhancement: f64,
+    free_ligand: f64,
+    free_metal: f64,
+    fractions: Vec<SpeciesFraction>,
+}
+
+/// Calculate the solubility of a salt in a complexing ligand solution
+///
+/// The metal ion of the salt is distributed over its complexes, so
+/// Ksp = (ν_M·s·α₀)^ν_M·(ν_X·s)^ν_X, and the free ligand follows from the ligand balance
+/// C_L = [L] + ν_M·s·Σn·αₙ. Protonation of the ligand is ignored.
+///
+/// Arguments:
+/// - salt_json: Salt formula from the Ksp table, or {Ksp, ions} with two ions
+/// - system_json: System key from the formation constant table, or an explicit system
+/// - ligand_total: Total ligand concentration C_L (mol/L)
+/// - ksp_data_json: Solubility product table
+/// - complex_data_json: Formation constant table
+#[wasm_func]
+pub fn complexation_solubility(
+    salt_json: &[u8],
+    system_json: &[u8],
+    ligand_total_bytes: &[u8],
+    ksp_data_json: &[u8],
+    complex_data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let salt_spec: SaltSpec = parse_json(salt_json, "salt")?;
+    let system_spec: ComplexSpec = parse_json(system_json, "metal–ligand system")?;
+    let ligand_total = parse_number(ligand_total_bytes, "total ligand concentration")?;
+    let ksp_data: HashMap<String, SolubilityData> =
+        parse_json(ksp_data_json, "solubility product data")?;
+    let complex_data: HashMap<String, ComplexData> =
+        parse_json(complex_data_json, "formation constant data")?;
+
+    check_positive("total ligand concentration", ligand_total)?;
+    let (label, salt) = salt_spec.resolve(&ksp_data)?;
+    let ksp = salt.ksp.ok_or_else(|| format!("No Ksp given for salt: {}", label))?;
+    check_positive("Ksp", ksp)?;
+    let system = system_spec.resolve(&complex_data)?;
+    if salt.ions.len() != 2 {
+        return Err(format!("Salt {} must dissolve into exactly two ions", label));
+    }
+    let metal_index = salt
+        .ions
+        .iter()
+        .position(|(ion, _)| *ion == system.metal)
+        .ok_or_else(|| format!("{} is not an ion of {}", system.metal, label))?;
+    let nu_m = salt.ions[metal_index].1;
+    let nu_x = salt.ions[1 - metal_index].1;
+
+    // Solubility for a given free ligand concentration
+    let solubility_at = |ligand: f64| -> f64 {
+        let alpha_0 = system.fractions(ligand)[0];
+        (ksp / ((nu_m * alpha_0).powf(nu_m) * nu_x.powf(nu_x))).powf(1.0 / (nu_m + nu_x))
+    };
+    let ligand_excess = |log_l: f64| -> f64 {
+        let ligand = 10_f64.powf(log_l);
+        ligand + nu_m * solubility_at(ligand) * system.mean_ligand_number(ligand) - ligand_total
+    };
+
+    let (mut lo, mut hi) = (-40.0_f64, ligand_total.log10());
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if ligand_excess(mid) > 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    let free_ligand = 10_f64.powf(0.5 * (lo + hi));
+    let s = solubility_at(free_ligand);
+    let pure_water = (ksp / (nu_m.powf(nu_m) * nu_x.powf(nu_x))).powf(1.0 / (nu_m + nu_x));
+    let alphas = system.fractions(free_ligand);
+
+    let result = ComplexationSolubility {
+        value: s,
+        unit: "mol/L".to_string(),
+        pure_water,
+        enhancement: s / pure_water,
+        free_ligand,
+        free_metal: nu_m * s * alphas[0],
+        fractions: system
+            .species()
+            .into_iter()
+            .zip(alphas)
+            .map(|(species, alpha)| SpeciesFraction {
+                species,
+                alpha,
+                concentration: alpha * nu_m * s,
+            })
+            .collect(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// GAS LAWS
+// ============================================================================
+
+/// Ideal gas problem: any three of P, V, n and T
+#[derive(Deserialize)]
+struct IdealGasInput {
+    pressure: Option<f64>,
+    volume: Option<f64>,
+    moles: Option<f64>,
+    temperature: Option<f64>,
+    /// Gas mass (g), giving n with the molar mass or the molar mass with n
+    mass: Option<f64>,
+    /// Molar mass (g/mol)
+    molar_mass: Option<f64>,
+    /// Gas formula, used for the molar mass
+    gas: Option<String>,
+    #[serde(default)]
+    pressure_unit: String,
+    #[serde(default)]
+    volume_unit: String,
+    #[serde(default)]
+    temperature_unit: String,
+}
+
+/// Solved ideal gas state with derived quantities
+#[derive(Serialize)]
+struct IdealGasState {
+    /// "pressure", "volume", "moles" or "temperature"
+    solved_for: String,
+    pressure: CalculationResult,
+    volume: CalculationResult,
+    moles: CalculationResult,
+    temperature: CalculationResult,
+    /// Molar volume V/n (L/mol)
+    molar_volume: CalculationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    molar_mass: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mass: Option<CalculationResult>,
+    /// Density PM/(RT) (g/L)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    density: Option<CalculationResult>,
+}
+
+/// Solve the ideal gas law PV = nRT for the missing quantity
+///
+/// Give any three of pressure, volume, amount and temperature, each in its chosen unit.
+/// The amount may be given as a mass with a molar mass (or gas formula); a mass given
+/// together with P, V and T yields the molar mass instead. Density follows whenever the
+/// molar mass is known.
+///
+/// Arguments:
+/// - input_json: Gas state, see `IdealGasInput`
+#[wasm_func]
+pub fn ideal_gas_solve(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: IdealGasInput = parse_json(input_json, "ideal gas input")?;
+
+    let (pa_per_unit, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let (litres_per_unit, volume_symbol) = parse_volume_unit(&input.volume_unit)?;
+    let scale = TemperatureScale::parse(&input.temperature_unit)?;
+
+    let formula_mass = match &input.gas {
+        Some(gas) => Some(parse_formula(gas)?.molar_mass()?),
+        None => None,
+    };
+    let known_molar_mass = match (input.molar_mass, formula_mass) {
+        (Some(_), Some(_)) => return Err("Give only one of molar mass and gas".to_string()),
+        (molar_mass, formula_mass) => molar_mass.or(formula_mass),
+    };
+    if let Some(molar_mass) = known_molar_mass {
+        check_positive("molar mass", molar_mass)?;
+    }
+    if let Some(mass) = input.mass {
+        check_positive("mass", mass)?;
+    }
+    let given_moles = match (input.moles, input.mass, known_molar_mass) {
+        (Some(_), Some(_), Some(_)) => {
+            return Err("Give the amount as either moles or mass".to_string())
+        }
+        (Some(n), _, _) => Some(n),
+        (None, Some(mass), Some(molar_mass)) => Some(mass / molar_mass),
+        _ => None,
+    };
+
+    // SI values: Pa, m³, mol, K
+    let pressure = input.pressure.map(|p| p * pa_per_unit);
+    let volume = input.volume.map(|v| v * litres_per_unit / 1000.0);
+    let temperature = input.temperature.map(|t| scale.to_kelvin(t));
+    if let Some(p) = input.pressure {
+        check_positive("pressure", p)?;
+    }
+    if let Some(v) = input.volume {
+        check_positive("volume", v)?;
+    }
+    if let Some(n) = given_moles {
+        check_positive("amount", n)?;
+    }
+    if let Some(t) = temperature {
+        check_temperature(t)?;
+    }
+
+    let (solved_for, p, v, n, t) = match (pressure, volume, given_moles, temperature) {
+        (None, Some(v), Some(n), Some(t)) => ("pressure", n * R * t / v, v, n, t),
+        (Some(p), None, Some(n), Some(t)) => ("volume", p, n * R * t / p, n, t),
+        (Some(p), Some(v), None, Some(t)) => ("moles", p, v, p * v / (R * t), t),
+        (Some(p), Some(v), Some(n), None) => ("temperature", p, v, n, p * v / (n * R)),
+        _ => {
+            return Err(
+                "Exactly one of pressure, volume, amount and temperature must be left unspecified"
+                    .to_string(),
+            )
+        }
+    };
+
+    // A mass with a computed amount gives the molar mass
+    let molar_mass = known_molar_mass.or_else(|| input.mass.map(|mass| mass / n));
+    let mass = input.mass.or_else(|| molar_mass.map(|m| m * n));
+
+    let result = IdealGasState {
+        solved_for: solved_for.to_string(),
+        pressure: CalculationResult::new(p / pa_per_unit, pressure_symbol),
+        volume: CalculationResult::new(v * 1000.0 / litres_per_unit, volume_symbol),
+        moles: CalculationResult::new(n, "mol"),
+        temperature: CalculationResult::new(scale.kelvin_to_scale(t), scale.symbol()),
+        molar_volume: CalculationResult::new(v * 1000.0 / n, "L/mol"),
+        density: molar_mass.map(|m| CalculationResult::new(p * m / (R * t) / 1000.0, "g/L")),
+        molar_mass: molar_mass.map(|m| CalculationResult::new(m, "g/mol")),
+        mass: mass.map(|m| CalculationResult::new(m, "g")),
+    };
+
+    to_json(&result)
+}
+
+/// Gas constants for the real-gas equations of state
+#[derive(Deserialize, Clone)]
+struct GasData {
+    #[serde(default)]
+    name: String,
+    /// van der Waals a (L²·bar/mol²)
+    a: Option<f64>,
+    /// van der Waals b (L/mol)
+    b: Option<f64>,
+    /// Critical temperature (K)
+    #[serde(rename = "Tc")]
+    tc: Option<f64>,
+    /// Critical pressure (bar)
+    #[serde(rename = "Pc")]
+    pc: Option<f64>,
+    /// Acentric factor
+    omega: Option<f64>,
+}
+
+/// Gas given by formula (looked up in the critical constant table) or explicitly
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GasSpec {
+    Id(String),
+    Explicit(GasData),
+}
+
+impl GasSpec {
+    fn resolve(&self, data: &HashMap<String, GasData>) -> Result<(String, GasData), String> {
+        match self {
+            GasSpec::Id(id) => data
+                .get(id)
+                .map(|gas| (id.clone(), gas.clone()))
+                .ok_or_else(|| format!("No critical constants found for gas: {}", id)),
+            GasSpec::Explicit(gas) => {
+                let label = if gas.name.is_empty() { "gas" } else { gas.name.as_str() };
+                Ok((label.to_string(), gas.clone()))
+            }
+        }
+    }
+}
+
+/// Real roots of x³ + c2·x² + c1·x + c0 = 0 in ascending order
+fn cubic_real_roots(c2: f64, c1: f64, c0: f64) -> Vec<f64> {
+    let p = c1 - c2 * c2 / 3.0;
+    let q = 2.0 * c2.powi(3) / 27.0 - c2 * c1 / 3.0 + c0;
+    let discriminant = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+    let shift = c2 / 3.0;
+
+    let mut roots = if discriminant > 0.0 {
+        let root = discriminant.sqrt();
+        vec![(-q / 2.0 + root).cbrt() + (-q / 2.0 - root).cbrt() - shift]
+    } else if p == 0.0 {
+        vec![-shift]
+    } else {
+        let r = 2.0 * (-p / 3.0).sqrt();
+        let phi = ((3.0 * q / (2.0 * p)) * (-3.0 / p).sqrt()).clamp(-1.0, 1.0).acos() / 3.0;
+        (0..3)
+            .map(|k| r * (phi - 2.0 * std::f64::consts::PI * k as f64 / 3.0).cos() - shift)
+            .collect()
+    };
+    // One Newton step polishes the closed-form roots
+    for x in roots.iter_mut() {
+        let f = ((*x + c2) * *x + c1) * *x + c0;
+        let df = (3.0 * *x + 2.0 * c2) * *x + c1;
+        if df != 0.0 {
+            *x -= f / df;
+        }
+    }
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+/// Cubic equation of state P = RT/(V - b) - a/(V² + u·b·V + w·b²) at a fixed temperature
+struct CubicEos {
+    /// Attraction parameter at the temperature (L²·bar/mol²)
+    a: f64,
+    /// Co-volume (L/mol)
+    b: f64,
+    u: f64,
+    w: f64,
+}
+
+impl CubicEos {
+    /// Build the equation for a model: "vdw", "rk", "srk" or "pr"
+    fn new(model: &str, label: &str, gas: &GasData, temperature: f64) -> Result<Self, String> {
+        let critical = || -> Result<(f64, f64), String> {
+            match (gas.tc, gas.pc) {
+                (Some(tc), Some(pc)) if tc > 0.0 && pc > 0.0 => Ok((tc, pc)),
+                _ => Err(format!("The {} model needs Tc and Pc for {}", model, label)),
+            }
+        };
+        let omega = || -> Result<f64, String> {
+            gas.omega.ok_or_else(|| {
+                format!("The {} model needs the acentric factor of {}", model, label)
+            })
+        };
+        let r = R_LITRE_BAR;
+
+        let eos = match model {
+            "vdw" => {
+                let (a, b) = match (gas.a, gas.b) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => {
+                        let (tc, pc) = critical()?;
+                        (27.0 * (r * tc).powi(2) / (64.0 * pc), r * tc / (8.0 * pc))
+                    }
+                };
+                CubicEos { a, b, u: 0.0, w: 0.0 }
+            }
+            "rk" => {
+                let (tc, pc) = critical()?;
+                CubicEos {
+                    a: 0.42748 * r * r * tc.powf(2.5) / pc / temperature.sqrt(),
+                    b: 0.08664 * r * tc / pc,
+                    u: 1.0,
+                    w: 0.0,
+                }
+            }
+            "srk" => {
+                let (tc, pc) = critical()?;
+                let omega = omega()?;
+                let m = 0.480 + 1.574 * omega - 0.176 * omega * omega;
+                let alpha = (1.0 + m * (1.0 - (temperature / tc).sqrt())).powi(2);
+                CubicEos {
+                    a: 0.42748 * (r * tc).powi(2) / pc * alpha,
+                    b: 0.08664 * r * tc / pc,
+                    u: 1.0,
+                    w: 0.0,
+                }
+            }
+            "pr" => {
+                let (tc, pc) = critical()?;
+                let omega = omega()?;
+                let m = 0.37464 + 1.54226 * omega - 0.26992 * omega * omega;
+                let alpha = (1.0 + m * (1.0 - (temperature / tc).sqrt())).powi(2);
+                CubicEos {
+                    a: 0.45724 * (r * tc).powi(2) / pc * alpha,
+                    b: 0.07780 * r * tc / pc,
+                    u: 2.0,
+                    w: -1.0,
+                }
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown equation of state: {} (expected vdw, rk, srk or pr)",
+                    model
+                ))
+            }
+        };
+        check_positive(&format!("a of {}", label), eos.a)?;
+        check_positive(&format!("b of {}", label), eos.b)?;
+        Ok(eos)
+    }
+
+    /// Pressure (bar) at a molar volume (L/mol)
+    fn pressure(&self, temperature: f64, volume: f64) -> f64 {
+        R_LITRE_BAR * temperature / (volume - self.b)
+            - self.a / (volume * volume + self.u * self.b * volume + self.w * self.b * self.b)
+    }
+
+    /// Physical compressibility factors (Z > B) at a pressure (bar), ascending
+    fn z_roots(&self, temperature: f64, pressure: f64) -> Vec<f64> {
+        let rt = R_LITRE_BAR * temperature;
+        let a = self.a * pressure / (rt * rt);
+        let b = self.b * pressure / rt;
+        let (u, w) = (self.u, self.w);
+        let roots = cubic_real_roots(
+            -(1.0 + b - u * b),
+            a + w * b * b - u * b - u * b * b,
+            -(a * b + w * b * b + w * b.powi(3)),
+        );
+        let mut physical: Vec<f64> = roots.into_iter().filter(|z| *z > b).collect();
+        physical.dedup_by(|x, y| (*x - *y).abs() < 1e-10);
+        physical
+    }
+
+    /// ln φ of the pure gas for a root Z at a pressure (bar)
+    fn ln_phi(&self, temperature: f64, pressure: f64, z: f64) -> f64 {
+        let rt = R_LITRE_BAR * temperature;
+        let a = self.a * pressure / (rt * rt);
+        let b = self.b * pressure / rt;
+        let root = (self.u * self.u - 4.0 * self.w).sqrt();
+        let attraction = if root == 0.0 {
+            a / z
+        } else {
+            a / (b * root)
+                * ((2.0 * z + b * (self.u + root)) / (2.0 * z + b * (self.u - root))).ln()
+        };
+        z - 1.0 - (z - b).ln() - attraction
+    }
+
+    /// Pick a root Z: "vapor" (largest), "liquid" (smallest) or "auto" (lowest fugacity,
+    /// i.e. the stable phase)
+    fn select_root(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        roots: &[f64],
+        phase: &str,
+    ) -> Result<f64, String> {
+        let root = match phase {
+            "vapor" => roots.last().copied(),
+            "liquid" => roots.first().copied(),
+            "auto" => roots.iter().copied().min_by(|x, y| {
+                self.ln_phi(temperature, pressure, *x)
+                    .total_cmp(&self.ln_phi(temperature, pressure, *y))
+            }),
+            _ => return Err(format!("Unknown phase: {} (expected auto, vapor or liquid)", phase)),
+        };
+        root.ok_or_else(|| "Equation of state has no physical root".to_string())
+    }
+}
+
+/// Real gas problem: T and one of P or the molar volume
+#[derive(Deserialize)]
+struct RealGasInput {
+    gas: GasSpec,
+    #[serde(default = "default_eos")]
+    model: String,
+    temperature: f64,
+    pressure: Option<f64>,
+    /// Molar volume (L/mol)
+    molar_volume: Option<f64>,
+    /// Root for P → V when three exist: "auto", "vapor" or "liquid"
+    #[serde(default = "default_phase")]
+    phase: String,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+fn default_eos() -> String {
+    "pr".to_string()
+}
+
+fn default_phase() -> String {
+    "auto".to_string()
+}
+
+/// Real gas state and its deviation from ideality
+#[derive(Serialize)]
+struct RealGasState {
+    gas: String,
+    model: String,
+    /// "pressure" or "molar_volume"
+    solved_for: String,
+    pressure: CalculationResult,
+    molar_volume: CalculationResult,
+    /// Compressibility factor Z = PV/(RT)
+    z: f64,
+    /// All physical roots Z for the given pressure
+    roots: Vec<f64>,
+    /// Solved quantity for an ideal gas at the same conditions
+    ideal: CalculationResult,
+    /// (real - ideal)/ideal (%)
+    deviation: f64,
+    a: f64,
+    b: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduced_temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduced_pressure: Option<f64>,
+}
+
+/// Solve a cubic equation of state for P or V
+///
+/// Models: van der Waals ("vdw"), Redlich–Kwong ("rk"), Soave–Redlich–Kwong ("srk") and
+/// Peng–Robinson ("pr"). Given V the pressure follows directly; given P the cubic in Z is
+/// solved and, when it has three roots (below Tc), the vapor-like (largest) or
+/// liquid-like (smallest) root is chosen; "auto" takes the stable root with the lower
+/// fugacity.
+///
+/// Arguments:
+/// - input_json: Gas state, see `RealGasInput`
+/// - data_json: Critical constant table
+#[wasm_func]
+pub fn real_gas_solve(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: RealGasInput = parse_json(input_json, "real gas input")?;
+    let data: HashMap<String, GasData> = parse_json(data_json, "critical constant data")?;
+
+    let (pa_per_unit, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let bar_per_unit = pa_per_unit / 1e5;
+    let (label, gas) = input.gas.resolve(&data)?;
+    let t = input.temperature;
+    check_temperature(t)?;
+    let eos = CubicEos::new(&input.model, &label, &gas, t)?;
+    let rt = R_LITRE_BAR * t;
+
+    let (solved_for, pressure, volume, roots) = match (input.pressure, input.molar_volume) {
+        (None, Some(v)) => {
+            check_domain("molar volume", v, v > eos.b, &format!("above b = {:.5} L/mol", eos.b))?;
+            let p = eos.pressure(t, v);
+            ("pressure", p, v, vec![p * v / rt])
+        }
+        (Some(p), None) => {
+            let p = p * bar_per_unit;
+            check_positive("pressure", p)?;
+            let roots = eos.z_roots(t, p);
+            let z = eos.select_root(t, p, &roots, &input.phase)?;
+            ("molar_volume", p, z * rt / p, roots)
+        }
+        _ => return Err("Give exactly one of pressure and molar volume".to_string()),
+    };
+
+    let z = pressure * volume / rt;
+    let (ideal, real, ideal_result) = if solved_for == "pressure" {
+        let ideal = rt / volume;
+        (ideal, pressure, CalculationResult::new(ideal / bar_per_unit, pressure_symbol))
+    } else {
+        let ideal = rt / pressure;
+        (ideal, volume, CalculationResult::new(ideal, "L/mol"))
+    };
+
+    let result = RealGasState {
+        gas: label,
+        model: input.model,
+        solved_for: solved_for.to_string(),
+        pressure: CalculationResult::new(pressure / bar_per_unit, pressure_symbol),
+        molar_volume: CalculationResult::new(volume, "L/mol"),
+        z,
+        roots,
+        ideal: ideal_result,
+        deviation: (real - ideal) / ideal * 100.0,
+        a: eos.a,
+        b: eos.b,
+        reduced_temperature: gas.tc.map(|tc| t / tc),
+        reduced_pressure: gas.pc.map(|pc| pressure / pc),
+    };
+
+    to_json(&result)
+}
+
+/// Compressibility factor curves of a real gas
+#[derive(Serialize)]
+struct CompressibilityCurve {
+    gas: String,
+    model: String,
+    temperature: f64,
+    /// (P in bar, Z) points using the vapor-like root
+    points: Vec<(f64, f64)>,
+}
+
+/// Generate Z against P from a cubic equation of state
+///
+/// Arguments:
+/// - gas_json: Gas formula from the table, or {a, b, Tc, Pc, omega}
+/// - data_json: Critical constant table
+/// - model: "vdw", "rk", "srk" or "pr"
+/// - T: Temperature (K)
+/// - range_json: [min, max] pressure (bar)
+/// - points: Number of points
+#[wasm_func]
+pub fn compressibility_curve(
+    gas_json: &[u8],
+    data_json: &[u8],
+    model_bytes: &[u8],
+    temperature_bytes: &[u8],
+    range_json: &[u8],
+    points_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let spec: GasSpec = parse_json(gas_json, "gas")?;
+    let data: HashMap<String, GasData> = parse_json(data_json, "critical constant data")?;
+    let model: String = parse_arg(model_bytes, "model")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let range: (f64, f64) = parse_json(range_json, "pressure range")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+
+    check_temperature(temperature)?;
+    if points < 2 || range.0 < 0.0 || range.1 <= range.0 {
+        return Err(
+            "Curves need at least 2 points and an increasing, non-negative range".to_string(),
+        );
+    }
+    let (label, gas) = spec.resolve(&data)?;
+    let eos = CubicEos::new(&model, &label, &gas, temperature)?;
+
+    let points = sample_times(range.1 - range.0, points)
+        .into_iter()
+        .map(|offset| {
+            let p = (range.0 + offset).max(1e-9);
+            let z = eos.z_roots(temperature, p).last().copied().unwrap_or(f64::NAN);
+            (p, z)
+        })
+        .collect();
+
+    let result = CompressibilityCurve {
+        gas: label,
+        model,
+        temperature,
+        points,
+    };
+
+    to_json(&result)
+}
+
+/// ln φ from the truncated virial equation with the Pitzer correlation for B
+/// B·Pc/(R·Tc) = B⁰ + ω·B¹, B⁰ = 0.083 - 0.422/Tr^1.6, B¹ = 0.139 - 0.172/Tr^4.2
+fn virial_ln_phi(
+    label: &str,
+    gas: &GasData,
+    temperature: f64,
+    pressure: f64,
+) -> Result<f64, String> {
+    let (tc, pc) = match (gas.tc, gas.pc) {
+        (Some(tc), Some(pc)) if tc > 0.0 && pc > 0.0 => (tc, pc),
+        _ => return Err(format!("The virial correlation needs Tc and Pc for {}", label)),
+    };
+    let tr = temperature / tc;
+    let b0 = 0.083 - 0.422 / tr.powf(1.6);
+    let b1 = 0.139 - 0.172 / tr.powf(4.2);
+    Ok(pressure / pc / tr * (b0 + gas.omega.unwrap_or(0.0) * b1))
+}
+
+/// ln φ and Z of a pure gas at T (K) and P (bar) by an EOS or the virial correlation
+fn gas_ln_phi(
+    method: &str,
+    label: &str,
+    gas: &GasData,
+    temperature: f64,
+    pressure: f64,
+    phase: &str,
+) -> Result<(f64, f64), String> {
+    match method {
+        "ideal" => Ok((0.0, 1.0)),
+        "virial" => {
+            let ln_phi = virial_ln_phi(label, gas, temperature, pressure)?;
+            // Z = 1 + B·P/(RT) has the same form as ln φ in this truncation
+            Ok((ln_phi, 1.0 + ln_phi))
+        }
+        _ => {
+            let eos = CubicEos::new(method, label, gas, temperature)?;
+            let roots = eos.z_roots(temperature, pressure);
+            let z = eos.select_root(temperature, pressure, &roots, phase)?;
+            Ok((eos.ln_phi(temperature, pressure, z), z))
+        }
+    }
+}
+
+/// Fugacity problem for a pure gas
+#[derive(Deserialize)]
+struct FugacityInput {
+    gas: GasSpec,
+    /// "vdw", "rk", "srk", "pr" or "virial"
+    #[serde(default = "default_eos")]
+    method: String,
+    temperature: f64,
+    pressure: f64,
+    #[serde(default = "default_phase")]
+    phase: String,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+/// Fugacity of a pure gas
+#[derive(Serialize)]
+struct FugacityResult {
+    gas: String,
+    method: String,
+    fugacity: CalculationResult,
+    /// Fugacity coefficient φ = f/P
+    phi: f64,
+    ln_phi: f64,
+    /// Compressibility factor of the chosen root
+    z: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduced_temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduced_pressure: Option<f64>,
+}
+
+/// Calculate the fugacity coefficient of a pure gas
+///
+/// Cubic EOS, with δ = √(u² - 4w):
+/// ln φ = Z - 1 - ln(Z - B) - A/(B·δ)·ln[(2Z + B(u + δ))/(2Z + B(u - δ))]
+/// (ln φ = Z - 1 - ln(Z - B) - A/Z for van der Waals). Generalized correlation ("virial"):
+/// ln φ = (Pr/Tr)·(B⁰ + ω·B¹), reliable at low to moderate reduced pressures.
+///
+/// Arguments:
+/// - input_json: Gas state, see `FugacityInput`
+/// - data_json: Critical constant table
+#[wasm_func]
+pub fn fugacity_coefficient(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: FugacityInput = parse_json(input_json, "fugacity input")?;
+    let data: HashMap<String, GasData> = parse_json(data_json, "critical constant data")?;
+
+    let (pa_per_unit, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let (label, gas) = input.gas.resolve(&data)?;
+    let t = input.temperature;
+    let p = input.pressure * pa_per_unit / 1e5;
+    check_temperature(t)?;
+    check_positive("pressure", input.pressure)?;
+    let (ln_phi, z) = gas_ln_phi(&input.method, &label, &gas, t, p, &input.phase)?;
+    let phi = ln_phi.exp();
+
+    let result = FugacityResult {
+        gas: label,
+        method: input.method,
+        fugacity: CalculationResult::new(phi * input.pressure, pressure_symbol),
+        phi,
+        ln_phi,
+        z,
+        reduced_temperature: gas.tc.map(|tc| t / tc),
+        reduced_pressure: gas.pc.map(|pc| p / pc),
+    };
+
+    to_json(&result)
+}
+
+/// Gas-phase reaction at given partial pressures
+#[derive(Deserialize)]
+struct GasReactionInput {
+    /// Standard reaction Gibbs energy at 1 bar (kJ/mol)
+    delta_g: f64,
+    temperature: f64,
+    /// (formula, coefficient, partial pressure)
+    reactants: Vec<(String, f64, f64)>,
+    products: Vec<(String, f64, f64)>,
+    /// "ideal" or a fugacity method of `fugacity_coefficient`
+    #[serde(default = "default_fugacity_method")]
+    method: String,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+fn default_fugacity_method() -> String {
+    "ideal".to_string()
+}
+
+/// Reaction Gibbs energy and equilibrium constants of a gas reaction
+#[derive(Serialize)]
+struct GasReactionResult {
+    /// ΔG = ΔG° + RT·ln Q (kJ/mol)
+    delta_g: CalculationResult,
+    standard_delta_g: f64,
+    /// Q from fugacities (partial pressures for "ideal"), standard state 1 bar
+    reaction_quotient: f64,
+    /// Thermodynamic K = exp(-ΔG°/RT)
+    k: f64,
+    /// Π φ^ν at the given state
+    k_phi: f64,
+    /// Equilibrium pressure quotient Kp = K/Kφ (bar^Δn)
+    kp: f64,
+    /// Fugacity coefficient of each gas
+    fugacity_coefficients: HashMap<String, f64>,
+    method: String,
+}
+
+/// Calculate ΔG and K of a gas reaction with ideal pressures or fugacities
+///
+/// Fugacities follow the Lewis–Randall rule f = φ·p, with φ of each pure gas evaluated at
+/// the total pressure. ΔG = ΔG° + RT·ln Q with Q = Π (f/1 bar)^ν; at equilibrium
+/// K = Kφ·Kp, so the pressure quotient is Kp = K/Kφ.
+///
+/// Arguments:
+/// - input_json: Reaction, see `GasReactionInput`
+/// - data_json: Critical constant table
+#[wasm_func]
+pub fn gas_reaction_gibbs(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: GasReactionInput = parse_json(input_json, "gas reaction input")?;
+    let data: HashMap<String, GasData> = parse_json(data_json, "critical constant data")?;
+
+    let (pa_per_unit, _) = parse_pressure_unit(&input.pressure_unit)?;
+    let bar_per_unit = pa_per_unit / 1e5;
+    let t = input.temperature;
+    check_temperature(t)?;
+    let terms: Vec<(&str, f64, f64)> = input
+        .reactants
+        .iter()
+        .map(|(gas, nu, p)| (gas.as_str(), -nu, p * bar_per_unit))
+        .chain(input.products.iter().map(|(gas, nu, p)| (gas.as_str(), *nu, p * bar_per_unit)))
+        .collect();
+    for &(gas, _, p) in &terms {
+        check_positive(&format!("partial pressure of {}", gas), p / bar_per_unit)?;
+    }
+    let total: f64 = terms.iter().map(|&(_, _, p)| p).sum();
+
+    let mut ln_q = 0.0;
+    let mut ln_k_phi = 0.0;
+    let mut fugacity_coefficients = HashMap::new();
+    for &(formula, nu, p) in &terms {
+        let ln_phi = if input.method == "ideal" {
+            0.0
+        } else {
+            let gas = data
+                .get(formula)
+                .ok_or_else(|| format!("No critical constants found for gas: {}", formula))?;
+            gas_ln_phi(&input.method, formula, gas, t, total, "vapor")?.0
+        };
+        ln_q += nu * (p.ln() + ln_phi);
+        ln_k_phi += nu * ln_phi;
+        fugacity_coefficients.insert(formula.to_string(), ln_phi.exp());
+    }
+
+    let rt = R * t / 1000.0;
+    let k = (-input.delta_g / rt).exp();
+    let k_phi = ln_k_phi.exp();
+
+    let result = GasReactionResult {
+        delta_g: CalculationResult::new(input.delta_g + rt * ln_q, "kJ/mol"),
+        standard_delta_g: input.delta_g,
+        reaction_quotient: ln_q.exp(),
+        k,
+        k_phi,
+        kp: k / k_phi,
+        fugacity_coefficients,
+        method: input.method,
+    };
+
+    to_json(&result)
+}
+
+/// Gas-phase equilibrium from Kp and the initial state
+#[derive(Deserialize)]
+struct GasEquilibriumInput {
+    /// Kp with partial pressures in `pressure_unit`
+    kp: f64,
+    /// (formula, coefficient, initial partial pressure, or moles with `total_pressure`)
+    reactants: Vec<(String, f64, f64)>,
+    products: Vec<(String, f64, f64)>,
+    /// Initial total pressure; if given, the initial amounts are moles
+    #[serde(default)]
+    total_pressure: Option<f64>,
+    /// Inert gas, as a partial pressure or moles like the other amounts
+    #[serde(default)]
+    inert: f64,
+    /// "volume" or "pressure", the quantity held constant as the reaction proceeds
+    #[serde(default = "default_gas_equilibrium_constant")]
+    constant: String,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+fn default_gas_equilibrium_constant() -> String {
+    "volume".to_string()
+}
+
+/// One gas of an equilibrium mixture
+#[derive(Serialize)]
+struct GasEquilibriumSpecies {
+    species: String,
+    coefficient: f64,
+    initial_pressure: f64,
+    pressure: f64,
+    mole_fraction: f64,
+    /// Equilibrium amount (mol), when initial moles were given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moles: Option<f64>,
+}
+
+/// Equilibrium composition of a gas-phase reaction
+#[derive(Serialize)]
+struct GasEquilibrium {
+    reactants: Vec<GasEquilibriumSpecies>,
+    products: Vec<GasEquilibriumSpecies>,
+    /// Extent of reaction ξ (mol, or the pressure unit for initial partial pressures)
+    extent: f64,
+    /// Fraction of the limiting reactant converted
+    conversion: f64,
+    initial_total_pressure: f64,
+    total_pressure: f64,
+    /// Partial pressure of the inert gas at equilibrium
+    inert_pressure: f64,
+    /// Δn(gas)
+    delta_n: f64,
+    kp: f64,
+    constant: String,
+    pressure_unit: String,
+}
+
+/// Solve the equilibrium of an ideal gas-phase reaction for the extent ξ
+/// Kp = Π pᵢ^νᵢ with nᵢ = nᵢ° + νᵢ·ξ
+///
+/// At constant volume each pᵢ is proportional to nᵢ, so the total pressure changes with
+/// Δn(gas); at constant pressure pᵢ = xᵢ·P. Initial amounts are partial pressures, or
+/// moles when the initial total pressure is given. ln Q rises monotonically with ξ, which
+/// is found by bisection between the limits set by the amounts present.
+///
+/// Arguments:
+/// - input_json: Kp, reaction with initial amounts and conditions, see `GasEquilibriumInput`
+#[wasm_func]
+pub fn gas_equilibrium(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: GasEquilibriumInput = parse_json(input_json, "gas equilibrium input")?;
+    let (_, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+
+    check_positive("Kp", input.kp)?;
+    if input.reactants.is_empty() || input.products.is_empty() {
+        return Err("The reaction needs reactants and products".to_string());
+    }
+    let species: Vec<(&String, f64, f64)> = input
+        .reactants
+        .iter()
+        .map(|(s, nu, a)| (s, -nu, *a))
+        .chain(input.products.iter().map(|(s, nu, a)| (s, *nu, *a)))
+        .collect();
+    for (name, nu, amount) in &species {
+        check_domain(&format!("coefficient of {}", name), *nu, *nu != 0.0, "non-zero")?;
+        check_non_negative(&format!("initial amount of {}", name), *amount)?;
+    }
+    check_non_negative("amount of inert gas", input.inert)?;
+    let initial_total: f64 = species.iter().map(|(_, _, a)| a).sum::<f64>() + input.inert;
+    if initial_total <= 0.0 {
+        return Err("The initial mixture is empty".to_string());
+    }
+    if let Some(p) = input.total_pressure {
+        check_positive("total pressure", p)?;
+    }
+    let pressure = input.total_pressure.unwrap_or(initial_total);
+    let constant_volume = match input.constant.as_str() {
+        "volume" | "V" => true,
+        "pressure" | "P" => false,
+        _ => {
+            return Err(format!(
+                "Unknown constant quantity: {} (expected volume or pressure)",
+                input.constant
+            ))
+        }
+    };
+
+    let delta_n: f64 = species.iter().map(|(_, nu, _)| nu).sum();
+    // Partial pressures per unit amount: RT/V at constant volume, P/n_total at constant pressure
+    let pressure_per_amount = |xi: f64| {
+        if constant_volume {
+            pressure / initial_total
+        } else {
+            pressure / (initial_total + delta_n * xi)
+        }
+    };
+    let ln_q = |xi: f64| {
+        let scale = pressure_per_amount(xi);
+        species.iter().map(|(_, nu, a)| nu * ((a + nu * xi) * scale).ln()).sum::<f64>()
+    };
+
+    // ξ is bounded by the reactants (ν < 0) running out going forward and the products going back
+    let upper = species
+        .iter()
+        .filter(|(_, nu, _)| *nu < 0.0)
+        .map(|(_, nu, a)| a / -nu)
+        .fold(f64::INFINITY, f64::min);
+    let lower = species
+        .iter()
+        .filter(|(_, nu, _)| *nu > 0.0)
+        .map(|(_, nu, a)| -a / nu)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if upper <= lower {
+        return Err("The reaction cannot proceed in either direction from this mixture".to_string());
+    }
+    let ln_kp = input.kp.ln();
+    let (mut lo, mut hi) = (lower, upper);
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if ln_q(mid) < ln_kp {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let extent = 0.5 * (lo + hi);
+
+    let scale = pressure_per_amount(extent);
+    let total_amount = initial_total + delta_n * extent;
+    let moles_given = input.total_pressure.is_some();
+    let initial_scale = pressure / initial_total;
+    let state = |side: &[(String, f64, f64)], sign: f64| -> Vec<GasEquilibriumSpecies> {
+        side.iter()
+            .map(|(name, nu, a)| {
+                let amount = a + sign * nu * extent;
+                GasEquilibriumSpecies {
+                    species: name.clone(),
+                    coefficient: *nu,
+                    initial_pressure: a * initial_scale,
+                    pressure: amount * scale,
+                    mole_fraction: amount / total_amount,
+                    moles: moles_given.then_some(amount),
+                }
+            })
+            .collect()
+    };
+    let conversion = if extent >= 0.0 {
+        extent / upper
+    } else {
+        0.0
+    };
+
+    let result = GasEquilibrium {
+        reactants: state(&input.reactants, -1.0),
+        products: state(&input.products, 1.0),
+        extent,
+        conversion,
+        initial_total_pressure: pressure,
+        total_pressure: total_amount * scale,
+        inert_pressure: input.inert * scale,
+        delta_n,
+        kp: input.kp,
+        constant: if constant_volume { "volume" } else { "pressure" }.to_string(),
+        pressure_unit: pressure_symbol.to_string(),
+    };
+
+    to_json(&result)
+}
+
+/// Species of a reaction mixture with its initial amount
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MixtureSpecies {
+    /// (formula, coefficient, initial amount in mol)
+    WithAmount(String, f64, f64),
+    /// (formula, coefficient); reactants start at their coefficient, products at zero
+    Stoichiometric(String, f64),
+}
+
+impl MixtureSpecies {
+    fn parts(&self, default_amount: bool) -> (&str, f64, f64) {
+        match self {
+            MixtureSpecies::WithAmount(name, nu, amount) => (name, *nu, *amount),
+            MixtureSpecies::Stoichiometric(name, nu) => {
+                (name, *nu, if default_amount { *nu } else { 0.0 })
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GibbsExtentInput {
+    /// ΔG° (kJ/mol)
+    delta_g: f64,
+    temperature: f64,
+    reactants: Vec<MixtureSpecies>,
+    products: Vec<MixtureSpecies>,
+    /// "ideal-gas" or "ideal-solution"
+    #[serde(default = "default_gibbs_mixture")]
+    mixture: String,
+    /// Total pressure of a gas mixture (bar)
+    #[serde(default = "default_gibbs_pressure")]
+    pressure: f64,
+    /// Inert gas or solvent (mol)
+    #[serde(default)]
+    inert: f64,
+    #[serde(default = "default_gibbs_points")]
+    points: usize,
+}
+
+fn default_gibbs_mixture() -> String {
+    "ideal-gas".to_string()
+}
+
+fn default_gibbs_pressure() -> f64 {
+    1.0
+}
+
+fn default_gibbs_points() -> usize {
+    101
+}
+
+/// Minimum of G(ξ)
+#[derive(Serialize)]
+struct GibbsMinimum {
+    extent: f64,
+    /// G at the minimum (kJ)
+    gibbs: f64,
+    /// Mole fractions at equilibrium, reactants then products
+    mole_fractions: Vec<(String, f64)>,
+}
+
+/// Gibbs energy of a reaction mixture along the extent of reaction
+#[derive(Serialize)]
+struct GibbsExtentCurve {
+    /// (ξ, G) with μ° of the reactants taken as zero (kJ)
+    points: Vec<(f64, f64)>,
+    /// (ξ, Σnᵢμᵢ) of the unmixed species, a straight line of slope ΔG° at P° (kJ)
+    unmixed: Vec<(f64, f64)>,
+    /// (ξ, ΔG_mix), always negative (kJ)
+    mixing: Vec<(f64, f64)>,
+    minimum: GibbsMinimum,
+    /// Range of ξ allowed by the amounts present (mol)
+    extent_range: [f64; 2],
+    /// ΔG° (kJ/mol)
+    delta_g: f64,
+    /// K = exp(-ΔG°/RT), with mole fractions and P/P° for gases
+    k: f64,
+    mixture: String,
+    unit: String,
+}
+
+/// Generate G(ξ) for an ideal reaction mixture and locate its minimum
+/// G(ξ) = Σnᵢ(μᵢ° + RT·ln aᵢ) with nᵢ = nᵢ° + νᵢ·ξ
+///
+/// Activities are aᵢ = xᵢ·P/P° for an ideal gas and aᵢ = xᵢ for an ideal solution. The
+/// unmixed species alone would make G fall linearly to the side with the lower μ°; the
+/// always negative mixing term pulls the minimum inside the range, where dG/dξ =
+/// ΔG° + RT·ln Q = 0, i.e. Q = K. ΔG° is the slope of the unmixed line, not zero, at
+/// equilibrium. The μ° of the reactants are set to zero, so only differences in G matter.
+///
+/// Arguments:
+/// - input_json: ΔG°, temperature, reaction with initial amounts and mixture, see
+///   `GibbsExtentInput`
+#[wasm_func]
+pub fn gibbs_extent_curve(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: GibbsExtentInput = parse_json(input_json, "Gibbs energy curve input")?;
+
+    check_temperature(input.temperature)?;
+    if input.points < 3 {
+        return Err("At least 3 points are required".to_string());
+    }
+    if input.reactants.is_empty() || input.products.is_empty() {
+        return Err("The reaction needs reactants and products".to_string());
+    }
+    let (mixture, pressure_ratio) = match input.mixture.as_str() {
+        "ideal-gas" | "gas" => {
+            check_positive("pressure", input.pressure)?;
+            ("ideal-gas", input.pressure)
+        }
+        "ideal-solution" | "solution" => ("ideal-solution", 1.0),
+        _ => {
+            return Err(format!(
+                "Unknown mixture: {} (expected ideal-gas or ideal-solution)",
+                input.mixture
+            ))
+        }
+    };
+    let species: Vec<(&str, f64, f64)> = input
+        .reactants
+        .iter()
+        .map(|s| {
+            let (name, nu, amount) = s.parts(true);
+            (name, -nu, amount)
+        })
+        .chain(input.products.iter().map(|s| s.parts(false)))
+        .collect();
+    for (name, nu, amount) in &species {
+        check_domain(&format!("coefficient of {}", name), *nu, *nu != 0.0, "non-zero")?;
+        check_non_negative(&format!("initial amount of {}", name), *amount)?;
+    }
+    check_non_negative("amount of inert species", input.inert)?;
+    let delta_n: f64 = species.iter().map(|(_, nu, _)| nu).sum();
+    let initial_total = species.iter().map(|(_, _, a)| a).sum::<f64>() + input.inert;
+
+    let upper = species
+        .iter()
+        .filter(|(_, nu, _)| *nu < 0.0)
+        .map(|(_, nu, a)| a / -nu)
+        .fold(f64::INFINITY, f64::min);
+    let lower = species
+        .iter()
+        .filter(|(_, nu, _)| *nu > 0.0)
+        .map(|(_, nu, a)| 0.0 - a / nu)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if upper <= lower {
+        return Err("The reaction cannot proceed in either direction from this mixture".to_string());
+    }
+
+    let rt = R * input.temperature / 1000.0; // kJ/mol
+    let n_at = |xi: f64| -> Vec<f64> {
+        species.iter().map(|(_, nu, a)| (a + nu * xi).max(0.0)).collect()
+    };
+    // n·ln x, with 0·ln 0 = 0
+    let n_ln_x = |n: f64, total: f64| if n > 0.0 { n * (n / total).ln() } else { 0.0 };
+    // Unmixed species at P: ξ·ΔG° + RT·Σnᵢ·ln(P/P°)
+    let unmixed = |xi: f64| {
+        let total = initial_total + delta_n * xi;
+        xi * input.delta_g + rt * (total - input.inert) * pressure_ratio.ln()
+    };
+    let mixing = |xi: f64| {
+        let amounts = n_at(xi);
+        let total = initial_total + delta_n * xi;
+        rt * (amounts.iter().map(|&n| n_ln_x(n, total)).sum::<f64>() + n_ln_x(input.inert, total))
+    };
+    // dG/dξ = ΔG° + RT·ln Q, increasing in ξ
+    let slope = |xi: f64| {
+        let amounts = n_at(xi);
+        let total = initial_total + delta_n * xi;
+        input.delta_g
+            + rt * species
+                .iter()
+                .zip(&amounts)
+                .map(|((_, nu, _), n)| nu * (n / total * pressure_ratio).ln())
+                .sum::<f64>()
+    };
+    let (mut lo, mut hi) = (lower, upper);
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if slope(mid) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let extent = 0.5 * (lo + hi);
+
+    let mut points = Vec::with_capacity(input.points);
+    let mut unmixed_points = Vec::with_capacity(input.points);
+    let mut mixing_points = Vec::with_capacity(input.points);
+    for i in 0..input.points {
+        let xi = lower + (upper - lower) * i as f64 / (input.points - 1) as f64;
+        let (g_unmixed, g_mixing) = (unmixed(xi), mixing(xi));
+        points.push((xi, g_unmixed + g_mixing));
+        unmixed_points.push((xi, g_unmixed));
+        mixing_points.push((xi, g_mixing));
+    }
+    let total = initial_total + delta_n * extent;
+    let minimum = GibbsMinimum {
+        extent,
+        gibbs: unmixed(extent) + mixing(extent),
+        mole_fractions: species
+            .iter()
+            .zip(n_at(extent))
+            .map(|((name, _, _), n)| (name.to_string(), n / total))
+            .collect(),
+    };
+
+    let result = GibbsExtentCurve {
+        points,
+        unmixed: unmixed_points,
+        mixing: mixing_points,
+        minimum,
+        extent_range: [lower, upper],
+        delta_g: input.delta_g,
+        k: (-input.delta_g / rt).exp(),
+        mixture: mixture.to_string(),
+        unit: "kJ".to_string(),
+    };
+
+    to_json(&result)
+}
+
+/// Molar heat capacity of an ideal gas: Cv,m (J/(mol·K)) or a molecule type
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdealHeatCapacity {
+    Value(f64),
+    /// "monatomic" (3/2 R), "diatomic"/"linear" (5/2 R) or "nonlinear" (3 R)
+    Kind(String),
+}
+
+impl IdealHeatCapacity {
+    /// Cv,m (J/(mol·K)) with translational and rotational degrees of freedom only
+    fn cv(&self, r: f64) -> Result<f64, String> {
+        let cv = match self {
+            IdealHeatCapacity::Value(cv) => *cv,
+            IdealHeatCapacity::Kind(kind) => match kind.as_str() {
+                "monatomic" => 1.5 * r,
+                "diatomic" | "linear" => 2.5 * r,
+                "nonlinear" => 3.0 * r,
+                _ => {
+                    return Err(format!(
+                        "Unknown gas type: {} (expected monatomic, diatomic, linear or nonlinear)",
+                        kind
+                    ))
+                }
+            },
+        };
+        check_positive("heat capacity", cv)?;
+        Ok(cv)
+    }
+}
+
+fn default_heat_capacity() -> IdealHeatCapacity {
+    IdealHeatCapacity::Kind("monatomic".to_string())
+}
+
+fn default_moles() -> f64 {
+    1.0
+}
+
+/// Ideal-gas process from an initial state (T and P or V) to a final state
+#[derive(Deserialize)]
+struct GasProcessInput {
+    /// "isothermal-reversible", "isothermal-irreversible", "adiabatic-reversible",
+    /// "isobaric" or "isochoric"
+    process: String,
+    #[serde(default = "default_moles")]
+    moles: f64,
+    /// Initial temperature (K)
+    temperature: f64,
+    initial_pressure: Option<f64>,
+    initial_volume: Option<f64>,
+    final_pressure: Option<f64>,
+    final_volume: Option<f64>,
+    /// Final temperature (K)
+    final_temperature: Option<f64>,
+    /// Opposing pressure of an irreversible isothermal step (default: final pressure)
+    external_pressure: Option<f64>,
+    #[serde(default = "default_heat_capacity")]
+    cv: IdealHeatCapacity,
+    #[serde(default)]
+    pressure_unit: String,
+    #[serde(default)]
+    volume_unit: String,
+}
+
+/// State of an ideal gas sample
+#[derive(Serialize)]
+struct GasPoint {
+    temperature: CalculationResult,
+    pressure: CalculationResult,
+    volume: CalculationResult,
+}
+
+/// Energy balance of an ideal-gas process
+#[derive(Serialize)]
+struct GasProcessResult {
+    process: String,
+    /// Work done on the gas (J)
+    work: CalculationResult,
+    /// Heat absorbed by the gas (J)
+    heat: CalculationResult,
+    internal_energy: CalculationResult,
+    enthalpy: CalculationResult,
+    /// Entropy change of the gas (J/K)
+    entropy: CalculationResult,
+    initial: GasPoint,
+    #[serde(rename = "final")]
+    final_state: GasPoint,
+    /// Cv,m and Cp,m used (J/(mol·K))
+    cv: f64,
+    cp: f64,
+}
+
+/// Calculate w, q, ΔU, ΔH and ΔS of an ideal-gas process
+///
+/// Work is done on the gas (w < 0 for an expansion) and ΔU = q + w:
+/// - isothermal-reversible: w = -nRT·ln(V2/V1), ΔU = 0
+/// - isothermal-irreversible: w = -P_ext·(V2 - V1), ΔU = 0
+/// - adiabatic-reversible: T·V^(R/Cv) constant, q = 0, w = ΔU = n·Cv·ΔT
+/// - isobaric: q = n·Cp·ΔT, w = -nR·ΔT
+/// - isochoric: q = ΔU = n·Cv·ΔT, w = 0
+///
+/// ΔS = n·Cv·ln(T2/T1) + nR·ln(V2/V1) for every path.
+///
+/// Arguments:
+/// - input_json: Process, see `GasProcessInput`
+#[wasm_func]
+pub fn ideal_gas_process(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: GasProcessInput = parse_json(input_json, "gas process input")?;
+
+    let (pa_per_unit, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let (litres_per_unit, volume_symbol) = parse_volume_unit(&input.volume_unit)?;
+    let to_pa = |p: Option<f64>| p.map(|p| p * pa_per_unit);
+    let to_m3 = |v: Option<f64>| v.map(|v| v * litres_per_unit / 1000.0);
+
+    let n = input.moles;
+    let t1 = input.temperature;
+    let cv = input.cv.cv(R)?;
+    let cp = cv + R;
+    let values = [input.initial_pressure, input.initial_volume, input.final_pressure]
+        .into_iter()
+        .chain([input.final_volume, input.final_temperature, input.external_pressure]);
+    check_positive("amount", n)?;
+    check_temperature(t1)?;
+    let fields = [
+        "initial pressure",
+        "initial volume",
+        "final pressure",
+        "final volume",
+        "final temperature",
+        "external pressure",
+    ];
+    for (field, value) in fields.into_iter().zip(values) {
+        if let Some(value) = value {
+            check_positive(field, value)?;
+        }
+    }
+
+    // SI values: Pa, m³, K
+    let (p1, v1) = match (to_pa(input.initial_pressure), to_m3(input.initial_volume)) {
+        (Some(p), None) => (p, n * R * t1 / p),
+        (None, Some(v)) => (n * R * t1 / v, v),
+        _ => return Err("Give exactly one of initial pressure and initial volume".to_string()),
+    };
+    let targets = (
+        to_pa(input.final_pressure),
+        to_m3(input.final_volume),
+        input.final_temperature,
+    );
+    let process = input.process.as_str();
+    let one_target = |allowed: &str| {
+        format!("The {} process needs exactly one of {}", process, allowed)
+    };
+
+    // Final temperature and volume
+    let (t2, v2) = match process {
+        "isothermal-reversible" | "isothermal-irreversible" => match targets {
+            (Some(p), None, None) => (t1, n * R * t1 / p),
+            (None, Some(v), None) => (t1, v),
+            _ => return Err(one_target("final pressure and final volume")),
+        },
+        "adiabatic-reversible" => match targets {
+            (Some(p), None, None) => {
+                let t2 = t1 * (p / p1).powf(R / cp);
+                (t2, n * R * t2 / p)
+            }
+            (None, Some(v), None) => (t1 * (v1 / v).powf(R / cv), v),
+            (None, None, Some(t)) => (t, v1 * (t1 / t).powf(cv / R)),
+            _ => return Err(one_target("final pressure, volume and temperature")),
+        },
+        "isobaric" => match targets {
+            (None, Some(v), None) => (t1 * v / v1, v),
+            (None, None, Some(t)) => (t, v1 * t / t1),
+            _ => return Err(one_target("final volume and final temperature")),
+        },
+        "isochoric" => match targets {
+            (Some(p), None, None) => (t1 * p / p1, v1),
+            (None, None, Some(t)) => (t, v1),
+            _ => return Err(one_target("final pressure and final temperature")),
+        },
+        _ => {
+            return Err(format!(
+                "Unknown process: {} (expected isothermal-reversible, isothermal-irreversible, \
+                 adiabatic-reversible, isobaric or isochoric)",
+                process
+            ))
+        }
+    };
+    let p2 = n * R * t2 / v2;
+
+    let delta_u = n * cv * (t2 - t1);
+    let work = match process {
+        "isothermal-reversible" => -n * R * t1 * (v2 / v1).ln(),
+        "isothermal-irreversible" => {
+            let external = to_pa(input.external_pressure).unwrap_or(p2);
+            -external * (v2 - v1)
+        }
+        "adiabatic-reversible" => delta_u,
+        "isobaric" => -p1 * (v2 - v1),
+        _ => 0.0,
+    };
+    let entropy = if process == "adiabatic-reversible" {
+        0.0
+    } else {
+        n * cv * (t2 / t1).ln() + n * R * (v2 / v1).ln()
+    };
+
+    let point = |t: f64, p: f64, v: f64| GasPoint {
+        temperature: CalculationResult::new(t, "K"),
+        pressure: CalculationResult::new(p / pa_per_unit, pressure_symbol),
+        volume: CalculationResult::new(v * 1000.0 / litres_per_unit, volume_symbol),
+    };
+    let result = GasProcessResult {
+        process: input.process.clone(),
+        work: CalculationResult::new(work, "J"),
+        heat: CalculationResult::new(delta_u - work, "J"),
+        internal_energy: CalculationResult::new(delta_u, "J"),
+        enthalpy: CalculationResult::new(n * cp * (t2 - t1), "J"),
+        entropy: CalculationResult::new(entropy, "J/K"),
+        initial: point(t1, p1, v1),
+        final_state: point(t2, p2, v2),
+        cv,
+        cp,
+    };
+
+    to_json(&result)
+}
+
+/// Reversible adiabatic step from T1 (and optionally P1) to one final condition
+#[derive(Deserialize)]
+struct AdiabaticInput {
+    /// Initial temperature (K)
+    temperature: f64,
+    initial_pressure: Option<f64>,
+    final_pressure: Option<f64>,
+    /// V2/V1
+    volume_ratio: Option<f64>,
+    /// Final temperature (K)
+    final_temperature: Option<f64>,
+    /// Heat capacity ratio Cp/Cv
+    gamma: Option<f64>,
+    /// Cp,m (J/(mol·K))
+    cp: Option<f64>,
+    /// Active degrees of freedom f, giving Cv,m = f·R/2
+    degrees_of_freedom: Option<f64>,
+    /// "monatomic", "diatomic"/"linear" or "nonlinear"
+    gas_type: Option<String>,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+/// Final state of a reversible adiabatic step
+#[derive(Serialize)]
+struct AdiabaticState {
+    gamma: f64,
+    /// Cv,m and Cp,m (J/(mol·K))
+    cv: f64,
+    cp: f64,
+    final_temperature: CalculationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_pressure: Option<CalculationResult>,
+    temperature_ratio: f64,
+    pressure_ratio: f64,
+    volume_ratio: f64,
+    /// Molar work done on the gas, Cv·ΔT (J/mol)
+    work: CalculationResult,
+}
+
+/// Solve a reversible adiabatic expansion or compression of an ideal gas
+/// T2/T1 = (V1/V2)^(γ-1) = (P2/P1)^((γ-1)/γ)
+///
+/// γ comes from exactly one of: γ itself, Cp,m (Cv = Cp - R), the degrees of freedom
+/// (Cv = fR/2) or a gas type (monatomic γ = 5/3, diatomic 7/5, nonlinear 4/3); with none
+/// given the gas is monatomic.
+///
+/// Arguments:
+/// - input_json: Initial state and target, see `AdiabaticInput`
+#[wasm_func]
+pub fn adiabatic_state(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: AdiabaticInput = parse_json(input_json, "adiabatic input")?;
+
+    let (_, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let t1 = input.temperature;
+    let values = [input.initial_pressure, input.final_pressure, input.volume_ratio]
+        .into_iter()
+        .chain([input.final_temperature, input.cp, input.degrees_of_freedom]);
+    check_temperature(t1)?;
+    let fields = [
+        "initial pressure",
+        "final pressure",
+        "volume ratio",
+        "final temperature",
+        "Cp",
+        "degrees of freedom",
+    ];
+    for (field, value) in fields.into_iter().zip(values) {
+        if let Some(value) = value {
+            check_positive(field, value)?;
+        }
+    }
+
+    let cv = match (input.gamma, input.cp, input.degrees_of_freedom, &input.gas_type) {
+        (Some(gamma), None, None, None) => {
+            check_domain("heat capacity ratio", gamma, gamma > 1.0, "above 1")?;
+            R / (gamma - 1.0)
+        }
+        (None, Some(cp), None, None) => {
+            check_domain("Cp", cp, cp > R, &format!("above R = {:.3} J/(mol·K)", R))?;
+            cp - R
+        }
+        (None, None, Some(f), None) => f * R / 2.0,
+        (None, None, None, Some(kind)) => IdealHeatCapacity::Kind(kind.clone()).cv(R)?,
+        (None, None, None, None) => 1.5 * R,
+        _ => {
+            return Err(
+                "Give only one of gamma, Cp, degrees of freedom and gas type".to_string(),
+            )
+        }
+    };
+    let cp = cv + R;
+    let gamma = cp / cv;
+
+    let target = (input.final_pressure, input.volume_ratio, input.final_temperature);
+    let temperature_ratio = match target {
+        (Some(p2), None, None) => {
+            let p1 = input
+                .initial_pressure
+                .ok_or("A final pressure needs the initial pressure")?;
+            (p2 / p1).powf((gamma - 1.0) / gamma)
+        }
+        (None, Some(ratio), None) => ratio.powf(1.0 - gamma),
+        (None, None, Some(t2)) => t2 / t1,
+        _ => {
+            return Err(
+                "Give exactly one of final pressure, volume ratio and final temperature"
+                    .to_string(),
+            )
+        }
+    };
+    let pressure_ratio = temperature_ratio.powf(gamma / (gamma - 1.0));
+    let t2 = t1 * temperature_ratio;
+
+    let result = AdiabaticState {
+        gamma,
+        cv,
+        cp,
+        final_temperature: CalculationResult::new(t2, "K"),
+        final_pressure: input
+            .initial_pressure
+            .map(|p1| CalculationResult::new(p1 * pressure_ratio, pressure_symbol)),
+        temperature_ratio,
+        pressure_ratio,
+        volume_ratio: temperature_ratio.powf(-1.0 / (gamma - 1.0)),
+        work: CalculationResult::new(cv * (t2 - t1), "J/mol"),
+    };
+
+    to_json(&result)
+}
+
+/// Throttling of a van der Waals gas
+#[derive(Deserialize)]
+struct JouleThomsonInput {
+    gas: GasSpec,
+    temperature: f64,
+    /// Cp,m (J/(mol·K))
+    cp: Option<f64>,
+    /// "monatomic", "diatomic"/"linear" or "nonlinear", used when Cp is not given
+    gas_type: Option<String>,
+    /// Pressure change P_final - P_initial of a throttling step
+    pressure_change: Option<f64>,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+/// Joule–Thomson coefficient, inversion temperature and throttling temperature change
+#[derive(Serialize)]
+struct JouleThomsonResult {
+    gas: String,
+    /// μ_JT = (∂T/∂P)_H in K per pressure unit
+    value: f64,
+    unit: String,
+    inversion_temperature: CalculationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature_change: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_temperature: Option<CalculationResult>,
+    /// van der Waals constants (L²·bar/mol², L/mol) and Cp,m (J/(mol·K)) used
+    a: f64,
+    b: f64,
+    cp: f64,
+}
+
+/// Estimate the Joule–Thomson coefficient of a van der Waals gas
+/// μ_JT ≈ (2a/(RT) - b)/Cp,m, T_inv = 2a/(Rb)
+///
+/// The temperature change of a throttling step integrates dT/dP = μ_JT(T) over the pressure
+/// change, so μ follows the gas as it cools or warms. The constants come from the table
+/// (tabulated a and b, or Tc and Pc).
+///
+/// Arguments:
+/// - input_json: Gas and conditions, see `JouleThomsonInput`
+/// - data_json: Critical constant table
+#[wasm_func]
+pub fn joule_thomson(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: JouleThomsonInput = parse_json(input_json, "Joule–Thomson input")?;
+    let data: HashMap<String, GasData> = parse_json(data_json, "critical constant data")?;
+
+    let (pa_per_unit, pressure_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+    let bar_per_unit = pa_per_unit / 1e5;
+    let (label, gas) = input.gas.resolve(&data)?;
+    let t = input.temperature;
+    check_temperature(t)?;
+    let cp = match (input.cp, &input.gas_type) {
+        (Some(cp), None) => {
+            check_positive("Cp", cp)?;
+            cp
+        }
+        (None, Some(kind)) => IdealHeatCapacity::Kind(kind.clone()).cv(R)? + R,
+        _ => return Err("Give exactly one of Cp and gas type".to_string()),
+    };
+    let eos = CubicEos::new("vdw", &label, &gas, t)?;
+    let (a, b) = (eos.a, eos.b);
+
+    // K/bar: (L/mol)·(100 J/(L·bar))/(J/(mol·K))
+    let mu = |t: f64| (2.0 * a / (R_LITRE_BAR * t) - b) * 100.0 / cp;
+
+    let final_temperature = match input.pressure_change {
+        Some(dp) => {
+            let steps = 200;
+            let h = dp * bar_per_unit / steps as f64;
+            let mut temperature = t;
+            for _ in 0..steps {
+                let k1 = mu(temperature);
+                let k2 = mu(temperature + 0.5 * h * k1);
+                let k3 = mu(temperature + 0.5 * h * k2);
+                let k4 = mu(temperature + h * k3);
+                temperature += h * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
+                if temperature <= 0.0 {
+                    return Err("Throttling would cool the gas below 0 K".to_string());
+                }
+            }
+            Some(temperature)
+        }
+        None => None,
+    };
+
+    let result = JouleThomsonResult {
+        gas: label,
+        value: mu(t) * bar_per_unit,
+        unit: format!("K/{}", pressure_symbol),
+        inversion_temperature: CalculationResult::new(2.0 * a / (R_LITRE_BAR * b), "K"),
+        temperature_change: final_temperature.map(|tf| CalculationResult::new(tf - t, "K")),
+        final_temperature: final_temperature.map(|tf| CalculationResult::new(tf, "K")),
+        a,
+        b,
+        cp,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// HEAT ENGINES
+// ============================================================================
+
+/// Check that a hot reservoir is hotter than a positive cold reservoir
+fn check_reservoirs(t_hot: f64, t_cold: f64) -> Result<(), String> {
+    check_temperature_of("cold reservoir temperature", t_cold)?;
+    check_domain(
+        "hot reservoir temperature",
+        t_hot,
+        t_hot > t_cold,
+        "above the cold reservoir temperature",
+    )?;
+    Ok(())
+}
+
+/// Reversible heat engine between two reservoirs
+#[derive(Serialize)]
+struct CarnotResult {
+    /// Carnot efficiency 1 - Tc/Th
+    value: f64,
+    unit: String,
+    /// Maximum work from the heat absorbed (J)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    work: Option<f64>,
+    /// Heat rejected to the cold reservoir at maximum work (J)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heat_rejected: Option<f64>,
+    /// Actual efficiency over Carnot efficiency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    second_law_efficiency: Option<f64>,
+}
+
+/// Calculate the Carnot efficiency of a heat engine
+/// η = 1 - Tc/Th
+///
+/// Arguments:
+/// - t_hot: Hot reservoir temperature (K)
+/// - t_cold: Cold reservoir temperature (K)
+/// - heat: Heat absorbed from the hot reservoir (J, 0 to skip)
+/// - actual: Actual efficiency of the engine (0 to skip)
+#[wasm_func]
+pub fn carnot_efficiency(
+    t_hot_bytes: &[u8],
+    t_cold_bytes: &[u8],
+    heat_bytes: &[u8],
+    actual_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let t_hot = parse_number(t_hot_bytes, "hot reservoir temperature")?;
+    let t_cold = parse_number(t_cold_bytes, "cold reservoir temperature")?;
+    let heat = parse_number(heat_bytes, "heat")?;
+    let actual = parse_number(actual_bytes, "actual efficiency")?;
+
+    check_reservoirs(t_hot, t_cold)?;
+    let efficiency = 1.0 - t_cold / t_hot;
+    check_domain(
+        "actual efficiency",
+        actual,
+        (0.0..=efficiency).contains(&actual),
+        &format!("between 0 and the Carnot limit {:.4}", efficiency),
+    )?;
+    let heat = (heat != 0.0).then_some(heat);
+
+    let result = CarnotResult {
+        value: efficiency,
+        unit: String::new(),
+        work: heat.map(|q| efficiency * q),
+        heat_rejected: heat.map(|q| q * t_cold / t_hot),
+        second_law_efficiency: (actual > 0.0).then_some(actual / efficiency),
+    };
+
+    to_json(&result)
+}
+
+/// Coefficient of performance of a refrigerator or heat pump
+#[derive(Serialize)]
+struct PerformanceResult {
+    /// Reversible (Carnot) COP
+    value: f64,
+    unit: String,
+    /// "refrigerator" or "heat-pump"
+    mode: String,
+    /// Heat extracted (refrigerator) or delivered (heat pump) for the work input (J)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heat: Option<f64>,
+    /// Actual COP over Carnot COP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    second_law_efficiency: Option<f64>,
+}
+
+/// Calculate the reversible coefficient of performance
+/// Refrigerator: COP = Tc/(Th - Tc); heat pump: COP = Th/(Th - Tc)
+///
+/// Arguments:
+/// - t_hot: Hot reservoir temperature (K)
+/// - t_cold: Cold reservoir temperature (K)
+/// - mode: "refrigerator" or "heat-pump"
+/// - work: Work input (J, 0 to skip)
+/// - actual: Actual COP of the device (0 to skip)
+#[wasm_func]
+pub fn coefficient_of_performance(
+    t_hot_bytes: &[u8],
+    t_cold_bytes: &[u8],
+    mode_bytes: &[u8],
+    work_bytes: &[u8],
+    actual_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let t_hot = parse_number(t_hot_bytes, "hot reservoir temperature")?;
+    let t_cold = parse_number(t_cold_bytes, "cold reservoir temperature")?;
+    let mode: String = parse_arg(mode_bytes, "mode")?;
+    let work = parse_number(work_bytes, "work")?;
+    let actual = parse_number(actual_bytes, "actual COP")?;
+
+    check_reservoirs(t_hot, t_cold)?;
+    let cop = match mode.as_str() {
+        "refrigerator" => t_cold / (t_hot - t_cold),
+        "heat-pump" => t_hot / (t_hot - t_cold),
+        _ => {
+            return Err(format!(
+                "Unknown device: {} (expected refrigerator or heat-pump)",
+                mode
+            ))
+        }
+    };
+    check_domain(
+        "actual COP",
+        actual,
+        (0.0..=cop).contains(&actual),
+        &format!("between 0 and the Carnot limit {:.4}", cop),
+    )?;
+
+    let result = PerformanceResult {
+        value: cop,
+        unit: String::new(),
+        mode,
+        heat: (work != 0.0).then_some(cop * work),
+        second_law_efficiency: (actual > 0.0).then_some(actual / cop),
+    };
+
+    to_json(&result)
+}
+
+/// Entropy balance of heat flowing from a hot to a cold reservoir
+#[derive(Serialize)]
+struct EntropyGeneration {
+    /// Total entropy generated, q/Tc - q/Th (J/K)
+    value: f64,
+    unit: String,
+    /// Entropy change of the hot reservoir, -q/Th (J/K)
+    hot_reservoir: f64,
+    /// Entropy change of the cold reservoir, q/Tc (J/K)
+    cold_reservoir: f64,
+    /// Work lost compared with a Carnot engine, T0·S_gen (J)
+    lost_work: f64,
+}
+
+/// Calculate the entropy generated by irreversible heat transfer between two reservoirs
+/// S_gen = q·(1/Tc - 1/Th)
+///
+/// Arguments:
+/// - heat: Heat transferred from the hot to the cold reservoir (J)
+/// - t_hot: Hot reservoir temperature (K)
+/// - t_cold: Cold reservoir temperature (K)
+/// - t_surroundings: Dead-state temperature T0 for the lost work (K, 0 for Tc)
+#[wasm_func]
+pub fn heat_transfer_entropy(
+    heat_bytes: &[u8],
+    t_hot_bytes: &[u8],
+    t_cold_bytes: &[u8],
+    t_surroundings_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let heat = parse_number(heat_bytes, "heat")?;
+    let t_hot = parse_number(t_hot_bytes, "hot reservoir temperature")?;
+    let t_cold = parse_number(t_cold_bytes, "cold reservoir temperature")?;
+    let t_surroundings = parse_number(t_surroundings_bytes, "surroundings temperature")?;
+
+    check_reservoirs(t_hot, t_cold)?;
+    check_non_negative("heat", heat)?;
+    check_non_negative("surroundings temperature", t_surroundings)?;
+    let t0 = if t_surroundings > 0.0 { t_surroundings } else { t_cold };
+    let generated = heat / t_cold - heat / t_hot;
+
+    let result = EntropyGeneration {
+        value: generated,
+        unit: "J/K".to_string(),
+        hot_reservoir: -heat / t_hot,
+        cold_reservoir: heat / t_cold,
+        lost_work: t0 * generated,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// STATISTICAL THERMODYNAMICS
+// ============================================================================
+
+fn default_symmetry_number() -> f64 {
+    1.0
+}
+
+fn default_electronic_levels() -> Vec<(f64, f64)> {
+    vec![(0.0, 1.0)]
+}
+
+/// Molecular constants of an ideal-gas species
+#[derive(Deserialize)]
+struct Molecule {
+    /// Label used in reaction results
+    #[serde(default)]
+    name: String,
+    /// Molar mass (g/mol) or formula
+    mass: MolarMassSpec,
+    /// Rotational constants (cm⁻¹): none for an atom, [B] for a linear molecule,
+    /// [A, B, C] for a nonlinear one
+    #[serde(default)]
+    rotational: Vec<f64>,
+    /// Rotational symmetry number σ
+    #[serde(default = "default_symmetry_number")]
+    symmetry: f64,
+    /// Harmonic vibrational wavenumbers (cm⁻¹)
+    #[serde(default)]
+    vibrations: Vec<f64>,
+    /// Electronic levels as (energy in cm⁻¹, degeneracy), ground level first
+    #[serde(default = "default_electronic_levels")]
+    electronic: Vec<(f64, f64)>,
+}
+
+impl Molecule {
+    /// Check the constants and return the molar mass (g/mol)
+    fn validate(&self) -> Result<f64, String> {
+        if ![0, 1, 3].contains(&self.rotational.len()) {
+            return Err(
+                "Give no rotational constant (atom), one (linear) or three (nonlinear)"
+                    .to_string(),
+            );
+        }
+        for b in &self.rotational {
+            check_positive("rotational constant", *b)?;
+        }
+        for wavenumber in &self.vibrations {
+            check_positive("vibrational wavenumber", *wavenumber)?;
+        }
+        check_positive("symmetry number", self.symmetry)?;
+        if self.electronic.is_empty() {
+            return Err("Give at least one electronic level".to_string());
+        }
+        for &(energy, degeneracy) in &self.electronic {
+            check_non_negative("electronic energy", energy)?;
+            check_positive("electronic degeneracy", degeneracy)?;
+        }
+        self.mass.value()
+    }
+
+    /// Translational partition function per molecule in the volume kT/P (P in Pa)
+    fn q_translational(mass: f64, temperature: f64, pressure: f64) -> f64 {
+        let m = mass / 1000.0 / AVOGADRO;
+        let lambda = PLANCK / (2.0 * std::f64::consts::PI * m * BOLTZMANN * temperature).sqrt();
+        BOLTZMANN * temperature / pressure / lambda.powi(3)
+    }
+
+    /// High-temperature rotational partition function
+    fn q_rotational(&self, temperature: f64) -> f64 {
+        let x = temperature / HC_OVER_K;
+        match self.rotational.as_slice() {
+            [b] => x / (self.symmetry * b),
+            [a, b, c] => {
+                std::f64::consts::PI.sqrt() / self.symmetry * x.powf(1.5) / (a * b * c).sqrt()
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Harmonic vibrational partition function with the zero of energy at the ground state
+    fn q_vibrational(&self, temperature: f64) -> f64 {
+        self.vibrations
+            .iter()
+            .map(|nu| 1.0 / (1.0 - (-HC_OVER_K * nu / temperature).exp()))
+            .product()
+    }
+
+    /// Harmonic zero-point energy Σ hcṽ/2 (kJ/mol)
+    fn zero_point_energy(&self) -> f64 {
+        0.5 * self.vibrations.iter().sum::<f64>() * HC_OVER_K * BOLTZMANN * AVOGADRO / 1000.0
+    }
+
+    /// Electronic partition function and mean electronic energy over kT
+    fn q_electronic(&self, temperature: f64) -> (f64, f64) {
+        let (q, energy) = self.electronic.iter().fold((0.0, 0.0), |(q, energy), &(e, g)| {
+            let x = HC_OVER_K * e / temperature;
+            let weight = g * (-x).exp();
+            (q + weight, energy + x * weight)
+        });
+        (q, energy / q)
+    }
+}
+
+/// Molar entropy by mode of motion, J/(mol·K)
+#[derive(Serialize)]
+struct StatisticalEntropy {
+    /// Total molar entropy
+    value: f64,
+    unit: String,
+    /// Sackur–Tetrode translational entropy
+    translational: f64,
+    rotational: f64,
+    vibrational: f64,
+    electronic: f64,
+    temperature: f64,
+    /// Standard-state pressure used for the translational term (bar)
+    pressure: f64,
+}
+
+/// Calculate the molar entropy of an ideal gas from molecular constants
+///
+/// Translational (Sackur–Tetrode): S = R·[ln(kT/(P·Λ³)) + 5/2], Λ = h/√(2πmkT)
+/// Rotational: S = R·(ln q_rot + 1) for linear, R·(ln q_rot + 3/2) for nonlinear molecules
+/// Vibrational: S = R·Σ[x/(eˣ - 1) - ln(1 - e⁻ˣ)], x = hcṽ/(kT)
+/// Electronic: S = R·(ln q_el + ⟨ε⟩/(kT))
+///
+/// Arguments:
+/// - molecule_json: Molecular constants, see `Molecule`
+/// - T: Temperature (K)
+/// - P: Pressure (pressure unit)
+/// - pressure_unit: Unit of P
+#[wasm_func]
+pub fn statistical_entropy(
+    molecule_json: &[u8],
+    temperature_bytes: &[u8],
+    pressure_bytes: &[u8],
+    pressure_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molecule: Molecule = parse_json(molecule_json, "molecule")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let pressure = parse_number(pressure_bytes, "pressure")?;
+    let pressure_unit: String = parse_arg(pressure_unit_bytes, "pressure unit")?;
+
+    let mass = molecule.validate()?;
+    check_temperature(temperature)?;
+    check_positive("pressure", pressure)?;
+    let (pa_per_unit, _) = parse_pressure_unit(&pressure_unit)?;
+    let pressure_pa = pressure * pa_per_unit;
+
+    let translational =
+        R * (Molecule::q_translational(mass, temperature, pressure_pa).ln() + 2.5);
+    let rotational = match molecule.rotational.len() {
+        0 => 0.0,
+        1 => R * (molecule.q_rotational(temperature).ln() + 1.0),
+        _ => R * (molecule.q_rotational(temperature).ln() + 1.5),
+    };
+    let mut vibrational = 0.0;
+    for nu in &molecule.vibrations {
+        let x = HC_OVER_K * nu / temperature;
+        vibrational += R * (x / x.exp_m1() - (-(-x).exp()).ln_1p());
+    }
+    let (q_el, mean_energy) = molecule.q_electronic(temperature);
+    let electronic = R * (q_el.ln() + mean_energy);
+
+    let result = StatisticalEntropy {
+        value: translational + rotational + vibrational + electronic,
+        unit: "J/(mol·K)".to_string(),
+        translational,
+        rotational,
+        vibrational,
+        electronic,
+        temperature,
+        pressure: pressure_pa / 1e5,
+    };
+
+    to_json(&result)
+}
+
+/// Molecular partition functions at one temperature
+#[derive(Serialize)]
+struct PartitionFunctions {
+    /// Translational q in the molar volume kT/P of one molecule
+    translational: f64,
+    /// Translational q per unit volume, 1/Λ³ (m⁻³)
+    translational_per_volume: f64,
+    rotational: f64,
+    /// Vibrational q with the zero of energy at the ground state
+    vibrational: f64,
+    electronic: f64,
+    /// Product of all modes
+    total: f64,
+    /// Characteristic rotational temperatures hcB/k (K)
+    rotational_temperatures: Vec<f64>,
+    /// Characteristic vibrational temperatures hcṽ/k (K)
+    vibrational_temperatures: Vec<f64>,
+    /// Zero-point energy of the vibrations (kJ/mol)
+    zero_point_energy: f64,
+    temperature: f64,
+}
+
+/// Calculate molecular partition functions by mode of motion
+///
+/// q_trans = kT/(P·Λ³) with Λ = h/√(2πmkT); q_rot = kT/(σhcB) for linear molecules and
+/// (√π/σ)·(kT/hc)^(3/2)/√(ABC) for nonlinear ones (high-temperature limit);
+/// q_vib = Π 1/(1 - e^(-hcṽ/kT)) measured from the vibrational ground state;
+/// q_el = Σ g·e^(-hcε/kT).
+///
+/// Arguments:
+/// - molecule_json: Molecular constants, see `Molecule`
+/// - T: Temperature (K)
+/// - P: Pressure defining the translational volume kT/P (pressure unit)
+/// - pressure_unit: Unit of P
+#[wasm_func]
+pub fn partition_functions(
+    molecule_json: &[u8],
+    temperature_bytes: &[u8],
+    pressure_bytes: &[u8],
+    pressure_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let molecule: Molecule = parse_json(molecule_json, "molecule")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let pressure = parse_number(pressure_bytes, "pressure")?;
+    let pressure_unit: String = parse_arg(pressure_unit_bytes, "pressure unit")?;
+
+    let mass = molecule.validate()?;
+    check_temperature(temperature)?;
+    check_positive("pressure", pressure)?;
+    let (pa_per_unit, _) = parse_pressure_unit(&pressure_unit)?;
+    let pressure_pa = pressure * pa_per_unit;
+
+    let translational = Molecule::q_translational(mass, temperature, pressure_pa);
+    let rotational = molecule.q_rotational(temperature);
+    let vibrational = molecule.q_vibrational(temperature);
+    let (electronic, _) = molecule.q_electronic(temperature);
+
+    let result = PartitionFunctions {
+        translational,
+        translational_per_volume: translational * pressure_pa / (BOLTZMANN * temperature),
+        rotational,
+        vibrational,
+        electronic,
+        total: translational * rotational * vibrational * electronic,
+        rotational_temperatures: molecule.rotational.iter().map(|b| HC_OVER_K * b).collect(),
+        vibrational_temperatures: molecule.vibrations.iter().map(|nu| HC_OVER_K * nu).collect(),
+        zero_point_energy: molecule.zero_point_energy(),
+        temperature,
+    };
+
+    to_json(&result)
+}
+
+/// Standard molar partition function of one reaction species
+#[derive(Serialize)]
+struct SpeciesPartition {
+    name: String,
+    coefficient: f64,
+    /// q°/NA at the standard pressure
+    molar_partition_function: f64,
+}
+
+/// Equilibrium constant from statistical thermodynamics
+#[derive(Serialize)]
+struct StatisticalEquilibrium {
+    value: f64,
+    unit: String,
+    ln_k: f64,
+    /// ΔG° = -RT·ln K (kJ/mol), for comparison with thermochemical data
+    delta_g: f64,
+    /// Π (q°/NA)^ν
+    partition_factor: f64,
+    /// exp(-ΔE0/RT)
+    energy_factor: f64,
+    species: Vec<SpeciesPartition>,
+}
+
+/// Calculate the equilibrium constant of a gas reaction from partition functions
+/// K = Π (q°_m/NA)^ν · exp(-ΔE0/RT)
+///
+/// Standard molar partition functions use the standard pressure and measure vibrations
+/// from their ground states, so ΔE0 is the difference of the ground-state energies
+/// (products minus reactants, zero-point energies included).
+///
+/// Arguments:
+/// - reactants_json: Array of (molecule, coefficient)
+/// - products_json: Array of (molecule, coefficient)
+/// - delta_e0: ΔE0 (kJ/mol)
+/// - T: Temperature (K)
+/// - P_standard: Standard pressure (bar)
+#[wasm_func]
+pub fn equilibrium_constant_statistical(
+    reactants_json: &[u8],
+    products_json: &[u8],
+    delta_e0_bytes: &[u8],
+    temperature_bytes: &[u8],
+    standard_pressure_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let reactants: Vec<(Molecule, f64)> = parse_json(reactants_json, "reactants")?;
+    let products: Vec<(Molecule, f64)> = parse_json(products_json, "products")?;
+    let delta_e0 = parse_number(delta_e0_bytes, "ΔE0")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let standard_pressure = parse_number(standard_pressure_bytes, "standard pressure")?;
+
+    check_temperature(temperature)?;
+    check_positive("standard pressure", standard_pressure)?;
+    if reactants.is_empty() || products.is_empty() {
+        return Err("Reactions need at least one reactant and one product".to_string());
+    }
+
+    let mut ln_partition = 0.0;
+    let mut species = Vec::new();
+    let sides = reactants.iter().map(|(m, nu)| (m, -nu));
+    for (index, (molecule, nu)) in sides.chain(products.iter().map(|(m, nu)| (m, *nu))).enumerate()
+    {
+        let mass = molecule.validate()?;
+        // q°_m/NA is the molecular partition function in the volume kT/P°
+        let q_molar = Molecule::q_translational(mass, temperature, standard_pressure * 1e5)
+            * molecule.q_rotational(temperature)
+            * molecule.q_vibrational(temperature)
+            * molecule.q_electronic(temperature).0;
+        ln_partition += nu * q_molar.ln();
+        species.push(SpeciesPartition {
+            name: if molecule.name.is_empty() {
+                format!("species {}", index + 1)
+            } else {
+                molecule.name.clone()
+            },
+            coefficient: nu.abs(),
+            molar_partition_function: q_molar,
+        });
+    }
+
+    let ln_energy = -delta_e0 * 1000.0 / (R * temperature);
+    let ln_k = ln_partition + ln_energy;
+
+    let result = StatisticalEquilibrium {
+        value: ln_k.exp(),
+        unit: String::new(),
+        ln_k,
+        delta_g: -R * temperature * ln_k / 1000.0,
+        partition_factor: ln_partition.exp(),
+        energy_factor: ln_energy.exp(),
+        species,
+    };
+
+    to_json(&result)
+}
+
+/// Parse an energy unit of a level, returning E/k in kelvin per unit and its symbol
+fn parse_level_energy_unit(unit: &str) -> Result<(f64, &'static str), String> {
+    match unit {
+        "" | "cm-1" | "cm⁻¹" => Ok((HC_OVER_K, "cm⁻¹")),
+        "eV" => Ok((ELECTRON_VOLT / BOLTZMANN, "eV")),
+        "kJ/mol" => Ok((1000.0 / (BOLTZMANN * AVOGADRO), "kJ/mol")),
+        "J" => Ok((1.0 / BOLTZMANN, "J")),
+        "K" => Ok((1.0, "K")),
+        _ => Err(format!(
+            "Unknown energy unit: {} (expected cm-1, eV, kJ/mol, J or K)",
+            unit
+        )),
+    }
+}
+
+/// Energy level given alone (non-degenerate) or with its degeneracy
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EnergyLevel {
+    Energy(f64),
+    Degenerate(f64, f64),
+}
+
+impl EnergyLevel {
+    fn parts(&self) -> (f64, f64) {
+        match self {
+            EnergyLevel::Energy(e) => (*e, 1.0),
+            EnergyLevel::Degenerate(e, g) => (*e, *g),
+        }
+    }
+}
+
+/// Thermal population of one level
+#[derive(Serialize)]
+struct LevelPopulation {
+    energy: f64,
+    degeneracy: f64,
+    /// Fraction of molecules in the level, g·e^(-E/kT)/q
+    fraction: f64,
+    /// Population relative to the lowest level
+    relative: f64,
+}
+
+/// Boltzmann distribution over a set of levels
+#[derive(Serialize)]
+struct BoltzmannDistribution {
+    /// Partition function over the given levels, from the lowest level
+    partition_function: f64,
+    /// Mean energy in the energy unit
+    mean_energy: f64,
+    energy_unit: String,
+    temperature: f64,
+    levels: Vec<LevelPopulation>,
+}
+
+/// Calculate fractional Boltzmann populations of energy levels
+/// p_i = g_i·e^(-(E_i - E_0)/kT)/q, q = Σ g_i·e^(-(E_i - E_0)/kT)
+///
+/// Arguments:
+/// - levels_json: Array of energies or (energy, degeneracy) pairs
+/// - T: Temperature (K)
+/// - unit: Energy unit: "cm-1", "eV", "kJ/mol", "J" (per molecule) or "K"
+#[wasm_func]
+pub fn boltzmann_populations(
+    levels_json: &[u8],
+    temperature_bytes: &[u8],
+    unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let levels: Vec<EnergyLevel> = parse_json(levels_json, "energy levels")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let unit: String = parse_arg(unit_bytes, "energy unit")?;
+
+    let (kelvin_per_unit, symbol) = parse_level_energy_unit(&unit)?;
+    check_temperature(temperature)?;
+    let levels: Vec<(f64, f64)> = levels.iter().map(EnergyLevel::parts).collect();
+    if levels.is_empty() {
+        return Err("Give at least one energy level".to_string());
+    }
+    for (i, &(_, g)) in levels.iter().enumerate() {
+        check_positive(&format!("degeneracy of level {}", i + 1), g)?;
+    }
+
+    let lowest = levels.iter().map(|&(e, _)| e).fold(f64::INFINITY, f64::min);
+    let weights: Vec<f64> = levels
+        .iter()
+        .map(|&(e, g)| g * (-(e - lowest) * kelvin_per_unit / temperature).exp())
+        .collect();
+    let q: f64 = weights.iter().sum();
+    let ground_weight = levels
+        .iter()
+        .zip(&weights)
+        .find(|((e, _), _)| *e == lowest)
+        .map(|(_, w)| *w)
+        .unwrap();
+
+    let result = BoltzmannDistribution {
+        partition_function: q,
+        mean_energy: levels.iter().zip(&weights).map(|(&(e, _), w)| e * w).sum::<f64>() / q,
+        energy_unit: symbol.to_string(),
+        temperature,
+        levels: levels
+            .iter()
+            .zip(&weights)
+            .map(|(&(energy, degeneracy), &weight)| LevelPopulation {
+                energy,
+                degeneracy,
+                fraction: weight / q,
+                relative: weight / ground_weight,
+            })
+            .collect(),
+    };
+
+    to_json(&result)
+}
+
+/// Population ratio of a two-level system
+#[derive(Serialize)]
+struct TwoLevelRatio {
+    /// N_upper/N_lower = (g_upper/g_lower)·e^(-ΔE/kT)
+    value: f64,
+    unit: String,
+    /// Fraction in the upper level, N_upper/(N_upper + N_lower)
+    upper_fraction: f64,
+    /// ΔE/k (K)
+    characteristic_temperature: f64,
+}
+
+/// Calculate the Boltzmann population ratio of two levels
+/// N_upper/N_lower = (g_upper/g_lower)·e^(-ΔE/kT)
+///
+/// Arguments:
+/// - delta_e: Energy gap E_upper - E_lower (energy unit)
+/// - T: Temperature (K)
+/// - g_upper: Degeneracy of the upper level
+/// - g_lower: Degeneracy of the lower level
+/// - unit: Energy unit, as for `boltzmann_populations`
+#[wasm_func]
+pub fn boltzmann_ratio(
+    delta_e_bytes: &[u8],
+    temperature_bytes: &[u8],
+    g_upper_bytes: &[u8],
+    g_lower_bytes: &[u8],
+    unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let delta_e = parse_number(delta_e_bytes, "energy gap")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let g_upper = parse_number(g_upper_bytes, "upper degeneracy")?;
+    let g_lower = parse_number(g_lower_bytes, "lower degeneracy")?;
+    let unit: String = parse_arg(unit_bytes, "energy unit")?;
+
+    let (kelvin_per_unit, _) = parse_level_energy_unit(&unit)?;
+    check_temperature(temperature)?;
+    check_positive("upper degeneracy", g_upper)?;
+    check_positive("lower degeneracy", g_lower)?;
+    let theta = delta_e * kelvin_per_unit;
+    let ratio = g_upper / g_lower * (-theta / temperature).exp();
+
+    let result = TwoLevelRatio {
+        value: ratio,
+        unit: String::new(),
+        upper_fraction: ratio / (1.0 + ratio),
+        characteristic_temperature: theta,
+    };
+
+    to_json(&result)
+}
+
+/// Molar Cv of a solid per mole of atoms, J/(mol·K)
+///
+/// Einstein: 3R·x²·eˣ/(eˣ - 1)², x = θE/T
+/// Debye: 9R·(T/θD)³·∫₀^(θD/T) x⁴·eˣ/(eˣ - 1)² dx
+fn solid_heat_capacity_value(model: &str, theta: f64, temperature: f64) -> Result<f64, String> {
+    // x²·eˣ/(eˣ - 1)² written with e⁻ˣ so it stays finite for large x
+    let einstein = |x: f64| {
+        if x == 0.0 {
+            1.0
+        } else {
+            let decay = (-x).exp();
+            x * x * decay / (1.0 - decay).powi(2)
+        }
+    };
+    match model {
+        "einstein" => Ok(3.0 * R * einstein(theta / temperature)),
+        "debye" => {
+            // Simpson's rule; the integrand is negligible beyond x = 60
+            let upper = (theta / temperature).min(60.0);
+            let steps = 400;
+            let h = upper / steps as f64;
+            let integral = (0..=steps)
+                .map(|i| {
+                    let x = i as f64 * h;
+                    let weight = if i == 0 || i == steps {
+                        1.0
+                    } else if i % 2 == 1 {
+                        4.0
+                    } else {
+                        2.0
+                    };
+                    weight * x * x * einstein(x)
+                })
+                .sum::<f64>()
+                * h
+                / 3.0;
+            Ok(9.0 * R * (temperature / theta).powi(3) * integral)
+        }
+        _ => Err(format!("Unknown heat capacity model: {} (expected einstein or debye)", model)),
+    }
+}
+
+/// Heat capacity of a solid from the Einstein or Debye model
+#[derive(Serialize)]
+struct SolidHeatCapacity {
+    value: f64,
+    unit: String,
+    model: String,
+    /// Dulong–Petit limit 3nR (J/(mol·K))
+    dulong_petit: f64,
+    /// Cv over the Dulong–Petit limit
+    fraction_of_classical: f64,
+}
+
+/// Calculate Cv of a solid from the Einstein or Debye model
+///
+/// Arguments:
+/// - model: "einstein" or "debye"
+/// - theta: Einstein or Debye temperature (K)
+/// - T: Temperature (K)
+/// - atoms: Atoms per formula unit n (Cv per mole of formula units)
+#[wasm_func]
+pub fn solid_heat_capacity(
+    model_bytes: &[u8],
+    theta_bytes: &[u8],
+    temperature_bytes: &[u8],
+    atoms_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let model: String = parse_arg(model_bytes, "model")?;
+    let theta = parse_number(theta_bytes, "characteristic temperature")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let atoms = parse_number(atoms_bytes, "atoms per formula unit")?;
+
+    check_temperature_of("characteristic temperature", theta)?;
+    check_temperature(temperature)?;
+    check_positive("atoms per formula unit", atoms)?;
+    let per_atom = solid_heat_capacity_value(&model, theta, temperature)?;
+
+    let result = SolidHeatCapacity {
+        value: atoms * per_atom,
+        unit: "J/(mol·K)".to_string(),
+        model,
+        dulong_petit: 3.0 * atoms * R,
+        fraction_of_classical: per_atom / (3.0 * R),
+    };
+
+    to_json(&result)
+}
+
+/// Heat capacity curve of a solid
+#[derive(Serialize)]
+struct SolidHeatCapacityCurve {
+    model: String,
+    theta: f64,
+    /// (T in K, Cv in J/(mol·K)) points
+    points: Vec<(f64, f64)>,
+    /// Dulong–Petit limit 3nR (J/(mol·K))
+    dulong_petit: f64,
+}
+
+/// Generate Cv(T) from the Einstein or Debye model for plotting
+///
+/// Arguments:
+/// - model: "einstein" or "debye"
+/// - theta: Einstein or Debye temperature (K)
+/// - range_json: [min, max] temperature (K)
+/// - points: Number of points
+/// - atoms: Atoms per formula unit
+#[wasm_func]
+pub fn solid_heat_capacity_curve(
+    model_bytes: &[u8],
+    theta_bytes: &[u8],
+    range_json: &[u8],
+    points_bytes: &[u8],
+    atoms_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let model: String = parse_arg(model_bytes, "model")?;
+    let theta = parse_number(theta_bytes, "characteristic temperature")?;
+    let range: (f64, f64) = parse_json(range_json, "temperature range")?;
+    let points: usize = parse_arg(points_bytes, "points")?;
+    let atoms = parse_number(atoms_bytes, "atoms per formula unit")?;
+
+    check_temperature_of("characteristic temperature", theta)?;
+    check_positive("atoms per formula unit", atoms)?;
+    if points < 2 || range.0 < 0.0 || range.1 <= range.0 {
+        return Err(
+            "Curves need at least 2 points and an increasing, non-negative range".to_string(),
+        );
+    }
+
+    let points = sample_times(range.1 - range.0, points)
+        .into_iter()
+        .map(|offset| {
+            let t = range.0 + offset;
+            let cv = if t == 0.0 {
+                0.0
+            } else {
+                atoms * solid_heat_capacity_value(&model, theta, t)?
+            };
+            Ok((t, cv))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let result = SolidHeatCapacityCurve {
+        model,
+        theta,
+        points,
+        dulong_petit: 3.0 * atoms * R,
+    };
+
+    to_json(&result)
+}
+
+/// Thermal de Broglie wavelength of a gas
+#[derive(Serialize)]
+struct ThermalWavelength {
+    value: f64,
+    unit: String,
+    /// n·Λ³ at the given pressure; classical behavior needs n·Λ³ ≪ 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    degeneracy_parameter: Option<f64>,
+}
+
+/// Calculate the thermal de Broglie wavelength
+/// Λ = h/√(2πmkT)
+///
+/// Arguments:
+/// - molar_mass_json: Molar mass (g/mol) or formula
+/// - T: Temperature (K)
+/// - P: Pressure for n·Λ³ (pressure unit, 0 to skip)
+/// - pressure_unit: Unit of P
+#[wasm_func]
+pub fn thermal_wavelength(
+    molar_mass_json: &[u8],
+    temperature_bytes: &[u8],
+    pressure_bytes: &[u8],
+    pressure_unit_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let mass: MolarMassSpec = parse_json(molar_mass_json, "molar mass")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let pressure = parse_number(pressure_bytes, "pressure")?;
+    let pressure_unit: String = parse_arg(pressure_unit_bytes, "pressure unit")?;
+
+    let m = mass.value()? / 1000.0 / AVOGADRO;
+    check_temperature(temperature)?;
+    check_non_negative("pressure", pressure)?;
+    let (pa_per_unit, _) = parse_pressure_unit(&pressure_unit)?;
+    let lambda = PLANCK / (2.0 * std::f64::consts::PI * m * BOLTZMANN * temperature).sqrt();
+
+    let result = ThermalWavelength {
+        value: lambda * 1e12,
+        unit: "pm".to_string(),
+        degeneracy_parameter: (pressure > 0.0)
+            .then(|| pressure * pa_per_unit / (BOLTZMANN * temperature) * lambda.powi(3)),
+    };
+
+    to_json(&result)
+}
+
+/// Classical equipartition energy and heat capacities of an ideal gas
+#[derive(Serialize)]
+struct Equipartition {
+    /// Quadratic energy terms per molecule
+    quadratic_terms: f64,
+    translational: f64,
+    rotational: f64,
+    /// Vibrational modes counted (each contributes two quadratic terms)
+    vibrational_modes: f64,
+    /// ½RT per quadratic term (kJ/mol)
+    energy_per_term: f64,
+    /// Molar internal energy above the ground state (kJ/mol)
+    internal_energy: CalculationResult,
+    /// Molar Cv and Cp (J/(mol·K))
+    cv: f64,
+    cp: f64,
+    gamma: f64,
+}
+
+/// Calculate the classical equipartition energy of an ideal gas
+/// U = (f/2)·RT, Cv = (f/2)·R, Cp = Cv + R
+///
+/// Each molecule has 3 translational terms, 0 (monatomic), 2 (linear) or 3 (nonlinear)
+/// rotational terms, and, when vibrations are active, two terms for each of the 3N - 5
+/// (linear) or 3N - 6 (nonlinear) normal modes.
+///
+/// Arguments:
+/// - kind: "monatomic", "linear" (or "diatomic") or "nonlinear"
+/// - T: Temperature (K)
+/// - atoms: Number of atoms N, needed for vibrations (0 for none)
+/// - vibrations: "true" to count fully excited vibrations
+#[wasm_func]
+pub fn equipartition_energy(
+    kind_bytes: &[u8],
+    temperature_bytes: &[u8],
+    atoms_bytes: &[u8],
+    vibrations_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let kind: String = parse_arg(kind_bytes, "molecule type")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let atoms = parse_number(atoms_bytes, "atom count")?;
+    let vibrations: bool = parse_arg(vibrations_bytes, "vibration flag")?;
+
+    check_temperature(temperature)?;
+    let (rotational, fixed_modes, minimum_atoms) = match kind.as_str() {
+        "monatomic" => (0.0, 3.0, 1.0),
+        "linear" | "diatomic" => (2.0, 5.0, 2.0),
+        "nonlinear" => (3.0, 6.0, 3.0),
+        _ => {
+            return Err(format!(
+                "Unknown molecule type: {} (expected monatomic, linear, diatomic or nonlinear)",
+                kind
+            ))
+        }
+    };
+    let atoms = if kind == "diatomic" && atoms == 0.0 { 2.0 } else { atoms };
+    let vibrational_modes = if vibrations && kind != "monatomic" {
+        if atoms < minimum_atoms || atoms.fract() != 0.0 {
+            return Err(format!(
+                "A {} molecule needs a whole number of at least {} atoms",
+                kind, minimum_atoms
+            ));
+        }
+        3.0 * atoms - fixed_modes
+    } else {
+        0.0
+    };
+    let terms = 3.0 + rotational + 2.0 * vibrational_modes;
+    let cv = 0.5 * terms * R;
+
+    let result = Equipartition {
+        quadratic_terms: terms,
+        translational: 3.0,
+        rotational,
+        vibrational_modes,
+        energy_per_term: 0.5 * R * temperature / 1000.0,
+        internal_energy: CalculationResult::new(cv * temperature / 1000.0, "kJ/mol"),
+        cv,
+        cp: cv + R,
+        gamma: (cv + R) / cv,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// PHOTOCHEMISTRY
+// ============================================================================
+
+/// Convert a photon quantity (wavelength, frequency, wavenumber or energy) to joules per photon
+fn photon_energy_joules(value: f64, unit: &str) -> Result<f64, String> {
+    check_positive("photon quantity", value)?;
+    let hc = PLANCK * SPEED_OF_LIGHT;
+    match unit {
+        "nm" => Ok(hc / (value * 1e-9)),
+        "pm" => Ok(hc / (value * 1e-12)),
+        "um" | "μm" | "µm" => Ok(hc / (value * 1e-6)),
+        "cm" => Ok(hc / (value * 1e-2)),
+        "m" => Ok(hc / value),
+        "Hz" | "s-1" => Ok(PLANCK * value),
+        "THz" => Ok(PLANCK * value * 1e12),
+        "cm-1" | "cm⁻¹" => Ok(hc * value * 100.0),
+        "J" => Ok(value),
+        "eV" => Ok(value * ELECTRON_VOLT),
+        "kJ/mol" => Ok(value * 1000.0 / AVOGADRO),
+        _ => Err(format!(
+            "Unknown photon unit: {} (expected nm, pm, um, cm, m, Hz, THz, cm-1, J, eV or kJ/mol)",
+            unit
+        )),
+    }
+}
+
+/// Name of the electromagnetic region containing a wavelength (nm)
+fn spectral_region(wavelength_nm: f64) -> &'static str {
+    match wavelength_nm {
+        w if w < 0.01 => "gamma",
+        w if w < 10.0 => "X-ray",
+        w if w < 400.0 => "ultraviolet",
+        w if w < 700.0 => "visible",
+        w if w < 1e6 => "infrared",
+        w if w < 1e9 => "microwave",
+        _ => "radio",
+    }
+}
+
+/// Photon described by every equivalent quantity
+#[derive(Serialize)]
+struct PhotonConversion {
+    wavelength: CalculationResult,
+    frequency: CalculationResult,
+    wavenumber: CalculationResult,
+    /// Energy per photon (J)
+    energy: CalculationResult,
+    energy_ev: CalculationResult,
+    /// Energy per mole of photons (one einstein)
+    molar_energy: CalculationResult,
+    region: String,
+}
+
+impl PhotonConversion {
+    fn from_energy(energy: f64) -> Self {
+        let wavelength = PLANCK * SPEED_OF_LIGHT / energy;
+        Self {
+            wavelength: CalculationResult::new(wavelength * 1e9, "nm"),
+            frequency: CalculationResult::new(energy / PLANCK, "Hz"),
+            wavenumber: CalculationResult::new(0.01 / wavelength, "cm⁻¹"),
+            energy: CalculationResult::new(energy, "J"),
+            energy_ev: CalculationResult::new(energy / ELECTRON_VOLT, "eV"),
+            molar_energy: CalculationResult::new(energy * AVOGADRO / 1000.0, "kJ/mol"),
+            region: spectral_region(wavelength * 1e9).to_string(),
+        }
+    }
+}
+
+/// Convert between photon wavelength, frequency, wavenumber and energy
+/// E = hν = hc/λ = hc·ν̃
+///
+/// Arguments:
+/// - value: Photon quantity
+/// - unit: nm, pm, um, cm, m, Hz, THz, cm-1, J (per photon), eV or kJ/mol
+#[wasm_func]
+pub fn photon_energy(value_bytes: &[u8], unit_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let value = parse_number(value_bytes, "value")?;
+    let unit: String = parse_arg(unit_bytes, "unit")?;
+
+    let result = PhotonConversion::from_energy(photon_energy_joules(value, &unit)?);
+
+    to_json(&result)
+}
+
+/// Threshold photon for breaking a bond
+#[derive(Serialize)]
+struct BondPhotolysis {
+    /// Longest wavelength able to break the bond (nm)
+    value: f64,
+    unit: String,
+    threshold: PhotonConversion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_dissociate: Option<bool>,
+    /// Photon energy left over after breaking the bond (kJ/mol)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excess_energy: Option<CalculationResult>,
+}
+
+/// Calculate the longest wavelength able to break a bond, λmax = hc·NA/D
+///
+/// Arguments:
+/// - energy: Bond dissociation energy
+/// - unit: Unit of the energy (kJ/mol, eV, J or cm-1)
+/// - wavelength: Photon wavelength to test (nm, 0 to skip)
+#[wasm_func]
+pub fn bond_photolysis_wavelength(
+    energy_bytes: &[u8],
+    unit_bytes: &[u8],
+    wavelength_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let energy = parse_number(energy_bytes, "dissociation energy")?;
+    let unit: String = parse_arg(unit_bytes, "energy unit")?;
+    let wavelength = parse_number(wavelength_bytes, "wavelength")?;
+
+    if !matches!(unit.as_str(), "kJ/mol" | "eV" | "J" | "cm-1" | "cm⁻¹") {
+        return Err(format!(
+            "Unknown energy unit: {} (expected kJ/mol, eV, J or cm-1)",
+            unit
+        ));
+    }
+    let threshold = PhotonConversion::from_energy(photon_energy_joules(energy, &unit)?);
+    let photon = (wavelength > 0.0)
+        .then(|| photon_energy_joules(wavelength, "nm"))
+        .transpose()?;
+
+    let result = BondPhotolysis {
+        value: threshold.wavelength.value,
+        unit: "nm".to_string(),
+        can_dissociate: photon.map(|e| e >= threshold.energy.value),
+        excess_energy: photon.map(|e| {
+            CalculationResult::new(
+                (e - threshold.energy.value) * AVOGADRO / 1000.0,
+                "kJ/mol",
+            )
+        }),
+        threshold,
+    };
+
+    to_json(&result)
+}
+
+/// Rydberg constant for an infinitely heavy nucleus (cm⁻¹)
+const RYDBERG_INFINITY: f64 = 109_737.315_681_6;
+/// Electron mass (g/mol)
+const ELECTRON_MOLAR_MASS: f64 = 5.485_799_09e-4;
+
+/// Reduced-mass Rydberg constant of a hydrogen-like species (cm⁻¹)
+fn rydberg_constant(nuclear_mass: f64) -> f64 {
+    if nuclear_mass > 0.0 {
+        RYDBERG_INFINITY / (1.0 + ELECTRON_MOLAR_MASS / nuclear_mass)
+    } else {
+        RYDBERG_INFINITY
+    }
+}
+
+/// Name of the hydrogen series ending on a lower level
+fn hydrogen_series(n_lower: u32) -> String {
+    match n_lower {
+        1 => "Lyman".to_string(),
+        2 => "Balmer".to_string(),
+        3 => "Paschen".to_string(),
+        4 => "Brackett".to_string(),
+        5 => "Pfund".to_string(),
+        6 => "Humphreys".to_string(),
+        n => format!("n = {}", n),
+    }
+}
+
+fn check_hydrogen_like(z: f64, nuclear_mass: f64) -> Result<(), String> {
+    check_domain("Z", z, z >= 1.0 && z.fract() == 0.0, "a positive integer")?;
+    check_non_negative("nuclear mass", nuclear_mass)?;
+    Ok(())
+}
+
+/// Energy level of a hydrogen-like species
+#[derive(Serialize)]
+struct HydrogenLevel {
+    n: u32,
+    /// Energy relative to the ionization limit (eV)
+    energy: f64,
+    /// Term value RZ²/n² (cm⁻¹)
+    term: f64,
+    degeneracy: u32,
+}
+
+#[derive(Serialize)]
+struct HydrogenLevels {
+    rydberg: CalculationResult,
+    ionization_energy: CalculationResult,
+    levels: Vec<HydrogenLevel>,
+}
+
+/// Calculate the energy levels of a hydrogen-like species
+/// E_n = -hc·R·Z²/n²
+///
+/// Arguments:
+/// - z: Nuclear charge
+/// - n_max: Highest principal quantum number
+/// - nuclear_mass: Nuclear mass for the reduced-mass correction (g/mol, 0 for infinite)
+#[wasm_func]
+pub fn hydrogen_energy_levels(
+    z_bytes: &[u8],
+    n_max_bytes: &[u8],
+    nuclear_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let z = parse_number(z_bytes, "nuclear charge")?;
+    let n_max: u32 = parse_arg(n_max_bytes, "highest level")?;
+    let nuclear_mass = parse_number(nuclear_mass_bytes, "nuclear mass")?;
+
+    check_hydrogen_like(z, nuclear_mass)?;
+    if n_max < 1 {
+        return Err("Highest level must be at least 1".to_string());
+    }
+    let rydberg = rydberg_constant(nuclear_mass);
+    let ev_per_wavenumber = PLANCK * SPEED_OF_LIGHT * 100.0 / ELECTRON_VOLT;
+
+    let levels = (1..=n_max)
+        .map(|n| {
+            let term = rydberg * z * z / (n * n) as f64;
+            HydrogenLevel {
+                n,
+                energy: -term * ev_per_wavenumber,
+                term,
+                degeneracy: n * n,
+            }
+        })
+        .collect();
+
+    let result = HydrogenLevels {
+        rydberg: CalculationResult::new(rydberg, "cm⁻¹"),
+        ionization_energy: CalculationResult::new(rydberg * z * z * ev_per_wavenumber, "eV"),
+        levels,
+    };
+
+    to_json(&result)
+}
+
+/// Transition between two levels of a hydrogen-like species
+#[derive(Serialize)]
+struct HydrogenTransition {
+    n_upper: u32,
+    n_lower: u32,
+    series: String,
+    /// "emission" (n1 → n2 downwards) or "absorption"
+    process: String,
+    #[serde(flatten)]
+    photon: PhotonConversion,
+    /// Short-wavelength limit of the series (nm)
+    series_limit: CalculationResult,
+    /// Deviation from a measured wavelength (%), when identifying a line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deviation: Option<f64>,
+}
+
+impl HydrogenTransition {
+    fn new(n_initial: u32, n_final: u32, z: f64, rydberg: f64) -> Self {
+        let (n_upper, n_lower) = (n_initial.max(n_final), n_initial.min(n_final));
+        let limit = rydberg * z * z / (n_lower * n_lower) as f64;
+        let wavenumber = limit - rydberg * z * z / (n_upper * n_upper) as f64;
+        Self {
+            n_upper,
+            n_lower,
+            series: hydrogen_series(n_lower),
+            process: if n_initial > n_final { "emission" } else { "absorption" }.to_string(),
+            photon: PhotonConversion::from_energy(PLANCK * SPEED_OF_LIGHT * wavenumber * 100.0),
+            series_limit: CalculationResult::new(1e7 / limit, "nm"),
+            deviation: None,
+        }
+    }
+}
+
+/// Calculate a transition of a hydrogen-like species with the Rydberg formula
+/// ν̃ = R·Z²·(1/n_lower² - 1/n_upper²)
+///
+/// Arguments:
+/// - n_initial: Initial principal quantum number
+/// - n_final: Final principal quantum number
+/// - z: Nuclear charge
+/// - nuclear_mass: Nuclear mass for the reduced-mass correction (g/mol, 0 for infinite)
+#[wasm_func]
+pub fn hydrogen_transition(
+    n_initial_bytes: &[u8],
+    n_final_bytes: &[u8],
+    z_bytes: &[u8],
+    nuclear_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let n_initial: u32 = parse_arg(n_initial_bytes, "initial level")?;
+    let n_final: u32 = parse_arg(n_final_bytes, "final level")?;
+    let z = parse_number(z_bytes, "nuclear charge")?;
+    let nuclear_mass = parse_number(nuclear_mass_bytes, "nuclear mass")?;
+
+    check_hydrogen_like(z, nuclear_mass)?;
+    if n_initial < 1 || n_final < 1 || n_initial == n_final {
+        return Err("Levels must be different positive integers".to_string());
+    }
+
+    let result = HydrogenTransition::new(n_initial, n_final, z, rydberg_constant(nuclear_mass));
+
+    to_json(&result)
+}
+
+/// Ionization of a hydrogen-like species from level n
+#[derive(Serialize)]
+struct HydrogenIonization {
+    /// Ionization energy from level n (eV)
+    value: f64,
+    unit: String,
+    n: u32,
+    /// Threshold photon for ionization
+    threshold: PhotonConversion,
+}
+
+/// Calculate the ionization energy of a hydrogen-like species from level n, E = hc·R·Z²/n²
+///
+/// Arguments:
+/// - n: Principal quantum number
+/// - z: Nuclear charge
+/// - nuclear_mass: Nuclear mass for the reduced-mass correction (g/mol, 0 for infinite)
+#[wasm_func]
+pub fn hydrogen_ionization_energy(
+    n_bytes: &[u8],
+    z_bytes: &[u8],
+    nuclear_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let n: u32 = parse_arg(n_bytes, "level")?;
+    let z = parse_number(z_bytes, "nuclear charge")?;
+    let nuclear_mass = parse_number(nuclear_mass_bytes, "nuclear mass")?;
+
+    check_hydrogen_like(z, nuclear_mass)?;
+    if n < 1 {
+        return Err("Level must be a positive integer".to_string());
+    }
+    let term = rydberg_constant(nuclear_mass) * z * z / (n * n) as f64;
+    let threshold = PhotonConversion::from_energy(PLANCK * SPEED_OF_LIGHT * term * 100.0);
+
+    let result = HydrogenIonization {
+        value: threshold.energy_ev.value,
+        unit: "eV".to_string(),
+        n,
+        threshold,
+    };
+
+    to_json(&result)
+}
+
+/// Identify the hydrogen-like transition closest to an observed wavelength
+///
+/// Searches lower levels up to 10 and upper levels up to 50.
+///
+/// Arguments:
+/// - wavelength: Observed wavelength (nm)
+/// - z: Nuclear charge
+/// - nuclear_mass: Nuclear mass for the reduced-mass correction (g/mol, 0 for infinite)
+#[wasm_func]
+pub fn identify_hydrogen_line(
+    wavelength_bytes: &[u8],
+    z_bytes: &[u8],
+    nuclear_mass_bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    let wavelength = parse_number(wavelength_bytes, "wavelength")?;
+    let z = parse_number(z_bytes, "nuclear charge")?;
+    let nuclear_mass = parse_number(nuclear_mass_bytes, "nuclear mass")?;
+
+    check_hydrogen_like(z, nuclear_mass)?;
+    check_positive("wavelength", wavelength)?;
+    let rydberg = rydberg_constant(nuclear_mass);
+
+    let mut best: Option<HydrogenTransition> = None;
+    for n_lower in 1..=10 {
+        for n_upper in n_lower + 1..=50 {
+            let mut line = HydrogenTransition::new(n_upper, n_lower, z, rydberg);
+            let deviation = (line.photon.wavelength.value - wavelength) / wavelength * 100.0;
+            if best
+                .as_ref()
+                .is_none_or(|b| deviation.abs() < b.deviation.unwrap().abs())
+            {
+                line.deviation = Some(deviation);
+                best = Some(line);
+            }
+        }
+    }
+
+    to_json(&best.unwrap())
+}
+
+// ============================================================================
+// BIOCHEMICAL THERMODYNAMICS
+// ============================================================================
+
+fn default_biochemical_ph() -> f64 {
+    7.0
+}
+
+fn default_standard_state() -> String {
+    "chemical".to_string()
+}
+
+/// Reaction free energy to move between the chemical and biochemical standard states
+#[derive(Deserialize)]
+struct BiochemicalStandardInput {
+    /// ΔG° (chemical) or ΔG°′ (biochemical) in kJ/mol
+    delta_g: f64,
+    /// Standard state of the given value: "chemical" or "biochemical"
+    #[serde(default = "default_standard_state")]
+    from: String,
+    /// Net H⁺ produced by the reaction (negative if consumed)
+    #[serde(default)]
+    protons: f64,
+    #[serde(default = "default_biochemical_ph")]
+    ph: f64,
+    #[serde(default = "default_temperature")]
+    temperature: f64,
+    /// Ionic strength of the biochemical standard state (mol/L)
+    #[serde(default)]
+    ionic_strength: f64,
+    /// (charge, coefficient) of the other charged species, coefficients negative for
+    /// reactants
+    #[serde(default)]
+    charges: Vec<(f64, f64)>,
+}
+
+#[derive(Serialize)]
+struct BiochemicalStandardState {
+    /// The converted value
+    value: f64,
+    unit: String,
+    /// ΔG° with all activities 1 (kJ/mol)
+    chemical: f64,
+    /// ΔG°′ at the given pH and ionic strength (kJ/mol)
+    biochemical: f64,
+    /// -ν(H⁺)·RT·ln 10·pH (kJ/mol)
+    proton_term: f64,
+    /// RT·Σν·ln γ of the charged species (kJ/mol)
+    ionic_strength_term: f64,
+    k: f64,
+    k_prime: f64,
+}
+
+/// Convert between the chemical standard ΔG° and the biochemical ΔG°′
+/// ΔG°′ = ΔG° - ν(H⁺)·RT·ln 10·pH + RT·Σν·ln γ
+///
+/// The activity coefficients of the other charged species follow the extended
+/// Debye–Hückel form used for biochemical tables, log γ = -A·z²·√I/(1 + 1.6·√I).
+///
+/// Arguments:
+/// - input_json: {"delta_g", "from", "protons", "ph", "temperature", "ionic_strength",
+///   "charges"}
+#[wasm_func]
+pub fn biochemical_standard_state(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: BiochemicalStandardInput = parse_json(input_json, "input")?;
+
+    check_temperature(input.temperature)?;
+    check_non_negative("ionic strength", input.ionic_strength)?;
+    let rt = R * input.temperature / 1000.0;
+    let proton_term = 0.0 - input.protons * rt * std::f64::consts::LN_10 * input.ph;
+
+    let (a, _) = debye_huckel_constants(input.temperature);
+    let root = input.ionic_strength.sqrt();
+    let mut sum_log_gamma = 0.0;
+    for (charge, coefficient) in &input.charges {
+        sum_log_gamma += coefficient * -a * charge * charge * root / (1.0 + 1.6 * root);
+    }
+    let ionic_strength_term = rt * std::f64::consts::LN_10 * sum_log_gamma;
+
+    let correction = proton_term + ionic_strength_term;
+    let (chemical, biochemical) = match input.from.as_str() {
+        "chemical" => (input.delta_g, input.delta_g + correction),
+        "biochemical" => (input.delta_g - correction, input.delta_g),
+        _ => {
+            return Err(format!(
+                "Unknown standard state: {} (expected chemical or biochemical)",
+                input.from
+            ))
+        }
+    };
+
+    let result = BiochemicalStandardState {
+        value: if input.from == "chemical" { biochemical } else { chemical },
+        unit: "kJ/mol".to_string(),
+        chemical,
+        biochemical,
+        proton_term,
+        ionic_strength_term,
+        k: (-chemical / rt).exp(),
+        k_prime: (-biochemical / rt).exp(),
+    };
+
+    to_json(&result)
+}
+
+/// Biochemical free energy table entry
+#[derive(Deserialize, Clone)]
+struct BiochemicalData {
+    reaction: String,
+    /// ΔG°′ at pH 7 and 298.15 K (kJ/mol)
+    delta_g: f64,
+}
+
+/// Reaction free energy given directly (kJ/mol) or as a table key
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FreeEnergySpec {
+    Value(f64),
+    Name(String),
+}
+
+/// Reaction step of a coupled sequence, optionally scaled (negative to reverse it)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CoupledStep {
+    Single(FreeEnergySpec),
+    Scaled(FreeEnergySpec, f64),
+}
+
+#[derive(Serialize)]
+struct CoupledStepResult {
+    reaction: String,
+    delta_g: f64,
+    coefficient: f64,
+    contribution: f64,
+}
+
+#[derive(Serialize)]
+struct CoupledReactions {
+    /// Overall ΔG°′ (kJ/mol)
+    value: f64,
+    unit: String,
+    k_prime: f64,
+    spontaneous: bool,
+    steps: Vec<CoupledStepResult>,
+}
+
+/// Add the free energies of coupled reactions, ΔG°′ = Σ cᵢ·ΔG°′ᵢ, K′ = e^(-ΔG°′/RT)
+///
+/// Arguments:
+/// - steps_json: Array of ΔG°′ values (kJ/mol) or table keys, or [step, coefficient] pairs
+/// - T: Temperature (K)
+/// - data_json: Biochemical free energy table
+#[wasm_func]
+pub fn coupled_reactions(
+    steps_json: &[u8],
+    temperature_bytes: &[u8],
+    data_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let steps: Vec<CoupledStep> = parse_json(steps_json, "reaction steps")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let data: HashMap<String, BiochemicalData> = parse_json(data_json, "biochemical data")?;
+
+    if steps.is_empty() {
+        return Err("At least one reaction step is required".to_string());
+    }
+    check_temperature(temperature)?;
+
+    let lookup = |spec: &FreeEnergySpec| -> Result<(String, f64), String> {
+        match spec {
+            FreeEnergySpec::Value(value) => Ok((format!("ΔG°′ = {} kJ/mol", value), *value)),
+            FreeEnergySpec::Name(name) => {
+                let lowercase = name.to_lowercase();
+                data.iter()
+                    .find(|(key, _)| key.to_lowercase() == lowercase)
+                    .map(|(_, entry)| (entry.reaction.clone(), entry.delta_g))
+                    .ok_or_else(|| format!("No biochemical free energy found for: {}", name))
+            }
+        }
+    };
+
+    let steps = steps
+        .iter()
+        .map(|step| {
+            let (spec, coefficient) = match step {
+                CoupledStep::Single(spec) => (spec, 1.0),
+                CoupledStep::Scaled(spec, coefficient) => (spec, *coefficient),
+            };
+            let (reaction, delta_g) = lookup(spec)?;
+            Ok(CoupledStepResult {
+                reaction,
+                delta_g,
+                coefficient,
+                contribution: coefficient * delta_g,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut total = 0.0;
+    for step in &steps {
+        total += step.contribution;
+    }
+
+    let result = CoupledReactions {
+        value: total,
+        unit: "kJ/mol".to_string(),
+        k_prime: (-total * 1000.0 / (R * temperature)).exp(),
+        spontaneous: total < 0.0,
+        steps,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// BOND ENTHALPIES
+// ============================================================================
+
+/// Average bond enthalpy table entry
+#[derive(Deserialize, Clone)]
+struct BondData {
+    /// Mean bond enthalpy (kJ/mol)
+    enthalpy: f64,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Split a bond such as "C=O", "O-H" or "C≡N" into its atoms and bond order
+///
+/// Single bonds may be written with "-", "–" or "—", triple bonds with "≡" or "#".
+fn parse_bond(bond: &str) -> Result<(String, String, u8), String> {
+    let bond = bond.trim();
+    let (position, separator) = bond
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| matches!(c, '-' | '–' | '—' | '=' | '≡' | '#'))
+        .ok_or_else(|| format!("Invalid bond: {} (expected e.g. C-H, C=O or C≡N)", bond))?;
+    let order = match separator {
+        '=' => 2,
+        '≡' | '#' => 3,
+        _ => 1,
+    };
+    let first = bond[..position].trim();
+    let second = bond[position + separator.len_utf8()..].trim();
+    for atom in [first, second] {
+        if atomic_mass(atom).is_none() {
+            return Err(format!("Unknown element in bond {}: {}", bond, atom));
+        }
+    }
+    Ok((first.to_string(), second.to_string(), order))
+}
+
+/// Bond enthalpy looked up in the table
+#[derive(Serialize)]
+struct BondEnthalpy {
+    /// Table key of the bond
+    bond: String,
+    atoms: (String, String),
+    order: u8,
+    /// Mean bond enthalpy (kJ/mol)
+    value: f64,
+    unit: String,
+    /// Energy per bond (eV)
+    per_bond: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Look up the average enthalpy of a bond
+///
+/// The atoms may be given in either order, so "H-O" finds "O-H".
+///
+/// Arguments:
+/// - bond: Bond, e.g. "C-H", "C=O" or "N≡N"
+/// - data_json: Bond enthalpy table
+#[wasm_func]
+pub fn get_bond_enthalpy(bond_bytes: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let bond: String = parse_arg(bond_bytes, "bond")?;
+    let data: HashMap<String, BondData> = parse_json(data_json, "bond enthalpy data")?;
+
+    let (first, second, order) = parse_bond(&bond)?;
+    let symbol = match order {
+        1 => "-",
+        2 => "=",
+        _ => "≡",
+    };
+    let (key, atoms, entry) = [(first.clone(), second.clone()), (second, first)]
+        .into_iter()
+        .find_map(|atoms| {
+            let key = format!("{}{}{}", atoms.0, symbol, atoms.1);
+            data.get(&key).map(|entry| (key, atoms, entry.clone()))
+        })
+        .ok_or_else(|| format!("No bond enthalpy found for bond: {}", bond.trim()))?;
+
+    let result = BondEnthalpy {
+        bond: key,
+        atoms,
+        order,
+        value: entry.enthalpy,
+        unit: "kJ/mol".to_string(),
+        per_bond: entry.enthalpy * 1000.0 / (AVOGADRO * ELECTRON_VOLT),
+        note: entry.note,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// HEAT CAPACITIES AND PHASE CHANGES
+// ============================================================================
+
+/// Heat capacity and phase-change table entry (1 atm)
+#[derive(Deserialize, Clone)]
+struct PhaseData {
+    #[serde(default)]
+    name: String,
+    /// Normal melting point (K)
+    #[serde(rename = "Tm", default)]
+    tm: Option<f64>,
+    /// Normal boiling point (K)
+    #[serde(rename = "Tb", default)]
+    tb: Option<f64>,
+    /// Enthalpy of fusion at Tm (kJ/mol)
+    #[serde(rename = "delta_Hfus", default)]
+    delta_hfus: Option<f64>,
+    /// Enthalpy of vaporization at Tb (kJ/mol)
+    #[serde(rename = "delta_Hvap", default)]
+    delta_hvap: Option<f64>,
+    /// Molar heat capacities Cp,m (J/(mol·K))
+    #[serde(rename = "Cp_solid", default)]
+    cp_solid: Option<f64>,
+    #[serde(rename = "Cp_liquid", default)]
+    cp_liquid: Option<f64>,
+    #[serde(rename = "Cp_gas", default)]
+    cp_gas: Option<f64>,
+}
+
+/// Find a substance by table formula or (case-insensitive) name
+fn find_phase_data(
+    query: &str,
+    data: &HashMap<String, PhaseData>,
+) -> Result<(String, PhaseData), String> {
+    let lowercase = query.to_lowercase();
+    data.get_key_value(query)
+        .or_else(|| data.iter().find(|(_, entry)| entry.name.to_lowercase() == lowercase))
+        .map(|(key, entry)| (key.clone(), entry.clone()))
+        .ok_or_else(|| format!("No heat capacity or phase-change data found for: {}", query))
+}
+
+/// Heat capacity of one phase, per mole and per gram
+#[derive(Serialize)]
+struct PhaseHeatCapacity {
+    phase: String,
+    /// Cp,m (J/(mol·K))
+    molar: f64,
+    /// Specific heat c (J/(g·K))
+    specific: f64,
+}
+
+/// Heat capacities and phase-change data of a substance
+#[derive(Serialize)]
+struct PhaseProperties {
+    formula: String,
+    name: String,
+    molar_mass: CalculationResult,
+    heat_capacities: Vec<PhaseHeatCapacity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    melting_point: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boiling_point: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enthalpy_fusion: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enthalpy_vaporization: Option<CalculationResult>,
+    /// ΔHfus/Tm (J/(mol·K))
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entropy_fusion: Option<CalculationResult>,
+    /// ΔHvap/Tb (J/(mol·K)); about 85-88 for liquids following Trouton's rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entropy_vaporization: Option<CalculationResult>,
+}
+
+/// Look up the heat capacities, melting and boiling points and enthalpies of fusion and
+/// vaporization of a substance
+///
+/// Arguments:
+/// - substance: Table formula or name
+/// - data_json: Heat capacity and phase-change table
+#[wasm_func]
+pub fn get_phase_data(substance_bytes: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let substance: String = parse_arg(substance_bytes, "substance")?;
+    let data: HashMap<String, PhaseData> = parse_json(data_json, "phase-change data")?;
+
+    let (formula, entry) = find_phase_data(&substance, &data)?;
+    let molar_mass = parse_formula(&formula)?.molar_mass()?;
+
+    let heat_capacities = [
+        ("solid", entry.cp_solid),
+        ("liquid", entry.cp_liquid),
+        ("gas", entry.cp_gas),
+    ]
+    .into_iter()
+    .filter_map(|(phase, cp)| {
+        cp.map(|molar| PhaseHeatCapacity {
+            phase: phase.to_string(),
+            molar,
+            specific: molar / molar_mass,
+        })
+    })
+    .collect();
+    let transition_entropy = |delta_h: Option<f64>, t: Option<f64>| match (delta_h, t) {
+        (Some(delta_h), Some(t)) if t > 0.0 => {
+            Some(CalculationResult::new(delta_h * 1000.0 / t, "J/(mol·K)"))
+        }
+        _ => None,
+    };
+
+    let result = PhaseProperties {
+        name: entry.name.clone(),
+        molar_mass: CalculationResult::new(molar_mass, "g/mol"),
+        heat_capacities,
+        melting_point: entry.tm.map(|t| CalculationResult::new(t, "K")),
+        boiling_point: entry.tb.map(|t| CalculationResult::new(t, "K")),
+        enthalpy_fusion: entry.delta_hfus.map(|h| CalculationResult::new(h, "kJ/mol")),
+        enthalpy_vaporization: entry.delta_hvap.map(|h| CalculationResult::new(h, "kJ/mol")),
+        entropy_fusion: transition_entropy(entry.delta_hfus, entry.tm),
+        entropy_vaporization: transition_entropy(entry.delta_hvap, entry.tb),
+        formula,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// IONIZATION ENERGIES AND ELECTRON AFFINITIES
+// ============================================================================
+
+/// Ionization energy and electron affinity table entry
+#[derive(Deserialize, Clone)]
+struct AtomicEnergyData {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "Z")]
+    z: u32,
+    /// Successive ionization energies (kJ/mol)
+    #[serde(rename = "IE", default)]
+    ie: Vec<f64>,
+    /// Electron affinity, energy released on forming the anion (kJ/mol); absent when the
+    /// anion is unbound
+    #[serde(rename = "EA", default)]
+    ea: Option<f64>,
+}
+
+/// Find an element by symbol or (case-insensitive) name
+fn find_atomic_energies(
+    query: &str,
+    data: &HashMap<String, AtomicEnergyData>,
+) -> Result<(String, AtomicEnergyData), String> {
+    let lowercase = query.to_lowercase();
+    data.get_key_value(query)
+        .or_else(|| data.iter().find(|(_, entry)| entry.name.to_lowercase() == lowercase))
+        .map(|(key, entry)| (key.clone(), entry.clone()))
+        .ok_or_else(|| format!("No ionization data found for element: {}", query))
+}
+
+/// kJ/mol per eV
+fn kj_per_mol_per_ev() -> f64 {
+    ELECTRON_VOLT * AVOGADRO / 1000.0
+}
+
+/// Ionization energies and electron affinity of an element
+#[derive(Serialize)]
+struct AtomicEnergies {
+    element: String,
+    name: String,
+    z: u32,
+    /// Successive ionization energies (kJ/mol)
+    ionization_energies: Vec<f64>,
+    /// Successive ionization energies (eV)
+    ionization_energies_ev: Vec<f64>,
+    /// Energy to form the n+ cation, ΣIE₁…IEₙ (kJ/mol)
+    cumulative: Vec<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    electron_affinity: Option<CalculationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    electron_affinity_ev: Option<f64>,
+    /// Electron-gain enthalpy -EA, as used in Born–Haber cycles (kJ/mol)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    electron_gain_enthalpy: Option<CalculationResult>,
+    /// Whether the gas-phase anion is bound
+    stable_anion: bool,
+}
+
+/// Look up the ionization energies and electron affinity of an element
+///
+/// Arguments:
+/// - element: Element symbol or name
+/// - data_json: Ionization energy and electron affinity table
+#[wasm_func]
+pub fn get_ionization_data(element_bytes: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let element: String = parse_arg(element_bytes, "element")?;
+    let data: HashMap<String, AtomicEnergyData> = parse_json(data_json, "ionization data")?;
+
+    let (symbol, entry) = find_atomic_energies(&element, &data)?;
+    let per_ev = kj_per_mol_per_ev();
+    let mut total = 0.0;
+    let cumulative = entry
+        .ie
+        .iter()
+        .map(|ie| {
+            total += ie;
+            total
+        })
+        .collect();
+
+    let result = AtomicEnergies {
+        element: symbol,
+        name: entry.name,
+        z: entry.z,
+        ionization_energies_ev: entry.ie.iter().map(|ie| ie / per_ev).collect(),
+        ionization_energies: entry.ie,
+        cumulative,
+        electron_affinity: entry.ea.map(|ea| CalculationResult::new(ea, "kJ/mol")),
+        electron_affinity_ev: entry.ea.map(|ea| ea / per_ev),
+        electron_gain_enthalpy: entry.ea.map(|ea| CalculationResult::new(0.0 - ea, "kJ/mol")),
+        stable_anion: entry.ea.is_some_and(|ea| ea > 0.0),
+    };
+
+    to_json(&result)
+}
+
+/// One property across the elements, for periodic-trend plots
+#[derive(Serialize)]
+struct PeriodicTrend {
+    property: String,
+    unit: String,
+    /// (Z, symbol, value) in order of atomic number
+    points: Vec<(u32, String, f64)>,
+}
+
+/// Tabulate an ionization energy or the electron affinity against atomic number
+///
+/// Elements without a value (e.g. an unbound anion) are left out.
+///
+/// Arguments:
+/// - property: "IE1", "IE2", ... or "EA"
+/// - data_json: Ionization energy and electron affinity table
+#[wasm_func]
+pub fn ionization_trend(property_bytes: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let property: String = parse_arg(property_bytes, "property")?;
+    let data: HashMap<String, AtomicEnergyData> = parse_json(data_json, "ionization data")?;
+
+    // Index into the ionization energies, or None for the electron affinity
+    let index = if property == "EA" {
+        None
+    } else {
+        let n: usize = property
+            .strip_prefix("IE")
+            .and_then(|n| n.parse().ok())
+            .filter(|n| *n >= 1)
+            .ok_or_else(|| {
+                format!("Unknown property: {} (expected IE1, IE2, ... or EA)", property)
+            })?;
+        Some(n - 1)
+    };
+    let value = |entry: &AtomicEnergyData| match index {
+        Some(i) => entry.ie.get(i).copied(),
+        None => entry.ea,
+    };
+
+    let mut points: Vec<(u32, String, f64)> = data
+        .iter()
+        .filter_map(|(symbol, entry)| value(entry).map(|v| (entry.z, symbol.clone(), v)))
+        .collect();
+    points.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+
+    let result = PeriodicTrend {
+        property,
+        unit: "kJ/mol".to_string(),
+        points,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// DATA IMPORT
+// ============================================================================
+
+/// Options for reading a thermodynamic data table from CSV
+#[derive(Deserialize)]
+struct CsvOptions {
+    /// Field delimiter (default: "," or ";" with decimal commas)
+    #[serde(default)]
+    delimiter: Option<char>,
+    /// Numbers written with a decimal comma, e.g. "-285,83"
+    #[serde(default)]
+    decimal_comma: bool,
+    /// Header of the column holding each field, overriding automatic detection
+    #[serde(default)]
+    columns: HashMap<String, String>,
+}
+
+/// Formation data entry in the layout of the built-in table
+#[derive(Serialize)]
+struct ThermoTableEntry {
+    formula: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(rename = "delta_Hf")]
+    delta_hf: f64,
+    #[serde(rename = "S")]
+    s: f64,
+    #[serde(rename = "delta_Gf")]
+    delta_gf: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+/// Split one CSV record, honouring double-quoted fields with "" escapes
+fn split_csv_record(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields.iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// Field a CSV header refers to, ignoring case, spaces, symbols and a trailing unit
+fn csv_header_field(header: &str) -> Option<&'static str> {
+    let name = header.split(['(', '[', '/']).next().unwrap_or("");
+    let key: String = name
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+    match key.as_str() {
+        "formula" | "species" | "substance" | "compound" => Some("formula"),
+        "state" | "phase" => Some("state"),
+        "deltahf" | "δhf" | "δfh" | "dhf" | "hf" | "deltafh" | "enthalpy" => Some("delta_Hf"),
+        "s" | "s°" | "entropy" | "sm" => Some("S"),
+        "deltagf" | "δgf" | "δfg" | "dgf" | "gf" | "deltafg" | "gibbs" => Some("delta_Gf"),
+        "source" | "reference" | "ref" | "citation" => Some("source"),
+        _ => None,
+    }
+}
+
+/// Read a thermodynamic data table from CSV text
+///
+/// The header row names the columns: formula (or species/substance), an optional state, and
+/// ΔHf (kJ/mol), S (J/(mol·K)) and ΔGf (kJ/mol), matched loosely ("delta_Hf", "ΔHf (kJ/mol)",
+/// "dHf", ...) unless mapped explicitly. An optional source (or reference) column is kept as
+/// each entry's `source`. Blank lines and lines starting with "#" are skipped.
+/// Entries are keyed like the built-in table, e.g. "H2O(l)".
+///
+/// Arguments:
+/// - csv: CSV text
+/// - options_json: {"delimiter", "decimal_comma", "columns"}
+#[wasm_func]
+pub fn parse_thermo_csv(csv_bytes: &[u8], options_json: &[u8]) -> Result<Vec<u8>, String> {
+    let text: String = parse_arg(csv_bytes, "CSV")?;
+    let options: CsvOptions = parse_json(options_json, "CSV options")?;
+
+    let delimiter = options
+        .delimiter
+        .unwrap_or(if options.decimal_comma { ';' } else { ',' });
+    if options.decimal_comma && delimiter == ',' {
+        return Err("Decimal commas need a delimiter other than ','".to_string());
+    }
+    let mut records = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'));
+
+    let (_, header) = records.next().ok_or("CSV has no header row")?;
+    let headers = split_csv_record(header, delimiter);
+    let column = |field: &str| -> Option<usize> {
+        match options.columns.get(field) {
+            Some(name) => headers.iter().position(|h| h == name),
+            None => headers.iter().position(|h| csv_header_field(h) == Some(field)),
+        }
+    };
+    for (field, name) in &options.columns {
+        if !headers.contains(name) {
+            return Err(format!("Column {} for {} not found in the header", name, field));
+        }
+    }
+    let find = |field: &str| {
+        column(field).ok_or_else(|| format!("CSV header has no {} column", field))
+    };
+    let formula_col = find("formula")?;
+    let state_col = column("state");
+    let source_col = column("source");
+    let value_cols = [find("delta_Hf")?, find("S")?, find("delta_Gf")?];
+
+    let mut table = serde_json::Map::new();
+    for (index, line) in records {
+        let fields = split_csv_record(line, delimiter);
+        let line_number = index + 1;
+        let cell = |col: usize| fields.get(col).map(String::as_str).unwrap_or("");
+        let formula = cell(formula_col);
+        if formula.is_empty() {
+            return Err(format!("Line {}: missing formula", line_number));
+        }
+        let mut values = [0.0; 3];
+        for (value, col) in values.iter_mut().zip(value_cols) {
+            let raw = cell(col).replace('−', "-");
+            let raw = if options.decimal_comma { raw.replace(',', ".") } else { raw };
+            *value = raw.parse().map_err(|_| {
+                format!("Line {}: invalid number '{}' for {}", line_number, cell(col), headers[col])
+            })?;
+        }
+
+        // The state column, or a state symbol already in the formula, completes the key
+        let (base, state) = match state_col.map(cell).filter(|s| !s.is_empty()) {
+            Some(state) => {
+                let wrapped = format!("x({})", state);
+                let (_, symbol) = split_state(&wrapped);
+                let symbol =
+                    symbol.ok_or_else(|| format!("Line {}: unknown state '{}'", line_number, state))?;
+                (formula, Some(symbol))
+            }
+            None => split_state(formula),
+        };
+        let key = match state {
+            Some(symbol) => format!("{}({})", base, symbol),
+            None => base.to_string(),
+        };
+        let entry = ThermoTableEntry {
+            formula: base.to_string(),
+            state: state.map(|symbol| {
+                match symbol {
+                    "g" => "gas",
+                    "l" => "liquid",
+                    "s" => "solid",
+                    _ => "aqueous",
+                }
+                .to_string()
+            }),
+            delta_hf: values[0],
+            s: values[1],
+            delta_gf: values[2],
+            source: source_col.map(cell).filter(|s| !s.is_empty()).map(String::from),
+        };
+        if table.insert(key.clone(), serde_json::to_value(entry).unwrap()).is_some() {
+            return Err(format!("Line {}: duplicate entry {}", line_number, key));
+        }
+    }
+
+    to_json(&table)
+}
+
+/// Data tables to merge, in order
+#[derive(Deserialize)]
+struct MergeInput {
+    tables: Vec<serde_json::Map<String, serde_json::Value>>,
+    /// Labels of the tables used in conflict reports (default: "table 1", "table 2", ...)
+    #[serde(default)]
+    names: Vec<String>,
+    /// "last" (later tables override earlier ones) or "first"
+    #[serde(default)]
+    precedence: Option<String>,
+}
+
+/// Entry defined differently by several tables
+#[derive(Serialize)]
+struct MergeConflict {
+    key: String,
+    /// Tables defining the entry, in order
+    sources: Vec<String>,
+    /// Table whose entry was kept
+    used: String,
+}
+
+#[derive(Serialize)]
+struct MergedDatabase {
+    data: serde_json::Map<String, serde_json::Value>,
+    conflicts: Vec<MergeConflict>,
+    /// Entries taken from each table
+    counts: Vec<(String, usize)>,
+}
+
+/// Compare two table entries, treating numbers by value (1 and 1.0 are equal)
+fn same_entry(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64() == y.as_f64(),
+        (Value::Array(x), Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(x, y)| same_entry(x, y))
+        }
+        (Value::Object(x), Value::Object(y)) => {
+            x.len() == y.len()
+                && x.iter().all(|(key, x)| y.get(key).is_some_and(|y| same_entry(x, y)))
+        }
+        _ => a == b,
+    }
+}
+
+/// Merge data tables entry by entry with explicit precedence, reporting entries that the
+/// tables define differently
+///
+/// Arguments:
+/// - input_json: {"tables", "names", "precedence"}
+#[wasm_func]
+pub fn merge_databases(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: MergeInput = parse_json(input_json, "merge input")?;
+
+    let last_wins = match input.precedence.as_deref().unwrap_or("last") {
+        "last" => true,
+        "first" => false,
+        other => return Err(format!("Unknown precedence: {} (expected first or last)", other)),
+    };
+    if !input.names.is_empty() && input.names.len() != input.tables.len() {
+        return Err("Give one name per table".to_string());
+    }
+    let names: Vec<String> = (0..input.tables.len())
+        .map(|i| input.names.get(i).cloned().unwrap_or_else(|| format!("table {}", i + 1)))
+        .collect();
+
+    // Later tables are visited first when they take precedence
+    let mut order: Vec<usize> = (0..input.tables.len()).collect();
+    if last_wins {
+        order.reverse();
+    }
+    let mut data = serde_json::Map::new();
+    let mut chosen: HashMap<String, usize> = HashMap::new();
+    let mut counts = vec![0; input.tables.len()];
+    for &i in &order {
+        for (key, entry) in &input.tables[i] {
+            if !data.contains_key(key) {
+                data.insert(key.clone(), entry.clone());
+                chosen.insert(key.clone(), i);
+                counts[i] += 1;
+            }
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (key, entry) in &data {
+        let defining: Vec<usize> = (0..input.tables.len())
+            .filter(|i| input.tables[*i].contains_key(key))
+            .collect();
+        let differs = defining.iter().any(|i| !same_entry(&input.tables[*i][key], entry));
+        if differs {
+            conflicts.push(MergeConflict {
+                key: key.clone(),
+                sources: defining.iter().map(|i| names[*i].clone()).collect(),
+                used: names[chosen[key]].clone(),
+            });
+        }
+    }
+
+    let result = MergedDatabase {
+        data,
+        conflicts,
+        counts: names.into_iter().zip(counts).collect(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// DATA VALIDATION
+// ============================================================================
+
+/// Reference state of each element at 298.15 K: (element, formula, state symbol, S° of the
+/// formula unit in J/(mol·K))
+const REFERENCE_STATES: [(&str, &str, &str, f64); 25] = [
+    ("H", "H2", "g", 130.68),
+    ("O", "O2", "g", 205.15),
+    ("N", "N2", "g", 191.61),
+    ("F", "F2", "g", 202.79),
+    ("Cl", "Cl2", "g", 223.08),
+    ("Br", "Br2", "l", 152.21),
+    ("I", "I2", "s", 116.14),
+    ("C", "C", "s", 5.74),
+    ("S", "S", "s", 32.05),
+    ("P", "P", "s", 41.09),
+    ("Si", "Si", "s", 18.81),
+    ("Li", "Li", "s", 29.12),
+    ("Na", "Na", "s", 51.30),
+    ("K", "K", "s", 64.68),
+    ("Mg", "Mg", "s", 32.67),
+    ("Ca", "Ca", "s", 41.59),
+    ("Ba", "Ba", "s", 62.42),
+    ("Al", "Al", "s", 28.35),
+    ("Mn", "Mn", "s", 32.01),
+    ("Fe", "Fe", "s", 27.28),
+    ("Cu", "Cu", "s", 33.15),
+    ("Zn", "Zn", "s", 41.63),
+    ("Ag", "Ag", "s", 42.55),
+    ("Hg", "Hg", "l", 75.90),
+    ("Pb", "Pb", "s", 64.81),
+];
+
+#[derive(Deserialize)]
+struct ValidationOptions {
+    #[serde(default = "default_temperature")]
+    temperature: f64,
+    /// Largest accepted |ΔGf - (ΔHf - T·ΔSf)| (kJ/mol)
+    #[serde(default = "default_validation_tolerance")]
+    tolerance: f64,
+}
+
+fn default_validation_tolerance() -> f64 {
+    1.0
+}
+
+/// Problem found in one entry
+#[derive(Serialize)]
+struct DataIssue {
+    key: String,
+    /// "error" for unusable entries, "warning" for suspicious values
+    severity: String,
+    message: String,
+    /// ΔHf - T·ΔSf (kJ/mol), when it could be computed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_delta_gf: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct ValidationReport {
+    /// Whether no errors or warnings were found
+    valid: bool,
+    /// Entries checked for ΔGf consistency
+    checked: usize,
+    /// Entries whose elements have no reference entropy, or whose key is not a formula
+    skipped: Vec<String>,
+    issues: Vec<DataIssue>,
+}
+
+/// Check a thermodynamic data table for internal consistency
+///
+/// For each entry the entropy of formation ΔSf = S° - Σν·S°(elements) is built from the
+/// element reference states (taken from the table itself when it lists them, e.g. "O2(g)"),
+/// with ½·z·S°(H₂) added for ions on the H⁺(aq) = 0 convention, and ΔGf is compared with
+/// ΔHf - T·ΔSf. Values that agree only after a factor of 1000 are reported as J/kJ mix-ups.
+/// Missing or non-numeric fields, negative entropies of neutral species and non-zero
+/// formation values of elements in their reference state are flagged too.
+///
+/// Arguments:
+/// - data_json: Thermodynamic data table
+/// - options_json: {"temperature", "tolerance"}
+#[wasm_func]
+pub fn validate_database(data_json: &[u8], options_json: &[u8]) -> Result<Vec<u8>, String> {
+    let data: serde_json::Map<String, serde_json::Value> =
+        parse_json(data_json, "thermodynamic data")?;
+    let options: ValidationOptions = parse_json(options_json, "validation options")?;
+    check_temperature(options.temperature)?;
+    check_positive("tolerance", options.tolerance)?;
+    let t = options.temperature;
+
+    let field = |entry: &serde_json::Value, name: &str| entry.get(name).and_then(|v| v.as_f64());
+    // Entropy per atom of each element in its reference state, preferring the table's values
+    let reference_entropy = |element: &str| -> Option<f64> {
+        let (_, formula, state, s) = REFERENCE_STATES.iter().find(|(e, ..)| *e == element)?;
+        let atoms = parse_formula(formula).ok()?.count(element);
+        let tabulated = data
+            .get(&format!("{}({})", formula, state))
+            .and_then(|entry| field(entry, "S"));
+        Some(tabulated.unwrap_or(*s) / atoms)
+    };
+
+    let mut issues = Vec::new();
+    let mut skipped = Vec::new();
+    let mut checked = 0;
+    let mut issue = |key: &str, severity: &str, message: String, expected: Option<f64>| {
+        issues.push(DataIssue {
+            key: key.to_string(),
+            severity: severity.to_string(),
+            message,
+            expected_delta_gf: expected,
+        })
+    };
+
+    for (key, entry) in &data {
+        let values: Vec<Option<f64>> =
+            ["delta_Hf", "S", "delta_Gf"].iter().map(|name| field(entry, name)).collect();
+        let missing: Vec<&str> = ["delta_Hf", "S", "delta_Gf"]
+            .iter()
+            .zip(&values)
+            .filter(|(_, value)| value.is_none_or(|v| !v.is_finite()))
+            .map(|(name, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            issue(key, "error", format!("Missing or invalid {}", missing.join(", ")), None);
+            continue;
+        }
+        let (delta_hf, s, delta_gf) = (values[0].unwrap(), values[1].unwrap(), values[2].unwrap());
+
+        let Ok(formula) = parse_formula(key) else {
+            skipped.push(key.clone());
+            continue;
+        };
+        let state = formula.state.as_deref();
+        if s < 0.0 && formula.charge == 0.0 && state != Some("aq") {
+            issue(
+                key,
+                "warning",
+                format!("Negative entropy {} J/(mol·K) for a neutral species", s),
+                None,
+            );
+        }
+        let is_reference = formula.elements.len() == 1
+            && formula.charge == 0.0
+            && REFERENCE_STATES.iter().any(|(_, f, st, _)| {
+                key.as_str() == format!("{}({})", f, st)
+            });
+        if is_reference && (delta_hf.abs() > 1e-9 || delta_gf.abs() > 1e-9) {
+            issue(
+                key,
+                "warning",
+                "Element in its reference state should have ΔHf = ΔGf = 0".to_string(),
+                Some(0.0),
+            );
+            continue;
+        }
+
+        let mut elements_entropy = 0.0;
+        let mut known = true;
+        for (element, count) in &formula.elements {
+            match reference_entropy(element) {
+                Some(s_element) => elements_entropy += count * s_element,
+                None => known = false,
+            }
+        }
+        if !known {
+            skipped.push(key.clone());
+            continue;
+        }
+        checked += 1;
+
+        // Ions: M + z H⁺(aq) → Mᶻ⁺ + z/2 H₂, so each unit of charge adds ½S°(H₂)
+        let hydrogen = reference_entropy("H").unwrap();
+        let entropy_of = |s: f64| s - elements_entropy + formula.charge * hydrogen;
+        let expected = |h: f64, s: f64| h - t * entropy_of(s) / 1000.0;
+        let residual = (delta_gf - expected(delta_hf, s)).abs();
+        if residual <= options.tolerance {
+            continue;
+        }
+
+        let corrections = [
+            ("ΔHf appears to be in J/mol", (delta_hf / 1000.0, s, delta_gf)),
+            ("ΔGf appears to be in J/mol", (delta_hf, s, delta_gf / 1000.0)),
+            ("ΔHf and ΔGf appear to be in J/mol", (delta_hf / 1000.0, s, delta_gf / 1000.0)),
+            ("S appears to be in kJ/(mol·K)", (delta_hf, s * 1000.0, delta_gf)),
+        ];
+        let fix = corrections
+            .iter()
+            .find(|(_, (h, s, g))| (g - expected(*h, *s)).abs() <= options.tolerance);
+        match fix {
+            Some((message, _)) => issue(key, "error", message.to_string(), None),
+            None => issue(
+                key,
+                "warning",
+                format!(
+                    "ΔGf = {} kJ/mol differs from ΔHf - T·ΔSf = {:.2} kJ/mol by {:.2} kJ/mol",
+                    delta_gf,
+                    expected(delta_hf, s),
+                    residual
+                ),
+                Some(expected(delta_hf, s)),
+            ),
+        }
+    }
+
+    let result = ValidationReport {
+        valid: issues.is_empty(),
+        checked,
+        skipped,
+        issues,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// DATA LISTING
+// ============================================================================
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct SubstanceFilter {
+    /// Elements that must all occur in the formula
+    elements: Vec<String>,
+    /// State, in any spelling accepted in formulas ("g", "gas", "aq", ...)
+    phase: Option<String>,
+    /// Case-insensitive text contained in the key or the entry's name
+    search: Option<String>,
+}
+
+/// Entry of a data table with the composition read from its key
+#[derive(Serialize)]
+struct SubstanceListing {
+    key: String,
+    formula: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charge: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    molar_mass: Option<f64>,
+    /// The table entry as given
+    properties: serde_json::Value,
+}
+
+/// List the entries of a data table, optionally filtered by element, phase or text
+///
+/// Entries are sorted by key. Keys that are not formulas (e.g. bonds or reaction names) are
+/// listed without composition and are excluded by the element and phase filters.
+///
+/// Arguments:
+/// - data_json: Data table
+/// - filter_json: {"elements", "phase", "search"}
+#[wasm_func]
+pub fn list_substances(data_json: &[u8], filter_json: &[u8]) -> Result<Vec<u8>, String> {
+    let data: serde_json::Map<String, serde_json::Value> = parse_json(data_json, "data table")?;
+    let filter: SubstanceFilter = parse_json(filter_json, "substance filter")?;
+
+    let phase = match &filter.phase {
+        Some(phase) => Some(
+            split_state(&format!("X({})", phase))
+                .1
+                .ok_or_else(|| format!("Unknown phase: {}", phase))?,
+        ),
+        None => None,
+    };
+    let search = filter.search.as_ref().map(|text| text.to_lowercase());
+
+    let mut keys: Vec<&String> = data.keys().collect();
+    keys.sort();
+    let mut listing = Vec::new();
+    for key in keys {
+        let entry = &data[key];
+        let parsed = parse_formula(key).ok();
+        let state = split_state(key).1;
+        if phase.is_some() && state != phase {
+            continue;
+        }
+        if !filter.elements.iter().all(|element| {
+            parsed.as_ref().is_some_and(|formula| formula.count(element) > 0.0)
+        }) {
+            continue;
+        }
+        if let Some(text) = &search {
+            let name = entry.get("name").and_then(|n| n.as_str()).unwrap_or("");
+            if !key.to_lowercase().contains(text) && !name.to_lowercase().contains(text) {
+                continue;
+            }
+        }
+        listing.push(SubstanceListing {
+            key: key.clone(),
+            formula: split_state(key).0.to_string(),
+            state: state.map(String::from),
+            charge: parsed.as_ref().map(|formula| formula.charge),
+            molar_mass: parsed.as_ref().and_then(|formula| formula.molar_mass().ok()),
+            properties: entry.clone(),
+        });
+    }
+
+    to_json(&listing)
+}
+
+// ============================================================================
+// REACTION ALGEBRA
+// ============================================================================
+
+/// Reaction with the thermodynamic quantities known for it
+#[derive(Serialize, Deserialize, Clone)]
+struct ReactionValues {
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    /// ΔH (kJ/mol)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delta_h: Option<f64>,
+    /// ΔS (J/(mol·K))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delta_s: Option<f64>,
+    /// ΔG (kJ/mol)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delta_g: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    k: Option<f64>,
+    /// Temperature of ΔG and K (K)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+}
+
+/// Result of a reaction manipulation
+#[derive(Serialize)]
+struct TransformedReaction {
+    #[serde(flatten)]
+    reaction: ReactionValues,
+    equation: String,
+    /// The rules applied to the quantities
+    rules: Vec<String>,
+}
+
+impl TransformedReaction {
+    fn new(reaction: ReactionValues, rules: &[&str]) -> Self {
+        let equation = format!(
+            "{} → {}",
+            format_equation_side(&reaction.reactants),
+            format_equation_side(&reaction.products)
+        );
+        Self {
+            reaction,
+            equation,
+            rules: rules.iter().map(|rule| rule.to_string()).collect(),
+        }
+    }
+}
+
+/// Reverse a reaction: products become reactants
+/// ΔH, ΔS, ΔG → -ΔH, -ΔS, -ΔG and K → 1/K
+///
+/// Arguments:
+/// - reaction_json: {"reactants", "products", "delta_h", "delta_s", "delta_g", "k",
+///   "temperature"}, quantities optional
+#[wasm_func]
+pub fn reverse_reaction(reaction_json: &[u8]) -> Result<Vec<u8>, String> {
+    let reaction: ReactionValues = parse_json(reaction_json, "reaction")?;
+
+    let reversed = ReactionValues {
+        reactants: reaction.products,
+        products: reaction.reactants,
+        delta_h: reaction.delta_h.map(|h| -h),
+        delta_s: reaction.delta_s.map(|s| -s),
+        delta_g: reaction.delta_g.map(|g| -g),
+        k: reaction.k.map(|k| 1.0 / k),
+        temperature: reaction.temperature,
+    };
+    let result = TransformedReaction::new(
+        reversed,
+        &["ΔH, ΔS and ΔG change sign", "K is inverted: K' = 1/K"],
+    );
+
+    to_json(&result)
+}
+
+/// Multiply all coefficients of a reaction by a factor n
+/// ΔH, ΔS, ΔG → n·ΔH, n·ΔS, n·ΔG and K → Kⁿ
+///
+/// A negative factor also reverses the reaction.
+///
+/// Arguments:
+/// - reaction_json: Reaction as for `reverse_reaction`
+/// - factor: Multiplier n (non-zero)
+#[wasm_func]
+pub fn scale_reaction(reaction_json: &[u8], factor_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let reaction: ReactionValues = parse_json(reaction_json, "reaction")?;
+    let factor = parse_number(factor_bytes, "factor")?;
+
+    check_domain("factor", factor, factor != 0.0, "non-zero")?;
+    let n = factor.abs();
+    let scale = |side: Vec<(String, f64)>| -> Vec<(String, f64)> {
+        side.into_iter().map(|(species, coeff)| (species, coeff * n)).collect()
+    };
+    let (reactants, products) = if factor > 0.0 {
+        (scale(reaction.reactants), scale(reaction.products))
+    } else {
+        (scale(reaction.products), scale(reaction.reactants))
+    };
+    let scaled = ReactionValues {
+        reactants,
+        products,
+        delta_h: reaction.delta_h.map(|h| h * factor),
+        delta_s: reaction.delta_s.map(|s| s * factor),
+        delta_g: reaction.delta_g.map(|g| g * factor),
+        k: reaction.k.map(|k| k.powf(factor)),
+        temperature: reaction.temperature,
+    };
+    let mut rules = vec![
+        "ΔH, ΔS and ΔG are multiplied by the factor n",
+        "K is raised to the power n: K' = Kⁿ",
+    ];
+    if factor < 0.0 {
+        rules.push("A negative factor reverses the reaction");
+    }
+    let result = TransformedReaction::new(scaled, &rules);
+
+    to_json(&result)
+}
+
+/// Add reactions (Hess's law)
+/// ΔH = ΣΔHᵢ, ΔS = ΣΔSᵢ, ΔG = ΣΔGᵢ and K = ΠKᵢ
+///
+/// Species on both sides cancel. A quantity is only given for the sum if every reaction has
+/// it, and ΔG and K are only combined at a common temperature.
+///
+/// Arguments:
+/// - reactions_json: Array of reactions as for `reverse_reaction`
+#[wasm_func]
+pub fn add_reactions(reactions_json: &[u8]) -> Result<Vec<u8>, String> {
+    let reactions: Vec<ReactionValues> = parse_json(reactions_json, "reactions")?;
+
+    if reactions.is_empty() {
+        return Err("At least one reaction is required".to_string());
+    }
+    let temperatures: Vec<f64> = reactions.iter().filter_map(|r| r.temperature).collect();
+    if temperatures.iter().any(|t| (t - temperatures[0]).abs() > 1e-9) {
+        return Err("ΔG and K can only be added for reactions at the same temperature".to_string());
+    }
+
+    let mut reactants = Vec::new();
+    let mut products = Vec::new();
+    for reaction in &reactions {
+        add_species(&mut reactants, &reaction.reactants, 1.0);
+        add_species(&mut products, &reaction.products, 1.0);
+    }
+    // Cancel intermediates appearing on both sides
+    for (name, coeff) in reactants.iter_mut() {
+        if let Some((_, other)) = products.iter_mut().find(|(p, _)| p == name) {
+            let common = coeff.min(*other);
+            *coeff -= common;
+            *other -= common;
+        }
+    }
+    reactants.retain(|(_, coeff)| *coeff > 1e-12);
+    products.retain(|(_, coeff)| *coeff > 1e-12);
+
+    let sum = |value: fn(&ReactionValues) -> Option<f64>| -> Option<f64> {
+        reactions.iter().map(value).sum()
+    };
+    let total = ReactionValues {
+        reactants,
+        products,
+        delta_h: sum(|r| r.delta_h),
+        delta_s: sum(|r| r.delta_s),
+        delta_g: sum(|r| r.delta_g),
+        k: reactions.iter().map(|r| r.k).product(),
+        temperature: temperatures.first().copied(),
+    };
+    let result = TransformedReaction::new(
+        total,
+        &[
+            "ΔH, ΔS and ΔG of the steps are added (Hess's law)",
+            "K of the steps are multiplied: K = ΠKᵢ",
+            "Species appearing on both sides cancel",
+        ],
+    );
+
+    to_json(&result)
+}
+
+/// A change imposed on a system at equilibrium
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PerturbationSpec {
+    /// Change without further details, e.g. "increase-temperature"
+    Change(String),
+    Detailed(Perturbation),
+}
+
+#[derive(Deserialize)]
+struct Perturbation {
+    change: String,
+    /// Species added or removed
+    #[serde(default)]
+    species: Option<String>,
+    /// "volume" or "pressure", held constant when adding an inert gas
+    #[serde(default)]
+    constant: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LeChatelierInput {
+    reaction: ReactionValues,
+    perturbations: Vec<PerturbationSpec>,
+}
+
+/// Response of an equilibrium to one perturbation
+#[derive(Serialize)]
+struct EquilibriumShift {
+    change: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    species: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    constant: Option<String>,
+    /// "forward", "reverse", "none" or "unknown"
+    shift: String,
+    /// Effect on K: "increases", "decreases", "unchanged" or "unknown"
+    k_change: String,
+    /// Machine-readable reason, e.g. "fewer-gas-moles" or "endothermic-direction"
+    reason: String,
+    explanation: String,
+}
+
+/// Le Chatelier analysis of a reaction
+#[derive(Serialize)]
+struct LeChatelierAnalysis {
+    equation: String,
+    /// Δn(gas)
+    delta_n: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delta_h: Option<f64>,
+    /// "exothermic", "endothermic" or "thermoneutral", when ΔH is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thermicity: Option<String>,
+    shifts: Vec<EquilibriumShift>,
+    warnings: Vec<String>,
+}
+
+/// Predict the direction in which an equilibrium shifts after each perturbation
+///
+/// - Adding a reactant or removing a product makes Q < K (forward), and vice versa; pure
+///   solids and liquids have unit activity and cause no shift
+/// - Compression (higher P, smaller V) favours the side with fewer moles of gas
+/// - Heating favours the endothermic direction and is the only change that alters K
+/// - An inert gas leaves the partial pressures unchanged at constant volume, but dilutes the
+///   mixture like an expansion at constant pressure
+/// - A catalyst speeds up both directions and causes no shift
+///
+/// Δn(gas) comes from the "(g)" annotations and the sign of ΔH from `delta_h`.
+///
+/// Arguments:
+/// - input_json: {"reaction", "perturbations"}, the reaction as for `reverse_reaction` and
+///   each perturbation a change name or {"change", "species", "constant"}
+#[wasm_func]
+pub fn le_chatelier(input_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: LeChatelierInput = parse_json(input_json, "Le Chatelier input")?;
+    let reaction = &input.reaction;
+
+    let (delta_n, _, _, unspecified) = gas_mole_change(&reaction.reactants, &reaction.products);
+    let mut warnings = Vec::new();
+    if !unspecified.is_empty() {
+        warnings.push(format!(
+            "No state given for {}; not counted as gases",
+            unspecified.join(", ")
+        ));
+    }
+    let thermicity = reaction.delta_h.map(|h| {
+        if h > 0.0 {
+            "endothermic"
+        } else if h < 0.0 {
+            "exothermic"
+        } else {
+            "thermoneutral"
+        }
+    });
+
+    // Shift towards fewer gas moles (compression) or more gas moles (expansion, dilution)
+    let gas_shift = |towards_fewer: bool| -> (&str, &str, String) {
+        if delta_n == 0.0 {
+            return (
+                "none",
+                "equal-gas-moles",
+                "Both sides have the same number of moles of gas".to_string(),
+            );
+        }
+        let forward = (delta_n < 0.0) == towards_fewer;
+        let (side, reason) = if towards_fewer {
+            ("fewer", "fewer-gas-moles")
+        } else {
+            ("more", "more-gas-moles")
+        };
+        let direction = if forward { "forward" } else { "reverse" };
+        (
+            direction,
+            reason,
+            format!("Shifts towards the side with {} moles of gas (Δn(gas) = {})", side, delta_n),
+        )
+    };
+
+    let mut shifts = Vec::new();
+    for spec in input.perturbations {
+        let perturbation = match spec {
+            PerturbationSpec::Change(change) => Perturbation {
+                change,
+                species: None,
+                constant: None,
+            },
+            PerturbationSpec::Detailed(perturbation) => perturbation,
+        };
+        let mut constant = None;
+        let (shift, k_change, reason, explanation) = match perturbation.change.as_str() {
+            "add" | "remove" => {
+                let Some(species) = &perturbation.species else {
+                    return Err(format!("Perturbation {} needs a species", perturbation.change));
+                };
+                let target = SpeciesState::new(species).normalized;
+                let matches = |side: &[(String, f64)]| {
+                    side.iter().any(|(s, _)| SpeciesState::new(s).normalized == target)
+                };
+                let is_reactant = if matches(&reaction.reactants) {
+                    true
+                } else if matches(&reaction.products) {
+                    false
+                } else {
+                    return Err(format!("{} does not take part in the reaction", species));
+                };
+                let adding = perturbation.change == "add";
+                let role = if is_reactant { "reactant" } else { "product" };
+                if matches!(split_state(species).1, Some("s") | Some("l")) {
+                    (
+                        "none",
+                        "unchanged",
+                        "pure-phase".to_string(),
+                        format!("{} is a pure solid or liquid with unit activity", species),
+                    )
+                } else {
+                    let forward = adding == is_reactant;
+                    let (verb, quotient) = if adding {
+                        ("added", if is_reactant { "Q < K" } else { "Q > K" })
+                    } else {
+                        ("removed", if is_reactant { "Q > K" } else { "Q < K" })
+                    };
+                    (
+                        if forward { "forward" } else { "reverse" },
+                        "unchanged",
+                        format!("{}-{}", role, verb),
+                        format!("A {} is {}, so {}", role, verb, quotient),
+                    )
+                }
+            }
+            change @ ("increase-pressure" | "decrease-volume" | "decrease-pressure"
+            | "increase-volume") => {
+                let (shift, reason, explanation) =
+                    gas_shift(matches!(change, "increase-pressure" | "decrease-volume"));
+                (shift, "unchanged", reason.to_string(), explanation)
+            }
+            change @ ("increase-temperature" | "decrease-temperature") => {
+                let heating = change == "increase-temperature";
+                match reaction.delta_h {
+                    None => (
+                        "unknown",
+                        "unknown",
+                        "delta-h-unknown".to_string(),
+                        "ΔH of the reaction is needed".to_string(),
+                    ),
+                    Some(0.0) => (
+                        "none",
+                        "unchanged",
+                        "thermoneutral".to_string(),
+                        "ΔH = 0, so K does not depend on temperature".to_string(),
+                    ),
+                    Some(h) => {
+                        let forward = (h > 0.0) == heating;
+                        let favoured = if heating { "endothermic" } else { "exothermic" };
+                        (
+                            if forward { "forward" } else { "reverse" },
+                            if forward { "increases" } else { "decreases" },
+                            format!("{}-direction", favoured),
+                            format!(
+                                "{} favours the {} direction (ΔH = {} kJ/mol)",
+                                if heating { "Heating" } else { "Cooling" },
+                                favoured,
+                                h
+                            ),
+                        )
+                    }
+                }
+            }
+            "add-inert" => {
+                let held = perturbation.constant.as_deref().unwrap_or("volume");
+                constant = Some(held.to_string());
+                match held {
+                    "volume" => (
+                        "none",
+                        "unchanged",
+                        "partial-pressures-unchanged".to_string(),
+                        "At constant volume the partial pressures of the reacting gases do not change"
+                            .to_string(),
+                    ),
+                    "pressure" => {
+                        let (shift, reason, explanation) = gas_shift(false);
+                        (
+                            shift,
+                            "unchanged",
+                            reason.to_string(),
+                            format!("At constant pressure the gases are diluted. {}", explanation),
+                        )
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Unknown constant quantity: {} (expected volume or pressure)",
+                            held
+                        ))
+                    }
+                }
+            }
+            "catalyst" => (
+                "none",
+                "unchanged",
+                "rates-only".to_string(),
+                "A catalyst speeds up both directions equally".to_string(),
+            ),
+            other => {
+                return Err(format!(
+                    "Unknown perturbation: {} (expected add, remove, increase-pressure, \
+                     decrease-pressure, increase-volume, decrease-volume, increase-temperature, \
+                     decrease-temperature, add-inert or catalyst)",
+                    other
+                ))
+            }
+        };
+        shifts.push(EquilibriumShift {
+            change: perturbation.change.clone(),
+            species: perturbation.species.clone(),
+            constant,
+            shift: shift.to_string(),
+            k_change: k_change.to_string(),
+            reason,
+            explanation,
+        });
+    }
+
+    let result = LeChatelierAnalysis {
+        equation: format!(
+            "{} ⇌ {}",
+            format_equation_side(&reaction.reactants),
+            format_equation_side(&reaction.products)
+        ),
+        delta_n,
+        delta_h: reaction.delta_h,
+        thermicity: thermicity.map(String::from),
+        shifts,
+        warnings,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ENTHALPY INCREMENT TABLES
+// ============================================================================
+
+/// JANAF-style table of one species: H(T) - H(298.15 K) and S°(T) at several temperatures
+#[derive(Deserialize)]
+struct IncrementTable {
+    /// Temperatures (K), ascending
+    #[serde(rename = "T")]
+    t: Vec<f64>,
+    /// H(T) - H(298.15 K) (kJ/mol)
+    #[serde(rename = "H_minus_H298")]
+    h_increment: Vec<f64>,
+    /// S°(T) (J/(mol·K))
+    #[serde(rename = "S")]
+    s: Vec<f64>,
+}
+
+/// Interpolated enthalpy increment and entropy of a species
+#[derive(Serialize)]
+struct IncrementValues {
+    species: String,
+    temperature: f64,
+    /// H(T) - H(298.15 K) (kJ/mol)
+    h_increment: f64,
+    /// S°(T) (J/(mol·K))
+    s: f64,
+}
+
+/// Interpolate H(T) - H(298.15 K) and S°(T) of a species linearly in T
+fn increment_values(
+    species: &str,
+    tables: &HashMap<String, IncrementTable>,
+    temperature: f64,
+) -> Result<IncrementValues, String> {
+    let table = lookup_species(tables, species, "species")?;
+    let n = table.t.len();
+    if n < 2 || table.h_increment.len() != n || table.s.len() != n {
+        return Err(format!(
+            "Increment table of {} needs at least two temperatures and one H - H298 and S \
+             value per temperature",
+            species
+        ));
+    }
+    if table.t.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(format!("Temperatures of {} must be ascending", species));
+    }
+    let column = |values: &[f64]| -> Vec<(f64, f64)> {
+        table.t.iter().copied().zip(values.iter().copied()).collect()
+    };
+    let outside = || {
+        format!(
+            "{} K is outside the tabulated range {}–{} K of {}",
+            temperature,
+            table.t[0],
+            table.t[n - 1],
+            species
+        )
+    };
+    Ok(IncrementValues {
+        species: species.to_string(),
+        temperature,
+        h_increment: interpolate(&column(&table.h_increment), temperature).ok_or_else(outside)?,
+        s: interpolate(&column(&table.s), temperature).ok_or_else(outside)?,
+    })
+}
+
+/// H(T) - H(298.15 K) and S°(T) of a species from its increment table
+///
+/// Arguments:
+/// - species: Species name
+/// - T: Temperature (K), within the tabulated range
+/// - tables_json: Increment tables {"species": {"T", "H_minus_H298", "S"}}
+#[wasm_func]
+pub fn interpolate_increments(
+    species_bytes: &[u8],
+    temperature_bytes: &[u8],
+    tables_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let species: String = parse_arg(species_bytes, "species")?;
+    let temperature = parse_number(temperature_bytes, "temperature")?;
+    let tables: HashMap<String, IncrementTable> = parse_json(tables_json, "increment tables")?;
+
+    check_temperature(temperature)?;
+    let result = increment_values(&species, &tables, temperature)?;
+
+    to_json(&result)
+}
+
+#[derive(Deserialize)]
+struct IncrementReactionInput {
+    reactants: Vec<(String, f64)>,
+    products: Vec<(String, f64)>,
+    temperature: f64,
+}
+
+/// Reaction quantities at a temperature from enthalpy increment tables
+#[derive(Serialize)]
+struct IncrementReaction {
+    temperature: f64,
+    /// ΔH°(T) (kJ/mol)
+    enthalpy: CalculationResult,
+    /// ΔS°(T) (J/(mol·K))
+    entropy: CalculationResult,
+    /// ΔG°(T) = ΔH°(T) - T·ΔS°(T) (kJ/mol)
+    gibbs: CalculationResult,
+    equilibrium_constant: CalculationResult,
+    /// ΔH°(298.15 K) (kJ/mol)
+    enthalpy_298: f64,
+    /// Σν·[H(T) - H(298.15 K)] (kJ/mol)
+    enthalpy_correction: f64,
+    species: Vec<IncrementValues>,
+}
+
+/// Reaction enthalpy, entropy, Gibbs energy and K at any tabulated temperature
+///
+/// ΔH°(T) = ΔH°(298.15 K) + Σν·[H(T) - H(298.15 K)] with ΔH°(298.15 K) from the formation
+/// data, ΔS°(T) = Σν·S°(T) and ΔG°(T) = ΔH°(T) - T·ΔS°(T). Increments and entropies are
+/// interpolated linearly between the tabulated temperatures, as in JANAF or NIST tables;
+/// temperatures outside a species' table are rejected rather than extrapolated.
+///
+/// Arguments:
+/// - input_json: {"reactants", "products", "temperature"}
+/// - data_json: Formation data (ΔHf at 298.15 K)
+/// - tables_json: Increment tables of every species
+#[wasm_func]
+pub fn reaction_from_increments(
+    input_json: &[u8],
+    data_json: &[u8],
+    tables_json: &[u8],
+) -> Result<Vec<u8>, String> {
+    let input: IncrementReactionInput = parse_json(input_json, "reaction")?;
+    let data: HashMap<String, ThermodynamicData> = parse_json(data_json, "thermodynamic data")?;
+    let tables: HashMap<String, IncrementTable> = parse_json(tables_json, "increment tables")?;
+
+    let t = input.temperature;
+    check_temperature(t)?;
+    check_charge_balance(&input.reactants, &input.products)?;
+
+    let mut enthalpy_298 = 0.0;
+    let mut correction = 0.0;
+    let mut delta_s = 0.0;
+    let mut species = Vec::new();
+    let mut sources = Vec::new();
+    let sides = [(&input.reactants, -1.0, "reactant"), (&input.products, 1.0, "product")];
+    for (side, sign, role) in sides {
+        for (name, coeff) in side {
+            let formation = lookup_species(&data, name, role)?;
+            let values = increment_values(name, &tables, t)?;
+            enthalpy_298 += sign * coeff * formation.delta_hf;
+            correction += sign * coeff * values.h_increment;
+            delta_s += sign * coeff * values.s;
+            sources.push(&formation.source);
+            species.push(values);
+        }
+    }
+    let delta_h = enthalpy_298 + correction;
+    let delta_g = delta_h - t * delta_s / 1000.0;
+
+    let result = IncrementReaction {
+        temperature: t,
+        enthalpy: CalculationResult::new(delta_h, "kJ/mol").with_sources(sources),
+        entropy: CalculationResult::new(delta_s, "J/(mol·K)"),
+        gibbs: CalculationResult::new(delta_g, "kJ/mol"),
+        equilibrium_constant: CalculationResult::new((-delta_g * 1000.0 / (R * t)).exp(), ""),
+        enthalpy_298,
+        enthalpy_correction: correction,
+        species,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ELLINGHAM DIAGRAMS
+// ============================================================================
+
+/// Oxide of an Ellingham diagram: a formula from the data, or a line given directly
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EllinghamSpec {
+    Oxide(String),
+    /// ΔH° (kJ) and ΔS° (J/K) per mol O₂
+    Line {
+        label: String,
+        delta_h: f64,
+        delta_s: f64,
+    },
+}
+
+fn default_ellingham_points() -> usize {
+    50
+}
+
+#[derive(Deserialize)]
+struct EllinghamInput {
+    oxides: Vec<EllinghamSpec>,
+    /// [min, max] temperature (K)
+    range: (f64, f64),
+    #[serde(default = "default_ellingham_points")]
+    points: usize,
+}
+
+/// ΔG°(T) line of one oxidation reaction, per mol O₂
+#[derive(Serialize)]
+struct EllinghamLine {
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equation: Option<String>,
+    /// ΔH° (kJ/mol O₂)
+    delta_h: f64,
+    /// ΔS° (J/(K·mol O₂))
+    delta_s: f64,
+    /// (T in K, ΔG° in kJ/mol O₂) points
+    points: Vec<(f64, f64)>,
+    /// Temperature above which carbon (as CO) reduces the oxide, if there is one (K)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    carbon_reduction_temperature: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct EllinghamDiagram {
+    lines: Vec<EllinghamLine>,
+    /// 2C + O₂ → 2CO
+    carbon_line: EllinghamLine,
+    range: (f64, f64),
+}
+
+/// ΔH° and ΔS° per mol O₂ of forming an oxide (or other compound of one element with
+/// oxygen) from the element in its reference state, with the equation
+fn ellingham_reaction(
+    oxide: &str,
+    data: &HashMap<String, ThermodynamicData>,
+) -> Result<(f64, f64, String), String> {
+    let formula = parse_formula(oxide)?;
+    let (element, atoms) = match formula.elements.as_slice() {
+        [(a, x), (b, _)] | [(b, _), (a, x)] if b == "O" && a != "O" => (a.clone(), *x),
+        _ => return Err(format!("{} is not a binary oxide", oxide)),
+    };
+    let oxygen = formula.count("O");
+    let entry = lookup_species(data, oxide, "oxide")?;
+    let reference = |symbol: &str| -> Result<(String, f64, f64), String> {
+        let (_, ref_formula, state, s) = REFERENCE_STATES
+            .iter()
+            .find(|(e, ..)| *e == symbol)
+            .ok_or_else(|| format!("No reference state known for {}", symbol))?;
+        let key = format!("{}({})", ref_formula, state);
+        let per_unit = parse_formula(ref_formula)?.count(symbol);
+        let s = data.get(&key).map_or(*s, |d| d.s);
+        Ok((key, per_unit, s))
+    };
+    let (element_key, element_atoms, s_element) = reference(&element)?;
+    let (o2_key, _, s_o2) = reference("O")?;
+
+    // (2x/y) M + O₂ → (2/y) MₓOᵧ
+    let oxide_coeff = 2.0 / oxygen;
+    let element_coeff = oxide_coeff * atoms / element_atoms;
+    let delta_h = oxide_coeff * entry.delta_hf;
+    let delta_s = oxide_coeff * entry.s - element_coeff * s_element - s_o2;
+    let round = |c: f64| (c * 1000.0).round() / 1000.0;
+    let equation = format!(
+        "{} → {}",
+        format_equation_side(&[(element_key, round(element_coeff)), (o2_key, 1.0)]),
+        format_equation_side(&[(oxide.to_string(), round(oxide_coeff))])
+    );
+    Ok((delta_h, delta_s, equation))
+}
+
+/// Generate an Ellingham diagram: ΔG°(T) = ΔH° - T·ΔS° per mol O₂ for oxide formation
+///
+/// ΔH° and ΔS° are taken as constant (phase changes of the metal or oxide are not modelled).
+/// Elements missing from the data are taken in their reference states with ΔHf = 0 and
+/// tabulated standard entropies. Each line reports where it crosses the C/CO line
+/// 2C + O₂ → 2CO, above which carbon can reduce the oxide.
+///
+/// Arguments:
+/// - input_json: {"oxides", "range", "points"}; oxides are formulas such as "Fe2O3(s)" or
+///   {"label", "delta_h", "delta_s"} lines per mol O₂
+/// - data_json: Thermodynamic data
+#[wasm_func]
+pub fn ellingham_diagram(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: EllinghamInput = parse_json(input_json, "Ellingham input")?;
+    let data: HashMap<String, ThermodynamicData> = parse_json(data_json, "thermodynamic data")?;
+
+    let (t_min, t_max) = input.range;
+    if input.points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    check_temperature_of("lowest temperature", t_min)?;
+    check_domain("highest temperature", t_max, t_max > t_min, "above the lowest temperature")?;
+    let temperatures = sample_times(t_max - t_min, input.points);
+    let line = |label: String, equation: Option<String>, delta_h: f64, delta_s: f64| {
+        EllinghamLine {
+            label,
+            equation,
+            delta_h,
+            delta_s,
+            points: temperatures
+                .iter()
+                .map(|offset| {
+                    let t = t_min + offset;
+                    (t, delta_h - t * delta_s / 1000.0)
+                })
+                .collect(),
+            carbon_reduction_temperature: None,
+        }
+    };
+
+    let (h_carbon, s_carbon, carbon_equation) = ellingham_reaction("CO(g)", &data)?;
+    let carbon_line = line("C/CO".to_string(), Some(carbon_equation), h_carbon, s_carbon);
+
+    let mut lines = Vec::new();
+    for spec in input.oxides {
+        let mut oxide_line = match spec {
+            EllinghamSpec::Oxide(oxide) => {
+                let (delta_h, delta_s, equation) = ellingham_reaction(&oxide, &data)?;
+                line(oxide, Some(equation), delta_h, delta_s)
+            }
+            EllinghamSpec::Line { label, delta_h, delta_s } => line(label, None, delta_h, delta_s),
+        };
+        // ΔG°(oxide) = ΔG°(CO) where the lines cross; carbon reduces the oxide above it
+        // when the CO line falls faster
+        let slope_difference = oxide_line.delta_s - s_carbon;
+        if slope_difference.abs() > 1e-9 {
+            let t = (oxide_line.delta_h - h_carbon) / slope_difference * 1000.0;
+            if t > 0.0 && s_carbon > oxide_line.delta_s {
+                oxide_line.carbon_reduction_temperature = Some(t);
+            }
+        }
+        lines.push(oxide_line);
+    }
+
+    let result = EllinghamDiagram {
+        lines,
+        carbon_line,
+        range: input.range,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// PHASE DIAGRAMS
+// ============================================================================
+
+fn default_phase_points() -> usize {
+    50
+}
+
+fn default_atm() -> String {
+    "atm".to_string()
+}
+
+#[derive(Deserialize)]
+struct PhaseDiagramInput {
+    /// Formula or name in the phase-change data, filling in Tm, Tb, ΔHfus and ΔHvap
+    #[serde(default)]
+    substance: Option<String>,
+    /// Melting point at `reference_pressure` (K)
+    #[serde(default)]
+    melting_point: Option<f64>,
+    /// Boiling point at `reference_pressure` (K)
+    #[serde(default)]
+    boiling_point: Option<f64>,
+    /// Pressure of the melting and boiling points (default: 1 atm)
+    #[serde(default)]
+    reference_pressure: Option<f64>,
+    /// (T in K, P) of the triple point, if known
+    #[serde(default)]
+    triple_point: Option<(f64, f64)>,
+    /// (T in K, P) ending the liquid–gas curve
+    #[serde(default)]
+    critical_point: Option<(f64, f64)>,
+    /// Enthalpies of fusion, vaporization and sublimation (kJ/mol); ΔHsub = ΔHfus + ΔHvap
+    /// if not given
+    #[serde(default)]
+    delta_h_fus: Option<f64>,
+    #[serde(default)]
+    delta_h_vap: Option<f64>,
+    #[serde(default)]
+    delta_h_sub: Option<f64>,
+    /// Molar volumes (cm³/mol); without them the solid–liquid line is vertical
+    #[serde(default)]
+    molar_volume_solid: Option<f64>,
+    #[serde(default)]
+    molar_volume_liquid: Option<f64>,
+    /// [min, max] temperature (K)
+    #[serde(default)]
+    range: Option<(f64, f64)>,
+    /// Upper end of the solid–liquid line
+    #[serde(default)]
+    max_pressure: Option<f64>,
+    #[serde(default = "default_phase_points")]
+    points: usize,
+    #[serde(default = "default_atm")]
+    pressure_unit: String,
+}
+
+/// Triple point of a phase diagram
+#[derive(Serialize)]
+struct TriplePoint {
+    temperature: f64,
+    pressure: f64,
+    /// Whether it was estimated from the intersection of the boundary lines
+    estimated: bool,
+}
+
+/// P–T boundary curves of a single-component phase diagram
+#[derive(Serialize)]
+struct PhaseDiagram {
+    triple_point: TriplePoint,
+    /// (T in K, P) points of each boundary
+    solid_liquid: Vec<(f64, f64)>,
+    liquid_gas: Vec<(f64, f64)>,
+    solid_gas: Vec<(f64, f64)>,
+    /// dP/dT of the melting line (pressure unit per K); none if the line is taken as vertical
+    #[serde(skip_serializing_if = "Option::is_none")]
+    melting_slope: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    critical_point: Option<(f64, f64)>,
+    pressure_unit: String,
+}
+
+/// Generate the phase boundaries of a single-component P–T phase diagram
+///
+/// Solid–liquid: Clapeyron, P = Pm + ΔHfus/ΔVfus·ln(T/Tm). Liquid–gas and solid–gas:
+/// Clausius–Clapeyron, ln(P/P₀) = -ΔH/R·(1/T - 1/T₀), anchored at the boiling point and the
+/// triple point. Without a given triple point it is estimated where the melting and
+/// vaporization lines cross (at Tm if the molar volumes are unknown). Enthalpies and volumes
+/// are taken as constant.
+///
+/// Arguments:
+/// - input_json: Substance, transition points, enthalpies, molar volumes and plot options
+/// - data_json: Phase-change data
+#[wasm_func]
+pub fn phase_diagram(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: PhaseDiagramInput = parse_json(input_json, "phase diagram input")?;
+    let data: HashMap<String, PhaseData> = parse_json(data_json, "phase-change data")?;
+
+    let (pa_per_unit, unit) = parse_pressure_unit(&input.pressure_unit)?;
+    let table = match &input.substance {
+        Some(substance) => Some(find_phase_data(substance, &data)?.1),
+        None => None,
+    };
+    let from_table = |value: Option<f64>, field: fn(&PhaseData) -> Option<f64>| {
+        value.or_else(|| table.as_ref().and_then(field))
+    };
+    let melting = from_table(input.melting_point, |d| d.tm);
+    let boiling = from_table(input.boiling_point, |d| d.tb);
+    let h_fus = from_table(input.delta_h_fus, |d| d.delta_hfus)
+        .ok_or("Enthalpy of fusion is required")?
+        * 1000.0;
+    let h_vap = from_table(input.delta_h_vap, |d| d.delta_hvap)
+        .ok_or("Enthalpy of vaporization is required")?
+        * 1000.0;
+    let h_sub = input.delta_h_sub.map_or(h_fus + h_vap, |h| h * 1000.0);
+    check_positive("ΔHfus", h_fus / 1000.0)?;
+    check_positive("ΔHvap", h_vap / 1000.0)?;
+    check_positive("ΔHsub", h_sub / 1000.0)?;
+    if let Some(t) = melting {
+        check_temperature_of("melting point", t)?;
+    }
+    if let Some(t) = boiling {
+        check_temperature_of("boiling point", t)?;
+    }
+    if let Some(p) = input.reference_pressure {
+        check_positive("reference pressure", p)?;
+    }
+    if let Some((t, p)) = input.triple_point {
+        check_temperature_of("triple-point temperature", t)?;
+        check_positive("triple-point pressure", p)?;
+    }
+    let p_ref = input.reference_pressure.map_or(101_325.0, |p| p * pa_per_unit);
+    let delta_v = match (input.molar_volume_solid, input.molar_volume_liquid) {
+        (Some(solid), Some(liquid)) => {
+            check_positive("molar volume of the solid", solid)?;
+            check_positive("molar volume of the liquid", liquid)?;
+            Some((liquid - solid) * 1e-6).filter(|dv| *dv != 0.0)
+        }
+        (None, None) => None,
+        _ => return Err("Both molar volumes must be given".to_string()),
+    };
+    let triple_given = input.triple_point.map(|(t, p)| (t, p * pa_per_unit));
+
+    // Anchors of the melting and vaporization lines
+    let melt_anchor = melting.map(|t| (t, p_ref)).or(triple_given);
+    let boil_anchor = boiling.map(|t| (t, p_ref)).or(triple_given);
+    let (Some((t_m, p_m)), Some((t_b, p_b))) = (melt_anchor, boil_anchor) else {
+        return Err("Give the melting and boiling points, or the triple point".to_string());
+    };
+    let p_vap = |t: f64| p_b * (-h_vap / R * (1.0 / t - 1.0 / t_b)).exp();
+    let p_melt = |t: f64, dv: f64| p_m + h_fus / dv * (t / t_m).ln();
+
+    let (t_tp, p_tp) = match (triple_given, delta_v) {
+        (Some(point), _) => point,
+        (None, None) => (t_m, p_vap(t_m)),
+        (None, Some(dv)) => {
+            if p_vap(t_m) >= p_m {
+                return Err("The melting point must lie below the boiling curve".to_string());
+            }
+            // The melting line reaches P = 0 at t_zero; the crossing lies between it and Tm
+            let t_zero = t_m * (-p_m * dv / h_fus).exp();
+            let (mut low, mut high) = (t_m.min(t_zero), t_m.max(t_zero));
+            let g = |t: f64| p_melt(t, dv) - p_vap(t);
+            let low_sign = g(low).signum();
+            for _ in 0..200 {
+                let mid = 0.5 * (low + high);
+                if g(mid).signum() == low_sign {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            let t = 0.5 * (low + high);
+            (t, p_vap(t))
+        }
+    };
+
+    let critical = input.critical_point.map(|(t, p)| (t, p * pa_per_unit));
+    let (t_min, t_max) = input
+        .range
+        .unwrap_or((0.8 * t_tp, critical.map_or(1.25 * t_b, |(t, _)| t)));
+    if input.points < 2 {
+        return Err("At least 2 points are required".to_string());
+    }
+    check_temperature_of("lowest temperature", t_min)?;
+    check_domain("highest temperature", t_max, t_max > t_min, "above the lowest temperature")?;
+    let sample = |from: f64, to: f64| -> Vec<f64> {
+        sample_times(to - from, input.points).into_iter().map(|x| from + x).collect()
+    };
+    let to_unit = |(t, p): (f64, f64)| (t, p / pa_per_unit);
+
+    let p_sub = |t: f64| p_tp * (-h_sub / R * (1.0 / t - 1.0 / t_tp)).exp();
+    let solid_gas = if t_min < t_tp {
+        sample(t_min, t_tp).into_iter().map(|t| to_unit((t, p_sub(t)))).collect()
+    } else {
+        Vec::new()
+    };
+    let t_end = critical.map_or(t_max, |(t, _)| t.min(t_max));
+    let liquid_gas: Vec<(f64, f64)> =
+        sample(t_tp, t_end).into_iter().map(|t| to_unit((t, p_vap(t)))).collect();
+    let p_max = input.max_pressure.map_or_else(
+        || critical.map_or(liquid_gas.last().map_or(p_ref, |(_, p)| p * pa_per_unit), |c| c.1),
+        |p| p * pa_per_unit,
+    );
+    let solid_liquid = if p_max > p_tp {
+        sample(p_tp, p_max)
+            .into_iter()
+            .map(|p| {
+                let t = match delta_v {
+                    Some(dv) => t_tp * ((p - p_tp) * dv / h_fus).exp(),
+                    None => t_tp,
+                };
+                to_unit((t, p))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let result = PhaseDiagram {
+        triple_point: TriplePoint {
+            temperature: t_tp,
+            pressure: p_tp / pa_per_unit,
+            estimated: triple_given.is_none(),
+        },
+        solid_liquid,
+        liquid_gas,
+        solid_gas,
+        melting_slope: delta_v.map(|dv| h_fus / (t_tp * dv) / pa_per_unit),
+        critical_point: critical.map(to_unit),
+        pressure_unit: unit.to_string(),
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// HEATING CURVES
+// ============================================================================
+
+#[derive(Deserialize)]
+struct HeatingCurveInput {
+    /// Formula or name in the phase-change data
+    #[serde(default)]
+    substance: Option<String>,
+    /// Mass (g)
+    mass: f64,
+    /// Initial and final temperatures (K)
+    start: f64,
+    end: f64,
+    /// Molar mass (g/mol); from the formula if not given
+    #[serde(default)]
+    molar_mass: Option<f64>,
+    /// Overrides of the table values (K, kJ/mol, J/(mol·K))
+    #[serde(default)]
+    melting_point: Option<f64>,
+    #[serde(default)]
+    boiling_point: Option<f64>,
+    #[serde(default)]
+    delta_h_fus: Option<f64>,
+    #[serde(default)]
+    delta_h_vap: Option<f64>,
+    #[serde(default)]
+    cp_solid: Option<f64>,
+    #[serde(default)]
+    cp_liquid: Option<f64>,
+    #[serde(default)]
+    cp_gas: Option<f64>,
+}
+
+/// One step of a heating or cooling path
+#[derive(Serialize)]
+struct HeatingStep {
+    /// e.g. "heating solid", "melting", "cooling gas", "condensation"
+    process: String,
+    /// Temperatures at the start and end of the step (K)
+    from: f64,
+    to: f64,
+    /// Heat absorbed by the sample, negative when released (kJ)
+    heat: f64,
+}
+
+/// Heat and curve of a heating or cooling path across phase changes
+#[derive(Serialize)]
+struct HeatingCurve {
+    total_heat: CalculationResult,
+    amount: CalculationResult,
+    steps: Vec<HeatingStep>,
+    /// Cumulative heat (kJ) and temperature (K) at the start and end of every step
+    points: Vec<(f64, f64)>,
+}
+
+/// Calculate the heat needed to take a sample from T₁ to T₂ across phase changes
+///
+/// Every phase is heated with q = n·Cp,m·ΔT and every transition crossed adds n·ΔHtrs
+/// (with opposite signs when cooling). A transition is crossed when the path passes through
+/// its temperature: heating from Tm includes melting, heating up to Tm does not.
+///
+/// Arguments:
+/// - input_json: Substance, mass, temperatures and overrides of the table values
+/// - data_json: Phase-change data
+#[wasm_func]
+pub fn heating_curve(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: HeatingCurveInput = parse_json(input_json, "heating curve input")?;
+    let data: HashMap<String, PhaseData> = parse_json(data_json, "phase-change data")?;
+
+    let table = match &input.substance {
+        Some(substance) => Some(find_phase_data(substance, &data)?),
+        None => None,
+    };
+    let from_table = |value: Option<f64>, field: fn(&PhaseData) -> Option<f64>| {
+        value.or_else(|| table.as_ref().and_then(|(_, entry)| field(entry)))
+    };
+    let molar_mass = match (input.molar_mass, &table) {
+        (Some(m), _) => m,
+        (None, Some((formula, _))) => parse_formula(formula)?.molar_mass()?,
+        (None, None) => return Err("Give a substance or its molar mass".to_string()),
+    };
+    check_positive("mass", input.mass)?;
+    check_positive("molar mass", molar_mass)?;
+    check_temperature_of("start temperature", input.start)?;
+    check_temperature_of("end temperature", input.end)?;
+    let n = input.mass / molar_mass;
+
+    let melting = from_table(input.melting_point, |d| d.tm);
+    let boiling = from_table(input.boiling_point, |d| d.tb);
+    // (lower temperature, upper temperature, Cp,m, phase) of each phase
+    let phases = [
+        (0.0, melting.unwrap_or(0.0), from_table(input.cp_solid, |d| d.cp_solid), "solid"),
+        (
+            melting.unwrap_or(0.0),
+            boiling.unwrap_or(f64::INFINITY),
+            from_table(input.cp_liquid, |d| d.cp_liquid),
+            "liquid",
+        ),
+        (
+            boiling.unwrap_or(f64::INFINITY),
+            f64::INFINITY,
+            from_table(input.cp_gas, |d| d.cp_gas),
+            "gas",
+        ),
+    ];
+    // (temperature, ΔH in kJ/mol, forward and reverse process) of each transition
+    let transitions = [
+        (melting, from_table(input.delta_h_fus, |d| d.delta_hfus), "melting", "freezing"),
+        (boiling, from_table(input.delta_h_vap, |d| d.delta_hvap), "vaporization", "condensation"),
+    ];
+
+    let heating = input.end >= input.start;
+    let (low, high) = (input.start.min(input.end), input.start.max(input.end));
+    let mut steps = Vec::new();
+    for (i, &(phase_low, phase_high, cp, phase)) in phases.iter().enumerate() {
+        let (from, to) = (low.max(phase_low), high.min(phase_high));
+        if to > from {
+            let cp = cp.ok_or_else(|| format!("Heat capacity of the {} is required", phase))?;
+            let process = if heating { "heating" } else { "cooling" };
+            steps.push((
+                from,
+                to,
+                format!("{} {}", process, phase),
+                n * cp * (to - from) / 1000.0,
+            ));
+        }
+        // Crossing into the next phase: low <= T < high when heating, low < T <= high when
+        // cooling
+        if let Some(&(Some(t), delta_h, forward, reverse)) = transitions.get(i) {
+            let crossed = if heating { low <= t && t < high } else { low < t && t <= high };
+            if crossed {
+                let delta_h = delta_h
+                    .ok_or_else(|| format!("Enthalpy of {} is required", forward))?;
+                let process = if heating { forward } else { reverse };
+                steps.push((t, t, process.to_string(), n * delta_h));
+            }
+        }
+    }
+    if !heating {
+        steps.reverse();
+    }
+
+    let sign = if heating { 1.0 } else { -1.0 };
+    let mut total = 0.0;
+    let mut points = vec![(0.0, input.start)];
+    let steps: Vec<HeatingStep> = steps
+        .into_iter()
+        .map(|(from, to, process, heat)| {
+            let (from, to) = if heating { (from, to) } else { (to, from) };
+            total += sign * heat;
+            points.push((total, to));
+            HeatingStep { process, from, to, heat: sign * heat }
+        })
+        .collect();
+
+    let result = HeatingCurve {
+        total_heat: CalculationResult::new(total, "kJ"),
+        amount: CalculationResult::new(n, "mol"),
+        steps,
+        points,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ENTHALPY OF SOLUTION
+// ============================================================================
+
+/// Hydration enthalpy table entry
+#[derive(Deserialize, Clone)]
+struct HydrationData {
+    #[serde(default)]
+    name: String,
+    /// Standard enthalpy of M^z+(g) → M^z+(aq) (kJ/mol)
+    #[serde(rename = "delta_Hhyd")]
+    delta_hhyd: f64,
+}
+
+/// Hydration enthalpy of an ion
+#[derive(Serialize)]
+struct HydrationEnthalpy {
+    ion: String,
+    name: String,
+    charge: f64,
+    value: f64,
+    unit: String,
+}
+
+/// Look up the standard hydration enthalpy of a gaseous ion
+///
+/// Arguments:
+/// - ion: Ion, e.g. "Na+", "Mg2+" or "SO42-"
+/// - data_json: Hydration enthalpy table
+#[wasm_func]
+pub fn get_hydration_enthalpy(ion_bytes: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let ion: String = parse_arg(ion_bytes, "ion")?;
+    let data: HashMap<String, HydrationData> = parse_json(data_json, "hydration enthalpy data")?;
+
+    let entry = lookup_species(&data, &ion, "hydration enthalpy")?;
+    let result = HydrationEnthalpy {
+        charge: parse_formula(&ion).map_or(0.0, |formula| formula.charge),
+        ion: ion.trim().to_string(),
+        name: entry.name.clone(),
+        value: entry.delta_hhyd,
+        unit: "kJ/mol".to_string(),
+    };
+
+    to_json(&result)
+}
+
+#[derive(Deserialize)]
+struct SolutionCycleInput {
+    /// (ion, count) per formula unit, e.g. [["Mg2+", 1], ["Cl-", 2]]
+    #[serde(default)]
+    ions: Vec<(String, f64)>,
+    /// ΔHsoln (kJ/mol)
+    #[serde(default)]
+    solution: Option<f64>,
+    /// Lattice dissociation enthalpy, MX(s) → M⁺(g) + X⁻(g) (kJ/mol, positive)
+    #[serde(default)]
+    lattice: Option<f64>,
+    /// ΣΔHhyd (kJ/mol); summed from the table over `ions` if not given
+    #[serde(default)]
+    hydration: Option<f64>,
+}
+
+/// Hydration contribution of one ion
+#[derive(Serialize)]
+struct IonHydration {
+    ion: String,
+    count: f64,
+    /// ΔHhyd of the ion (kJ/mol)
+    delta_h_hyd: f64,
+    /// count · ΔHhyd (kJ/mol)
+    contribution: f64,
+    /// Whether the value was solved for rather than taken from the table
+    solved: bool,
+}
+
+/// Terms of the dissolution cycle ΔHsoln = ΔHlatt + ΣΔHhyd
+#[derive(Serialize)]
+struct SolutionCycle {
+    solution: f64,
+    lattice: f64,
+    hydration: f64,
+    unit: String,
+    /// The term that was calculated: "solution", "lattice", "hydration" or "ΔHhyd(ion)"
+    solved: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ions: Vec<IonHydration>,
+}
+
+/// Solve the dissolution cycle ΔHsoln = ΔHlatt + ΣΔHhyd for its missing term
+///
+/// ΔHlatt is the lattice dissociation enthalpy (positive). Hydration enthalpies of the ions
+/// are taken from the table unless ΣΔHhyd is given; if ΔHsoln and ΔHlatt are known and a
+/// single ion is missing from the table, its hydration enthalpy is solved for instead.
+///
+/// Arguments:
+/// - input_json: Ions and the known terms
+/// - data_json: Hydration enthalpy table
+#[wasm_func]
+pub fn enthalpy_of_solution(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: SolutionCycleInput = parse_json(input_json, "enthalpy of solution input")?;
+    let data: HashMap<String, HydrationData> = parse_json(data_json, "hydration enthalpy data")?;
+
+    if let Some(lattice) = input.lattice {
+        check_domain(
+            "lattice enthalpy",
+            lattice,
+            lattice > 0.0,
+            "positive (the lattice dissociation enthalpy)",
+        )?;
+    }
+    let mut charge = 0.0;
+    for (ion, count) in &input.ions {
+        check_positive(&format!("count of {}", ion), *count)?;
+        charge += count * parse_formula(ion)?.charge;
+    }
+    if charge.abs() > 1e-6 {
+        return Err(format!("Ions are not charge balanced: total charge {:+}", charge));
+    }
+
+    let looked_up: Vec<Result<f64, String>> = input
+        .ions
+        .iter()
+        .map(|(ion, _)| lookup_species(&data, ion, "hydration enthalpy").map(|e| e.delta_hhyd))
+        .collect();
+    let missing: Vec<usize> = (0..looked_up.len()).filter(|&i| looked_up[i].is_err()).collect();
+    let table_sum = if input.ions.is_empty() || !missing.is_empty() {
+        None
+    } else {
+        Some(input.ions.iter().zip(&looked_up).map(|((_, n), h)| n * h.clone().unwrap()).sum())
+    };
+    let hydration = input.hydration.or(table_sum);
+
+    let mut solved_ion = None;
+    let (solution, lattice, hydration, solved) = match (input.solution, input.lattice, hydration) {
+        (None, Some(l), Some(h)) => (l + h, l, h, "solution".to_string()),
+        (Some(s), None, Some(h)) => {
+            if s - h <= 0.0 {
+                return Err("ΔHsoln - ΣΔHhyd gives a non-positive lattice enthalpy".to_string());
+            }
+            (s, s - h, h, "lattice".to_string())
+        }
+        (Some(s), Some(l), None) if input.ions.is_empty() => (s, l, s - l, "hydration".to_string()),
+        (Some(s), Some(l), None) if missing.len() == 1 => {
+            let i = missing[0];
+            let known: f64 = input
+                .ions
+                .iter()
+                .zip(&looked_up)
+                .filter_map(|((_, n), h)| h.as_ref().ok().map(|h| n * h))
+                .sum();
+            let (ion, count) = &input.ions[i];
+            solved_ion = Some((i, (s - l - known) / count));
+            (s, l, s - l, format!("ΔHhyd({})", ion))
+        }
+        (Some(_), Some(_), Some(_)) => {
+            return Err("Leave one of ΔHsoln, ΔHlatt and ΣΔHhyd unknown to solve for it".to_string())
+        }
+        _ if missing.len() > 1 => {
+            let names: Vec<&str> = missing.iter().map(|&i| input.ions[i].0.as_str()).collect();
+            return Err(format!("No hydration enthalpies found for: {}", names.join(", ")));
+        }
+        _ if !missing.is_empty() => return Err(looked_up[missing[0]].clone().unwrap_err()),
+        _ => return Err("Two of ΔHsoln, ΔHlatt and ΣΔHhyd (or the ions) are required".to_string()),
+    };
+
+    let ions = input
+        .ions
+        .iter()
+        .zip(looked_up)
+        .enumerate()
+        .filter_map(|(i, ((ion, count), value))| {
+            let (delta_h_hyd, solved) = match (solved_ion, value) {
+                (Some((j, value)), _) if i == j => (value, true),
+                (_, Ok(value)) => (value, false),
+                (_, Err(_)) => return None,
+            };
+            Some(IonHydration {
+                ion: ion.clone(),
+                count: *count,
+                delta_h_hyd,
+                contribution: count * delta_h_hyd,
+                solved,
+            })
+        })
+        .collect();
+
+    let result = SolutionCycle {
+        solution,
+        lattice,
+        hydration,
+        unit: "kJ/mol".to_string(),
+        solved,
+        ions,
+    };
+
+    to_json(&result)
+}
+
+// ============================================================================
+// ANTOINE VAPOR PRESSURES
+// ============================================================================
+
+/// Antoine coefficients, log10(P) = A - B/(C + T)
+#[derive(Deserialize, Clone)]
+struct AntoineData {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "A")]
+    a: f64,
+    #[serde(rename = "B")]
+    b: f64,
+    #[serde(rename = "C")]
+    c: f64,
+    /// Pressure unit of the coefficients (default: bar, as in the NIST WebBook)
+    #[serde(rename = "P_unit", default)]
+    pressure_unit: String,
+    /// Temperature unit of the coefficients (default: K)
+    #[serde(rename = "T_unit", default)]
+    temperature_unit: String,
+    /// Temperatures (K) over which the coefficients were fitted
+    #[serde(rename = "T_range", default)]
+    t_range: Option<[f64; 2]>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+/// Substance in the Antoine table, or its coefficients
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AntoineSubstance {
+    Name(String),
+    Coefficients(AntoineData),
+}
+
+#[derive(Deserialize)]
+struct AntoineInput {
+    substance: AntoineSubstance,
+    /// Temperature (K), to calculate the vapor pressure
+    #[serde(default)]
+    temperature: Option<f64>,
+    /// Pressure, to calculate the boiling temperature
+    #[serde(default)]
+    pressure: Option<f64>,
+    #[serde(default)]
+    pressure_unit: String,
+}
+
+/// Antoine coefficients and the units they apply to
+#[derive(Serialize)]
+struct AntoineCoefficients {
+    a: f64,
+    b: f64,
+    c: f64,
+    pressure_unit: String,
+    temperature_unit: String,
+}
+
+/// Point on the vapor-pressure curve of a substance
+#[derive(Serialize)]
+struct VaporPressure {
+    substance: String,
+    name: String,
+    /// Temperature (K)
+    temperature: f64,
+    /// Vapor pressure at `temperature`
+    pressure: f64,
+    pressure_unit: String,
+    /// The quantity that was calculated: "pressure" or "temperature"
+    solved: String,
+    coefficients: AntoineCoefficients,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<DataWarning>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<String>,
+}
+
+/// Calculate a vapor pressure, or the boiling temperature at a pressure, with the Antoine
+/// equation log10(P) = A - B/(C + T)
+///
+/// Coefficients are used in their own pressure and temperature units (`P_unit`, `T_unit`);
+/// results outside their `T_range` carry a warning.
+///
+/// Arguments:
+/// - input_json: Substance (table formula, name or coefficients) and temperature or pressure
+/// - data_json: Antoine coefficient table
+#[wasm_func]
+pub fn antoine_vapor_pressure(input_json: &[u8], data_json: &[u8]) -> Result<Vec<u8>, String> {
+    let input: AntoineInput = parse_json(input_json, "vapor pressure input")?;
+    let data: HashMap<String, AntoineData> = parse_json(data_json, "Antoine coefficients")?;
+
+    let (substance, entry) = match input.substance {
+        AntoineSubstance::Name(query) => {
+            let lowercase = query.trim().to_lowercase();
+            match data.iter().find(|(_, entry)| entry.name.to_lowercase() == lowercase) {
+                Some((key, entry)) => (key.clone(), entry.clone()),
+                None => {
+                    let entry = lookup_species(&data, &query, "Antoine coefficients")?;
+                    (query.trim().to_string(), entry.clone())
+                }
+            }
+        }
+        AntoineSubstance::Coefficients(entry) => (entry.name.clone(), entry),
+    };
+
+    let scale = TemperatureScale::parse(&entry.temperature_unit)?;
+    let (coefficient_pa, coefficient_unit) = parse_pressure_unit(&entry.pressure_unit)?;
+    let (unit_pa, unit_symbol) = parse_pressure_unit(&input.pressure_unit)?;
+
+    let (temperature, pressure, solved) = match (input.temperature, input.pressure) {
+        (Some(temperature), None) => {
+            check_temperature(temperature)?;
+            let denominator = entry.c + scale.kelvin_to_scale(temperature);
+            if denominator <= 0.0 {
+                return Err(format!(
+                    "{} K lies below the pole of the Antoine equation (T = -C)",
+                    temperature
+                ));
+            }
+            let log_p = entry.a - entry.b / denominator;
+            (temperature, 10f64.powf(log_p) * coefficient_pa / unit_pa, "pressure")
+        }
+        (None, Some(pressure)) => {
+            check_positive("pressure", pressure)?;
+            let gap = entry.a - (pressure * unit_pa / coefficient_pa).log10();
+            if gap <= 0.0 {
+                return Err(format!(
+                    "No boiling temperature: {} {} is beyond the range of the Antoine equation",
+                    pressure, unit_symbol
+                ));
+            }
+            let temperature = scale.to_kelvin(entry.b / gap - entry.c);
+            (temperature, pressure, "temperature")
+        }
+        (Some(_), Some(_)) => {
+            return Err("Give either the temperature or the pressure, not both".to_string())
+        }
+        (None, None) => return Err("The temperature or the pressure is required".to_string()),
+    };
+
+    let label = if substance.is_empty() { "the substance" } else { substance.as_str() };
+    let result = VaporPressure {
+        warnings: DataWarning::temperature(label, entry.t_range, temperature)
+            .into_iter()
+            .collect(),
+        sources: entry.source.iter().cloned().collect(),
+        name: entry.name,
+        substance,
+        temperature,
+        pressure,
+        pressure_unit: unit_symbol.to_string(),
+        solved: solved.to_string(),
+        coefficients: AntoineCoefficients {
+            a: entry.a,
+            b: entry.b,
+            c: entry.c,
+            pressure_unit: coefficient_unit.to_string(),
+            temperature_unit: scale.symbol().to_string(),
+        },
+    };
+
+    to_json(&result)
 }